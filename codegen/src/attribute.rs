@@ -46,6 +46,10 @@ struct Step {
     /// Argument of the attribute.
     attr_arg: AttributeArgument,
 
+    /// Indicator whether this [`Step`] is a soft one (`soft = true`
+    /// argument).
+    soft: bool,
+
     /// Function the attribute is applied to.
     func: syn::ItemFn,
 
@@ -63,7 +67,10 @@ impl Step {
         attr: TokenStream,
         body: TokenStream,
     ) -> syn::Result<Self> {
-        let attr_arg = syn::parse2::<AttributeArgument>(attr)?;
+        let AttributeArguments {
+            arg: attr_arg,
+            soft,
+        } = syn::parse2::<AttributeArguments>(attr)?;
         let mut func = syn::parse2::<syn::ItemFn>(body)?;
 
         let step_arg_name = {
@@ -96,6 +103,7 @@ impl Step {
         Ok(Self {
             attr_name,
             attr_arg,
+            soft,
             func,
             step_arg_name,
         })
@@ -112,6 +120,7 @@ impl Step {
             self.fn_arguments_and_additional_parsing()?;
 
         let regex = self.gen_regex()?;
+        let soft = self.soft;
 
         let caller_name =
             format_ident!("__cucumber_{}_{}", self.attr_name, func_name);
@@ -177,6 +186,7 @@ impl Step {
                         const F: ::cucumber::Step<#world> = #step_caller;
                         F
                     },
+                    soft: #soft,
                 }
             });
         })
@@ -260,7 +270,7 @@ impl Step {
             }
         } else if self.step_arg_name.is_some() {
             Ok((
-                quote! { ::std::borrow::Borrow::borrow(&__cucumber_ctx.step), },
+                quote! { ::std::borrow::Borrow::borrow(&__cucumber_ctx), },
                 None,
             ))
         } else {
@@ -293,7 +303,7 @@ impl Step {
         let decl = if is_ctx_arg {
             quote! {
                 let #ident =
-                    ::std::borrow::Borrow::borrow(&__cucumber_ctx.step);
+                    ::std::borrow::Borrow::borrow(&__cucumber_ctx);
             }
         } else {
             let ty = if let syn::Type::Path(p) = ty {
@@ -339,7 +349,7 @@ impl Step {
             let (ident, _) = parse_fn_arg(arg)?;
             if name == ident {
                 return Ok(quote! {
-                    ::std::borrow::Borrow::borrow(&__cucumber_ctx.step),
+                    ::std::borrow::Borrow::borrow(&__cucumber_ctx),
                 });
             }
         }
@@ -370,9 +380,14 @@ impl Step {
                 Ok(quote! { ::cucumber::codegen::Regex::new(#lit).unwrap() })
             }
             AttributeArgument::Regex(re) => {
-                drop(Regex::new(re.value().as_str()).map_err(|e| {
+                let regex = Regex::new(re.value().as_str()).map_err(|e| {
                     syn::Error::new(re.span(), format!("Invalid regex: {}", e))
-                })?);
+                })?;
+                check_regex_captures(
+                    &regex,
+                    &self.func,
+                    self.step_arg_name.as_ref(),
+                )?;
 
                 Ok(quote! { ::cucumber::codegen::Regex::new(#re).unwrap() })
             }
@@ -474,7 +489,8 @@ impl<'p> Parameters<'p> {
             })
             .collect::<syn::Result<Vec<_>>>()?;
 
-        expr.0
+        let params = expr
+            .0
             .into_iter()
             .filter_map(|e| match e {
                 SingleExpression::Parameter(par) => Some(par),
@@ -483,6 +499,27 @@ impl<'p> Parameters<'p> {
                 | SingleExpression::Text(_)
                 | SingleExpression::Whitespaces(_) => None,
             })
+            .collect::<Vec<_>>();
+
+        // Arguments, having no corresponding parameters, would never be found
+        // in the matched `Step`, unless all the captures are parsed as a slice.
+        if param_tys.len() > params.len()
+            && find_first_slice(&func.sig).is_none()
+        {
+            return Err(syn::Error::new(
+                func.sig.inputs.span(),
+                format!(
+                    "Function has {} argument(s) to be parsed from the \
+                     matched step, but the cucumber expression has only {} \
+                     parameter(s)",
+                    param_tys.len(),
+                    params.len(),
+                ),
+            ));
+        }
+
+        params
+            .into_iter()
             .zip(param_tys.into_iter().map(Some).chain(iter::repeat(None)))
             .filter_map(|(ast, param_ty)| {
                 if DEFAULT_PARAMETERS.iter().any(|s| s == &**ast) {
@@ -643,6 +680,41 @@ impl<'p> Parameters<'p> {
     }
 }
 
+/// Arguments of the attribute macro.
+#[derive(Clone, Debug)]
+struct AttributeArguments {
+    /// Argument specifying how the step is matched.
+    arg: AttributeArgument,
+
+    /// Value of the optional `soft = bool` argument.
+    soft: bool,
+}
+
+impl Parse for AttributeArguments {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let arg = input.parse::<AttributeArgument>()?;
+
+        let mut soft = false;
+        if input.parse::<Option<syn::Token![,]>>()?.is_some()
+            && !input.is_empty()
+        {
+            let meta = input.parse::<syn::MetaNameValue>()?;
+            match (meta.path.get_ident(), &meta.lit) {
+                (Some(i), syn::Lit::Bool(b)) if i == "soft" => soft = b.value,
+                _ => {
+                    return Err(syn::Error::new(
+                        meta.span(),
+                        "Expected `soft = bool` argument",
+                    ))
+                }
+            }
+            let _ = input.parse::<Option<syn::Token![,]>>()?;
+        }
+
+        Ok(Self { arg, soft })
+    }
+}
+
 /// Argument of the attribute macro.
 #[derive(Clone, Debug)]
 enum AttributeArgument {
@@ -786,6 +858,47 @@ fn parse_fn_arg(arg: &syn::FnArg) -> syn::Result<(&syn::Ident, &syn::Type)> {
     Ok((ident, arg.ty.as_ref()))
 }
 
+/// Checks whether the given [`Regex`] has enough capturing groups for all the
+/// `func`'s arguments to be parsed from.
+///
+/// # Errors
+///
+/// If the `func` has more arguments to be parsed from the matched [`Step`],
+/// than the [`Regex`] has capturing groups, unless all the captures are parsed
+/// as a slice.
+fn check_regex_captures(
+    regex: &Regex,
+    func: &syn::ItemFn,
+    step: Option<&syn::Ident>,
+) -> syn::Result<()> {
+    if find_first_slice(&func.sig).is_some() {
+        return Ok(());
+    }
+
+    let captures = regex.captures_len() - 1;
+    let args = func
+        .sig
+        .inputs
+        .iter()
+        .skip(1)
+        .filter(|arg| {
+            parse_fn_arg(arg).map_or(true, |(ident, _)| step != Some(ident))
+        })
+        .count();
+
+    if args > captures {
+        return Err(syn::Error::new(
+            func.sig.inputs.span(),
+            format!(
+                "Function has {} argument(s) to be parsed from the matched \
+                 step, but the regex has only {} capturing group(s)",
+                args, captures,
+            ),
+        ));
+    }
+    Ok(())
+}
+
 /// Parses type of a first slice element of the given function signature.
 fn find_first_slice(sig: &syn::Signature) -> Option<&syn::TypePath> {
     sig.inputs.iter().find_map(|arg| {
@@ -863,3 +976,79 @@ fn to_string_literal(l: syn::Lit) -> syn::Result<syn::LitStr> {
         Err(syn::Error::new(l.span(), "Expected string literal"))
     }
 }
+
+#[cfg(test)]
+mod spec {
+    use regex::Regex;
+    use syn::parse_quote;
+
+    use super::{check_regex_captures, Parameters};
+
+    #[test]
+    fn errors_on_arguments_without_parameters() {
+        let func = parse_quote! {
+            fn step(w: &mut World, count: usize, name: String) {}
+        };
+
+        let err = Parameters::new("I have {int} cukes", &func, None)
+            .err()
+            .expect("error is expected");
+
+        assert_eq!(
+            err.to_string(),
+            "Function has 2 argument(s) to be parsed from the matched step, \
+             but the cucumber expression has only 1 parameter(s)",
+        );
+    }
+
+    #[test]
+    fn allows_step_argument_and_slices() {
+        let with_step = parse_quote! {
+            fn step(w: &mut World, count: usize, #[step] s: &Step) {}
+        };
+        let step = parse_quote! { s };
+        assert!(
+            Parameters::new("I have {int} cukes", &with_step, Some(&step))
+                .is_ok()
+        );
+
+        let with_slice = parse_quote! {
+            fn step(w: &mut World, matches: &[String]) {}
+        };
+        assert!(Parameters::new("I have cukes", &with_slice, None).is_ok());
+    }
+
+    #[test]
+    fn errors_on_arguments_without_regex_captures() {
+        let func = parse_quote! {
+            fn step(w: &mut World, count: usize, name: String) {}
+        };
+        let regex = Regex::new(r"^I have (\d+) cukes$").unwrap();
+
+        let err = check_regex_captures(&regex, &func, None)
+            .expect_err("error is expected");
+
+        assert_eq!(
+            err.to_string(),
+            "Function has 2 argument(s) to be parsed from the matched step, \
+             but the regex has only 1 capturing group(s)",
+        );
+    }
+
+    #[test]
+    fn allows_step_argument_and_slices_with_regex() {
+        let regex = Regex::new(r"^I have (\d+) cukes$").unwrap();
+
+        let with_step = parse_quote! {
+            fn step(w: &mut World, count: usize, #[step] s: &Step) {}
+        };
+        let step = parse_quote! { s };
+        assert!(check_regex_captures(&regex, &with_step, Some(&step)).is_ok());
+
+        let with_slice = parse_quote! {
+            fn step(w: &mut World, matches: &[String]) {}
+        };
+        let no_captures = Regex::new(r"^I have \d+ cukes$").unwrap();
+        assert!(check_regex_captures(&no_captures, &with_slice, None).is_ok());
+    }
+}
@@ -162,6 +162,12 @@ macro_rules! step_attribute {
         ///   Matches the step with an **exact** literal only. Doesn't allow any
         ///   values capturing to use as function arguments.
         ///
+        /// - `#[given(regex = "regex", soft = true)]`
+        ///
+        ///   Marks the step as a soft (informational) one, meaning that its
+        ///   panics are reported, but don't fail the [`Scenario`]. May be
+        ///   combined with any of the arguments above.
+        ///
         /// # Function arguments
         ///
         /// - First argument has to be mutable reference to the [`WorldInit`]
@@ -169,7 +175,9 @@ macro_rules! step_attribute {
         /// - Other argument's types have to implement [`FromStr`] or it has to
         ///   be a slice where the element type also implements [`FromStr`].
         /// - To use [`gherkin::Step`], name the argument as `step`,
-        ///   **or** mark the argument with a `#[step]` attribute.
+        ///   **or** mark the argument with a `#[step]` attribute. The same way
+        ///   the whole `cucumber::step::Context` may be used, providing access
+        ///   to [`Scenario`]-scoped variables.
         ///
         /// ```rust
         /// # use std::convert::Infallible;
@@ -216,6 +224,7 @@ macro_rules! step_attribute {
         /// [`FromStr`]: std::str::FromStr
         /// [`Regex`]: regex::Regex
         /// [`gherkin::Step`]: https://bit.ly/3j42hcd
+        /// [`Scenario`]: https://cucumber.io/docs/gherkin/reference#scenario
         /// [`World`]: https://bit.ly/3j0aWw7
         /// [1]: cucumber_expressions
         #[proc_macro_attribute]
@@ -75,6 +75,9 @@ fn generate_step_structs(
 
                     #[doc(hidden)]
                     #world_vis func: ::cucumber::Step<#world>,
+
+                    #[doc(hidden)]
+                    #world_vis soft: bool,
                 }
 
                 #[automatically_derived]
@@ -86,6 +89,10 @@ fn generate_step_structs(
                     ) {
                         (self.loc, self.regex, self.func)
                     }
+
+                    fn is_soft(&self) -> bool {
+                        self.soft
+                    }
                 }
 
                 #[automatically_derived]
@@ -100,6 +107,7 @@ mod spec {
     use quote::quote;
     use syn::parse_quote;
 
+    #[allow(clippy::too_many_lines)]
     #[test]
     fn expands() {
         let input = parse_quote! {
@@ -124,6 +132,9 @@ mod spec {
 
                  #[doc(hidden)]
                  pub func: ::cucumber::Step<World>,
+
+                    #[doc(hidden)]
+                    pub soft: bool,
             }
 
             #[automatically_derived]
@@ -137,6 +148,10 @@ mod spec {
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn is_soft(&self) -> bool {
+                    self.soft
+                }
             }
 
             #[automatically_derived]
@@ -153,6 +168,9 @@ mod spec {
 
                  #[doc(hidden)]
                  pub func: ::cucumber::Step<World>,
+
+                    #[doc(hidden)]
+                    pub soft: bool,
             }
 
             #[automatically_derived]
@@ -166,6 +184,10 @@ mod spec {
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn is_soft(&self) -> bool {
+                    self.soft
+                }
             }
 
             #[automatically_derived]
@@ -182,6 +204,9 @@ mod spec {
 
                  #[doc(hidden)]
                  pub func: ::cucumber::Step<World>,
+
+                    #[doc(hidden)]
+                    pub soft: bool,
             }
 
             #[automatically_derived]
@@ -195,6 +220,10 @@ mod spec {
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn is_soft(&self) -> bool {
+                    self.soft
+                }
             }
 
             #[automatically_derived]
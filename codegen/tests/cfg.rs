@@ -0,0 +1,41 @@
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{given, then, World, WorldInit};
+
+#[derive(Debug, WorldInit)]
+pub struct MyWorld;
+
+#[async_trait(?Send)]
+impl World for MyWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Self::Error> {
+        Ok(Self)
+    }
+}
+
+#[given("unix step")]
+#[cfg(unix)]
+fn unix_below(_: &mut MyWorld) {}
+
+#[cfg(not(unix))]
+#[given("non-unix step")]
+fn non_unix_above(_: &mut MyWorld) {}
+
+#[then("platform-specific step")]
+#[cfg(unix)]
+fn platform_unix(_: &mut MyWorld) {}
+
+#[then("platform-specific step")]
+#[cfg(not(unix))]
+fn platform_non_unix(_: &mut MyWorld) {}
+
+#[tokio::main]
+async fn main() {
+    let writer = MyWorld::cucumber().run("./tests/cfg").await;
+
+    assert_eq!(writer.steps.passed, 2);
+    assert_eq!(writer.steps.skipped, 1);
+    assert_eq!(writer.steps.failed, 0);
+}
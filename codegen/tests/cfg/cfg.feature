@@ -0,0 +1,10 @@
+Feature: Conditionally compiled steps
+
+  Scenario: unix
+    Given unix step
+
+  Scenario: non-unix
+    Given non-unix step
+
+  Scenario: any platform
+    Then platform-specific step
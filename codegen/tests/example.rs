@@ -1,7 +1,9 @@
 use std::{fs, io, panic::AssertUnwindSafe, time::Duration};
 
 use async_trait::async_trait;
-use cucumber::{gherkin::Step, given, then, when, World, WorldInit};
+use cucumber::{
+    gherkin::Step, given, step::Context, then, when, World, WorldInit,
+};
 use futures::FutureExt as _;
 use tempfile::TempDir;
 use tokio::time;
@@ -89,6 +91,32 @@ fn test_return_result_read(
     Ok(())
 }
 
+#[when(regex = r#"^I remember (\d+) as "(\S+)"$"#)]
+fn test_set_variable(
+    _: &mut MyWorld,
+    val: u32,
+    name: String,
+    #[step] ctx: &Context,
+) {
+    ctx.set(name, val);
+}
+
+#[then(regex = r#"^"(\S+)" is remembered as (\d+)$"#)]
+fn test_get_variable(
+    _: &mut MyWorld,
+    name: String,
+    val: u32,
+    #[step] ctx: &Context,
+) {
+    assert_eq!(ctx.get::<u32>(&name).unwrap(), val);
+    assert!(ctx.get::<String>(&name).is_err());
+}
+
+#[then(regex = r#"^"(\S+)" is not remembered$"#)]
+fn test_no_variable(_: &mut MyWorld, name: String, #[step] ctx: &Context) {
+    assert!(ctx.get::<u32>(&name).is_err());
+}
+
 #[tokio::main]
 async fn main() {
     let res = MyWorld::cucumber()
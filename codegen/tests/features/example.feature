@@ -22,3 +22,10 @@ Feature: Example feature
   Scenario: Steps returning result and failing
     When I write "abc" to 'myfile.txt'
     Then the file 'not-here.txt' should contain "abc"
+
+  Scenario: Steps sharing variables
+    When I remember 42 as "answer"
+    Then "answer" is remembered as 42
+
+  Scenario: Steps not sharing variables between scenarios
+    Then "answer" is not remembered
@@ -0,0 +1,167 @@
+// Copyright (c) 2018-2021  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Capturing of an output written by [`Step`]s via [`stdout()`] and
+//! [`stderr()`] handles.
+//!
+//! Once [enabled][0], everything written via [`stdout()`] and [`stderr()`]
+//! while a [`Step`] is executed is captured into its own buffer, rather than
+//! interleaving with the output of other concurrently executed [`Step`]s. The
+//! captured output is emitted as an [`event::Step::Output`] for the failed
+//! [`Step`]s only, and discarded for the passed ones.
+//!
+//! Only these handles are captured: the process' [`io::Stdout`] and
+//! [`io::Stderr`] are never redirected, so anything written into them
+//! directly (by the [`print!`] macro, a logging library or a child process,
+//! for example) isn't captured and interleaves as usual.
+//!
+//! The buffer is swapped in and out around each poll of a [`Step`] future, so
+//! the output is attributed correctly even if [`Step`]s are polled
+//! concurrently on the same thread. However, an output written from other
+//! threads (like the ones spawned by a [`Step`]) isn't captured.
+//!
+//! To have an output captured, use [`write!`]/[`writeln!`] macros with
+//! [`stdout()`] or [`stderr()`] instead of [`print!`]/[`println!`] ones:
+//! ```rust
+//! # use std::io::Write as _;
+//! #
+//! # use cucumber::capture;
+//! #
+//! writeln!(capture::stdout(), "connecting to database...").unwrap();
+//! ```
+//!
+//! Without capturing enabled, [`stdout()`] and [`stderr()`] are equivalent to
+//! [`io::stdout()`] and [`io::stderr()`].
+//!
+//! [0]: crate::Cucumber::capture_output()
+//! [`event::Step::Output`]: crate::event::Step::Output
+//! [`Step`]: gherkin::Step
+
+use std::{cell::RefCell, future::Future, io, mem};
+
+use futures::{future, pin_mut};
+
+thread_local! {
+    /// Buffer capturing the output of the [`Step`] being polled at the moment,
+    /// if any.
+    ///
+    /// [`Step`]: gherkin::Step
+    static BUFFER: RefCell<Option<Vec<u8>>> = RefCell::new(None);
+}
+
+/// Returns a handle writing into the captured output of the current [`Step`],
+/// or into the [`io::Stdout`] if it isn't captured.
+///
+/// [`Step`]: gherkin::Step
+#[must_use]
+pub const fn stdout() -> Stdout {
+    Stdout(())
+}
+
+/// Returns a handle writing into the captured output of the current [`Step`],
+/// or into the [`io::Stderr`] if it isn't captured.
+///
+/// [`Step`]: gherkin::Step
+#[must_use]
+pub const fn stderr() -> Stderr {
+    Stderr(())
+}
+
+/// Handle writing into the captured output of the current [`Step`], or into
+/// the [`io::Stdout`] if it isn't captured.
+///
+/// Created via [`stdout()`].
+///
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Copy, Debug)]
+pub struct Stdout(());
+
+impl io::Write for Stdout {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        write_captured(buf).unwrap_or_else(|| io::stdout().write(buf))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stdout().flush()
+    }
+}
+
+/// Handle writing into the captured output of the current [`Step`], or into
+/// the [`io::Stderr`] if it isn't captured.
+///
+/// Created via [`stderr()`].
+///
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Copy, Debug)]
+pub struct Stderr(());
+
+impl io::Write for Stderr {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        write_captured(buf).unwrap_or_else(|| io::stderr().write(buf))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stderr().flush()
+    }
+}
+
+/// Writes the given `buf` into the captured output of the current [`Step`],
+/// returning [`None`] if it isn't captured.
+///
+/// [`Step`]: gherkin::Step
+fn write_captured(buf: &[u8]) -> Option<io::Result<usize>> {
+    BUFFER.with(|b| {
+        b.borrow_mut().as_mut().map(|captured| {
+            captured.extend_from_slice(buf);
+            Ok(buf.len())
+        })
+    })
+}
+
+/// Executes the given `fut`ure capturing everything written via [`stdout()`]
+/// and [`stderr()`] while it's being polled.
+pub(crate) async fn captured<F: Future>(fut: F) -> (F::Output, String) {
+    pin_mut!(fut);
+    let mut buffer = Vec::new();
+    let out = future::poll_fn(|cx| {
+        let _swap = Swap::new(&mut buffer);
+        fut.as_mut().poll(cx)
+    })
+    .await;
+    (out, String::from_utf8_lossy(&buffer).into_owned())
+}
+
+/// Guard swapping its buffer into the [`BUFFER`] for its lifetime, restoring
+/// the previous one on [`Drop`], even if a polled future panics.
+struct Swap<'b> {
+    /// Buffer of the future being polled.
+    buffer: &'b mut Vec<u8>,
+
+    /// Buffer being captured before this [`Swap`], if any.
+    previous: Option<Vec<u8>>,
+}
+
+impl<'b> Swap<'b> {
+    /// Swaps the given `buffer` into the [`BUFFER`].
+    fn new(buffer: &'b mut Vec<u8>) -> Self {
+        let previous =
+            BUFFER.with(|b| b.borrow_mut().replace(mem::take(buffer)));
+        Self { buffer, previous }
+    }
+}
+
+impl Drop for Swap<'_> {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        *self.buffer = BUFFER
+            .with(|b| mem::replace(&mut *b.borrow_mut(), previous))
+            .unwrap_or_default();
+    }
+}
@@ -27,9 +27,24 @@
 //! [`Writer`]: crate::Writer
 //! [1]: https://cucumber.io/docs/cucumber/api#tag-expressions
 
-use gherkin::tagexpr::TagOperation;
+#[cfg(feature = "config-file")]
+use std::{env, ffi::OsString, fs, io, path::Path};
+
+#[cfg(feature = "config-file")]
+use derive_more::{Display, Error, From};
+use globset::Glob;
 use regex::Regex;
-use structopt::StructOpt;
+#[cfg(feature = "config-file")]
+use structopt::clap;
+use structopt::{StructOpt, StructOptInternal};
+
+use crate::tag;
+
+/// Path of the config file, [`Opts`] take their defaults from, if it exists.
+///
+/// See [`Opts::from_args_with_config()`] for details.
+#[cfg(feature = "config-file")]
+pub const DEFAULT_CONFIG_FILE: &str = "cucumber.toml";
 
 // Workaround for overwritten doc-comments.
 // https://github.com/TeXitoi/structopt/issues/333#issuecomment-712265332
@@ -110,19 +125,37 @@ where
         name = "regex",
         visible_alias = "scenario-name"
     )]
+    #[cfg_attr(feature = "config-file", structopt(env = "CUCUMBER_NAME"))]
     pub re_filter: Option<Regex>,
 
+    /// Match the `--name` regex case-insensitively.
+    #[structopt(long = "name-ignore-case", requires = "regex")]
+    pub name_ignore_case: bool,
+
+    /// Match the `--name` regex against feature names too, running all the
+    /// scenarios of a matching feature.
+    #[structopt(long = "name-match-feature", requires = "regex")]
+    pub name_match_feature: bool,
+
     /// Tag expression to filter scenarios by.
     ///
+    /// Besides tag presence, values of `@key:value` tags may be compared, like
+    /// `--tags "@smoke and priority >= 2"`.
+    ///
     /// Note: Tags from Feature, Rule and Scenario are merged together on
-    /// filtering, so be careful about conflicting tags on different levels.
-    #[structopt(
-        short = "t",
-        long = "tags",
-        name = "tagexpr",
-        conflicts_with = "regex"
-    )]
-    pub tags_filter: Option<TagOperation>,
+    /// filtering (unless configured otherwise), so be careful about
+    /// conflicting tags on different levels.
+    #[structopt(short = "t", long = "tags", name = "tagexpr")]
+    #[cfg_attr(feature = "config-file", structopt(env = "CUCUMBER_TAGS"))]
+    pub tags_filter: Option<tag::Expression>,
+
+    /// Glob pattern to filter features by their file path.
+    ///
+    /// Matched against the path relative to the current directory, so
+    /// `--path "tests/features/api/**"` runs only the features located there.
+    #[structopt(long = "path", name = "path-glob")]
+    #[cfg_attr(feature = "config-file", structopt(env = "CUCUMBER_PATH"))]
+    pub path_filter: Option<Glob>,
 
     /// [`Parser`] CLI options.
     ///
@@ -147,6 +180,182 @@ where
     pub custom: Custom,
 }
 
+impl<P, R, Wr, C> Opts<P, R, Wr, C>
+where
+    P: StructOptInternal,
+    R: StructOptInternal,
+    Wr: StructOptInternal,
+    C: StructOptInternal,
+{
+    /// Parses [`Opts`] from the command line, taking defaults from the
+    /// `cucumber.toml` file, if it exists and the `config-file` feature is
+    /// enabled.
+    pub(crate) fn from_args_and_config() -> Self {
+        #[cfg(feature = "config-file")]
+        if Path::new(DEFAULT_CONFIG_FILE).is_file() {
+            return Self::from_args_with_config(DEFAULT_CONFIG_FILE);
+        }
+        Self::from_args()
+    }
+
+    /// Parses [`Opts`] from the command line, taking defaults from the TOML
+    /// config file at the given `path`.
+    ///
+    /// Keys of the config file are long names of CLI options (with either `-`
+    /// or `_` as separator), and values are their values (`true` for flags).
+    /// Arrays specify the option multiple times.
+    ///
+    /// ```toml
+    /// input = "tests/features/**/*.feature"
+    /// format = "json"
+    /// tags = "@smoke and not @slow"
+    /// concurrency = 4
+    /// verbose = true
+    /// ```
+    ///
+    /// Options are resolved with the following precedence:
+    /// 1. Explicit CLI options. Config file options conflicting with them are
+    ///    dropped.
+    /// 2. Environment variables (`CUCUMBER_INPUT`, `CUCUMBER_FORMAT`,
+    ///    `CUCUMBER_NAME`, `CUCUMBER_TAGS`, `CUCUMBER_PATH`,
+    ///    `CUCUMBER_CONCURRENCY`, `CUCUMBER_RETRIES`,
+    ///    `CUCUMBER_RETRY_TAG_FILTER` and `CUCUMBER_SHUFFLE_SEED`).
+    /// 3. Config file values.
+    /// 4. Defaults.
+    ///
+    /// Exits the process with an error message, if the config file cannot be
+    /// read or the resulting options are invalid, the same way
+    /// [`StructOpt::from_args()`] does.
+    #[cfg(feature = "config-file")]
+    #[must_use]
+    pub fn from_args_with_config(path: impl AsRef<Path>) -> Self {
+        Self::from_iter_with_config(env::args_os(), path).unwrap_or_else(|e| {
+            if let ConfigFileError::Cli(e) = e {
+                e.exit()
+            }
+            clap::Error::with_description(
+                &e.to_string(),
+                clap::ErrorKind::InvalidValue,
+            )
+            .exit()
+        })
+    }
+
+    /// Parses [`Opts`] from the given CLI arguments, taking defaults from the
+    /// TOML config file at the given `path`.
+    ///
+    /// See [`Opts::from_args_with_config()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// If the config file cannot be read or parsed, or the resulting
+    /// options are invalid.
+    #[cfg(feature = "config-file")]
+    pub fn from_iter_with_config<I>(
+        args: I,
+        path: impl AsRef<Path>,
+    ) -> Result<Self, ConfigFileError>
+    where
+        I: IntoIterator,
+        I::Item: Into<OsString>,
+    {
+        let config = toml::from_str(&fs::read_to_string(path)?)?;
+
+        let mut args = args.into_iter().map(Into::into);
+        let bin = args.next();
+        let args = args.collect::<Vec<_>>();
+        let app =
+            || Self::clap().setting(clap::AppSettings::AllArgsOverrideSelf);
+
+        // Config file options conflicting with the explicit CLI ones are
+        // dropped, as the latter take precedence.
+        let mut file_args = Vec::new();
+        for option in config_args(&config)? {
+            let conflicts = app()
+                .get_matches_from_safe(
+                    bin.iter().chain(&option).chain(&args).cloned(),
+                )
+                .map_or_else(
+                    |e| e.kind == clap::ErrorKind::ArgumentConflict,
+                    |_| false,
+                );
+            if !conflicts {
+                file_args.extend(option);
+            }
+        }
+
+        // Config file values go first, so any explicit CLI option overrides
+        // them as the last occurrence.
+        let matches = app().get_matches_from_safe(
+            bin.into_iter().chain(file_args).chain(args),
+        )?;
+
+        Ok(Self::from_clap(&matches))
+    }
+}
+
+/// Converts the given config file `table` into CLI arguments (grouped by
+/// option), omitting the ones overridden by environment variables.
+#[cfg(feature = "config-file")]
+fn config_args(
+    table: &toml::value::Table,
+) -> Result<Vec<Vec<OsString>>, ConfigFileError> {
+    let mut options = Vec::new();
+    for (key, value) in table {
+        let name = key.replace('_', "-");
+        let env_var = format!("CUCUMBER_{}", key.replace('-', "_"));
+        if env::var_os(env_var.to_uppercase()).is_some() {
+            continue;
+        }
+
+        let values = if let toml::Value::Array(values) = value {
+            values.iter().collect()
+        } else {
+            vec![value]
+        };
+        let mut args = Vec::new();
+        for v in values {
+            let arg = match v {
+                toml::Value::Boolean(true) => format!("--{}", name),
+                toml::Value::Boolean(false) => continue,
+                toml::Value::String(s) => format!("--{}={}", name, s),
+                toml::Value::Integer(i) => format!("--{}={}", name, i),
+                toml::Value::Float(f) => format!("--{}={}", name, f),
+                toml::Value::Datetime(_)
+                | toml::Value::Array(_)
+                | toml::Value::Table(_) => {
+                    return Err(ConfigFileError::UnsupportedValue(key.clone()))
+                }
+            };
+            args.push(arg.into());
+        }
+        options.push(args);
+    }
+    Ok(options)
+}
+
+/// Error of reading [`Opts`] with a config file.
+#[cfg(feature = "config-file")]
+#[derive(Debug, Display, Error, From)]
+pub enum ConfigFileError {
+    /// Config file cannot be read.
+    #[display(fmt = "Failed to read config file: {}", _0)]
+    Io(io::Error),
+
+    /// Config file isn't a valid TOML table.
+    #[display(fmt = "Failed to parse config file: {}", _0)]
+    Toml(toml::de::Error),
+
+    /// Config file option has a value not representable as a CLI option.
+    #[display(fmt = "Unsupported value of `{}` config file option", _0)]
+    #[from(ignore)]
+    UnsupportedValue(#[error(not(source))] String),
+
+    /// Resulting CLI options are invalid.
+    #[display(fmt = "{}", _0)]
+    Cli(clap::Error),
+}
+
 // Workaround for overwritten doc-comments.
 // https://github.com/TeXitoi/structopt/issues/333#issuecomment-712265332
 #[cfg_attr(doc, doc = "Empty CLI options.")]
@@ -154,7 +363,7 @@ where
     not(doc),
     allow(missing_docs, clippy::missing_docs_in_private_items)
 )]
-#[derive(Clone, Copy, Debug, StructOpt)]
+#[derive(Clone, Copy, Debug, Default, StructOpt)]
 pub struct Empty {
     /// This field exists only because [`StructOpt`] derive macro doesn't
     /// support unit structs.
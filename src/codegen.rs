@@ -13,8 +13,12 @@
 use std::{fmt::Debug, path::Path};
 
 use async_trait::async_trait;
+use gherkin::StepType;
 
-use crate::{cucumber::DefaultCucumber, step, Cucumber, Step, World};
+use crate::{
+    cucumber::DefaultCucumber, parser, runner, step, writer, Cucumber, Step,
+    World,
+};
 
 pub use cucumber_expressions::{
     expand::parameters::Provider as ParametersProvider, Expression, Spanned,
@@ -39,16 +43,25 @@ pub trait WorldInit: Debug + WorldInventory {
 
         for given in inventory::iter::<Self::Given> {
             let (loc, regex, fun) = given.inner();
+            if given.is_soft() {
+                out = out.soft(StepType::Given, Some(loc), regex());
+            }
             out = out.given(Some(loc), regex(), fun);
         }
 
         for when in inventory::iter::<Self::When> {
             let (loc, regex, fun) = when.inner();
+            if when.is_soft() {
+                out = out.soft(StepType::When, Some(loc), regex());
+            }
             out = out.when(Some(loc), regex(), fun);
         }
 
         for then in inventory::iter::<Self::Then> {
             let (loc, regex, fun) = then.inner();
+            if then.is_soft() {
+                out = out.soft(StepType::Then, Some(loc), regex());
+            }
             out = out.then(Some(loc), regex(), fun);
         }
 
@@ -61,6 +74,23 @@ pub trait WorldInit: Debug + WorldInventory {
         Cucumber::new().steps(Self::collection())
     }
 
+    /// Returns default [`Cucumber`] with all auto-wired [`Step`]s, parsing a
+    /// [`Feature`] from an in-memory [Gherkin] text via [`parser::Text`]
+    /// instead of reading `.feature` files.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [Gherkin]: https://cucumber.io/docs/gherkin/reference
+    #[must_use]
+    fn cucumber_text<I: AsRef<str>>() -> Cucumber<
+        Self,
+        parser::Text,
+        I,
+        runner::Basic<Self>,
+        writer::Summarize<writer::Normalize<Self, writer::Basic>>,
+    > {
+        Self::cucumber::<&Path>().with_parser(parser::Text::new())
+    }
+
     /// Runs [`Cucumber`].
     ///
     /// [`Feature`]s sourced by [`Parser`] are fed into [`Runner`] where the
@@ -80,6 +110,21 @@ pub trait WorldInit: Debug + WorldInventory {
         Self::cucumber().run_and_exit(input).await;
     }
 
+    /// Runs [`Cucumber`] on a [`Feature`] parsed from the given in-memory
+    /// [Gherkin] text.
+    ///
+    /// # Panics
+    ///
+    /// If encountered errors while parsing the [`Feature`] or at least one
+    /// [`Step`] panicked.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Step`]: crate::Step
+    /// [Gherkin]: https://cucumber.io/docs/gherkin/reference
+    async fn run_text<I: AsRef<str>>(text: I) {
+        Self::cucumber_text().run_and_exit(text).await;
+    }
+
     /// Runs [`Cucumber`] with [`Scenario`]s filter.
     ///
     /// [`Feature`]s sourced by [`Parser`] are fed into [`Runner`] where the
@@ -142,6 +187,13 @@ pub type LazyRegex = fn() -> Regex;
 pub trait StepConstructor<W> {
     /// Returns an inner [`Step`] with the corresponding [`Regex`].
     fn inner(&self) -> (step::Location, LazyRegex, Step<W>);
+
+    /// Indicates whether the [`Step`] is a [soft] one.
+    ///
+    /// [soft]: step::Collection::soft()
+    fn is_soft(&self) -> bool {
+        false
+    }
 }
 
 /// Custom parameter of a [Cucumber Expression].
@@ -14,21 +14,52 @@
 
 use std::{
     borrow::Cow,
+    cell::RefCell,
+    collections::HashMap,
+    env,
     fmt::{Debug, Formatter},
+    io,
     marker::PhantomData,
     mem,
-    path::Path,
+    path::{Path, PathBuf},
+    process,
+    rc::Rc,
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
-use futures::{future::LocalBoxFuture, StreamExt as _};
-use regex::Regex;
+use futures::{
+    future::{self, LocalBoxFuture},
+    Future, StreamExt as _,
+};
+use globset::{Glob, GlobMatcher};
+use regex::{Regex, RegexBuilder};
 use structopt::{StructOpt, StructOptInternal};
 
 use crate::{
-    cli, event, parser, runner, step, tag::Ext as _, writer, Event, Parser,
-    Runner, ScenarioType, Step, World, Writer, WriterExt as _,
+    cli, event,
+    feature::Ext as _,
+    parser, runner, step,
+    tag::{self, Ext as _},
+    writer, Event, Parser, Runner, ScenarioType, Step, World, WorldTeardown,
+    WorldWithContext, Writer, WriterExt as _,
 };
 
+/// Alias for [`fn`] rendering the name of a [`Scenario`] in the context of
+/// its [`Feature`] and [`Rule`].
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+pub type ScenarioDisplayFn =
+    fn(&gherkin::Feature, Option<&gherkin::Rule>, &gherkin::Scenario) -> String;
+
+/// Predicate deciding whether a [`Cucumber`] run has failed, based on its
+/// [`RunSummary`].
+///
+/// Used by [`Cucumber::fail_if()`].
+pub type FailIfFn = fn(&RunSummary) -> bool;
+
 /// Top-level [Cucumber] executor.
 ///
 /// Most of the time you don't need to work with it directly, just use
@@ -75,6 +106,11 @@ where
     #[allow(clippy::type_complexity)] // not really
     cli: Option<cli::Opts<P::Cli, R::Cli, Wr::Cli, Cli>>,
 
+    /// [`Options`] of running [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    options: Options,
+
     /// Type of the [`World`] this [`Cucumber`] run on.
     _world: PhantomData<W>,
 
@@ -99,6 +135,7 @@ where
             runner,
             writer,
             cli: None,
+            options: Options::default(),
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -113,12 +150,18 @@ where
     where
         NewP: Parser<NewI>,
     {
-        let Self { runner, writer, .. } = self;
+        let Self {
+            runner,
+            writer,
+            options,
+            ..
+        } = self;
         Cucumber {
             parser,
             runner,
             writer,
             cli: None,
+            options,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -133,12 +176,18 @@ where
     where
         NewR: Runner<W>,
     {
-        let Self { parser, writer, .. } = self;
+        let Self {
+            parser,
+            writer,
+            options,
+            ..
+        } = self;
         Cucumber {
             parser,
             runner,
             writer,
             cli: None,
+            options,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -153,29 +202,29 @@ where
     where
         NewWr: Writer<W>,
     {
-        let Self { parser, runner, .. } = self;
+        let Self {
+            parser,
+            runner,
+            options,
+            ..
+        } = self;
         Cucumber {
             parser,
             runner,
             writer,
             cli: None,
+            options,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
     }
 
-    /// Re-outputs [`Skipped`] steps for easier navigation.
+    /// Replaces [`Writer`] with a [`Summarize`]d [`writer::Format`] one,
+    /// choosing a built-in output format at runtime via `--format` CLI option
+    /// or `CUCUMBER_FORMAT` environment variable.
     ///
     /// # Example
     ///
-    /// Output with a regular [`Cucumber::run()`]:
-    /// <script
-    ///     id="asciicast-0d92qlT8Mbc4WXyvRbHJmjsqN"
-    ///     src="https://asciinema.org/a/0d92qlT8Mbc4WXyvRbHJmjsqN.js"
-    ///     async data-autoplay="true" data-rows="17">
-    /// </script>
-    ///
-    /// Adjust [`Cucumber`] to re-output all the [`Skipped`] steps at the end:
     /// ```rust
     /// # use std::convert::Infallible;
     /// #
@@ -197,42 +246,35 @@ where
     /// # #[tokio::main(flavor = "current_thread")]
     /// # async fn main() {
     /// MyWorld::cucumber()
-    ///     .repeat_skipped()
+    ///     .with_format_writer()
     ///     .run_and_exit("tests/features/readme")
     ///     .await;
     /// # }
     /// ```
-    /// <script
-    ///     id="asciicast-ox14HynkBIw8atpfhyfvKrsO3"
-    ///     src="https://asciinema.org/a/ox14HynkBIw8atpfhyfvKrsO3.js"
-    ///     async data-autoplay="true" data-rows="19">
-    /// </script>
     ///
-    /// [`Scenario`]: gherkin::Scenario
-    /// [`Skipped`]: crate::event::Step::Skipped
+    /// [`Summarize`]: writer::Summarize
     #[must_use]
-    pub fn repeat_skipped(
+    pub fn with_format_writer(
         self,
-    ) -> Cucumber<W, P, I, R, writer::Repeat<W, Wr>, Cli>
+    ) -> Cucumber<W, P, I, R, writer::Summarize<writer::Format<W>>, Cli>
     where
-        Wr: writer::NonTransforming,
+        W: Debug,
     {
-        Cucumber {
-            parser: self.parser,
-            runner: self.runner,
-            writer: self.writer.repeat_skipped(),
-            cli: self.cli,
-            _world: PhantomData,
-            _parser_input: PhantomData,
-        }
+        self.with_writer(writer::Format::default().summarized())
     }
 
-    /// Re-outputs [`Failed`] steps for easier navigation.
+    /// Runs only [`Scenario`]s matching the given [tag expression][1] (like
+    /// `@smoke and not @wip` or `(@a or @b) and @c`), evaluated against their
+    /// effective tags (see [`Cucumber::tag_inheritance()`]).
+    ///
+    /// Applies to all the running methods, along with their own filters. The
+    /// `--tags` CLI option, if specified, takes precedence over the given
+    /// `tags`. Not matching [`Scenario`]s are omitted, unless
+    /// [`Cucumber::skip_filtered_out()`] is enabled.
     ///
     /// # Example
     ///
-    /// Output with a regular [`Cucumber::fail_on_skipped()`]:
-    /// ```rust,should_panic
+    /// ```rust
     /// # use std::convert::Infallible;
     /// #
     /// # use async_trait::async_trait;
@@ -253,23 +295,39 @@ where
     /// # #[tokio::main(flavor = "current_thread")]
     /// # async fn main() {
     /// MyWorld::cucumber()
-    ///     .fail_on_skipped()
+    ///     .filter_tags("@smoke and not @wip".parse().unwrap())
     ///     .run_and_exit("tests/features/readme")
     ///     .await;
     /// # }
     /// ```
-    /// <script
-    ///     id="asciicast-UcipuopO6IFEsIDty6vaJlCH9"
-    ///     src="https://asciinema.org/a/UcipuopO6IFEsIDty6vaJlCH9.js"
-    ///     async data-autoplay="true" data-rows="21">
-    /// </script>
     ///
-    /// Adjust [`Cucumber`] to re-output all the [`Failed`] steps at the end:
-    /// ```rust,should_panic
+    /// [`Scenario`]: gherkin::Scenario
+    /// [1]: https://cucumber.io/docs/cucumber/api#tag-expressions
+    #[must_use]
+    pub fn filter_tags(mut self, tags: tag::Expression) -> Self {
+        self.options.tags_filter = Some(tags);
+        self
+    }
+
+    /// Sets the [`tag::Inheritance`] policy used for computing effective tags
+    /// of [`Scenario`]s when filtering them with a tag expression.
+    ///
+    /// The policy is also passed to the [`Runner`] when [running][0] (see
+    /// [`Runner::with_tag_inheritance()`]), and to [`Writer`]s via the
+    /// [`Cucumber::ParsingFinished`] event, so the [`runner::Basic`] considers
+    /// it for `@retry(N)` and `@timeout(...)` tags, and the [`writer::Route`]
+    /// routes [`Scenario`]s by it.
+    ///
+    /// By default, [`tag::Inheritance::Inherit`] is used, so [`Scenario`]s
+    /// inherit tags of their [`Feature`] and [`Rule`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
     /// # use std::convert::Infallible;
     /// #
     /// # use async_trait::async_trait;
-    /// # use cucumber::WorldInit;
+    /// # use cucumber::{tag, WorldInit};
     /// #
     /// # #[derive(Debug, WorldInit)]
     /// # struct MyWorld;
@@ -286,47 +344,56 @@ where
     /// # #[tokio::main(flavor = "current_thread")]
     /// # async fn main() {
     /// MyWorld::cucumber()
-    ///     .repeat_failed()
-    ///     .fail_on_skipped()
+    ///     .tag_inheritance(tag::Inheritance::Override)
     ///     .run_and_exit("tests/features/readme")
     ///     .await;
     /// # }
     /// ```
-    /// <script
-    ///     id="asciicast-ofOljvyEMb41OTLhE081QKv68"
-    ///     src="https://asciinema.org/a/ofOljvyEMb41OTLhE081QKv68.js"
-    ///     async data-autoplay="true" data-rows="24">
-    /// </script>
     ///
-    /// [`Failed`]: crate::event::Step::Failed
+    /// [0]: Runner::run()
+    /// [`Cucumber::ParsingFinished`]: event::Cucumber::ParsingFinished
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
     #[must_use]
-    pub fn repeat_failed(
-        self,
-    ) -> Cucumber<W, P, I, R, writer::Repeat<W, Wr>, Cli>
-    where
-        Wr: writer::NonTransforming,
-    {
-        Cucumber {
-            parser: self.parser,
-            runner: self.runner,
-            writer: self.writer.repeat_failed(),
-            cli: self.cli,
-            _world: PhantomData,
-            _parser_input: PhantomData,
-        }
+    pub const fn tag_inheritance(mut self, policy: tag::Inheritance) -> Self {
+        self.options.tag_inheritance = policy;
+        self
     }
 
-    /// Re-outputs steps by the given `filter` predicate.
+    /// Makes [`Scenario`]s not matching the filters of the run to be reported
+    /// as [skipped][1] without being executed, rather than omitted entirely
+    /// (default).
+    ///
+    /// Skipped this way [`Scenario`]s don't fail the run. They're reported
+    /// once their [`Feature`] or [`Rule`] is finished, so may appear after the
+    /// executed ones.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [1]: event::SkipReason::FilteredOut
+    #[must_use]
+    pub const fn skip_filtered_out(mut self, enabled: bool) -> Self {
+        self.options.skip_filtered_out = enabled;
+        self
+    }
+
+    /// Runs only [`Scenario`]s whose name matches the given [`Regex`].
+    ///
+    /// Applies to all the running methods, after filtering by tags. The
+    /// `--name` CLI option, if specified, takes precedence over the given
+    /// `regex`. Not matching [`Scenario`]s are omitted before any [`World`] is
+    /// created for them, unless [`Cucumber::skip_filtered_out()`] is enabled.
     ///
     /// # Example
     ///
-    /// Output with a regular [`Cucumber::fail_on_skipped()`]:
-    /// ```rust,should_panic
+    /// ```rust
     /// # use std::convert::Infallible;
     /// #
     /// # use async_trait::async_trait;
-    /// # use futures::FutureExt as _;
     /// # use cucumber::WorldInit;
+    /// # use regex::Regex;
     /// #
     /// # #[derive(Debug, WorldInit)]
     /// # struct MyWorld;
@@ -343,24 +410,58 @@ where
     /// # #[tokio::main(flavor = "current_thread")]
     /// # async fn main() {
     /// MyWorld::cucumber()
-    ///     .fail_on_skipped()
+    ///     .filter_name(Regex::new("hungry cat").unwrap())
+    ///     .filter_name_ignore_case(true)
     ///     .run_and_exit("tests/features/readme")
     ///     .await;
     /// # }
     /// ```
-    /// <script
-    ///     id="asciicast-UcipuopO6IFEsIDty6vaJlCH9"
-    ///     src="https://asciinema.org/a/UcipuopO6IFEsIDty6vaJlCH9.js"
-    ///     async data-autoplay="true" data-rows="21">
-    /// </script>
     ///
-    /// Adjust [`Cucumber`] to re-output all the [`Failed`] steps ta the end by
-    /// providing a custom `filter` predicate:
-    /// ```rust,should_panic
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn filter_name(mut self, regex: Regex) -> Self {
+        self.options.name_filter = Some(regex);
+        self
+    }
+
+    /// Makes the [name filter][1] match case-insensitively.
+    ///
+    /// May be enabled via the `--name-ignore-case` CLI option as well.
+    ///
+    /// [1]: Cucumber::filter_name()
+    #[must_use]
+    pub const fn filter_name_ignore_case(mut self, enabled: bool) -> Self {
+        self.options.name_ignore_case = enabled;
+        self
+    }
+
+    /// Makes the [name filter][1] match [`Feature`] names too, running all the
+    /// [`Scenario`]s of a matching [`Feature`].
+    ///
+    /// May be enabled via the `--name-match-feature` CLI option as well.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [1]: Cucumber::filter_name()
+    #[must_use]
+    pub const fn filter_name_match_feature(mut self, enabled: bool) -> Self {
+        self.options.name_match_feature = enabled;
+        self
+    }
+
+    /// Makes [`Cucumber::run_and_exit()`] exit the process with the provided
+    /// [`ExitCodes`] instead of panicking on failure.
+    ///
+    /// Useful for distinguishing different kinds of failures (like failed
+    /// [`Step`]s and parsing errors) in CI.
+    ///
+    /// # Example
+    ///
+    /// ```rust
     /// # use std::convert::Infallible;
     /// #
     /// # use async_trait::async_trait;
-    /// # use cucumber::WorldInit;
+    /// # use cucumber::{ExitCodes, WorldInit};
     /// #
     /// # #[derive(Debug, WorldInit)]
     /// # struct MyWorld;
@@ -377,85 +478,38 @@ where
     /// # #[tokio::main(flavor = "current_thread")]
     /// # async fn main() {
     /// MyWorld::cucumber()
-    ///     .repeat_if(|ev| {
-    ///         use cucumber::event::{Cucumber, Feature, Rule, Scenario, Step};
-    ///
-    ///         matches!(
-    ///             ev.as_deref(),
-    ///             Ok(Cucumber::Feature(
-    ///                 _,
-    ///                 Feature::Rule(
-    ///                     _,
-    ///                     Rule::Scenario(
-    ///                         _,
-    ///                         Scenario::Step(_, Step::Failed(..))
-    ///                             | Scenario::Background(_, Step::Failed(..))
-    ///                     )
-    ///                 ) | Feature::Scenario(
-    ///                     _,
-    ///                     Scenario::Step(_, Step::Failed(..))
-    ///                         | Scenario::Background(_, Step::Failed(..))
-    ///                 )
-    ///             )) | Err(_)
-    ///         )
+    ///     .exit_codes(ExitCodes {
+    ///         failures: 1,
+    ///         parse_errors: 2,
+    ///         hook_errors: 2,
+    ///         ..ExitCodes::default()
     ///     })
-    ///     .fail_on_skipped()
     ///     .run_and_exit("tests/features/readme")
     ///     .await;
     /// # }
     /// ```
-    /// <script
-    ///     id="asciicast-ofOljvyEMb41OTLhE081QKv68"
-    ///     src="https://asciinema.org/a/ofOljvyEMb41OTLhE081QKv68.js"
-    ///     async data-autoplay="true" data-rows="24">
-    /// </script>
     ///
-    /// [`Failed`]: crate::event::Step::Failed
+    /// [`Step`]: gherkin::Step
     #[must_use]
-    pub fn repeat_if<F>(
-        self,
-        filter: F,
-    ) -> Cucumber<W, P, I, R, writer::Repeat<W, Wr, F>, Cli>
-    where
-        F: Fn(&parser::Result<Event<event::Cucumber<W>>>) -> bool,
-        Wr: writer::NonTransforming,
-    {
-        Cucumber {
-            parser: self.parser,
-            runner: self.runner,
-            writer: self.writer.repeat_if(filter),
-            cli: self.cli,
-            _world: PhantomData,
-            _parser_input: PhantomData,
-        }
+    pub fn exit_codes(mut self, codes: ExitCodes) -> Self {
+        self.options.exit_codes = Some(codes);
+        self
     }
-}
 
-impl<W, P, I, R, Wr, Cli> Cucumber<W, P, I, R, Wr, Cli>
-where
-    W: World,
-    P: Parser<I>,
-    R: Runner<W>,
-    Wr: Writer<W> + for<'val> writer::Arbitrary<'val, W, String>,
-    Cli: StructOpt,
-{
-    /// Consider [`Skipped`] steps as [`Failed`] if their [`Scenario`] isn't
-    /// marked with `@allow.skipped` tag.
+    /// Decides whether a run has failed with the given [`FailIfFn`] predicate,
+    /// evaluated over the [`RunSummary`] once the run is finished.
     ///
-    /// It's useful option for ensuring that all the steps were covered.
+    /// Replaces the default failure detection of [`Cucumber::run_and_exit()`]
+    /// (and its `filter_` variant), so policies like "fail on any undefined
+    /// [`Step`]" or "fail if the run takes longer than 10 minutes" may be
+    /// expressed in one place. If [`Cucumber::exit_codes()`] are configured,
+    /// a failed run exits with the selected code, or with `1` if none of the
+    /// [`ExitCodes`] categories occurred.
     ///
     /// # Example
     ///
-    /// Output with a regular [`Cucumber::run()`]:
-    /// <script
-    ///     id="asciicast-0d92qlT8Mbc4WXyvRbHJmjsqN"
-    ///     src="https://asciinema.org/a/0d92qlT8Mbc4WXyvRbHJmjsqN.js"
-    ///     async data-autoplay="true" data-rows="17">
-    /// </script>
-    ///
-    /// To fail all the [`Skipped`] steps setup [`Cucumber`] like this:
-    /// ```rust,should_panic
-    /// # use std::convert::Infallible;
+    /// ```rust
+    /// # use std::{convert::Infallible, time::Duration};
     /// #
     /// # use async_trait::async_trait;
     /// # use cucumber::WorldInit;
@@ -475,53 +529,75 @@ where
     /// # #[tokio::main(flavor = "current_thread")]
     /// # async fn main() {
     /// MyWorld::cucumber()
-    ///     .fail_on_skipped()
+    ///     .fail_if(|summary| {
+    ///         summary.failed_steps > 0
+    ///             || summary.duration > Duration::from_secs(600)
+    ///     })
     ///     .run_and_exit("tests/features/readme")
     ///     .await;
     /// # }
     /// ```
-    /// <script
-    ///     id="asciicast-IHLxMEgku9BtBVkR4k2DtOjMd"
-    ///     src="https://asciinema.org/a/IHLxMEgku9BtBVkR4k2DtOjMd.js"
-    ///     async data-autoplay="true" data-rows="21">
-    /// </script>
     ///
-    /// To intentionally suppress some [`Skipped`] steps failing, use the
-    /// `@allow.skipped` tag:
-    /// ```gherkin
-    /// Feature: Animal feature
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn fail_if(mut self, predicate: FailIfFn) -> Self {
+        self.options.fail_if = Some(predicate);
+        self
+    }
+
+    /// Renders [`Scenario`] names with the given [`ScenarioDisplayFn`], so
+    /// generic names (like "happy path") become unique across reports.
     ///
-    ///   Scenario: If we feed a hungry cat it will no longer be hungry
-    ///     Given a hungry cat
-    ///     When I feed the cat
-    ///     Then the cat is not hungry
+    /// Names are rendered only for [`Writer`]s (and any ids they derive from
+    /// names), while the [`Runner`] (along with its hooks and [`Step`]s) and
+    /// filters (like `--name` CLI option) see the original ones. By default,
+    /// names are left as is.
     ///
-    ///   @allow.skipped
-    ///   Scenario: If we feed a satiated dog it will not become hungry
-    ///     Given a satiated dog
-    ///     When I feed the dog
-    ///     Then the dog is not hungry
-    /// ```
+    /// # Example
     ///
-    /// [`Failed`]: crate::event::Step::Failed
-    /// [`Scenario`]: gherkin::Scenario
-    /// [`Skipped`]: crate::event::Step::Skipped
-    #[must_use]
-    pub fn fail_on_skipped(
-        self,
-    ) -> Cucumber<W, P, I, R, writer::FailOnSkipped<Wr>, Cli> {
-        Cucumber {
-            parser: self.parser,
-            runner: self.runner,
-            writer: self.writer.fail_on_skipped(),
-            cli: self.cli,
-            _world: PhantomData,
-            _parser_input: PhantomData,
-        }
+    /// ```rust
+    /// # use std::convert::Infallible;
+    /// #
+    /// # use async_trait::async_trait;
+    /// # use cucumber::WorldInit;
+    /// #
+    /// # #[derive(Debug, WorldInit)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[async_trait(?Send)]
+    /// # impl cucumber::World for MyWorld {
+    /// #     type Error = Infallible;
+    /// #
+    /// #     async fn new() -> Result<Self, Self::Error> {
+    /// #         Ok(Self)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .scenario_display(|feature, rule, scenario| {
+    ///         let rule = rule.map(|r| format!("{} :: ", r.name));
+    ///         format!(
+    ///             "{} :: {}{}",
+    ///             feature.name,
+    ///             rule.unwrap_or_default(),
+    ///             scenario.name,
+    ///         )
+    ///     })
+    ///     .run("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn scenario_display(mut self, display: ScenarioDisplayFn) -> Self {
+        self.options.scenario_display = Some(display);
+        self
     }
 
-    /// Consider [`Skipped`] steps as [`Failed`] if the given `filter` predicate
-    /// returns `true`.
+    /// Re-outputs [`Skipped`] steps for easier navigation.
     ///
     /// # Example
     ///
@@ -532,9 +608,8 @@ where
     ///     async data-autoplay="true" data-rows="17">
     /// </script>
     ///
-    /// Adjust [`Cucumber`] to fail on all [`Skipped`] steps, but the ones
-    /// marked with a `@dog` tag:
-    /// ```rust,should_panic
+    /// Adjust [`Cucumber`] to re-output all the [`Skipped`] steps at the end:
+    /// ```rust
     /// # use std::convert::Infallible;
     /// #
     /// # use async_trait::async_trait;
@@ -555,113 +630,47 @@ where
     /// # #[tokio::main(flavor = "current_thread")]
     /// # async fn main() {
     /// MyWorld::cucumber()
-    ///     .fail_on_skipped_with(|_, _, s| !s.tags.iter().any(|t| t == "dog"))
+    ///     .repeat_skipped()
     ///     .run_and_exit("tests/features/readme")
     ///     .await;
     /// # }
     /// ```
-    /// ```gherkin
-    /// Feature: Animal feature
-    ///
-    ///   Scenario: If we feed a hungry cat it will no longer be hungry
-    ///     Given a hungry cat
-    ///     When I feed the cat
-    ///     Then the cat is not hungry
-    ///
-    ///   Scenario: If we feed a satiated dog it will not become hungry
-    ///     Given a satiated dog
-    ///     When I feed the dog
-    ///     Then the dog is not hungry
-    /// ```
     /// <script
-    ///     id="asciicast-IHLxMEgku9BtBVkR4k2DtOjMd"
-    ///     src="https://asciinema.org/a/IHLxMEgku9BtBVkR4k2DtOjMd.js"
-    ///     async data-autoplay="true" data-rows="21">
+    ///     id="asciicast-ox14HynkBIw8atpfhyfvKrsO3"
+    ///     src="https://asciinema.org/a/ox14HynkBIw8atpfhyfvKrsO3.js"
+    ///     async data-autoplay="true" data-rows="19">
     /// </script>
     ///
-    /// And to avoid failing, use the `@dog` tag:
-    /// ```gherkin
-    /// Feature: Animal feature
-    ///
-    ///   Scenario: If we feed a hungry cat it will no longer be hungry
-    ///     Given a hungry cat
-    ///     When I feed the cat
-    ///     Then the cat is not hungry
-    ///
-    ///   @dog
-    ///   Scenario: If we feed a satiated dog it will not become hungry
-    ///     Given a satiated dog
-    ///     When I feed the dog
-    ///     Then the dog is not hungry
-    /// ```
-    ///
-    /// [`Failed`]: crate::event::Step::Failed
     /// [`Scenario`]: gherkin::Scenario
     /// [`Skipped`]: crate::event::Step::Skipped
     #[must_use]
-    pub fn fail_on_skipped_with<Filter>(
+    pub fn repeat_skipped(
         self,
-        filter: Filter,
-    ) -> Cucumber<W, P, I, R, writer::FailOnSkipped<Wr, Filter>, Cli>
+    ) -> Cucumber<W, P, I, R, writer::Repeat<W, Wr>, Cli>
     where
-        Filter: Fn(
-            &gherkin::Feature,
-            Option<&gherkin::Rule>,
-            &gherkin::Scenario,
-        ) -> bool,
+        Wr: writer::NonTransforming,
     {
         Cucumber {
             parser: self.parser,
             runner: self.runner,
-            writer: self.writer.fail_on_skipped_with(filter),
+            writer: self.writer.repeat_skipped(),
             cli: self.cli,
+            options: self.options,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
     }
-}
-
-impl<W, P, I, R, Wr, Cli> Cucumber<W, P, I, R, Wr, Cli>
-where
-    W: World,
-    P: Parser<I>,
-    R: Runner<W>,
-    Wr: Writer<W> + writer::Normalized,
-    Cli: StructOpt + StructOptInternal,
-{
-    /// Runs [`Cucumber`].
-    ///
-    /// [`Feature`]s sourced from a [`Parser`] are fed to a [`Runner`], which
-    /// produces events handled by a [`Writer`].
-    ///
-    /// [`Feature`]: gherkin::Feature
-    pub async fn run(self, input: I) -> Wr {
-        self.filter_run(input, |_, _, _| true).await
-    }
 
-    /// Consumes already parsed [`cli::Opts`].
-    ///
-    /// This method allows to pre-parse [`cli::Opts`] for custom needs before
-    /// using them inside [`Cucumber`].
-    ///
-    /// Also, any additional custom CLI options may be specified as a
-    /// [`StructOpt`] deriving type, used as the last type parameter of
-    /// [`cli::Opts`].
-    ///
-    /// > ⚠️ __WARNING__: Any CLI options of [`Parser`], [`Runner`], [`Writer`]
-    ///                   or custom ones should not overlap, otherwise
-    ///                   [`cli::Opts`] will fail to parse on startup.
+    /// Re-outputs [`Failed`] steps for easier navigation.
     ///
     /// # Example
     ///
-    /// ```rust
-    /// # use std::{convert::Infallible, time::Duration};
+    /// Output with a regular [`Cucumber::fail_on_skipped()`]:
+    /// ```rust,should_panic
+    /// # use std::convert::Infallible;
     /// #
     /// # use async_trait::async_trait;
-    /// # use cucumber::{cli, WorldInit};
-    /// # use futures::FutureExt as _;
-    /// # use structopt::StructOpt;
-    /// # use tokio::time;
+    /// # use cucumber::WorldInit;
     /// #
     /// # #[derive(Debug, WorldInit)]
     /// # struct MyWorld;
@@ -677,78 +686,81 @@ where
     /// #
     /// # #[tokio::main(flavor = "current_thread")]
     /// # async fn main() {
-    /// #[derive(StructOpt)]
-    /// struct CustomCli {
-    ///     /// Additional time to wait in a before hook.
-    ///     #[structopt(
-    ///         long,
-    ///         parse(try_from_str = humantime::parse_duration)
-    ///     )]
-    ///     before_time: Option<Duration>,
-    /// }
-    ///
-    /// let cli = cli::Opts::<_, _, _, CustomCli>::from_args();
-    /// let time = cli.custom.before_time.unwrap_or_default();
-    ///
     /// MyWorld::cucumber()
-    ///     .before(move |_, _, _, _| time::sleep(time).boxed_local())
-    ///     .with_cli(cli)
+    ///     .fail_on_skipped()
     ///     .run_and_exit("tests/features/readme")
     ///     .await;
     /// # }
     /// ```
-    /// ```gherkin
-    /// Feature: Animal feature
+    /// <script
+    ///     id="asciicast-UcipuopO6IFEsIDty6vaJlCH9"
+    ///     src="https://asciinema.org/a/UcipuopO6IFEsIDty6vaJlCH9.js"
+    ///     async data-autoplay="true" data-rows="21">
+    /// </script>
     ///
-    ///   Scenario: If we feed a hungry cat it will no longer be hungry
-    ///     Given a hungry cat
-    ///     When I feed the cat
-    ///     Then the cat is not hungry
+    /// Adjust [`Cucumber`] to re-output all the [`Failed`] steps at the end:
+    /// ```rust,should_panic
+    /// # use std::convert::Infallible;
+    /// #
+    /// # use async_trait::async_trait;
+    /// # use cucumber::WorldInit;
+    /// #
+    /// # #[derive(Debug, WorldInit)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[async_trait(?Send)]
+    /// # impl cucumber::World for MyWorld {
+    /// #     type Error = Infallible;
+    /// #
+    /// #     async fn new() -> Result<Self, Self::Error> {
+    /// #         Ok(Self)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .repeat_failed()
+    ///     .fail_on_skipped()
+    ///     .run_and_exit("tests/features/readme")
+    ///     .await;
+    /// # }
     /// ```
     /// <script
-    ///     id="asciicast-0KvTxnfaMRjsvsIKsalS611Ta"
-    ///     src="https://asciinema.org/a/0KvTxnfaMRjsvsIKsalS611Ta.js"
-    ///     async data-autoplay="true" data-rows="14">
+    ///     id="asciicast-ofOljvyEMb41OTLhE081QKv68"
+    ///     src="https://asciinema.org/a/ofOljvyEMb41OTLhE081QKv68.js"
+    ///     async data-autoplay="true" data-rows="24">
     /// </script>
     ///
-    /// Also, specifying `--help` flag will describe `--before-time` now.
-    ///
-    /// [`Feature`]: gherkin::Feature
-    pub fn with_cli<CustomCli>(
+    /// [`Failed`]: crate::event::Step::Failed
+    #[must_use]
+    pub fn repeat_failed(
         self,
-        cli: cli::Opts<P::Cli, R::Cli, Wr::Cli, CustomCli>,
-    ) -> Cucumber<W, P, I, R, Wr, CustomCli>
+    ) -> Cucumber<W, P, I, R, writer::Repeat<W, Wr>, Cli>
     where
-        CustomCli: StructOpt,
+        Wr: writer::NonTransforming,
     {
-        let Cucumber {
-            parser,
-            runner,
-            writer,
-            ..
-        } = self;
         Cucumber {
-            parser,
-            runner,
-            writer,
-            cli: Some(cli),
+            parser: self.parser,
+            runner: self.runner,
+            writer: self.writer.repeat_failed(),
+            cli: self.cli,
+            options: self.options,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
     }
 
-    /// Runs [`Cucumber`] with [`Scenario`]s filter.
-    ///
-    /// [`Feature`]s sourced from a [`Parser`] are fed to a [`Runner`], which
-    /// produces events handled by a [`Writer`].
+    /// Re-outputs steps by the given `filter` predicate.
     ///
     /// # Example
     ///
-    /// Adjust [`Cucumber`] to run only [`Scenario`]s marked with `@cat` tag:
-    /// ```rust
+    /// Output with a regular [`Cucumber::fail_on_skipped()`]:
+    /// ```rust,should_panic
     /// # use std::convert::Infallible;
     /// #
     /// # use async_trait::async_trait;
+    /// # use futures::FutureExt as _;
     /// # use cucumber::WorldInit;
     /// #
     /// # #[derive(Debug, WorldInit)]
@@ -766,370 +778,1751 @@ where
     /// # #[tokio::main(flavor = "current_thread")]
     /// # async fn main() {
     /// MyWorld::cucumber()
-    ///     .filter_run("tests/features/readme", |_, _, sc| {
-    ///         sc.tags.iter().any(|t| t == "cat")
-    ///     })
+    ///     .fail_on_skipped()
+    ///     .run_and_exit("tests/features/readme")
     ///     .await;
     /// # }
     /// ```
-    /// ```gherkin
-    /// Feature: Animal feature
-    ///
-    ///   @cat
-    ///   Scenario: If we feed a hungry cat it will no longer be hungry
-    ///     Given a hungry cat
-    ///     When I feed the cat
-    ///     Then the cat is not hungry
-    ///
-    ///   @dog
-    ///   Scenario: If we feed a satiated dog it will not become hungry
-    ///     Given a satiated dog
-    ///     When I feed the dog
-    ///     Then the dog is not hungry
-    /// ```
     /// <script
-    ///     id="asciicast-0KvTxnfaMRjsvsIKsalS611Ta"
-    ///     src="https://asciinema.org/a/0KvTxnfaMRjsvsIKsalS611Ta.js"
-    ///     async data-autoplay="true" data-rows="14">
-    /// </script>
+    ///     id="asciicast-UcipuopO6IFEsIDty6vaJlCH9"
+    ///     src="https://asciinema.org/a/UcipuopO6IFEsIDty6vaJlCH9.js"
+    ///     async data-autoplay="true" data-rows="21">
+    /// </script>
+    ///
+    /// Adjust [`Cucumber`] to re-output all the [`Failed`] steps ta the end by
+    /// providing a custom `filter` predicate:
+    /// ```rust,should_panic
+    /// # use std::convert::Infallible;
+    /// #
+    /// # use async_trait::async_trait;
+    /// # use cucumber::WorldInit;
+    /// #
+    /// # #[derive(Debug, WorldInit)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[async_trait(?Send)]
+    /// # impl cucumber::World for MyWorld {
+    /// #     type Error = Infallible;
+    /// #
+    /// #     async fn new() -> Result<Self, Self::Error> {
+    /// #         Ok(Self)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .repeat_if(|ev| {
+    ///         use cucumber::event::{Cucumber, Feature, Rule, Scenario, Step};
+    ///
+    ///         matches!(
+    ///             ev.as_deref(),
+    ///             Ok(Cucumber::Feature(
+    ///                 _,
+    ///                 Feature::Rule(
+    ///                     _,
+    ///                     Rule::Scenario(
+    ///                         _,
+    ///                         Scenario::Step(_, Step::Failed(..))
+    ///                             | Scenario::Background(_, Step::Failed(..))
+    ///                     )
+    ///                 ) | Feature::Scenario(
+    ///                     _,
+    ///                     Scenario::Step(_, Step::Failed(..))
+    ///                         | Scenario::Background(_, Step::Failed(..))
+    ///                 )
+    ///             )) | Err(_)
+    ///         )
+    ///     })
+    ///     .fail_on_skipped()
+    ///     .run_and_exit("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    /// <script
+    ///     id="asciicast-ofOljvyEMb41OTLhE081QKv68"
+    ///     src="https://asciinema.org/a/ofOljvyEMb41OTLhE081QKv68.js"
+    ///     async data-autoplay="true" data-rows="24">
+    /// </script>
+    ///
+    /// [`Failed`]: crate::event::Step::Failed
+    #[must_use]
+    pub fn repeat_if<F>(
+        self,
+        filter: F,
+    ) -> Cucumber<W, P, I, R, writer::Repeat<W, Wr, F>, Cli>
+    where
+        F: Fn(&parser::Result<Event<event::Cucumber<W>>>) -> bool,
+        Wr: writer::NonTransforming,
+    {
+        Cucumber {
+            parser: self.parser,
+            runner: self.runner,
+            writer: self.writer.repeat_if(filter),
+            cli: self.cli,
+            options: self.options,
+            _world: PhantomData,
+            _parser_input: PhantomData,
+        }
+    }
+}
+
+impl<W, P, I, R, Wr, Cli> Cucumber<W, P, I, R, Wr, Cli>
+where
+    W: World,
+    P: Parser<I>,
+    R: Runner<W>,
+    Wr: Writer<W> + for<'val> writer::Arbitrary<'val, W, String>,
+    Cli: StructOpt,
+{
+    /// Consider [`Skipped`] steps as [`Failed`] if their [`Scenario`] isn't
+    /// marked with `@allow.skipped` tag.
+    ///
+    /// It's useful option for ensuring that all the steps were covered.
+    ///
+    /// # Example
+    ///
+    /// Output with a regular [`Cucumber::run()`]:
+    /// <script
+    ///     id="asciicast-0d92qlT8Mbc4WXyvRbHJmjsqN"
+    ///     src="https://asciinema.org/a/0d92qlT8Mbc4WXyvRbHJmjsqN.js"
+    ///     async data-autoplay="true" data-rows="17">
+    /// </script>
+    ///
+    /// To fail all the [`Skipped`] steps setup [`Cucumber`] like this:
+    /// ```rust,should_panic
+    /// # use std::convert::Infallible;
+    /// #
+    /// # use async_trait::async_trait;
+    /// # use cucumber::WorldInit;
+    /// #
+    /// # #[derive(Debug, WorldInit)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[async_trait(?Send)]
+    /// # impl cucumber::World for MyWorld {
+    /// #     type Error = Infallible;
+    /// #
+    /// #     async fn new() -> Result<Self, Self::Error> {
+    /// #         Ok(Self)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .fail_on_skipped()
+    ///     .run_and_exit("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    /// <script
+    ///     id="asciicast-IHLxMEgku9BtBVkR4k2DtOjMd"
+    ///     src="https://asciinema.org/a/IHLxMEgku9BtBVkR4k2DtOjMd.js"
+    ///     async data-autoplay="true" data-rows="21">
+    /// </script>
+    ///
+    /// To intentionally suppress some [`Skipped`] steps failing, use the
+    /// `@allow.skipped` tag:
+    /// ```gherkin
+    /// Feature: Animal feature
+    ///
+    ///   Scenario: If we feed a hungry cat it will no longer be hungry
+    ///     Given a hungry cat
+    ///     When I feed the cat
+    ///     Then the cat is not hungry
+    ///
+    ///   @allow.skipped
+    ///   Scenario: If we feed a satiated dog it will not become hungry
+    ///     Given a satiated dog
+    ///     When I feed the dog
+    ///     Then the dog is not hungry
+    /// ```
+    ///
+    /// [`Failed`]: crate::event::Step::Failed
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Skipped`]: crate::event::Step::Skipped
+    #[must_use]
+    pub fn fail_on_skipped(
+        self,
+    ) -> Cucumber<W, P, I, R, writer::FailOnSkipped<Wr>, Cli> {
+        Cucumber {
+            parser: self.parser,
+            runner: self.runner,
+            writer: self.writer.fail_on_skipped(),
+            cli: self.cli,
+            options: self.options,
+            _world: PhantomData,
+            _parser_input: PhantomData,
+        }
+    }
+
+    /// Consider [`Skipped`] steps as [`Failed`] if the given `filter` predicate
+    /// returns `true`.
+    ///
+    /// # Example
+    ///
+    /// Output with a regular [`Cucumber::run()`]:
+    /// <script
+    ///     id="asciicast-0d92qlT8Mbc4WXyvRbHJmjsqN"
+    ///     src="https://asciinema.org/a/0d92qlT8Mbc4WXyvRbHJmjsqN.js"
+    ///     async data-autoplay="true" data-rows="17">
+    /// </script>
+    ///
+    /// Adjust [`Cucumber`] to fail on all [`Skipped`] steps, but the ones
+    /// marked with a `@dog` tag:
+    /// ```rust,should_panic
+    /// # use std::convert::Infallible;
+    /// #
+    /// # use async_trait::async_trait;
+    /// # use cucumber::WorldInit;
+    /// #
+    /// # #[derive(Debug, WorldInit)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[async_trait(?Send)]
+    /// # impl cucumber::World for MyWorld {
+    /// #     type Error = Infallible;
+    /// #
+    /// #     async fn new() -> Result<Self, Self::Error> {
+    /// #         Ok(Self)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .fail_on_skipped_with(|_, _, s, _| {
+    ///         !s.tags.iter().any(|t| t == "dog")
+    ///     })
+    ///     .run_and_exit("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    /// ```gherkin
+    /// Feature: Animal feature
+    ///
+    ///   Scenario: If we feed a hungry cat it will no longer be hungry
+    ///     Given a hungry cat
+    ///     When I feed the cat
+    ///     Then the cat is not hungry
+    ///
+    ///   Scenario: If we feed a satiated dog it will not become hungry
+    ///     Given a satiated dog
+    ///     When I feed the dog
+    ///     Then the dog is not hungry
+    /// ```
+    /// <script
+    ///     id="asciicast-IHLxMEgku9BtBVkR4k2DtOjMd"
+    ///     src="https://asciinema.org/a/IHLxMEgku9BtBVkR4k2DtOjMd.js"
+    ///     async data-autoplay="true" data-rows="21">
+    /// </script>
+    ///
+    /// And to avoid failing, use the `@dog` tag:
+    /// ```gherkin
+    /// Feature: Animal feature
+    ///
+    ///   Scenario: If we feed a hungry cat it will no longer be hungry
+    ///     Given a hungry cat
+    ///     When I feed the cat
+    ///     Then the cat is not hungry
+    ///
+    ///   @dog
+    ///   Scenario: If we feed a satiated dog it will not become hungry
+    ///     Given a satiated dog
+    ///     When I feed the dog
+    ///     Then the dog is not hungry
+    /// ```
+    ///
+    /// [`Failed`]: crate::event::Step::Failed
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Skipped`]: crate::event::Step::Skipped
+    #[must_use]
+    pub fn fail_on_skipped_with<Filter>(
+        self,
+        filter: Filter,
+    ) -> Cucumber<W, P, I, R, writer::FailOnSkipped<Wr, Filter>, Cli>
+    where
+        Filter: Fn(
+            &gherkin::Feature,
+            Option<&gherkin::Rule>,
+            &gherkin::Scenario,
+            &gherkin::Step,
+        ) -> bool,
+    {
+        Cucumber {
+            parser: self.parser,
+            runner: self.runner,
+            writer: self.writer.fail_on_skipped_with(filter),
+            cli: self.cli,
+            options: self.options,
+            _world: PhantomData,
+            _parser_input: PhantomData,
+        }
+    }
+}
+
+impl<W, P, I, R, Wr, Cli> Cucumber<W, P, I, R, Wr, Cli>
+where
+    W: World,
+    P: Parser<I>,
+    R: Runner<W>,
+    Wr: Writer<W> + writer::Normalized,
+    Cli: StructOpt + StructOptInternal,
+{
+    /// Runs [`Cucumber`].
+    ///
+    /// [`Feature`]s sourced from a [`Parser`] are fed to a [`Runner`], which
+    /// produces events handled by a [`Writer`].
+    ///
+    /// Returns only once the [`Writer`] has handled all the events, so the
+    /// output of built-in [`Writer`]s is fully flushed by then.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    pub async fn run(self, input: I) -> Wr {
+        self.filter_run(input, |_, _, _| true).await
+    }
+
+    /// Consumes already parsed [`cli::Opts`].
+    ///
+    /// This method allows to pre-parse [`cli::Opts`] for custom needs before
+    /// using them inside [`Cucumber`].
+    ///
+    /// Also, any additional custom CLI options may be specified as a
+    /// [`StructOpt`] deriving type, used as the last type parameter of
+    /// [`cli::Opts`].
+    ///
+    /// If not called, [`cli::Opts`] are parsed from the command line on
+    /// startup, taking defaults from the `cucumber.toml` file, if it exists
+    /// and `config-file` feature is enabled. Use
+    /// `cli::Opts::from_args_with_config()` to read them from another config
+    /// file.
+    ///
+    /// > ⚠️ __WARNING__: Any CLI options of [`Parser`], [`Runner`], [`Writer`]
+    ///                   or custom ones should not overlap, otherwise
+    ///                   [`cli::Opts`] will fail to parse on startup.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::{convert::Infallible, time::Duration};
+    /// #
+    /// # use async_trait::async_trait;
+    /// # use cucumber::{cli, WorldInit};
+    /// # use futures::FutureExt as _;
+    /// # use structopt::StructOpt;
+    /// # use tokio::time;
+    /// #
+    /// # #[derive(Debug, WorldInit)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[async_trait(?Send)]
+    /// # impl cucumber::World for MyWorld {
+    /// #     type Error = Infallible;
+    /// #
+    /// #     async fn new() -> Result<Self, Self::Error> {
+    /// #         Ok(Self)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// #[derive(StructOpt)]
+    /// struct CustomCli {
+    ///     /// Additional time to wait in a before hook.
+    ///     #[structopt(
+    ///         long,
+    ///         parse(try_from_str = humantime::parse_duration)
+    ///     )]
+    ///     before_time: Option<Duration>,
+    /// }
+    ///
+    /// let cli = cli::Opts::<_, _, _, CustomCli>::from_args();
+    /// let time = cli.custom.before_time.unwrap_or_default();
+    ///
+    /// MyWorld::cucumber()
+    ///     .before(move |_, _, _, _| time::sleep(time).boxed_local())
+    ///     .with_cli(cli)
+    ///     .run_and_exit("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    /// ```gherkin
+    /// Feature: Animal feature
+    ///
+    ///   Scenario: If we feed a hungry cat it will no longer be hungry
+    ///     Given a hungry cat
+    ///     When I feed the cat
+    ///     Then the cat is not hungry
+    /// ```
+    /// <script
+    ///     id="asciicast-0KvTxnfaMRjsvsIKsalS611Ta"
+    ///     src="https://asciinema.org/a/0KvTxnfaMRjsvsIKsalS611Ta.js"
+    ///     async data-autoplay="true" data-rows="14">
+    /// </script>
+    ///
+    /// Also, specifying `--help` flag will describe `--before-time` now.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    pub fn with_cli<CustomCli>(
+        self,
+        cli: cli::Opts<P::Cli, R::Cli, Wr::Cli, CustomCli>,
+    ) -> Cucumber<W, P, I, R, Wr, CustomCli>
+    where
+        CustomCli: StructOpt,
+    {
+        let Cucumber {
+            parser,
+            runner,
+            writer,
+            options,
+            ..
+        } = self;
+        Cucumber {
+            parser,
+            runner,
+            writer,
+            cli: Some(cli),
+            options,
+            _world: PhantomData,
+            _parser_input: PhantomData,
+        }
+    }
+
+    /// Runs [`Cucumber`] with [`Scenario`]s filter.
+    ///
+    /// [`Feature`]s sourced from a [`Parser`] are fed to a [`Runner`], which
+    /// produces events handled by a [`Writer`].
+    ///
+    /// [`Scenario`]s not matching the `filter` don't appear in the output at
+    /// all, as well as [`Feature`]s and [`Rule`]s having all their
+    /// [`Scenario`]s filtered out.
+    ///
+    /// # Example
+    ///
+    /// Adjust [`Cucumber`] to run only [`Scenario`]s marked with `@cat` tag:
+    /// ```rust
+    /// # use std::convert::Infallible;
+    /// #
+    /// # use async_trait::async_trait;
+    /// # use cucumber::WorldInit;
+    /// #
+    /// # #[derive(Debug, WorldInit)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[async_trait(?Send)]
+    /// # impl cucumber::World for MyWorld {
+    /// #     type Error = Infallible;
+    /// #
+    /// #     async fn new() -> Result<Self, Self::Error> {
+    /// #         Ok(Self)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .filter_run("tests/features/readme", |_, _, sc| {
+    ///         sc.tags.iter().any(|t| t == "cat")
+    ///     })
+    ///     .await;
+    /// # }
+    /// ```
+    /// ```gherkin
+    /// Feature: Animal feature
+    ///
+    ///   @cat
+    ///   Scenario: If we feed a hungry cat it will no longer be hungry
+    ///     Given a hungry cat
+    ///     When I feed the cat
+    ///     Then the cat is not hungry
+    ///
+    ///   @dog
+    ///   Scenario: If we feed a satiated dog it will not become hungry
+    ///     Given a satiated dog
+    ///     When I feed the dog
+    ///     Then the dog is not hungry
+    /// ```
+    /// <script
+    ///     id="asciicast-0KvTxnfaMRjsvsIKsalS611Ta"
+    ///     src="https://asciinema.org/a/0KvTxnfaMRjsvsIKsalS611Ta.js"
+    ///     async data-autoplay="true" data-rows="14">
+    /// </script>
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    pub async fn filter_run<F>(self, input: I, filter: F) -> Wr
+    where
+        F: Fn(
+                &gherkin::Feature,
+                Option<&gherkin::Rule>,
+                &gherkin::Scenario,
+            ) -> bool
+            + 'static,
+    {
+        self.filter_run_inner(input, filter, None).await
+    }
+
+    /// Runs [`Cucumber`] only for [`Feature`]s whose file path matches the
+    /// provided [`Glob`].
+    ///
+    /// [`Feature`]s are filtered out right after being parsed, so the ones
+    /// not matching the [`Glob`] don't appear in the output and aren't
+    /// counted at all.
+    /// Parsing errors are not filtered, as there is no [`Feature`] to match.
+    ///
+    /// The [`Glob`] is matched against a [`Feature`] path relative to the
+    /// current directory. `--path` CLI option, if specified, takes precedence
+    /// over the provided [`Glob`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::convert::Infallible;
+    /// #
+    /// # use async_trait::async_trait;
+    /// # use cucumber::{Glob, WorldInit};
+    /// #
+    /// # #[derive(Debug, WorldInit)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[async_trait(?Send)]
+    /// # impl cucumber::World for MyWorld {
+    /// #     type Error = Infallible;
+    /// #
+    /// #     async fn new() -> Result<Self, Self::Error> {
+    /// #         Ok(Self)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .filter_run_by_path(
+    ///         "tests/features",
+    ///         Glob::new("tests/features/readme/**").unwrap(),
+    ///     )
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Glob`]: globset::Glob
+    pub async fn filter_run_by_path(self, input: I, glob: Glob) -> Wr {
+        self.filter_run_inner(input, |_, _, _| true, Some(glob))
+            .await
+    }
+
+    /// Runs [`Cucumber`] with [`Scenario`]s filter and an optional [`Glob`]
+    /// filtering [`Feature`]s by their path.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Glob`]: globset::Glob
+    /// [`Scenario`]: gherkin::Scenario
+    async fn filter_run_inner<F>(
+        self,
+        input: I,
+        filter: F,
+        path_glob: Option<Glob>,
+    ) -> Wr
+    where
+        F: Fn(
+                &gherkin::Feature,
+                Option<&gherkin::Rule>,
+                &gherkin::Scenario,
+            ) -> bool
+            + 'static,
+    {
+        let cli::Opts {
+            re_filter,
+            name_ignore_case: cli_name_ignore_case,
+            name_match_feature: cli_name_match_feature,
+            tags_filter,
+            path_filter,
+            parser: parser_cli,
+            runner: runner_cli,
+            writer: writer_cli,
+            ..
+        } = self
+            .cli
+            .unwrap_or_else(cli::Opts::<_, _, _, _>::from_args_and_config);
+
+        let tag_inheritance = self.options.tag_inheritance;
+        let own_tags_filter = self.options.tags_filter;
+        // Filters specified via CLI take precedence over the provided `filter`.
+        let cli_filtered = re_filter.is_some() || tags_filter.is_some();
+        let name_match_feature =
+            cli_name_match_feature || self.options.name_match_feature;
+        let name_filter = name_regex(
+            re_filter.or(self.options.name_filter),
+            cli_name_ignore_case || self.options.name_ignore_case,
+        );
+        let filter =
+            move |feat: &gherkin::Feature,
+                  rule: Option<&gherkin::Rule>,
+                  scenario: &gherkin::Scenario| {
+                tags_filter.as_ref().or(own_tags_filter.as_ref()).map_or(
+                    true,
+                    |tags| {
+                        tags.eval(tag_inheritance.tags(feat, rule, scenario))
+                    },
+                ) && name_filter.as_ref().map_or(true, |re| {
+                    matches_name(re, feat, scenario, name_match_feature)
+                }) && (cli_filtered || filter(feat, rule, scenario))
+            };
+
+        let Cucumber {
+            parser,
+            runner,
+            mut writer,
+            options:
+                Options {
+                    scenario_display,
+                    skip_filtered_out,
+                    ..
+                },
+            ..
+        } = self;
+
+        let path_matcher =
+            path_filter.or(path_glob).map(|g| g.compile_matcher());
+        let features = parser.parse(input, parser_cli).filter(move |feature| {
+            future::ready(matches_path(path_matcher.as_ref(), feature))
+        });
+
+        // `Feature`s and `Rule`s having all their `Scenario`s filtered out are
+        // omitted entirely, so they don't appear in the output, unless they're
+        // reported as skipped by the `FilteredOut`.
+        let filtered_out = Rc::new(RefCell::new(FilteredOut::default()));
+        let skeletons = Rc::clone(&filtered_out);
+        let filtered = features.filter_map(move |feature| {
+            let feature = match feature {
+                Ok(f) => f,
+                Err(e) => return future::ready(Some(Err(e))),
+            };
+            let had_scenarios = feature.count_scenarios() > 0;
+            let (feature, skeleton) = split_filtered_out(feature, &filter);
+
+            let fully_filtered_out =
+                had_scenarios && feature.count_scenarios() == 0;
+            if skip_filtered_out {
+                skeletons.borrow_mut().insert(
+                    skeleton,
+                    &feature.rules,
+                    fully_filtered_out,
+                );
+            }
+            if fully_filtered_out {
+                return future::ready(None);
+            }
+
+            future::ready(Some(Ok(feature)))
+        });
+
+        let mut names = scenario_display.map(RenderedScenarios::new);
+        let runner = runner.with_tag_inheritance(tag_inheritance);
+        let events_stream = runner.run(filtered, runner_cli);
+        futures::pin_mut!(events_stream);
+        while let Some(ev) = events_stream.next().await {
+            let events = filtered_out.borrow_mut().inject(ev);
+            for mut injected in events {
+                if let Some(names) = names.as_mut() {
+                    injected = names.render(injected);
+                }
+                writer.handle_event(injected, &writer_cli).await;
+            }
+        }
+        writer
+    }
+}
+
+impl<W, P, I, R, Wr, Cli> Debug for Cucumber<W, P, I, R, Wr, Cli>
+where
+    W: World,
+    P: Debug + Parser<I>,
+    R: Debug + Runner<W>,
+    Wr: Debug + Writer<W>,
+    Cli: StructOpt,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cucumber")
+            .field("parser", &self.parser)
+            .field("runner", &self.runner)
+            .field("writer", &self.writer)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Shortcut for the [`Cucumber`] type returned by its [`Default`] impl.
+pub(crate) type DefaultCucumber<W, I> = Cucumber<
+    W,
+    parser::Basic,
+    I,
+    runner::Basic<W>,
+    writer::Summarize<writer::Normalize<W, writer::Basic>>,
+>;
+
+impl<W, I> Default for DefaultCucumber<W, I>
+where
+    W: World + Debug,
+    I: AsRef<Path>,
+{
+    fn default() -> Self {
+        Self::custom(
+            parser::Basic::new(),
+            runner::Basic::default(),
+            writer::Basic::stdout().summarized(),
+        )
+    }
+}
+
+impl<W, I> DefaultCucumber<W, I>
+where
+    W: World + Debug,
+    I: AsRef<Path>,
+{
+    /// Creates a default [`Cucumber`] executor.
+    ///
+    /// * [`Parser`] — [`parser::Basic`]
+    ///
+    /// * [`Runner`] — [`runner::Basic`]
+    ///   * [`ScenarioType`] — [`Concurrent`] by default, [`Serial`] if
+    ///     `@serial` [tag] is present on a [`Scenario`];
+    ///   * Allowed to run up to 64 [`Concurrent`] [`Scenario`]s.
+    ///
+    /// * [`Writer`] — [`Normalize`] and [`Summarize`] [`writer::Basic`].
+    ///
+    /// [`Concurrent`]: runner::basic::ScenarioType::Concurrent
+    /// [`Normalize`]: writer::Normalize
+    /// [`Parser`]: parser::Parser
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Serial`]: runner::basic::ScenarioType::Serial
+    /// [`ScenarioType`]: runner::basic::ScenarioType
+    /// [`Summarize`]: writer::Summarize
+    ///
+    /// [tag]: https://cucumber.io/docs/cucumber/api/#tags
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<W, I, R, Wr, Cli> Cucumber<W, parser::Basic, I, R, Wr, Cli>
+where
+    W: World,
+    R: Runner<W>,
+    Wr: Writer<W>,
+    Cli: StructOpt,
+    I: AsRef<Path>,
+{
+    /// Sets the provided language of [`gherkin`] files.
+    ///
+    /// Files having a `# language:` header are parsed in the language it
+    /// specifies anyway, while an unsupported one is reported as a parsing
+    /// error.
+    ///
+    /// # Errors
+    ///
+    /// If the provided language isn't supported.
+    pub fn language(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<Self, parser::basic::UnsupportedLanguageError> {
+        self.parser = self.parser.language(name)?;
+        Ok(self)
+    }
+
+    /// Sets the maximum number of `.feature` files to be parsed concurrently,
+    /// so the startup of big suites isn't slowed down by sequential parsing.
+    ///
+    /// [`Feature`]s are still emitted in the sorted order, and failing to
+    /// parse one file doesn't prevent others from being parsed. See
+    /// [`parser::Basic::concurrency()`] for details.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[must_use]
+    pub fn parsing_concurrency(mut self, workers: usize) -> Self {
+        self.parser = self.parser.concurrency(workers);
+        self
+    }
+}
+
+impl<W, I, R, Wr, Cli> Cucumber<W, parser::Text, I, R, Wr, Cli>
+where
+    W: World,
+    R: Runner<W>,
+    Wr: Writer<W>,
+    Cli: StructOpt,
+    I: AsRef<str>,
+{
+    /// Sets the provided language of [`gherkin`] text.
+    ///
+    /// # Errors
+    ///
+    /// If the provided language isn't supported.
+    pub fn language(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<Self, parser::basic::UnsupportedLanguageError> {
+        self.parser = self.parser.language(name)?;
+        Ok(self)
+    }
+}
+
+impl<W, R, Wr, Cli> Cucumber<W, parser::Stdin, (), R, Wr, Cli>
+where
+    W: World,
+    R: Runner<W>,
+    Wr: Writer<W>,
+    Cli: StructOpt,
+{
+    /// Sets the provided language of [`gherkin`] text read from
+    /// [`io::Stdin`].
+    ///
+    /// # Errors
+    ///
+    /// If the provided language isn't supported.
+    ///
+    /// [`io::Stdin`]: std::io::Stdin
+    pub fn language(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<Self, parser::basic::UnsupportedLanguageError> {
+        self.parser = self.parser.language(name)?;
+        Ok(self)
+    }
+}
+
+impl<W, I, R, Wr, Cli> Cucumber<W, parser::Basic, I, R, Wr, Cli>
+where
+    W: World,
+    R: Runner<W>,
+    Wr: Writer<W> + writer::Normalized,
+    Cli: StructOpt + StructOptInternal,
+    I: AsRef<Path>,
+{
+    /// Runs [`Cucumber`] with [`Feature`]s from all the given `paths` (files,
+    /// directories or glob patterns) in a single run.
+    ///
+    /// [`Feature`]s are parsed in the order of the given `paths`, and the ones
+    /// discovered via overlapping `paths` are run only once. See
+    /// [`parser::basic::Paths`] for details.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    pub async fn run_many(
+        self,
+        paths: impl IntoIterator<Item = impl Into<PathBuf>>,
+    ) -> Wr {
+        let Self {
+            parser,
+            runner,
+            writer,
+            cli,
+            options,
+            ..
+        } = self;
+        Cucumber::<_, _, parser::basic::Paths, _, _, _> {
+            parser,
+            runner,
+            writer,
+            cli,
+            options,
+            _world: PhantomData,
+            _parser_input: PhantomData,
+        }
+        .run(paths.into_iter().collect())
+        .await
+    }
+
+    /// Runs [`Cucumber`] with [`Feature`]s from all the files matching the
+    /// given glob `patterns`, except the ones matching `!`-prefixed patterns.
+    ///
+    /// [`Feature`]s are discovered in a sorted order, so the output is stable
+    /// across machines. See [`parser::basic::Globs`] for details.
+    ///
+    /// # Example
+    ///
+    /// Keep work-in-progress [`Feature`]s alongside the running ones:
+    /// ```rust
+    /// # use std::convert::Infallible;
+    /// #
+    /// # use async_trait::async_trait;
+    /// # use cucumber::WorldInit;
+    /// #
+    /// # #[derive(Debug, WorldInit)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[async_trait(?Send)]
+    /// # impl cucumber::World for MyWorld {
+    /// #     type Error = Infallible;
+    /// #
+    /// #     async fn new() -> Result<Self, Self::Error> {
+    /// #         Ok(Self)
+    /// #     }
+    /// # }
+    /// #
+    /// # let fut = async {
+    /// MyWorld::cucumber::<&str>()
+    ///     .run_globs([
+    ///         "tests/features/readme/**/*.feature",
+    ///         "!tests/features/readme/wip/**",
+    ///     ])
+    ///     .await;
+    /// # };
+    /// #
+    /// # futures::executor::block_on(fut);
+    /// ```
+    ///
+    /// [`Feature`]: gherkin::Feature
+    pub async fn run_globs(
+        self,
+        patterns: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Wr {
+        let Self {
+            parser,
+            runner,
+            writer,
+            cli,
+            options,
+            ..
+        } = self;
+        Cucumber::<_, _, parser::basic::Globs, _, _, _> {
+            parser,
+            runner,
+            writer,
+            cli,
+            options,
+            _world: PhantomData,
+            _parser_input: PhantomData,
+        }
+        .run(patterns.into_iter().collect())
+        .await
+    }
+}
+
+impl<W, I, P, Wr, F, B, A, Cli>
+    Cucumber<W, P, I, runner::Basic<W, F, B, A>, Wr, Cli>
+where
+    W: World,
+    P: Parser<I>,
+    Wr: Writer<W>,
+    Cli: StructOpt,
+    F: Fn(
+            &gherkin::Feature,
+            Option<&gherkin::Rule>,
+            &gherkin::Scenario,
+        ) -> ScenarioType
+        + 'static,
+    B: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a mut W,
+        ) -> LocalBoxFuture<'a, ()>
+        + 'static,
+    A: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a event::ScenarioFinished,
+            Option<&'a mut W>,
+        ) -> LocalBoxFuture<'a, ()>
+        + 'static,
+{
+    /// If `max` is [`Some`] number of concurrently executed [`Scenario`]s will
+    /// be limited.
+    ///
+    /// The limit applies across all the [`Feature`]s, so `Some(1)` executes
+    /// [`Scenario`]s one by one, while [`None`] (or `Some(0)`) executes them
+    /// all at once. See [`runner::Basic::max_concurrent_scenarios()`] for
+    /// details, including the interaction with [`World::new()`] costs.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`World::new()`]: crate::World::new()
+    #[must_use]
+    pub fn max_concurrent_scenarios(
+        mut self,
+        max: impl Into<Option<usize>>,
+    ) -> Self {
+        self.runner = self.runner.max_concurrent_scenarios(max);
+        self
+    }
+
+    /// Makes failed [`Scenario`]s to be retried up to the given number of
+    /// `times`, reporting them as failed only if every attempt fails.
+    ///
+    /// See [`runner::Basic::retries()`] for details.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn retries(mut self, times: usize) -> Self {
+        self.runner = self.runner.retries(times);
+        self
+    }
+
+    /// Makes only the [`Scenario`]s matching the given tag `filter` (like
+    /// `@flaky`) to be [retried][1].
+    ///
+    /// See [`runner::Basic::retry_filter()`] for details.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [1]: Cucumber::retries()
+    #[must_use]
+    pub fn retry_filter(
+        mut self,
+        filter: impl Into<Option<tag::Expression>>,
+    ) -> Self {
+        self.runner = self.runner.retry_filter(filter);
+        self
+    }
+
+    /// Makes failed [`Scenario`]s to be [retried][1] only after the given
+    /// `delay`, rather than immediately.
+    ///
+    /// See [`runner::Basic::retry_after()`] for details.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [1]: Cucumber::retries()
+    #[must_use]
+    pub fn retry_after(mut self, delay: impl Into<Option<Duration>>) -> Self {
+        self.runner = self.runner.retry_after(delay);
+        self
+    }
+
+    /// Sets a hook, executed exactly once before any [`Scenario`] is started.
+    ///
+    /// Useful for setting up process-wide state, shared by all [`World`]s. If
+    /// the hook panics, then no [`Scenario`] is executed, and the run fails.
+    ///
+    /// See [`runner::Basic::before_all()`] for details.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn before_all<Hook, Fut>(mut self, hook: Hook) -> Self
+    where
+        Hook: FnOnce() -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        self.runner = self.runner.before_all(hook);
+        self
+    }
+
+    /// Sets a hook, executed exactly once after all [`Scenario`]s are
+    /// finished.
+    ///
+    /// See [`runner::Basic::after_all()`] for details.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn after_all<Hook, Fut>(mut self, hook: Hook) -> Self
+    where
+        Hook: FnOnce() -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        self.runner = self.runner.after_all(hook);
+        self
+    }
+
+    /// Limits the duration of each [`Step`] to the given `timeout`, reporting
+    /// the exceeded ones as failed with an [`event::StepError::TimedOut`].
+    ///
+    /// See [`runner::Basic::step_timeout()`] for details.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn step_timeout(
+        mut self,
+        timeout: impl Into<Option<Duration>>,
+    ) -> Self {
+        self.runner = self.runner.step_timeout(timeout);
+        self
+    }
+
+    /// Limits the duration of each [`Scenario`] (including all its [`Step`]s
+    /// and hooks) to the given `timeout`.
+    ///
+    /// See [`runner::Basic::scenario_timeout()`] for details.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn scenario_timeout(
+        mut self,
+        timeout: impl Into<Option<Duration>>,
+    ) -> Self {
+        self.runner = self.runner.scenario_timeout(timeout);
+        self
+    }
+
+    /// Makes [`Scenario`]s of each [`Feature`] to be executed in a random
+    /// order, shuffled with the given `seed`, or a random one if [`None`].
+    ///
+    /// The used `seed` is outputted at the start, so a failed run can be
+    /// replayed via this method or the `--shuffle-seed` CLI option. See
+    /// [`runner::Basic::shuffle()`] for details.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn shuffle(mut self, seed: impl Into<Option<u64>>) -> Self {
+        self.runner = self.runner.shuffle(seed);
+        self
+    }
+
+    /// If `enabled`, makes [`Cucumber`] stop starting new [`Scenario`]s once
+    /// any [`Step`] or hook fails, cancelling the ones being executed at the
+    /// moment.
+    ///
+    /// The summary outputs the number of [`Scenario`]s not executed due to
+    /// the run being aborted early, and the run is still considered failed.
+    /// May be enabled via the `--fail-fast` CLI option as well. See
+    /// [`runner::Basic::fail_fast()`] for details.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn fail_fast(mut self, enabled: bool) -> Self {
+        self.runner = self.runner.fail_fast(enabled);
+        self
+    }
+
+    /// Makes [`Cucumber`] [fail fast][1], but completing the [`Scenario`]s
+    /// being executed at the moment of a failure, instead of cancelling them.
+    ///
+    /// See [`runner::Basic::fail_fast_complete()`] for details.
+    ///
+    /// [1]: Cucumber::fail_fast()
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn fail_fast_complete(mut self) -> Self {
+        self.runner = self.runner.fail_fast_complete();
+        self
+    }
+
+    /// Makes [`Cucumber`] only match [`Step`]s against the registered step
+    /// functions, without executing them or constructing any [`World`].
+    ///
+    /// The run is considered failed if any [`Step`] is unmatched or ambiguous.
+    /// May be enabled via the `--dry-run` CLI option as well. See
+    /// [`runner::Basic::dry_run()`] for details.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn dry_run(mut self, enabled: bool) -> Self {
+        self.runner = self.runner.dry_run(enabled);
+        self
+    }
+
+    /// Sets whether a [`Step`] matching several [`Step`] functions fails its
+    /// [`Scenario`] (default), even in a [`dry_run()`], or is executed with
+    /// the first of them and reported as a [soft failure][0] warning about
+    /// the ambiguity.
+    ///
+    /// See [`runner::Basic::fail_on_ambiguous()`] for details.
+    ///
+    /// [0]: event::Step::SoftFailed
+    /// [`dry_run()`]: Self::dry_run()
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn fail_on_ambiguous(mut self, enabled: bool) -> Self {
+        self.runner = self.runner.fail_on_ambiguous(enabled);
+        self
+    }
+
+    /// Function determining whether a [`Scenario`] is [`Concurrent`] or
+    /// a [`Serial`] one.
+    ///
+    /// [`Concurrent`]: ScenarioType::Concurrent
+    /// [`Serial`]: ScenarioType::Serial
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn which_scenario<Which>(
+        self,
+        func: Which,
+    ) -> Cucumber<W, P, I, runner::Basic<W, Which, B, A>, Wr, Cli>
+    where
+        Which: Fn(
+                &gherkin::Feature,
+                Option<&gherkin::Rule>,
+                &gherkin::Scenario,
+            ) -> ScenarioType
+            + 'static,
+    {
+        let Self {
+            parser,
+            runner,
+            writer,
+            cli,
+            options,
+            ..
+        } = self;
+        Cucumber {
+            parser,
+            runner: runner.which_scenario(func),
+            writer,
+            cli,
+            options,
+            _world: PhantomData,
+            _parser_input: PhantomData,
+        }
+    }
+
+    /// Sets a hook, executed on each [`Scenario`] before running all its
+    /// [`Step`]s, including [`Background`] ones.
     ///
-    /// [`Feature`]: gherkin::Feature
+    /// [`Background`]: gherkin::Background
     /// [`Scenario`]: gherkin::Scenario
-    pub async fn filter_run<F>(self, input: I, filter: F) -> Wr
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn before<Before>(
+        self,
+        func: Before,
+    ) -> Cucumber<W, P, I, runner::Basic<W, F, Before, A>, Wr, Cli>
     where
-        F: Fn(
-                &gherkin::Feature,
-                Option<&gherkin::Rule>,
-                &gherkin::Scenario,
-            ) -> bool
+        Before: for<'a> Fn(
+                &'a gherkin::Feature,
+                Option<&'a gherkin::Rule>,
+                &'a gherkin::Scenario,
+                &'a mut W,
+            ) -> LocalBoxFuture<'a, ()>
             + 'static,
     {
-        let cli::Opts {
-            re_filter,
-            tags_filter,
-            parser: parser_cli,
-            runner: runner_cli,
-            writer: writer_cli,
+        let Self {
+            parser,
+            runner,
+            writer,
+            cli,
+            options,
             ..
-        } = self.cli.unwrap_or_else(cli::Opts::<_, _, _, _>::from_args);
-
-        let filter = move |feat: &gherkin::Feature,
-                           rule: Option<&gherkin::Rule>,
-                           scenario: &gherkin::Scenario| {
-            re_filter.as_ref().map_or_else(
-                || {
-                    tags_filter.as_ref().map_or_else(
-                        || filter(feat, rule, scenario),
-                        |tags| {
-                            // The order `Feature` -> `Rule` -> `Scenario`
-                            // matters here.
-                            tags.eval(
-                                feat.tags
-                                    .iter()
-                                    .chain(
-                                        rule.into_iter()
-                                            .flat_map(|r| r.tags.iter()),
-                                    )
-                                    .chain(scenario.tags.iter()),
-                            )
-                        },
-                    )
-                },
-                |re| re.is_match(&scenario.name),
-            )
-        };
+        } = self;
+        Cucumber {
+            parser,
+            runner: runner.before(func),
+            writer,
+            cli,
+            options,
+            _world: PhantomData,
+            _parser_input: PhantomData,
+        }
+    }
 
-        let Cucumber {
+    /// Sets a hook, executed on each [`Scenario`] after running all its
+    /// [`Step`]s (including [`Background`] ones), even after [`Skipped`] or
+    /// [`Failed`] [`Step`]s, or a failed [`before`] hook (in which case no
+    /// [`Step`]s are executed at all).
+    ///
+    /// The [`ScenarioFinished`] outcome of the [`Scenario`] is supplied to the
+    /// function, so it may do the appropriate cleanup.
+    ///
+    /// Last `World` argument is supplied to the function, in case it was
+    /// initialized before by running [`before`] hook or any non-failed
+    /// [`Step`]. In case the last [`Scenario`]'s [`Step`] failed, we want to
+    /// return event with an exact `World` state. Also, we don't want to impose
+    /// additional [`Clone`] bounds on `World`, so the only option left is to
+    /// pass [`None`] to the function.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`before`]: Self::before()
+    /// [`Failed`]: event::Step::Failed
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`ScenarioFinished`]: event::ScenarioFinished
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn after<After>(
+        self,
+        func: After,
+    ) -> Cucumber<W, P, I, runner::Basic<W, F, B, After>, Wr, Cli>
+    where
+        After: for<'a> Fn(
+                &'a gherkin::Feature,
+                Option<&'a gherkin::Rule>,
+                &'a gherkin::Scenario,
+                &'a event::ScenarioFinished,
+                Option<&'a mut W>,
+            ) -> LocalBoxFuture<'a, ()>
+            + 'static,
+    {
+        let Self {
             parser,
             runner,
-            mut writer,
+            writer,
+            cli,
+            options,
             ..
         } = self;
-
-        let features = parser.parse(input, parser_cli);
-
-        let filtered = features.map(move |feature| {
-            let mut feature = feature?;
-            let feat_scenarios = mem::take(&mut feature.scenarios);
-            feature.scenarios = feat_scenarios
-                .into_iter()
-                .filter(|s| filter(&feature, None, s))
-                .collect();
-
-            let mut rules = mem::take(&mut feature.rules);
-            for r in &mut rules {
-                let rule_scenarios = mem::take(&mut r.scenarios);
-                r.scenarios = rule_scenarios
-                    .into_iter()
-                    .filter(|s| filter(&feature, Some(r), s))
-                    .collect();
-            }
-            feature.rules = rules;
-
-            Ok(feature)
-        });
-
-        let events_stream = runner.run(filtered, runner_cli);
-        futures::pin_mut!(events_stream);
-        while let Some(ev) = events_stream.next().await {
-            writer.handle_event(ev, &writer_cli).await;
+        Cucumber {
+            parser,
+            runner: runner.after(func),
+            writer,
+            cli,
+            options,
+            _world: PhantomData,
+            _parser_input: PhantomData,
         }
-        writer
     }
-}
 
-impl<W, P, I, R, Wr, Cli> Debug for Cucumber<W, P, I, R, Wr, Cli>
-where
-    W: World,
-    P: Debug + Parser<I>,
-    R: Debug + Runner<W>,
-    Wr: Debug + Writer<W>,
-    Cli: StructOpt,
-{
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Cucumber")
-            .field("parser", &self.parser)
-            .field("runner", &self.runner)
-            .field("writer", &self.writer)
-            .finish()
+    /// Enables [`WorldTeardown`] of [`World`]s, awaited once their
+    /// [`Scenario`] finishes, after the [`after`] hook.
+    ///
+    /// [`World`]s of failed or panicked [`Scenario`]s are torn down too, so
+    /// they're no longer passed into the failure events.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::convert::Infallible;
+    /// #
+    /// # use async_trait::async_trait;
+    /// # use cucumber::{WorldInit, WorldTeardown};
+    /// #
+    /// # #[derive(Debug, WorldInit)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[async_trait(?Send)]
+    /// # impl cucumber::World for MyWorld {
+    /// #     type Error = Infallible;
+    /// #
+    /// #     async fn new() -> Result<Self, Self::Error> {
+    /// #         Ok(Self)
+    /// #     }
+    /// # }
+    /// #
+    /// #[async_trait(?Send)]
+    /// impl WorldTeardown for MyWorld {
+    ///     async fn drop(self) -> Result<(), Self::Error> {
+    ///         // Release asynchronous resources here.
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .teardown()
+    ///     .run_and_exit("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`after`]: Self::after()
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn teardown(mut self) -> Self
+    where
+        W: WorldTeardown,
+    {
+        self.runner = self.runner.teardown();
+        self
     }
-}
 
-/// Shortcut for the [`Cucumber`] type returned by its [`Default`] impl.
-pub(crate) type DefaultCucumber<W, I> = Cucumber<
-    W,
-    parser::Basic,
-    I,
-    runner::Basic<W>,
-    writer::Summarize<writer::Normalize<W, writer::Basic>>,
->;
+    /// Makes [`Cucumber`] construct [`World`]s via
+    /// [`WorldWithContext::new_with()`] instead of [`World::new()`], passing a
+    /// clone of the given `ctx` for each [`Scenario`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::convert::Infallible;
+    /// #
+    /// # use async_trait::async_trait;
+    /// # use cucumber::{WorldInit, WorldWithContext};
+    /// #
+    /// #[derive(Debug, WorldInit)]
+    /// struct MyWorld {
+    ///     base_url: String,
+    /// }
+    ///
+    /// #[async_trait(?Send)]
+    /// impl cucumber::World for MyWorld {
+    ///     type Error = Infallible;
+    ///
+    ///     async fn new() -> Result<Self, Self::Error> {
+    ///         Ok(Self { base_url: "http://localhost:8080".into() })
+    ///     }
+    /// }
+    ///
+    /// #[async_trait(?Send)]
+    /// impl WorldWithContext for MyWorld {
+    ///     type Context = String;
+    ///
+    ///     async fn new_with(base_url: String) -> Result<Self, Self::Error> {
+    ///         Ok(Self { base_url })
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .with_context("http://staging:8080".into())
+    ///     .run_and_exit("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn with_context(mut self, ctx: W::Context) -> Self
+    where
+        W: WorldWithContext,
+    {
+        self.runner = self.runner.with_context(ctx);
+        self
+    }
 
-impl<W, I> Default for DefaultCucumber<W, I>
-where
-    W: World + Debug,
-    I: AsRef<Path>,
-{
-    fn default() -> Self {
-        Self::custom(
-            parser::Basic::new(),
-            runner::Basic::default(),
-            writer::Basic::stdout().summarized(),
-        )
+    /// Makes [`Cucumber`] skip the rest of a [`Feature`]'s [`Scenario`]s once
+    /// its [`Background`] [`Step`] fails, without aborting other [`Feature`]s.
+    ///
+    /// Skipped [`Scenario`]s are reported with a [`Scenario::Skipped`] event
+    /// referencing the failed [`Background`] [`Step`].
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Scenario::Skipped`]: event::Scenario::Skipped
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn skip_feature_on_background_failure(mut self) -> Self {
+        self.runner = self.runner.skip_feature_on_background_failure();
+        self
     }
-}
 
-impl<W, I> DefaultCucumber<W, I>
-where
-    W: World + Debug,
-    I: AsRef<Path>,
-{
-    /// Creates a default [`Cucumber`] executor.
+    /// Makes [`Cucumber`] emit [`Scenario::StepHeartbeat`] events every
+    /// `interval` while a [`Step`] is still being executed, so slow [`Step`]s
+    /// don't look stuck (or trip inactivity timeouts in CI).
     ///
-    /// * [`Parser`] — [`parser::Basic`]
+    /// [`Scenario::StepHeartbeat`]: event::Scenario::StepHeartbeat
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn step_heartbeat(
+        mut self,
+        interval: impl Into<Option<Duration>>,
+    ) -> Self {
+        self.runner = self.runner.step_heartbeat(interval);
+        self
+    }
+
+    /// Makes [`Cucumber`] [capture][0] the output of [`Step`]s (written via
+    /// [`capture::stdout()`] and [`capture::stderr()`]), so it doesn't
+    /// interleave under concurrency, and is shown for the failed [`Step`]s
+    /// only.
+    ///
+    /// The process' [`io::Stdout`] and [`io::Stderr`] (like [`print!`]
+    /// output) aren't captured.
+    ///
+    /// [0]: crate::capture
+    /// [`io::Stderr`]: std::io::Stderr
+    /// [`io::Stdout`]: std::io::Stdout
+    /// [`capture::stderr()`]: crate::capture::stderr
+    /// [`capture::stdout()`]: crate::capture::stdout
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn capture_output(mut self, enabled: bool) -> Self {
+        self.runner = self.runner.capture_output(enabled);
+        self
+    }
+
+    /// Aborts the whole run once its total wall-clock duration exceeds the
+    /// given `timeout`, so a pathological run doesn't consume the whole CI
+    /// window.
     ///
-    /// * [`Runner`] — [`runner::Basic`]
-    ///   * [`ScenarioType`] — [`Concurrent`] by default, [`Serial`] if
-    ///     `@serial` [tag] is present on a [`Scenario`];
-    ///   * Allowed to run up to 64 [`Concurrent`] [`Scenario`]s.
+    /// The in-flight [`Scenario`]s are given a grace period to finish (see
+    /// [`runner::Basic::run_timeout_grace_period()`]), while the rest of them
+    /// are reported as [skipped][0], and the run finishes as a failed one.
     ///
-    /// * [`Writer`] — [`Normalize`] and [`Summarize`] [`writer::Basic`].
+    /// Finer timeouts of [`Step`]s or [`Scenario`]s work independently of this
+    /// one: whichever fires first wins.
     ///
-    /// [`Concurrent`]: runner::basic::ScenarioType::Concurrent
-    /// [`Normalize`]: writer::Normalize
-    /// [`Parser`]: parser::Parser
+    /// [0]: event::SkipReason::RunTimeout
     /// [`Scenario`]: gherkin::Scenario
-    /// [`Serial`]: runner::basic::ScenarioType::Serial
-    /// [`ScenarioType`]: runner::basic::ScenarioType
-    /// [`Summarize`]: writer::Summarize
-    ///
-    /// [tag]: https://cucumber.io/docs/cucumber/api/#tags
+    /// [`Step`]: gherkin::Step
     #[must_use]
-    pub fn new() -> Self {
-        Self::default()
+    pub fn run_timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+        self.runner = self.runner.run_timeout(timeout);
+        self
     }
-}
 
-impl<W, I, R, Wr, Cli> Cucumber<W, parser::Basic, I, R, Wr, Cli>
-where
-    W: World,
-    R: Runner<W>,
-    Wr: Writer<W>,
-    Cli: StructOpt,
-    I: AsRef<Path>,
-{
-    /// Sets the provided language of [`gherkin`] files.
+    /// Sets a callback, executed on each [`Scenario`] once all its [`Step`]s
+    /// and hooks pass, so success-only side effects (like recording
+    /// baselines) can be performed.
     ///
-    /// # Errors
+    /// See [`runner::Basic::on_scenario_passed()`] for more details.
     ///
-    /// If the provided language isn't supported.
-    pub fn language(
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn on_scenario_passed(
         mut self,
-        name: impl Into<Cow<'static, str>>,
-    ) -> Result<Self, parser::basic::UnsupportedLanguageError> {
-        self.parser = self.parser.language(name)?;
-        Ok(self)
+        func: runner::basic::ScenarioOutcomeFn,
+    ) -> Self {
+        self.runner = self.runner.on_scenario_passed(func);
+        self
     }
-}
 
-impl<W, I, P, Wr, F, B, A, Cli>
-    Cucumber<W, P, I, runner::Basic<W, F, B, A>, Wr, Cli>
-where
-    W: World,
-    P: Parser<I>,
-    Wr: Writer<W>,
-    Cli: StructOpt,
-    F: Fn(
-            &gherkin::Feature,
-            Option<&gherkin::Rule>,
-            &gherkin::Scenario,
-        ) -> ScenarioType
-        + 'static,
-    B: for<'a> Fn(
-            &'a gherkin::Feature,
-            Option<&'a gherkin::Rule>,
-            &'a gherkin::Scenario,
-            &'a mut W,
-        ) -> LocalBoxFuture<'a, ()>
-        + 'static,
-    A: for<'a> Fn(
-            &'a gherkin::Feature,
-            Option<&'a gherkin::Rule>,
-            &'a gherkin::Scenario,
-            Option<&'a mut W>,
-        ) -> LocalBoxFuture<'a, ()>
-        + 'static,
-{
-    /// If `max` is [`Some`] number of concurrently executed [`Scenario`]s will
-    /// be limited.
+    /// Sets a callback, executed on each [`Scenario`] once any of its
+    /// [`Step`]s or hooks fails.
+    ///
+    /// See [`runner::Basic::on_scenario_failed()`] for more details.
     ///
     /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
     #[must_use]
-    pub fn max_concurrent_scenarios(
+    pub fn on_scenario_failed(
         mut self,
-        max: impl Into<Option<usize>>,
+        func: runner::basic::ScenarioOutcomeFn,
     ) -> Self {
-        self.runner = self.runner.max_concurrent_scenarios(max);
+        self.runner = self.runner.on_scenario_failed(func);
         self
     }
 
-    /// Function determining whether a [`Scenario`] is [`Concurrent`] or
-    /// a [`Serial`] one.
+    /// Executes the [`Scenario`]s matching the given `selector` once as a
+    /// warm-up before the actual run, discarding their outcomes.
     ///
-    /// [`Concurrent`]: ScenarioType::Concurrent
-    /// [`Serial`]: ScenarioType::Serial
+    /// Useful for timing-sensitive [`Scenario`]s, so JIT or caching effects
+    /// don't skew the measured run.
+    ///
+    /// If any warm-up [`Scenario`] fails, the run is aborted, reporting the
+    /// failure as the one of the [`GlobalHookType::WarmUp`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::convert::Infallible;
+    /// #
+    /// # use async_trait::async_trait;
+    /// # use cucumber::WorldInit;
+    /// #
+    /// # #[derive(Debug, WorldInit)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[async_trait(?Send)]
+    /// # impl cucumber::World for MyWorld {
+    /// #     type Error = Infallible;
+    /// #
+    /// #     async fn new() -> Result<Self, Self::Error> {
+    /// #         Ok(Self)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .warmup(|_, _, sc| sc.tags.iter().any(|t| t == "benchmark"))
+    ///     .run("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`GlobalHookType::WarmUp`]: event::GlobalHookType::WarmUp
     /// [`Scenario`]: gherkin::Scenario
     #[must_use]
-    pub fn which_scenario<Which>(
-        self,
-        func: Which,
-    ) -> Cucumber<W, P, I, runner::Basic<W, Which, B, A>, Wr, Cli>
-    where
-        Which: Fn(
-                &gherkin::Feature,
-                Option<&gherkin::Rule>,
-                &gherkin::Scenario,
-            ) -> ScenarioType
-            + 'static,
-    {
-        let Self {
-            parser,
-            runner,
-            writer,
-            cli,
-            ..
-        } = self;
-        Cucumber {
-            parser,
-            runner: runner.which_scenario(func),
-            writer,
-            cli,
-            _world: PhantomData,
-            _parser_input: PhantomData,
-        }
+    pub fn warmup(mut self, selector: runner::basic::WarmUpFn) -> Self {
+        self.runner = self.runner.warmup(selector);
+        self
     }
 
-    /// Sets a hook, executed on each [`Scenario`] before running all its
-    /// [`Step`]s, including [`Background`] ones.
+    /// Provides each [`Scenario`] with its own working directory, derived by
+    /// the given `func` and exposed to [`Step`]s via
+    /// [`step::Context::working_dir`].
+    ///
+    /// The process-wide current directory is never changed. See
+    /// [`runner::Basic::working_dir()`] for more details.
     ///
-    /// [`Background`]: gherkin::Background
     /// [`Scenario`]: gherkin::Scenario
     /// [`Step`]: gherkin::Step
     #[must_use]
-    pub fn before<Before>(
-        self,
-        func: Before,
-    ) -> Cucumber<W, P, I, runner::Basic<W, F, Before, A>, Wr, Cli>
-    where
-        Before: for<'a> Fn(
-                &'a gherkin::Feature,
-                Option<&'a gherkin::Rule>,
-                &'a gherkin::Scenario,
-                &'a mut W,
-            ) -> LocalBoxFuture<'a, ()>
-            + 'static,
-    {
-        let Self {
-            parser,
-            runner,
-            writer,
-            cli,
-            ..
-        } = self;
-        Cucumber {
-            parser,
-            runner: runner.before(func),
-            writer,
-            cli,
-            _world: PhantomData,
-            _parser_input: PhantomData,
-        }
+    pub fn working_dir(mut self, func: runner::basic::WorkingDirFn) -> Self {
+        self.runner = self.runner.working_dir(func);
+        self
     }
 
-    /// Sets a hook, executed on each [`Scenario`] after running all its
-    /// [`Step`]s, even after [`Skipped`] of [`Failed`] [`Step`]s.
+    /// Provides each [`Scenario`] with its own temporary working directory,
+    /// exposed to [`Step`]s via [`step::Context::working_dir`] and removed once
+    /// the [`Scenario`] finishes.
     ///
-    /// Last `World` argument is supplied to the function, in case it was
-    /// initialized before by running [`before`] hook or any non-failed
-    /// [`Step`]. In case the last [`Scenario`]'s [`Step`] failed, we want to
-    /// return event with an exact `World` state. Also, we don't want to impose
-    /// additional [`Clone`] bounds on `World`, so the only option left is to
-    /// pass [`None`] to the function.
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::{convert::Infallible, process::Command};
+    /// #
+    /// # use async_trait::async_trait;
+    /// # use cucumber::{given, step, WorldInit};
+    /// #
+    /// # #[derive(Debug, WorldInit)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[async_trait(?Send)]
+    /// # impl cucumber::World for MyWorld {
+    /// #     type Error = Infallible;
+    /// #
+    /// #     async fn new() -> Result<Self, Self::Error> {
+    /// #         Ok(Self)
+    /// #     }
+    /// # }
+    /// #
+    /// #[given("a clean directory")]
+    /// fn clean_dir(_: &mut MyWorld, #[step] ctx: &step::Context) {
+    ///     let dir = ctx.working_dir.as_ref().unwrap();
+    ///     Command::new("git").arg("init").current_dir(dir).status().unwrap();
+    /// }
     ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .temp_working_dir()
+    ///     .run_and_exit("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
     ///
-    /// [`before`]: Self::before()
-    /// [`Failed`]: event::Step::Failed
     /// [`Scenario`]: gherkin::Scenario
-    /// [`Skipped`]: event::Step::Skipped
     /// [`Step`]: gherkin::Step
     #[must_use]
-    pub fn after<After>(
-        self,
-        func: After,
-    ) -> Cucumber<W, P, I, runner::Basic<W, F, B, After>, Wr, Cli>
+    pub fn temp_working_dir(mut self) -> Self {
+        self.runner = self.runner.temp_working_dir();
+        self
+    }
+
+    /// Resolves the effective [`RunConfig`] of this [`Cucumber`], merging its
+    /// builder settings with the CLI options.
+    ///
+    /// If no CLI options were provided via [`Cucumber::with_cli()`], they're
+    /// parsed from the command line and reused by the subsequent run.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::convert::Infallible;
+    /// #
+    /// # use async_trait::async_trait;
+    /// # use cucumber::{cli, WorldInit};
+    /// # use structopt::StructOpt as _;
+    /// #
+    /// # #[derive(Debug, WorldInit)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[async_trait(?Send)]
+    /// # impl cucumber::World for MyWorld {
+    /// #     type Error = Infallible;
+    /// #
+    /// #     async fn new() -> Result<Self, Self::Error> {
+    /// #         Ok(Self)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let cli = cli::Opts::<_, _, _>::from_iter(&["test", "-c", "4"]);
+    /// let mut cucumber = MyWorld::cucumber()
+    ///     .max_concurrent_scenarios(16)
+    ///     .with_cli(cli);
+    ///
+    /// let config = cucumber.run_config();
+    /// assert_eq!(config.max_concurrent_scenarios, Some(4));
+    ///
+    /// cucumber.run("tests/features/readme").await;
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn run_config(&mut self) -> RunConfig
     where
-        After: for<'a> Fn(
-                &'a gherkin::Feature,
-                Option<&'a gherkin::Rule>,
-                &'a gherkin::Scenario,
-                Option<&'a mut W>,
-            ) -> LocalBoxFuture<'a, ()>
-            + 'static,
+        Cli: StructOptInternal,
     {
-        let Self {
-            parser,
-            runner,
-            writer,
-            cli,
-            ..
-        } = self;
-        Cucumber {
-            parser,
-            runner: runner.after(func),
-            writer,
-            cli,
-            _world: PhantomData,
-            _parser_input: PhantomData,
+        let cli = self
+            .cli
+            .get_or_insert_with(cli::Opts::<_, _, _, _>::from_args_and_config);
+        RunConfig {
+            name_filter: cli
+                .re_filter
+                .as_ref()
+                .or(self.options.name_filter.as_ref())
+                .map(|re| re.as_str().to_owned()),
+            tags_filter: cli
+                .tags_filter
+                .as_ref()
+                .or(self.options.tags_filter.as_ref())
+                .map(ToString::to_string),
+            path_filter: cli.path_filter.as_ref().map(|g| g.glob().to_owned()),
+            tag_inheritance: self.options.tag_inheritance,
+            max_concurrent_scenarios: if self.runner.pauses_on_failure() {
+                Some(1)
+            } else {
+                cli.runner.concurrency.or_else(|| self.runner.concurrency())
+            },
+            retries: cli
+                .runner
+                .retries
+                .unwrap_or_else(|| self.runner.max_retries()),
+            retry_after: self.runner.retry_delay(),
+            shuffle_seed: cli
+                .runner
+                .shuffle_seed
+                .or_else(|| self.runner.shuffle_seed()),
+            fail_fast: cli.runner.fail_fast || self.runner.fails_fast(),
+            dry_run: cli.runner.dry_run || self.runner.is_dry_run(),
+            step_timeout: self.runner.step_time_limit(),
+            scenario_timeout: self.runner.scenario_time_limit(),
+            run_timeout: self.runner.run_time_limit(),
+            exit_codes: self.options.exit_codes,
+        }
+    }
+
+    /// Makes [`Cucumber`] pause on each [`Failed`] [`Step`] or hook until the
+    /// user presses Enter, keeping the failed `World` alive for inspection.
+    ///
+    /// Entering `q` aborts the run instead, as if [failing fast][1]. While
+    /// paused, timeouts are suspended. [`Scenario`]s are executed one at a
+    /// time for the failure to be printed before pausing, regardless of a
+    /// `--concurrency` CLI option. Pausing only happens if the standard input
+    /// is a TTY, so this is no-op in CI.
+    ///
+    /// See [`runner::Basic::pause_on_failure()`] for reading the input from
+    /// elsewhere.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::convert::Infallible;
+    /// #
+    /// # use async_trait::async_trait;
+    /// # use cucumber::WorldInit;
+    /// #
+    /// # #[derive(Debug, WorldInit)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[async_trait(?Send)]
+    /// # impl cucumber::World for MyWorld {
+    /// #     type Error = Infallible;
+    /// #
+    /// #     async fn new() -> Result<Self, Self::Error> {
+    /// #         Ok(Self)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .pause_on_failure()
+    ///     .run("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [1]: Cucumber::fail_fast()
+    /// [`Failed`]: event::Step::Failed
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn pause_on_failure(mut self) -> Self {
+        if atty::is(atty::Stream::Stdin) {
+            self.runner = self
+                .runner
+                .pause_on_failure(io::BufReader::new(io::stdin()));
         }
+        self
     }
 
     /// Replaces [`Collection`] of [`Step`]s.
@@ -1186,7 +2579,8 @@ where
     /// # Panics
     ///
     /// If encountered errors while parsing [`Feature`]s or at least one
-    /// [`Step`] [`Failed`].
+    /// [`Step`] [`Failed`], unless [`Cucumber::exit_codes()`] were specified,
+    /// in which case the process exits with the corresponding code instead.
     ///
     /// [`Failed`]: crate::event::Step::Failed
     /// [`Feature`]: gherkin::Feature
@@ -1203,7 +2597,8 @@ where
     /// # Panics
     ///
     /// If encountered errors while parsing [`Feature`]s or at least one
-    /// [`Step`] [`Failed`].
+    /// [`Step`] [`Failed`], unless [`Cucumber::exit_codes()`] were specified,
+    /// in which case the process exits with the corresponding code instead.
     ///
     /// # Example
     ///
@@ -1269,9 +2664,53 @@ where
             ) -> bool
             + 'static,
     {
+        let exit_codes = self.options.exit_codes;
+        let fail_if = self.options.fail_if;
+        let started = Instant::now();
         let writer = self.filter_run(input, filter).await;
-        if writer.execution_has_failed() {
-            let mut msg = Vec::with_capacity(3);
+
+        let failed = fail_if.map(|predicate| {
+            let (passed, failed, undefined, skipped) = (
+                writer.passed_steps(),
+                writer.failed_steps(),
+                writer.skipped_steps(),
+                writer.not_executed_steps(),
+            );
+            predicate(&RunSummary {
+                total_steps: passed + failed + undefined + skipped,
+                passed_steps: passed,
+                failed_steps: failed,
+                undefined_steps: undefined,
+                skipped_steps: skipped,
+                parsing_errors: writer.parsing_errors(),
+                hook_errors: writer.hook_errors(),
+                timed_out_scenarios: writer.timed_out_scenarios(),
+                duration: started.elapsed(),
+            })
+        });
+
+        if let Some(codes) = exit_codes {
+            // Scenarios interrupted by the run timeout count as failures.
+            let selected = codes.select(
+                writer.failed_steps() + writer.timed_out_scenarios(),
+                writer.parsing_errors(),
+                writer.hook_errors(),
+                writer.skipped_steps(),
+            );
+            let code = match (failed, selected) {
+                (Some(false), _) => 0,
+                (Some(true), 0) => 1,
+                (_, code) => code,
+            };
+            if code != 0 {
+                #[allow(clippy::exit)]
+                process::exit(code);
+            }
+            return;
+        }
+
+        if failed.unwrap_or_else(|| writer.execution_has_failed()) {
+            let mut msg = Vec::with_capacity(4);
 
             let failed_steps = writer.failed_steps();
             if failed_steps > 0 {
@@ -1300,7 +2739,719 @@ where
                 ));
             }
 
+            let timed_out = writer.timed_out_scenarios();
+            if timed_out > 0 {
+                msg.push(format!(
+                    "{} scenario{} interrupted: run timeout",
+                    timed_out,
+                    (timed_out > 1).then(|| "s").unwrap_or_default(),
+                ));
+            }
+
+            if msg.is_empty() {
+                msg.push("`Cucumber::fail_if()` predicate matched".to_owned());
+            }
+
             panic!("{}", msg.join(", "));
         }
     }
 }
+
+/// Options of running [`Scenario`]s by a [`Cucumber`], not depending on its
+/// [`Parser`], [`Runner`] or [`Writer`].
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Default)]
+struct Options {
+    /// [`tag::Inheritance`] policy of computing effective tags of
+    /// [`Scenario`]s for filtering.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    tag_inheritance: tag::Inheritance,
+
+    /// [`ExitCodes`] to exit the process with, if any.
+    ///
+    /// If [`None`], then [`Cucumber::run_and_exit()`] panics on failure.
+    exit_codes: Option<ExitCodes>,
+
+    /// [`ScenarioDisplayFn`] to render [`Scenario`] names with, if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    scenario_display: Option<ScenarioDisplayFn>,
+
+    /// [`FailIfFn`] deciding whether a run has failed, if any.
+    ///
+    /// If [`None`], then any failure fails the run.
+    fail_if: Option<FailIfFn>,
+
+    /// [`tag::Expression`] to filter [`Scenario`]s with, if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    tags_filter: Option<tag::Expression>,
+
+    /// Indicator whether filtered out [`Scenario`]s should be reported as
+    /// [skipped][1] rather than omitted entirely.
+    ///
+    /// [1]: event::SkipReason::FilteredOut
+    /// [`Scenario`]: gherkin::Scenario
+    skip_filtered_out: bool,
+
+    /// [`Regex`] to filter [`Scenario`]s with by their name, if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    name_filter: Option<Regex>,
+
+    /// Indicator whether the [`Options::name_filter`] ignores case.
+    name_ignore_case: bool,
+
+    /// Indicator whether the [`Options::name_filter`] matches [`Feature`]
+    /// names too.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    name_match_feature: bool,
+}
+
+/// [`Scenario`]s with their names rendered by a [`ScenarioDisplayFn`] for
+/// passing them to a [`Writer`], while a [`Runner`] keeps the original ones.
+///
+/// [`Scenario`]: gherkin::Scenario
+struct RenderedScenarios {
+    /// [`ScenarioDisplayFn`] to render names with.
+    display: ScenarioDisplayFn,
+
+    /// Rendered [`Scenario`]s by the original ones, so every event of a
+    /// [`Scenario`] refers to the same rendered one.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    scenarios: HashMap<Arc<gherkin::Scenario>, Arc<gherkin::Scenario>>,
+}
+
+impl RenderedScenarios {
+    /// Creates new [`RenderedScenarios`] rendering names with the given
+    /// [`ScenarioDisplayFn`].
+    fn new(display: ScenarioDisplayFn) -> Self {
+        Self {
+            display,
+            scenarios: HashMap::new(),
+        }
+    }
+
+    /// Replaces the [`Scenario`] of the given event (if any) with the one
+    /// having its name rendered.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn render<W>(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+    ) -> parser::Result<Event<event::Cucumber<W>>> {
+        use event::{Cucumber, Feature, Rule};
+
+        let (ev, meta) = match ev {
+            Ok(ev) => ev.split(),
+            Err(e) => return Err(e),
+        };
+        Ok(meta.wrap(match ev {
+            Cucumber::Feature(f, Feature::Scenario(sc, ev)) => {
+                let sc = self.rendered(&f, None, sc, &ev);
+                Cucumber::Feature(f, Feature::Scenario(sc, ev))
+            }
+            Cucumber::Feature(f, Feature::Rule(r, Rule::Scenario(sc, ev))) => {
+                let sc = self.rendered(&f, Some(&r), sc, &ev);
+                Cucumber::Feature(f, Feature::Rule(r, Rule::Scenario(sc, ev)))
+            }
+            ev @ (Cucumber::Started
+            | Cucumber::ParsingFinished { .. }
+            | Cucumber::Feature(
+                _,
+                Feature::Started
+                | Feature::Finished
+                | Feature::Rule(_, Rule::Started | Rule::Finished),
+            )
+            | Cucumber::GlobalHookFailed(..)
+            | Cucumber::Finished) => ev,
+        }))
+    }
+
+    /// Returns the rendered [`Scenario`] for the given original one, forgetting
+    /// it once the [`Scenario`] is finished.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn rendered<W>(
+        &mut self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: Arc<gherkin::Scenario>,
+        ev: &event::Scenario<W>,
+    ) -> Arc<gherkin::Scenario> {
+        let display = self.display;
+        let rendered = if matches!(ev, event::Scenario::Finished) {
+            self.scenarios.remove(&scenario)
+        } else {
+            self.scenarios.get(&scenario).cloned()
+        };
+        rendered.unwrap_or_else(|| {
+            let sc = Arc::new(gherkin::Scenario {
+                name: display(feature, rule, &scenario),
+                ..(*scenario).clone()
+            });
+            if !matches!(ev, event::Scenario::Finished) {
+                drop(self.scenarios.insert(scenario, Arc::clone(&sc)));
+            }
+            sc
+        })
+    }
+}
+
+/// Makes the provided name filter [`Regex`] case-insensitive, if required.
+fn name_regex(re: Option<Regex>, ignore_case: bool) -> Option<Regex> {
+    re.map(|re| {
+        if ignore_case {
+            // Recompiling an already valid `Regex` cannot fail.
+            RegexBuilder::new(re.as_str())
+                .case_insensitive(true)
+                .build()
+                .unwrap_or(re)
+        } else {
+            re
+        }
+    })
+}
+
+/// Checks whether the provided [`Scenario`]'s name (or its [`Feature`]'s one,
+/// if `match_feature` is `true`) matches the given [`Regex`].
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Scenario`]: gherkin::Scenario
+fn matches_name(
+    re: &Regex,
+    feature: &gherkin::Feature,
+    scenario: &gherkin::Scenario,
+    match_feature: bool,
+) -> bool {
+    re.is_match(&scenario.name) || (match_feature && re.is_match(&feature.name))
+}
+
+/// Splits the provided [`Feature`] by the given `filter` into the one with
+/// the kept [`Scenario`]s and the one with the filtered out [`Scenario`]s.
+///
+/// [`Rule`]s having all their [`Scenario`]s filtered out are omitted from the
+/// first [`Feature`], and ones having none of them are omitted from the second.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+fn split_filtered_out<F>(
+    mut feature: gherkin::Feature,
+    filter: F,
+) -> (gherkin::Feature, gherkin::Feature)
+where
+    F: Fn(
+        &gherkin::Feature,
+        Option<&gherkin::Rule>,
+        &gherkin::Scenario,
+    ) -> bool,
+{
+    let feat_scenarios = mem::take(&mut feature.scenarios);
+    let (kept, feat_skipped): (Vec<_>, Vec<_>) = feat_scenarios
+        .into_iter()
+        .partition(|s| filter(&feature, None, s));
+    feature.scenarios = kept;
+
+    let mut skipped_rules = Vec::new();
+    let rules = mem::take(&mut feature.rules);
+    feature.rules = rules
+        .into_iter()
+        .filter_map(|mut r| {
+            let rule_had_scenarios = !r.scenarios.is_empty();
+            let rule_scenarios = mem::take(&mut r.scenarios);
+            let (rule_kept, rule_skipped): (Vec<_>, Vec<_>) = rule_scenarios
+                .into_iter()
+                .partition(|s| filter(&feature, Some(&r), s));
+            if !rule_skipped.is_empty() {
+                skipped_rules.push(gherkin::Rule {
+                    scenarios: rule_skipped,
+                    ..r.clone()
+                });
+            }
+            r.scenarios = rule_kept;
+            (!rule_had_scenarios || !r.scenarios.is_empty()).then(|| r)
+        })
+        .collect();
+
+    let skeleton = gherkin::Feature {
+        scenarios: feat_skipped,
+        rules: skipped_rules,
+        ..feature.clone()
+    };
+    (feature, skeleton)
+}
+
+/// Checks whether the provided [`Feature`]'s path matches the given
+/// [`GlobMatcher`], either relatively to the current directory or as it is.
+///
+/// [`Feature`]s without a path never match, while parsing errors and any
+/// [`Feature`]s always match in case no [`GlobMatcher`] is provided.
+///
+/// [`Feature`]: gherkin::Feature
+fn matches_path(
+    matcher: Option<&GlobMatcher>,
+    feature: &parser::Result<gherkin::Feature>,
+) -> bool {
+    let (matcher, feature) = match (matcher, feature) {
+        (Some(m), Ok(f)) => (m, f),
+        (None, _) | (_, Err(_)) => return true,
+    };
+    feature.path.as_ref().map_or(false, |path| {
+        env::current_dir()
+            .ok()
+            .and_then(|dir| path.strip_prefix(dir).ok())
+            .map_or(false, |rel| matcher.is_match(rel))
+            || matcher.is_match(path)
+    })
+}
+
+/// [`Scenario`]s filtered out of a run, to be reported as [skipped][1] once
+/// their [`Feature`] or [`Rule`] is finished.
+///
+/// [1]: event::SkipReason::FilteredOut
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Debug, Default)]
+struct FilteredOut {
+    /// [`Feature`]s containing only the filtered out [`Scenario`]s (and
+    /// [`Rule`]s).
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    skeletons: Vec<gherkin::Feature>,
+
+    /// Number of [`Feature`]s having all their [`Scenario`]s filtered out.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    features: usize,
+
+    /// Number of [`Rule`]s having all their [`Scenario`]s filtered out.
+    ///
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    rules: usize,
+
+    /// Number of filtered out [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    scenarios: usize,
+}
+
+impl FilteredOut {
+    /// Remembers the given `skeleton` of a [`Feature`], whose `executed`
+    /// [`Rule`]s are passed to a [`Runner`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    fn insert(
+        &mut self,
+        skeleton: gherkin::Feature,
+        executed: &[gherkin::Rule],
+        fully_filtered_out: bool,
+    ) {
+        let scenarios = skeleton.count_scenarios();
+        if scenarios == 0 {
+            return;
+        }
+
+        self.scenarios += scenarios;
+        if fully_filtered_out {
+            self.features += 1;
+        }
+        self.rules += skeleton
+            .rules
+            .iter()
+            .filter(|r| !executed.iter().any(|e| e.position == r.position))
+            .count();
+        self.skeletons.push(skeleton);
+    }
+
+    /// Returns the events to be passed to a [`Writer`] instead of the given
+    /// one, accounting the filtered out [`Scenario`]s and reporting them as
+    /// skipped right before their [`Feature`] or [`Rule`] is finished.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    fn inject<W>(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+    ) -> Vec<parser::Result<Event<event::Cucumber<W>>>> {
+        use event::{Cucumber, Feature, Rule};
+
+        let (cucumber_ev, meta) = match ev {
+            Ok(e) => e.split(),
+            Err(e) => return vec![Err(e)],
+        };
+
+        let mut events = Vec::new();
+        match cucumber_ev {
+            Cucumber::ParsingFinished {
+                features,
+                rules,
+                scenarios,
+                steps,
+                parser_errors,
+                shuffle_seed,
+                dry_run,
+                tag_inheritance,
+            } => events.push(Cucumber::ParsingFinished {
+                features: features + self.features,
+                rules: rules + self.rules,
+                scenarios: scenarios + self.scenarios,
+                steps,
+                parser_errors,
+                shuffle_seed,
+                dry_run,
+                tag_inheritance,
+            }),
+            Cucumber::Feature(f, Feature::Rule(r, Rule::Finished)) => {
+                let skipped = self
+                    .skeleton_mut(&f)
+                    .and_then(|skeleton| {
+                        let at = skeleton
+                            .rules
+                            .iter()
+                            .position(|sr| sr.position == r.position)?;
+                        Some(skeleton.rules.remove(at).scenarios)
+                    })
+                    .unwrap_or_default();
+                for sc in skipped {
+                    Self::skip(&mut events, &f, Some(&r), sc);
+                }
+                events.push(Cucumber::rule_finished(f, r));
+            }
+            Cucumber::Feature(f, Feature::Finished) => {
+                let at = self.skeletons.iter().position(|s| same(s, &f));
+                if let Some(skeleton) = at.map(|i| self.skeletons.remove(i)) {
+                    Self::skip_feature(&mut events, &f, skeleton);
+                }
+                events.push(Cucumber::feature_finished(f));
+            }
+            Cucumber::Finished => {
+                for skeleton in mem::take(&mut self.skeletons) {
+                    let f = Arc::new(skeleton.clone());
+                    events.push(Cucumber::feature_started(Arc::clone(&f)));
+                    Self::skip_feature(&mut events, &f, skeleton);
+                    events.push(Cucumber::feature_finished(f));
+                }
+                events.push(Cucumber::Finished);
+            }
+            other @ (Cucumber::Started
+            | Cucumber::Feature(..)
+            | Cucumber::GlobalHookFailed(..)) => events.push(other),
+        }
+
+        let last = events.pop().map(|last| meta.wrap(last));
+        events
+            .into_iter()
+            .map(Event::new)
+            .chain(last)
+            .map(Ok)
+            .collect()
+    }
+
+    /// Returns the remembered skeleton of the given [`Feature`], if any.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    fn skeleton_mut(
+        &mut self,
+        feature: &gherkin::Feature,
+    ) -> Option<&mut gherkin::Feature> {
+        self.skeletons.iter_mut().find(|s| same(s, feature))
+    }
+
+    /// Pushes the events of all the [`Scenario`]s of the given `skeleton`
+    /// being skipped, along with its [`Rule`]s being started and finished.
+    ///
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    fn skip_feature<W>(
+        events: &mut Vec<event::Cucumber<W>>,
+        feature: &Arc<gherkin::Feature>,
+        skeleton: gherkin::Feature,
+    ) {
+        for sc in skeleton.scenarios {
+            Self::skip(events, feature, None, sc);
+        }
+        for mut rule in skeleton.rules {
+            let scenarios = mem::take(&mut rule.scenarios);
+            let r = Arc::new(rule);
+            events.push(event::Cucumber::rule_started(
+                Arc::clone(feature),
+                Arc::clone(&r),
+            ));
+            for sc in scenarios {
+                Self::skip(events, feature, Some(&r), sc);
+            }
+            events.push(event::Cucumber::rule_finished(Arc::clone(feature), r));
+        }
+    }
+
+    /// Pushes the events of the given [`Scenario`] being skipped.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn skip<W>(
+        events: &mut Vec<event::Cucumber<W>>,
+        feature: &Arc<gherkin::Feature>,
+        rule: Option<&Arc<gherkin::Rule>>,
+        scenario: gherkin::Scenario,
+    ) {
+        let scenario = Arc::new(scenario);
+        for ev in [
+            event::Scenario::Started,
+            event::Scenario::Skipped(event::SkipReason::FilteredOut),
+            event::Scenario::Finished,
+        ] {
+            events.push(event::Cucumber::scenario(
+                Arc::clone(feature),
+                rule.cloned(),
+                Arc::clone(&scenario),
+                ev,
+            ));
+        }
+    }
+}
+
+/// Checks whether the given [`Feature`]s are the same `.feature` file ones.
+///
+/// [`Feature`]: gherkin::Feature
+fn same(left: &gherkin::Feature, right: &gherkin::Feature) -> bool {
+    left.path == right.path && left.position == right.position
+}
+
+/// Process exit codes used by [`Cucumber::run_and_exit()`] for different
+/// outcome categories of a run.
+///
+/// When several categories occur at once, the code of the highest-priority one
+/// is used, in the following order: parsing errors, hook errors, failed
+/// [`Step`]s, undefined [`Step`]s. A category mapped to `0` is ignored.
+///
+/// [`Step`]: gherkin::Step
+#[cfg_attr(feature = "output-json", derive(serde::Serialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ExitCodes {
+    /// Exit code for failed [`Step`]s and [`Scenario`]s interrupted by the
+    /// [`Cucumber::run_timeout()`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub failures: i32,
+
+    /// Exit code for errors encountered while parsing [`Feature`]s.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    pub parse_errors: i32,
+
+    /// Exit code for failed [`Scenario`] hooks.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub hook_errors: i32,
+
+    /// Exit code for undefined [`Step`]s, which means that no matching
+    /// [`Step`] function was found for them.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub undefined: i32,
+}
+
+impl Default for ExitCodes {
+    /// Exits with `1` on any failure, ignoring undefined [`Step`]s.
+    ///
+    /// [`Step`]: gherkin::Step
+    fn default() -> Self {
+        Self {
+            failures: 1,
+            parse_errors: 1,
+            hook_errors: 1,
+            undefined: 0,
+        }
+    }
+}
+
+impl ExitCodes {
+    /// Selects the exit code of the highest-priority occurred outcome
+    /// category, returning `0` if none of the mapped categories occurred.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::ExitCodes;
+    /// #
+    /// let codes = ExitCodes {
+    ///     failures: 1,
+    ///     parse_errors: 2,
+    ///     hook_errors: 2,
+    ///     undefined: 3,
+    /// };
+    ///
+    /// assert_eq!(codes.select(0, 0, 0, 0), 0);
+    /// assert_eq!(codes.select(0, 0, 0, 5), 3);
+    /// assert_eq!(codes.select(2, 0, 0, 5), 1);
+    /// assert_eq!(codes.select(2, 1, 0, 5), 2);
+    /// ```
+    #[must_use]
+    pub fn select(
+        &self,
+        failed_steps: usize,
+        parsing_errors: usize,
+        hook_errors: usize,
+        skipped_steps: usize,
+    ) -> i32 {
+        [
+            (parsing_errors, self.parse_errors),
+            (hook_errors, self.hook_errors),
+            (failed_steps, self.failures),
+            (skipped_steps, self.undefined),
+        ]
+        .iter()
+        .find(|(occurred, code)| *occurred > 0 && *code != 0)
+        .map_or(0, |(_, code)| *code)
+    }
+}
+
+/// Aggregated outcome of a finished [`Cucumber`] run, passed to a
+/// [`FailIfFn`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct RunSummary {
+    /// Total number of [`Step`]s, either executed or not (sum of all the
+    /// other [`Step`] counters).
+    ///
+    /// [`Step`]: gherkin::Step
+    pub total_steps: usize,
+
+    /// Number of passed [`Step`]s.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub passed_steps: usize,
+
+    /// Number of failed [`Step`]s.
+    ///
+    /// During a [dry run][0], undefined [`Step`]s are counted as failed ones
+    /// too.
+    ///
+    /// [0]: Cucumber::dry_run()
+    /// [`Step`]: gherkin::Step
+    pub failed_steps: usize,
+
+    /// Number of undefined [`Step`]s, not matching any [`Step`] function.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub undefined_steps: usize,
+
+    /// Number of skipped [`Step`]s, not executed at all, as a previous
+    /// [`Step`] or hook of their [`Scenario`] has failed or is undefined, or
+    /// the whole [`Scenario`] was skipped.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub skipped_steps: usize,
+
+    /// Number of errors encountered while parsing [`Feature`]s.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    pub parsing_errors: usize,
+
+    /// Number of failed [`Scenario`] hooks.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub hook_errors: usize,
+
+    /// Number of [`Scenario`]s interrupted by the
+    /// [`Cucumber::run_timeout()`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub timed_out_scenarios: usize,
+
+    /// Total wall-clock duration of the run.
+    pub duration: Duration,
+}
+
+/// Effective configuration of a [`Cucumber`] run, merged from its builder
+/// settings and CLI options.
+///
+/// Intended for making runs self-documenting, e.g. by outputting it along
+/// with a report.
+///
+/// Returned by [`Cucumber::run_config()`].
+#[cfg_attr(feature = "output-json", derive(serde::Serialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RunConfig {
+    /// Regex filtering [`Scenario`]s by their name, if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub name_filter: Option<String>,
+
+    /// Tag expression filtering [`Scenario`]s, if any, in its textual
+    /// representation.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub tags_filter: Option<String>,
+
+    /// Glob pattern filtering [`Feature`]s by their file path, if any.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    pub path_filter: Option<String>,
+
+    /// [`tag::Inheritance`] policy used for filtering by tags.
+    pub tag_inheritance: tag::Inheritance,
+
+    /// Maximum number of concurrently executed [`Scenario`]s, if limited.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub max_concurrent_scenarios: Option<usize>,
+
+    /// Number of times a failed [`Scenario`] is retried.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub retries: usize,
+
+    /// Delay before retrying a failed [`Scenario`], if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub retry_after: Option<Duration>,
+
+    /// Seed [`Scenario`]s of each [`Feature`] are shuffled with, if shuffling
+    /// is enabled.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    pub shuffle_seed: Option<u64>,
+
+    /// Indicator whether no more [`Scenario`]s are started once any [`Step`]
+    /// or hook fails.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub fail_fast: bool,
+
+    /// Indicator whether [`Step`]s are only matched without being executed.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub dry_run: bool,
+
+    /// Maximum duration of a single [`Step`], if limited.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub step_timeout: Option<Duration>,
+
+    /// Maximum duration of a whole [`Scenario`], if limited.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub scenario_timeout: Option<Duration>,
+
+    /// Maximum duration of the whole run, if limited.
+    pub run_timeout: Option<Duration>,
+
+    /// [`ExitCodes`] used by [`Cucumber::run_and_exit()`], if configured.
+    pub exit_codes: Option<ExitCodes>,
+}
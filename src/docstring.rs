@@ -0,0 +1,271 @@
+// Copyright (c) 2018-2021  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Typed deserialization of [`gherkin::Step`] [docstrings][0] via [`serde`].
+//!
+//! [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+
+use std::borrow::Cow;
+
+use derive_more::{Display, Error};
+use sealed::sealed;
+use serde::de::DeserializeOwned;
+
+/// Helper methods to deserialize a [docstring][0] of a [`gherkin::Step`] into
+/// user types.
+///
+/// Format of the [docstring][0] is picked by its media type, specified right
+/// after the opening delimiter:
+/// ```gherkin
+/// Given the user
+///   """yaml
+///   name: Alice
+///   age: 30
+///   """
+/// ```
+///
+/// Lines of the [docstring][0] are counted starting from 1 at its first line
+/// after the media type (if any).
+///
+/// [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+#[sealed]
+pub trait StepExt {
+    /// Returns the media type of the [docstring][0] of this [`gherkin::Step`],
+    /// if it has one.
+    ///
+    /// [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+    #[must_use]
+    fn docstring_media_type(&self) -> Option<&str>;
+
+    /// Returns the raw content of the [docstring][0] of this [`gherkin::Step`],
+    /// without its media type.
+    ///
+    /// # Errors
+    ///
+    /// If this [`gherkin::Step`] has no [docstring][0].
+    ///
+    /// [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+    fn docstring_content(&self) -> Result<Cow<'_, str>, Error>;
+
+    /// Deserializes the [docstring][0] of this [`gherkin::Step`] into a `T`
+    /// according to its media type, being JSON if none is specified.
+    ///
+    /// # Errors
+    ///
+    /// - If this [`gherkin::Step`] has no [docstring][0].
+    /// - If the media type of the [docstring][0] is neither JSON nor YAML
+    ///   (the latter requires the `serde-docstring-yaml` Cargo feature).
+    /// - If the [docstring][0] cannot be deserialized into a `T`.
+    ///
+    /// [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+    fn docstring_as<T: DeserializeOwned>(&self) -> Result<T, Error>;
+
+    /// Deserializes the [docstring][0] of this [`gherkin::Step`] from JSON into
+    /// a `T`, ignoring its media type.
+    ///
+    /// # Errors
+    ///
+    /// - If this [`gherkin::Step`] has no [docstring][0].
+    /// - If the [docstring][0] cannot be deserialized into a `T`.
+    ///
+    /// [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+    fn docstring_as_json<T: DeserializeOwned>(&self) -> Result<T, Error>;
+
+    /// Deserializes the [docstring][0] of this [`gherkin::Step`] from YAML into
+    /// a `T`, ignoring its media type.
+    ///
+    /// # Errors
+    ///
+    /// - If this [`gherkin::Step`] has no [docstring][0].
+    /// - If the [docstring][0] cannot be deserialized into a `T`.
+    ///
+    /// [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+    #[cfg(feature = "serde-docstring-yaml")]
+    fn docstring_as_yaml<T: DeserializeOwned>(&self) -> Result<T, Error>;
+}
+
+#[sealed]
+impl StepExt for gherkin::Step {
+    fn docstring_media_type(&self) -> Option<&str> {
+        split_docstring(self).ok().and_then(|(ty, _)| ty)
+    }
+
+    fn docstring_content(&self) -> Result<Cow<'_, str>, Error> {
+        split_docstring(self).map(|(_, content)| content)
+    }
+
+    fn docstring_as<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        let (media_type, content) = split_docstring(self)?;
+        match media_type {
+            None => from_json(&content),
+            Some(ty) if is_json(ty) => from_json(&content),
+            #[cfg(feature = "serde-docstring-yaml")]
+            Some(ty) if is_yaml(ty) => from_yaml(&content),
+            Some(ty) => Err(Error::UnsupportedMediaType(ty.to_owned())),
+        }
+    }
+
+    fn docstring_as_json<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        from_json(&split_docstring(self)?.1)
+    }
+
+    #[cfg(feature = "serde-docstring-yaml")]
+    fn docstring_as_yaml<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        from_yaml(&split_docstring(self)?.1)
+    }
+}
+
+/// Splits the [docstring][0] of the given [`gherkin::Step`] into its media
+/// type (if any) and content.
+///
+/// [`gherkin`] keeps the media type as the first line of a [docstring][0], so
+/// it's the one consisting of a single word followed by some other lines. As
+/// such [docstring][0] isn't dedented by [`gherkin`], its content is dedented
+/// here.
+///
+/// # Errors
+///
+/// If the [`gherkin::Step`] has no [docstring][0].
+///
+/// [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+fn split_docstring(
+    step: &gherkin::Step,
+) -> Result<(Option<&str>, Cow<'_, str>), Error> {
+    let doc = step.docstring.as_deref().ok_or(Error::NoDocString)?;
+    Ok(match doc.split_once('\n') {
+        Some(("", content)) => (None, content.into()),
+        Some((ty, content))
+            if ty
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "+-./_".contains(c)) =>
+        {
+            (Some(ty), dedent(content).into())
+        }
+        _ => (None, doc.into()),
+    })
+}
+
+/// Removes the common leading whitespace of all the non-blank lines of the
+/// given text.
+fn dedent(text: &str) -> String {
+    let is_blank = |l: &str| l.trim().is_empty();
+    let indent = text
+        .lines()
+        .filter(|l| !is_blank(l))
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or_default();
+
+    text.lines()
+        .map(|l| if is_blank(l) { "" } else { &l[indent..] })
+        .fold(String::with_capacity(text.len()), |mut out, l| {
+            out.push_str(l);
+            out.push('\n');
+            out
+        })
+}
+
+/// Checks whether the given media type denotes JSON.
+fn is_json(media_type: &str) -> bool {
+    let ty = media_type.to_ascii_lowercase();
+    ty == "json" || ty == "application/json" || ty.ends_with("+json")
+}
+
+/// Checks whether the given media type denotes YAML.
+#[cfg(feature = "serde-docstring-yaml")]
+fn is_yaml(media_type: &str) -> bool {
+    let ty = media_type.to_ascii_lowercase();
+    [
+        "yaml",
+        "yml",
+        "application/yaml",
+        "application/x-yaml",
+        "text/yaml",
+    ]
+    .contains(&ty.as_str())
+        || ty.ends_with("+yaml")
+}
+
+/// Deserializes a `T` from the given JSON [docstring][0] content.
+///
+/// # Errors
+///
+/// If the content cannot be deserialized into a `T`.
+///
+/// [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+fn from_json<T: DeserializeOwned>(content: &str) -> Result<T, Error> {
+    serde_json::from_str(content).map_err(|source| Error::Json {
+        line: (source.line() > 0).then(|| source.line()),
+        source,
+    })
+}
+
+/// Deserializes a `T` from the given YAML [docstring][0] content.
+///
+/// # Errors
+///
+/// If the content cannot be deserialized into a `T`.
+///
+/// [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+#[cfg(feature = "serde-docstring-yaml")]
+fn from_yaml<T: DeserializeOwned>(content: &str) -> Result<T, Error> {
+    serde_yaml::from_str(content).map_err(|source| Error::Yaml {
+        line: source.location().map(|l| l.line()),
+        source,
+    })
+}
+
+/// Error of deserializing a [docstring][0] of a [`gherkin::Step`].
+///
+/// [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+#[derive(Debug, Display, Error)]
+pub enum Error {
+    /// [`gherkin::Step`] has no [docstring][0].
+    ///
+    /// [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+    #[display(fmt = "Step has no docstring")]
+    NoDocString,
+
+    /// Media type of a [docstring][0] is neither JSON nor YAML (the latter
+    /// requires the `serde-docstring-yaml` Cargo feature).
+    ///
+    /// [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+    #[display(fmt = "Docstring media type `{}` is not supported", _0)]
+    UnsupportedMediaType(#[error(not(source))] String),
+
+    /// [Docstring][0] cannot be deserialized from JSON.
+    ///
+    /// [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+    #[display(fmt = "Failed to deserialize JSON docstring: {}", source)]
+    Json {
+        /// Line of the [docstring][0] the failure occurred at, if known.
+        ///
+        /// [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+        line: Option<usize>,
+
+        /// Deserialization error.
+        source: serde_json::Error,
+    },
+
+    /// [Docstring][0] cannot be deserialized from YAML.
+    ///
+    /// [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+    #[cfg(feature = "serde-docstring-yaml")]
+    #[display(fmt = "Failed to deserialize YAML docstring: {}", source)]
+    Yaml {
+        /// Line of the [docstring][0] the failure occurred at, if known.
+        ///
+        /// [0]: https://cucumber.io/docs/gherkin/reference/#doc-strings
+        line: Option<usize>,
+
+        /// Deserialization error.
+        source: serde_yaml::Error,
+    },
+}
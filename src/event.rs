@@ -19,14 +19,14 @@
 //! [`Runner`]: crate::Runner
 //! [Cucumber]: https://cucumber.io
 
-use std::{any::Any, fmt, sync::Arc};
+use std::{any::Any, fmt, sync::Arc, time::Duration};
 
 #[cfg(feature = "timestamps")]
 use std::time::SystemTime;
 
 use derive_more::{AsRef, Deref, DerefMut, Display, Error, From};
 
-use crate::{step, writer::basic::coerce_error};
+use crate::{step, tag, writer::basic::coerce_error};
 
 /// Alias for a [`catch_unwind()`] error.
 ///
@@ -123,9 +123,66 @@ pub enum Cucumber<World> {
     /// [`Cucumber`] execution being started.
     Started,
 
+    /// All [`Feature`]s have been parsed and filtered, so their total numbers
+    /// are known before execution begins.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    ParsingFinished {
+        /// Number of parsed [`Feature`]s.
+        ///
+        /// [`Feature`]: gherkin::Feature
+        features: usize,
+
+        /// Number of parsed [`Rule`]s.
+        ///
+        /// [`Rule`]: gherkin::Rule
+        rules: usize,
+
+        /// Number of parsed [`Scenario`]s.
+        ///
+        /// [`Scenario`]: gherkin::Scenario
+        scenarios: usize,
+
+        /// Number of parsed [`Step`]s, including [`Background`] ones.
+        ///
+        /// [`Background`]: gherkin::Background
+        /// [`Step`]: gherkin::Step
+        steps: usize,
+
+        /// Number of happened [`Parser`] errors.
+        ///
+        /// [`Parser`]: crate::Parser
+        parser_errors: usize,
+
+        /// Seed the [`Scenario`]s are shuffled with, if shuffling is enabled.
+        ///
+        /// [`Scenario`]: gherkin::Scenario
+        shuffle_seed: Option<u64>,
+
+        /// Indicator whether this run is a dry run, only matching [`Step`]s
+        /// without executing them.
+        ///
+        /// [`Step`]: gherkin::Step
+        dry_run: bool,
+
+        /// [`tag::Inheritance`] policy of computing effective tags of
+        /// [`Scenario`]s in this run.
+        ///
+        /// [`Scenario`]: gherkin::Scenario
+        tag_inheritance: tag::Inheritance,
+    },
+
     /// [`Feature`] event.
     Feature(Arc<gherkin::Feature>, Feature<World>),
 
+    /// Hook, executed exactly once for the whole run, failed.
+    ///
+    /// If the [`GlobalHookType::BeforeAll`] hook or the
+    /// [`GlobalHookType::WarmUp`] fails, then no [`Scenario`] is executed.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    GlobalHookFailed(GlobalHookType, Info),
+
     /// [`Cucumber`] execution being finished.
     Finished,
 }
@@ -136,7 +193,29 @@ impl<World> Clone for Cucumber<World> {
     fn clone(&self) -> Self {
         match self {
             Self::Started => Self::Started,
+            Self::ParsingFinished {
+                features,
+                rules,
+                scenarios,
+                steps,
+                parser_errors,
+                shuffle_seed,
+                dry_run,
+                tag_inheritance,
+            } => Self::ParsingFinished {
+                features: *features,
+                rules: *rules,
+                scenarios: *scenarios,
+                steps: *steps,
+                parser_errors: *parser_errors,
+                shuffle_seed: *shuffle_seed,
+                dry_run: *dry_run,
+                tag_inheritance: *tag_inheritance,
+            },
             Self::Feature(f, ev) => Self::Feature(Arc::clone(f), ev.clone()),
+            Self::GlobalHookFailed(ty, info) => {
+                Self::GlobalHookFailed(*ty, Arc::clone(info))
+            }
             Self::Finished => Self::Finished,
         }
     }
@@ -286,6 +365,14 @@ pub enum Step<World> {
     /// [`step::Collection`]: crate::step::Collection
     Skipped,
 
+    /// [`Step`] matched a function in a [`step::Collection`], but wasn't
+    /// executed, as the run is a [dry-run][0] one.
+    ///
+    /// [0]: crate::Cucumber::dry_run()
+    /// [`Step`]: gherkin::Step
+    /// [`step::Collection`]: crate::step::Collection
+    Matched(regex::CaptureLocations),
+
     /// [`Step`] passed.
     ///
     /// [`Step`]: gherkin::Step
@@ -299,6 +386,23 @@ pub enum Step<World> {
         Option<Arc<World>>,
         StepError,
     ),
+
+    /// [Soft][0] [`Step`] failed, which is reported, but doesn't fail its
+    /// [`Scenario`], so the following [`Step`]s are executed as usual.
+    ///
+    /// [0]: crate::step::Collection::soft()
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    SoftFailed(Option<regex::CaptureLocations>, StepError),
+
+    /// Output [captured][0] while executing a [`Step`], emitted right before
+    /// its [`Step::Failed`] or [`Step::SoftFailed`] event.
+    ///
+    /// The output of passed [`Step`]s is discarded.
+    ///
+    /// [0]: crate::capture
+    /// [`Step`]: gherkin::Step
+    Output(String),
 }
 
 // Manual implementation is required to omit the redundant `World: Clone` trait
@@ -308,10 +412,15 @@ impl<World> Clone for Step<World> {
         match self {
             Self::Started => Self::Started,
             Self::Skipped => Self::Skipped,
+            Self::Matched(captures) => Self::Matched(captures.clone()),
             Self::Passed(captures) => Self::Passed(captures.clone()),
             Self::Failed(captures, w, info) => {
                 Self::Failed(captures.clone(), w.clone(), info.clone())
             }
+            Self::SoftFailed(captures, info) => {
+                Self::SoftFailed(captures.clone(), info.clone())
+            }
+            Self::Output(output) => Self::Output(output.clone()),
         }
     }
 }
@@ -333,6 +442,17 @@ pub enum StepError {
     /// [`Step`]: gherkin::Step
     #[display(fmt = "Step panicked. Captured output: {}", "coerce_error(_0)")]
     Panic(#[error(not(source))] Info),
+
+    /// [`Step`] exceeded its own or its [`Scenario`]'s timeout, so was
+    /// cancelled.
+    ///
+    /// Contains the exceeded timeout.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[display(fmt = "Step timed out after {:.3}s", "_0.as_secs_f64()")]
+    #[from(ignore)]
+    TimedOut(#[error(not(source))] Duration),
 }
 
 /// Type of a hook executed before or after all [`Scenario`]'s [`Step`]s.
@@ -360,6 +480,121 @@ impl fmt::Display for HookType {
     }
 }
 
+/// Type of a hook executed exactly once for the whole run.
+#[derive(Clone, Copy, Debug)]
+pub enum GlobalHookType {
+    /// Executing once before any [`Scenario`] is started.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    BeforeAll,
+
+    /// Executing the warm-up [`Scenario`]s once before any other
+    /// [`Scenario`] is started.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    WarmUp,
+
+    /// Executing once after all [`Scenario`]s are finished.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    AfterAll,
+}
+
+impl fmt::Display for GlobalHookType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Reason of a whole [`Scenario`] being [skipped][0] without execution.
+///
+/// [0]: Scenario::Skipped
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug)]
+pub enum SkipReason {
+    /// The given [`Background`] [`Step`] of the [`Feature`] has failed in one
+    /// of its previous [`Scenario`]s.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    BackgroundFailed(Arc<gherkin::Step>),
+
+    /// The whole run has exceeded its [`Cucumber::run_timeout()`], so the
+    /// [`Scenario`] wasn't started, or was abandoned while being executed.
+    ///
+    /// [`Cucumber::run_timeout()`]: crate::Cucumber::run_timeout()
+    /// [`Scenario`]: gherkin::Scenario
+    RunTimeout,
+
+    /// Another [`Scenario`] has failed while [`Cucumber::fail_fast()`] is
+    /// enabled, so this one was abandoned while being executed.
+    ///
+    /// [`Cucumber::fail_fast()`]: crate::Cucumber::fail_fast()
+    /// [`Scenario`]: gherkin::Scenario
+    FailFast,
+
+    /// The [`Scenario`] doesn't match the filter of the run (like the one of
+    /// [`Cucumber::filter_tags()`]), while [`Cucumber::skip_filtered_out()`]
+    /// is enabled.
+    ///
+    /// [`Cucumber::filter_tags()`]: crate::Cucumber::filter_tags()
+    /// [`Cucumber::skip_filtered_out()`]: crate::Cucumber::skip_filtered_out()
+    /// [`Scenario`]: gherkin::Scenario
+    FilteredOut,
+}
+
+/// Resource usage of the current process, as sampled by a [`Runner`].
+///
+/// Values unsupported by the current platform are [`None`]. Sampling itself
+/// requires the `resource-usage` crate feature.
+///
+/// [`Runner`]: crate::Runner
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ResourceUsage {
+    /// Resident set size in bytes.
+    pub rss: Option<u64>,
+
+    /// Number of open file descriptors.
+    pub open_fds: Option<u64>,
+}
+
+#[cfg(feature = "resource-usage")]
+impl ResourceUsage {
+    /// Samples the [`ResourceUsage`] of the current process.
+    #[must_use]
+    pub fn sample() -> Self {
+        Self {
+            rss: Self::sample_rss(),
+            open_fds: Self::sample_open_fds(),
+        }
+    }
+
+    /// Reads the resident set size from `/proc/self/status`.
+    fn sample_rss() -> Option<u64> {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        status
+            .lines()
+            .find_map(|l| l.strip_prefix("VmRSS:"))
+            .and_then(|kb| kb.trim().trim_end_matches("kB").trim().parse().ok())
+            .map(|kb: u64| kb * 1024)
+    }
+
+    /// Counts entries of `/proc/self/fd` (or `/dev/fd`).
+    fn sample_open_fds() -> Option<u64> {
+        if !cfg!(unix) {
+            return None;
+        }
+        let dir = std::fs::read_dir("/proc/self/fd")
+            .or_else(|_| std::fs::read_dir("/dev/fd"))
+            .ok()?;
+        // The directory being read holds a descriptor itself.
+        let count = dir.count().saturating_sub(1);
+        u64::try_from(count).ok()
+    }
+}
+
 /// Event of running [`Before`] or [`After`] hook.
 ///
 /// [`After`]: HookType::After
@@ -388,6 +623,36 @@ impl<World> Clone for Hook<World> {
     }
 }
 
+/// Outcome of a [`Scenario`], passed to its [`After`] hook.
+///
+/// [`After`]: HookType::After
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug)]
+pub enum ScenarioFinished {
+    /// [`Before`] hook (or a [`World`] creation) failed, so no [`Step`]s were
+    /// executed.
+    ///
+    /// [`Before`]: HookType::Before
+    /// [`Step`]: gherkin::Step
+    /// [`World`]: crate::World
+    BeforeHookFailed(Info),
+
+    /// All the [`Step`]s passed.
+    ///
+    /// [`Step`]: gherkin::Step
+    StepPassed,
+
+    /// Some [`Step`] was skipped, so the rest of them weren't executed.
+    ///
+    /// [`Step`]: gherkin::Step
+    StepSkipped,
+
+    /// Some [`Step`] failed, so the rest of them weren't executed.
+    ///
+    /// [`Step`]: gherkin::Step
+    StepFailed(Option<regex::CaptureLocations>, StepError),
+}
+
 /// Event specific to a particular [Scenario].
 ///
 /// [Scenario]: https://cucumber.io/docs/gherkin/reference/#example
@@ -413,6 +678,65 @@ pub enum Scenario<World> {
     ///
     /// [`Scenario`]: gherkin::Scenario
     Finished,
+
+    /// [`Scenario`] being retried, as its previous attempt has failed.
+    ///
+    /// Emitted before the [`Scenario::Started`] event of the fresh attempt.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    Retried {
+        /// Number of the fresh attempt, starting from `1` for the first retry.
+        attempt: usize,
+
+        /// Maximum number of retries of the [`Scenario`].
+        ///
+        /// [`Scenario`]: gherkin::Scenario
+        retries: usize,
+
+        /// Error the previous attempt has failed with.
+        ///
+        /// Failed hooks are represented with a [`StepError::Panic`].
+        previous_error: StepError,
+    },
+
+    /// [`Scenario`] being skipped without execution for the given
+    /// [`SkipReason`].
+    ///
+    /// Emitted right after the [`Scenario::Started`] event, without running
+    /// any hooks or [`Step`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    Skipped(SkipReason),
+
+    /// [`Step`] (or a [`Background`] one) still being executed after the
+    /// provided [`Duration`] since it has started.
+    ///
+    /// Emitted periodically only if a heartbeat interval is configured in a
+    /// [`Runner`].
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Runner`]: crate::Runner
+    StepHeartbeat(Arc<gherkin::Step>, Duration),
+
+    /// [`ResourceUsage`] of the process sampled at the start and the end of
+    /// the [`Scenario`].
+    ///
+    /// Emitted right before the [`Scenario::Finished`] event, only if
+    /// sampling is enabled in a [`Runner`].
+    ///
+    /// [`Runner`]: crate::Runner
+    /// [`Scenario`]: gherkin::Scenario
+    ResourceUsage {
+        /// [`ResourceUsage`] sampled once the [`Scenario`] has started.
+        ///
+        /// [`Scenario`]: gherkin::Scenario
+        start: ResourceUsage,
+
+        /// [`ResourceUsage`] sampled once the [`Scenario`] has finished.
+        ///
+        /// [`Scenario`]: gherkin::Scenario
+        end: ResourceUsage,
+    },
 }
 
 // Manual implementation is required to omit the redundant `World: Clone` trait
@@ -427,6 +751,23 @@ impl<World> Clone for Scenario<World> {
             }
             Self::Step(st, ev) => Self::Step(Arc::clone(st), ev.clone()),
             Self::Finished => Self::Finished,
+            Self::Retried {
+                attempt,
+                retries,
+                previous_error,
+            } => Self::Retried {
+                attempt: *attempt,
+                retries: *retries,
+                previous_error: previous_error.clone(),
+            },
+            Self::Skipped(reason) => Self::Skipped(reason.clone()),
+            Self::StepHeartbeat(st, elapsed) => {
+                Self::StepHeartbeat(Arc::clone(st), *elapsed)
+            }
+            Self::ResourceUsage { start, end } => Self::ResourceUsage {
+                start: *start,
+                end: *end,
+            },
         }
     }
 }
@@ -460,6 +801,22 @@ impl<World> Scenario<World> {
         Self::Hook(which, Hook::Failed(world, info))
     }
 
+    /// Constructs an event of a [`Scenario`] being retried.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn retried(
+        attempt: usize,
+        retries: usize,
+        previous_error: impl Into<StepError>,
+    ) -> Self {
+        Self::Retried {
+            attempt,
+            retries,
+            previous_error: previous_error.into(),
+        }
+    }
+
     /// Constructs an event of a [`Step`] being started.
     ///
     /// [`Step`]: gherkin::Step
@@ -542,4 +899,55 @@ impl<World> Scenario<World> {
     ) -> Self {
         Self::Background(step, Step::Failed(captures, world, info.into()))
     }
+
+    /// Constructs an event of a failed [soft][0] [`Step`].
+    ///
+    /// [0]: crate::step::Collection::soft()
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn step_soft_failed(
+        step: Arc<gherkin::Step>,
+        captures: Option<regex::CaptureLocations>,
+        info: impl Into<StepError>,
+    ) -> Self {
+        Self::Step(step, Step::SoftFailed(captures, info.into()))
+    }
+
+    /// Constructs an event of a failed [soft][0] [`Background`] [`Step`].
+    ///
+    /// [0]: crate::step::Collection::soft()
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn background_step_soft_failed(
+        step: Arc<gherkin::Step>,
+        captures: Option<regex::CaptureLocations>,
+        info: impl Into<StepError>,
+    ) -> Self {
+        Self::Background(step, Step::SoftFailed(captures, info.into()))
+    }
+
+    /// Constructs an event of an output [captured][0] while executing a
+    /// [`Step`].
+    ///
+    /// [0]: crate::capture
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn step_output(step: Arc<gherkin::Step>, output: String) -> Self {
+        Self::Step(step, Step::Output(output))
+    }
+
+    /// Constructs an event of an output [captured][0] while executing a
+    /// [`Background`] [`Step`].
+    ///
+    /// [0]: crate::capture
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn background_step_output(
+        step: Arc<gherkin::Step>,
+        output: String,
+    ) -> Self {
+        Self::Background(step, Step::Output(output))
+    }
 }
@@ -66,6 +66,9 @@ pub trait Ext: Sized {
     ///       |    20 |   4 |   16 |
     /// ```
     ///
+    /// Each expanded [`Scenario`] keeps only the [`Examples`][2] header and the
+    /// row it was expanded from in its [`gherkin::Scenario::examples`].
+    ///
     /// # Errors
     ///
     /// Errors if the [`Examples`][2] cannot be expanded.
@@ -73,6 +76,7 @@ pub trait Ext: Sized {
     ///
     /// [1]: https://cucumber.io/docs/gherkin/reference/#scenario-outline
     /// [2]: https://cucumber.io/docs/gherkin/reference/#examples
+    /// [`Scenario`]: gherkin::Scenario
     fn expand_examples(self) -> Result<Self, ExpandExamplesError>;
 
     /// Counts all the [`Feature`]'s [`Scenario`]s, including [`Rule`]s inside.
@@ -138,11 +142,11 @@ fn expand_scenario(
         .filter_map(|ex| ex.table.rows.split_first().map(|(h, v)| (h, v, ex)))
         .flat_map(|(header, vals, example)| {
             vals.iter()
-                .map(|v| header.iter().zip(v))
                 .enumerate()
-                .zip(iter::repeat((example.position, example.tags.iter())))
+                .map(move |(id, vals)| (id, header, vals, example))
         })
-        .map(|((id, row), (position, tags))| {
+        .map(|(id, header, vals, example)| {
+            let row = header.iter().zip(vals);
             let replace_templates = |str: &str, pos| {
                 let mut err = None;
                 let replaced = TEMPLATE_REGEX
@@ -169,10 +173,20 @@ fn expand_scenario(
 
             // This is done to differentiate `Hash`es of
             // scenario outlines with the same examples.
-            expanded.position = position;
+            expanded.position = example.position;
             expanded.position.line += id + 1;
 
-            expanded.tags.extend(tags.cloned());
+            expanded.tags.extend(example.tags.iter().cloned());
+
+            // Only the header and the row this `Scenario` was expanded from
+            // are kept, so reporters are able to refer them.
+            expanded.examples = vec![gherkin::Examples {
+                table: gherkin::Table {
+                    rows: vec![header.clone(), vals.clone()],
+                    ..example.table.clone()
+                },
+                ..example.clone()
+            }];
 
             expanded.name =
                 replace_templates(&expanded.name, expanded.position)?;
@@ -99,13 +99,18 @@
 )]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+pub mod capture;
 pub mod cli;
 mod cucumber;
+#[cfg(feature = "serde-docstring")]
+pub mod docstring;
 pub mod event;
 pub mod feature;
 pub mod parser;
 pub mod runner;
 pub mod step;
+#[cfg(feature = "serde-table")]
+pub mod table;
 pub mod tag;
 pub mod writer;
 
@@ -117,6 +122,7 @@ use std::error::Error as StdError;
 use async_trait::async_trait;
 
 pub use gherkin;
+pub use globset::Glob;
 
 #[cfg(feature = "macros")]
 #[doc(inline)]
@@ -127,7 +133,9 @@ pub use cucumber_codegen::{given, then, when, Parameter, WorldInit};
 
 #[doc(inline)]
 pub use self::{
-    cucumber::Cucumber,
+    cucumber::{
+        Cucumber, ExitCodes, FailIfFn, RunConfig, RunSummary, ScenarioDisplayFn,
+    },
     event::Event,
     parser::Parser,
     runner::{Runner, ScenarioType},
@@ -153,9 +161,57 @@ pub use self::{
 /// [Cucumber]: https://cucumber.io
 #[async_trait(?Send)]
 pub trait World: Sized + 'static {
-    /// Error of creating a new [`World`] instance.
+    /// Error of creating a new [`World`] instance (or of tearing it down via
+    /// [`WorldTeardown`]).
     type Error: StdError;
 
     /// Creates a new [`World`] instance.
     async fn new() -> Result<Self, Self::Error>;
 }
+
+/// Asynchronous teardown of a [`World`], performed once its [scenario][0]
+/// finishes.
+///
+/// As [`Drop`] can't be `async`, this trait allows to release asynchronous
+/// resources (like connection pools) held by a [`World`]. It's enabled via
+/// [`Cucumber::teardown()`] and awaited after the [`After`] hook.
+///
+/// [`World`] is torn down regardless of whether its [scenario][0] has passed,
+/// failed or panicked. To do so, the [`Runner`] retains the [`World`] of a
+/// failure instead of passing it into the corresponding [`event`], and passes
+/// it into the [`After`] hook. Erroring or panicking in
+/// [`WorldTeardown::drop()`] fails the [scenario][0] with an [`After`] hook
+/// failure.
+///
+/// [0]: https://cucumber.io/docs/gherkin/reference/#descriptions
+/// [`After`]: event::HookType::After
+#[async_trait(?Send)]
+pub trait WorldTeardown: World {
+    /// Tears down this [`World`] asynchronously.
+    ///
+    /// # Errors
+    ///
+    /// If this [`World`] fails to release its resources.
+    async fn drop(self) -> Result<(), Self::Error>;
+}
+
+/// Construction of a [`World`] out of a context shared by the whole run.
+///
+/// Allows to pass resources (like a connection pool or a base URL) into each
+/// [`World`] without resorting to global statics. It's enabled via
+/// [`Cucumber::with_context()`], making the [`Runner`] construct [`World`]s via
+/// [`WorldWithContext::new_with()`] instead of [`World::new()`].
+#[async_trait(?Send)]
+pub trait WorldWithContext: World {
+    /// Context to construct [`World`]s out of.
+    ///
+    /// It's cloned for each [scenario][0], so should be cheap to clone (like an
+    /// [`Arc`]).
+    ///
+    /// [0]: https://cucumber.io/docs/gherkin/reference/#descriptions
+    /// [`Arc`]: std::sync::Arc
+    type Context: Clone + 'static;
+
+    /// Creates a new [`World`] instance out of the given `ctx`.
+    async fn new_with(ctx: Self::Context) -> Result<Self, Self::Error>;
+}
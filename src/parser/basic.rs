@@ -12,10 +12,11 @@
 
 use std::{
     borrow::Cow,
-    fmt,
+    collections::HashSet,
+    fmt, io, panic,
     path::{Path, PathBuf},
     str::FromStr,
-    vec,
+    thread, vec,
 };
 
 use derive_more::{Display, Error};
@@ -41,11 +42,18 @@ pub struct Cli {
     /// Glob pattern to look for feature files with. By default, looks for
     /// `*.feature`s in the path configured tests runner.
     #[structopt(long = "input", short = "i", name = "glob")]
+    #[cfg_attr(feature = "config-file", structopt(env = "CUCUMBER_INPUT"))]
     pub features: Option<Walker>,
 }
 
 /// Default [`Parser`].
 ///
+/// Parses the given `.feature` file, or all the `.feature` files of the given
+/// directory, or all the `.feature` files matching the given glob pattern
+/// (like `tests/features/**/login*.feature`) in a sorted order. Invalid glob
+/// patterns and the ones matching no `.feature` files are reported as parsing
+/// errors, while matched files of other extensions are ignored.
+///
 /// As there is no async runtime-agnostic way to interact with IO, this
 /// [`Parser`] is blocking.
 #[derive(Clone, Debug, Default)]
@@ -54,6 +62,11 @@ pub struct Basic {
     ///
     /// Default is English.
     language: Option<Cow<'static, str>>,
+
+    /// Maximum number of `.feature` files parsed concurrently.
+    ///
+    /// Default is `1` (sequential parsing).
+    concurrency: Option<usize>,
 }
 
 impl<I: AsRef<Path>> Parser<I> for Basic {
@@ -63,29 +76,139 @@ impl<I: AsRef<Path>> Parser<I> for Basic {
         stream::Iter<vec::IntoIter<Result<gherkin::Feature, ParseError>>>;
 
     fn parse(self, path: I, cli: Self::Cli) -> Self::Output {
-        let walk = |walker: GlobWalker| {
-            walker
-                .filter_map(Result::ok)
-                .sorted_by(|l, r| Ord::cmp(l.path(), r.path()))
-                .filter(|file| {
-                    file.path()
-                        .extension()
-                        .map(|ext| ext == "feature")
-                        .unwrap_or_default()
-                })
-                .map(|file| {
-                    let env = self
-                        .language
-                        .as_ref()
-                        .and_then(|l| GherkinEnv::new(l).ok())
-                        .unwrap_or_default();
-                    gherkin::Feature::parse_path(file.path(), env)
+        stream::iter(self.parse_features(path.as_ref(), cli.features))
+    }
+}
+
+impl Parser<Paths> for Basic {
+    type Cli = Cli;
+
+    type Output =
+        stream::Iter<vec::IntoIter<Result<gherkin::Feature, ParseError>>>;
+
+    fn parse(self, paths: Paths, cli: Self::Cli) -> Self::Output {
+        // `--input` CLI option overrides any provided paths.
+        if cli.features.is_some() {
+            return stream::iter(
+                self.parse_features(Path::new(""), cli.features),
+            );
+        }
+
+        // The same file may be discovered via overlapping paths, either
+        // absolute or relative ones.
+        let mut parsed = HashSet::new();
+        let features = paths
+            .0
+            .iter()
+            .flat_map(|path| self.parse_features(path, None))
+            .filter(|feature| {
+                feature.as_ref().map_or(true, |f| {
+                    parsed.insert(f.path.as_ref().map(|p| {
+                        p.canonicalize().unwrap_or_else(|_| p.clone())
+                    }))
                 })
-                .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        stream::iter(features)
+    }
+}
+
+impl Parser<Globs> for Basic {
+    type Cli = Cli;
+
+    type Output =
+        stream::Iter<vec::IntoIter<Result<gherkin::Feature, ParseError>>>;
+
+    fn parse(self, globs: Globs, cli: Self::Cli) -> Self::Output {
+        // `--input` CLI option overrides any provided patterns.
+        if cli.features.is_some() {
+            return stream::iter(
+                self.parse_features(Path::new(""), cli.features),
+            );
+        }
+
+        let unreadable = |source| {
+            vec![Err(gherkin::ParseFileError::Reading {
+                path: globs.0.join(" ").into(),
+                source,
+            }
+            .into())]
         };
+        let features =
+            match GlobWalkerBuilder::from_patterns(".", &globs.0).build() {
+                Ok(walker) => {
+                    let features = self.walk(walker);
+                    if features.is_empty() {
+                        unreadable(io::Error::new(
+                            io::ErrorKind::NotFound,
+                            "no `.feature` files match the glob patterns",
+                        ))
+                    } else {
+                        features.into_iter().map(expand).collect()
+                    }
+                }
+                Err(e) => {
+                    unreadable(io::Error::new(io::ErrorKind::InvalidInput, e))
+                }
+            };
+        stream::iter(features)
+    }
+}
+
+impl Basic {
+    /// Creates a new [`Basic`] [`Parser`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            language: None,
+            concurrency: None,
+        }
+    }
+
+    /// Sets the provided language to parse [`gherkin`] files with instead of
+    /// the default one (English).
+    ///
+    /// # Errors
+    ///
+    /// If the provided language isn't supported.
+    pub fn language(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<Self, UnsupportedLanguageError> {
+        let name = name.into();
+        if !gherkin::is_language_supported(&name) {
+            return Err(UnsupportedLanguageError(name));
+        }
+        self.language = Some(name);
+        Ok(self)
+    }
+
+    /// Sets the maximum number of `.feature` files to be parsed concurrently,
+    /// each one in a separate thread.
+    ///
+    /// Parsed [`Feature`]s are still emitted in the sorted order, and a file
+    /// failed to be parsed doesn't prevent others from being parsed.
+    ///
+    /// Default is `1` (sequential parsing). `0` is treated as `1`.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[must_use]
+    pub const fn concurrency(mut self, workers: usize) -> Self {
+        self.concurrency = Some(workers);
+        self
+    }
 
+    /// Parses [`Feature`]s located by the given `path` (or by the given
+    /// `input` [`Walker`], if any).
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[allow(clippy::too_many_lines)]
+    fn parse_features(
+        &self,
+        path: &Path,
+        input: Option<Walker>,
+    ) -> Vec<Result<gherkin::Feature, ParseError>> {
         let get_features_path = || {
-            let path = path.as_ref();
             path.canonicalize()
                 .or_else(|_| {
                     let mut buf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -103,8 +226,37 @@ impl<I: AsRef<Path>> Parser<I> for Basic {
         };
 
         let features = || {
-            let features = if let Some(walker) = cli.features {
-                walk(walker.0)
+            let features = if let Some(walker) = input {
+                self.walk(walker.0)
+            } else if is_glob(path) {
+                let unreadable = |source| {
+                    vec![Err(gherkin::ParseFileError::Reading {
+                        path: path.to_path_buf(),
+                        source,
+                    }
+                    .into())]
+                };
+                let walker = match globwalk::glob_builder(
+                    path.to_string_lossy(),
+                )
+                .build()
+                {
+                    Ok(w) => w,
+                    Err(e) => {
+                        return unreadable(io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            e,
+                        ))
+                    }
+                };
+                let features = self.walk(walker);
+                if features.is_empty() {
+                    return unreadable(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        "no `.feature` files match the glob pattern",
+                    ));
+                }
+                features
             } else {
                 let feats_path = match get_features_path() {
                     Ok(p) => p,
@@ -125,49 +277,118 @@ impl<I: AsRef<Path>> Parser<I> for Basic {
                         .unwrap_or_else(|e| {
                             unreachable!("GlobWalkerBuilder panicked: {}", e)
                         });
-                    walk(w)
+                    self.walk(w)
                 }
             };
 
-            features
-                .into_iter()
-                .map(|f| match f {
-                    Ok(f) => f.expand_examples().map_err(ParseError::from),
-                    Err(e) => Err(e.into()),
-                })
-                .collect()
+            features.into_iter().map(expand).collect()
         };
 
-        stream::iter(features().into_iter())
+        features()
     }
-}
 
-impl Basic {
-    /// Creates a new [`Basic`] [`Parser`].
-    #[must_use]
-    pub const fn new() -> Self {
-        Self { language: None }
+    /// Parses all the `.feature` files found by the given [`GlobWalker`] in a
+    /// sorted order, ignoring files of other extensions.
+    fn walk(
+        &self,
+        walker: GlobWalker,
+    ) -> Vec<Result<gherkin::Feature, gherkin::ParseFileError>> {
+        let files = walker
+            .filter_map(Result::ok)
+            .sorted_by(|l, r| Ord::cmp(l.path(), r.path()))
+            .dedup_by(|l, r| l.path() == r.path())
+            .filter(|file| {
+                file.path()
+                    .extension()
+                    .map(|ext| ext == "feature")
+                    .unwrap_or_default()
+            })
+            .map(globwalk::DirEntry::into_path)
+            .collect::<Vec<_>>();
+        self.parse_files(&files)
     }
 
-    /// Sets the provided language to parse [`gherkin`] files with instead of
-    /// the default one (English).
-    ///
-    /// # Errors
+    /// Parses the given `.feature` `files` with up to [`Basic::concurrency`]
+    /// threads, preserving their order.
     ///
-    /// If the provided language isn't supported.
-    pub fn language(
-        mut self,
-        name: impl Into<Cow<'static, str>>,
-    ) -> Result<Self, UnsupportedLanguageError> {
-        let name = name.into();
-        if !gherkin::is_language_supported(&name) {
-            return Err(UnsupportedLanguageError(name));
+    /// [`Basic::concurrency`]: Basic::concurrency()
+    fn parse_files(
+        &self,
+        files: &[PathBuf],
+    ) -> Vec<Result<gherkin::Feature, gherkin::ParseFileError>> {
+        let parse = |language: Option<&str>, file: &Path| {
+            let env = language
+                .and_then(|l| GherkinEnv::new(l).ok())
+                .unwrap_or_default();
+            gherkin::Feature::parse_path(file, env)
+        };
+
+        let workers =
+            self.concurrency.unwrap_or(1).clamp(1, files.len().max(1));
+        if workers == 1 {
+            return files
+                .iter()
+                .map(|f| parse(self.language.as_deref(), f))
+                .collect();
         }
-        self.language = Some(name);
-        Ok(self)
+
+        // Each worker parses every `workers`-th file, so the files are
+        // distributed evenly regardless of their order.
+        let handles = (0..workers)
+            .map(|worker| {
+                let language = self.language.clone();
+                let files = files
+                    .iter()
+                    .skip(worker)
+                    .step_by(workers)
+                    .cloned()
+                    .collect::<Vec<_>>();
+                thread::spawn(move || {
+                    files
+                        .iter()
+                        .map(|f| parse(language.as_deref(), f))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut parsed = handles
+            .into_iter()
+            .map(|h| {
+                h.join()
+                    .unwrap_or_else(|e| panic::resume_unwind(e))
+                    .into_iter()
+            })
+            .collect::<Vec<_>>();
+
+        (0..files.len())
+            .filter_map(|i| parsed[i % workers].next())
+            .collect()
     }
 }
 
+/// Expands [`Scenario Outline`][0] [`Examples`] of the given parsed
+/// [`gherkin::Feature`].
+///
+/// [`Examples`]: gherkin::Examples
+/// [0]: https://cucumber.io/docs/gherkin/reference/#scenario-outline
+fn expand(
+    feature: Result<gherkin::Feature, gherkin::ParseFileError>,
+) -> Result<gherkin::Feature, ParseError> {
+    match feature {
+        Ok(f) => f.expand_examples().map_err(ParseError::from),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Checks whether the given `path` is a glob pattern, rather than an existing
+/// file or directory.
+fn is_glob(path: &Path) -> bool {
+    !path.exists()
+        && path
+            .to_str()
+            .map_or(false, |p| p.contains(['*', '?', '[', '{']))
+}
+
 /// Error of [`gherkin`] not supporting keywords in some language.
 #[derive(Debug, Display, Error)]
 #[display(fmt = "Language {} isn't supported", _0)]
@@ -175,6 +396,42 @@ pub struct UnsupportedLanguageError(
     #[error(not(source))] pub Cow<'static, str>,
 );
 
+/// Multiple paths to parse [`Feature`]s from with a [`Basic`] [`Parser`] in
+/// a single run.
+///
+/// Each path is parsed the same way as a single one (see [`Basic`]), in the
+/// provided order. [`Feature`]s discovered via overlapping paths are parsed
+/// only once.
+///
+/// [`Feature`]: gherkin::Feature
+#[derive(Clone, Debug, Default)]
+pub struct Paths(pub Vec<PathBuf>);
+
+impl<P: Into<PathBuf>> FromIterator<P> for Paths {
+    fn from_iter<T: IntoIterator<Item = P>>(paths: T) -> Self {
+        Self(paths.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Glob patterns to parse [`Feature`]s from with a [`Basic`] [`Parser`] in a
+/// single run.
+///
+/// Patterns are relative to the current directory and prefixed with `!` to
+/// exclude the matched files (like
+/// `["tests/**/*.feature", "!tests/wip/**"]`). Matched [`Feature`]s are parsed
+/// in a sorted order, regardless of the patterns order. Invalid patterns and
+/// the ones matching no `.feature` files are reported as parsing errors.
+///
+/// [`Feature`]: gherkin::Feature
+#[derive(Clone, Debug, Default)]
+pub struct Globs(pub Vec<String>);
+
+impl<P: Into<String>> FromIterator<P> for Globs {
+    fn from_iter<T: IntoIterator<Item = P>>(patterns: T) -> Self {
+        Self(patterns.into_iter().map(Into::into).collect())
+    }
+}
+
 /// Wrapper over [`GlobWalker`] implementing a [`FromStr`].
 pub struct Walker(GlobWalker);
 
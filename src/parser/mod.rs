@@ -13,6 +13,8 @@
 //! [Gherkin]: https://cucumber.io/docs/gherkin/reference
 
 pub mod basic;
+pub mod stdin;
+pub mod text;
 
 use std::sync::Arc;
 
@@ -23,7 +25,7 @@ use structopt::StructOptInternal;
 use crate::feature::ExpandExamplesError;
 
 #[doc(inline)]
-pub use self::basic::Basic;
+pub use self::{basic::Basic, stdin::Stdin, text::Text};
 
 /// Source of parsed [`Feature`]s.
 ///
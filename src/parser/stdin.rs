@@ -0,0 +1,118 @@
+// Copyright (c) 2018-2021  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Parser`] of a [Gherkin] text read from [`io::Stdin`].
+//!
+//! [Gherkin]: https://cucumber.io/docs/gherkin/reference
+
+use std::{
+    borrow::Cow,
+    io::{self, Read as _},
+    iter,
+    path::PathBuf,
+};
+
+use futures::stream;
+
+use crate::cli;
+
+use super::{
+    basic::UnsupportedLanguageError, text::Text, Error as ParseError, Parser,
+};
+
+/// Path the [`Feature`] parsed by a [`Stdin`] [`Parser`] is reported with by
+/// default.
+///
+/// [`Feature`]: gherkin::Feature
+pub const DEFAULT_PATH: &str = "<stdin>";
+
+/// [`Parser`] of a single [`Feature`] read from [`io::Stdin`] until EOF, so it
+/// can be piped into a test binary (`cat my.feature | cargo test --test my`).
+///
+/// As the [`Feature`] has no file, it's reported with a synthetic
+/// [`DEFAULT_PATH`] (unless [another one][1] is specified). Failure to read
+/// [`io::Stdin`] or to parse the read text is reported as a parsing error.
+///
+/// Accepts no input, so a [`Cucumber`] using it is run with `()`.
+///
+/// [`Cucumber`]: crate::Cucumber
+/// [`Feature`]: gherkin::Feature
+/// [1]: Stdin::path()
+#[derive(Clone, Debug)]
+pub struct Stdin {
+    /// [`Text`] [`Parser`] to parse the read text with.
+    text: Text,
+
+    /// Path to report the parsed [`Feature`] with.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    path: PathBuf,
+}
+
+impl Parser<()> for Stdin {
+    type Cli = cli::Empty;
+
+    type Output = <Text as Parser<String>>::Output;
+
+    fn parse(self, (): (), cli: Self::Cli) -> Self::Output {
+        let mut text = String::new();
+        match io::stdin().lock().read_to_string(&mut text) {
+            Ok(_) => self.text.parse(text, cli),
+            Err(source) => stream::iter(iter::once(Err(ParseError::from(
+                gherkin::ParseFileError::Reading {
+                    path: self.path,
+                    source,
+                },
+            )))),
+        }
+    }
+}
+
+impl Default for Stdin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Stdin {
+    /// Creates a new [`Stdin`] [`Parser`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            text: Text::new().path(DEFAULT_PATH),
+            path: DEFAULT_PATH.into(),
+        }
+    }
+
+    /// Sets the provided language to parse [`gherkin`] text with instead of
+    /// the default one (English).
+    ///
+    /// # Errors
+    ///
+    /// If the provided language isn't supported.
+    pub fn language(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<Self, UnsupportedLanguageError> {
+        self.text = self.text.language(name)?;
+        Ok(self)
+    }
+
+    /// Sets the path to report the parsed [`Feature`] with, instead of the
+    /// [`DEFAULT_PATH`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[must_use]
+    pub fn path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = path.into();
+        self.text = self.text.path(self.path.clone());
+        self
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (c) 2018-2021  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Parser`] of an in-memory [Gherkin] text.
+//!
+//! [Gherkin]: https://cucumber.io/docs/gherkin/reference
+
+use std::{borrow::Cow, iter, path::PathBuf};
+
+use futures::stream;
+use gherkin::GherkinEnv;
+
+use crate::{cli, feature::Ext as _};
+
+use super::{basic::UnsupportedLanguageError, Error as ParseError, Parser};
+
+/// Path the [`Feature`]s parsed by a [`Text`] [`Parser`] are reported with
+/// by default.
+///
+/// [`Feature`]: gherkin::Feature
+pub const DEFAULT_PATH: &str = "<string>";
+
+/// [`Parser`] of a single [`Feature`] from an in-memory [Gherkin] text (like
+/// the one embedded via [`include_str!`]).
+///
+/// As the parsed [`Feature`] has no file, it's reported with a synthetic
+/// [`DEFAULT_PATH`] (unless [another one][1] is specified), while its parsing
+/// errors are reported the same way as the ones of a [`Basic`] [`Parser`].
+///
+/// [`Basic`]: super::Basic
+/// [`Feature`]: gherkin::Feature
+/// [Gherkin]: https://cucumber.io/docs/gherkin/reference
+/// [1]: Text::path()
+#[derive(Clone, Debug)]
+pub struct Text {
+    /// Optional custom language of [`gherkin`] keywords.
+    ///
+    /// Default is English.
+    language: Option<Cow<'static, str>>,
+
+    /// Path to report the parsed [`Feature`] with.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    path: PathBuf,
+}
+
+impl<I: AsRef<str>> Parser<I> for Text {
+    type Cli = cli::Empty;
+
+    type Output =
+        stream::Iter<iter::Once<Result<gherkin::Feature, ParseError>>>;
+
+    fn parse(self, text: I, _: Self::Cli) -> Self::Output {
+        let env = self
+            .language
+            .as_ref()
+            .and_then(|l| GherkinEnv::new(l).ok())
+            .unwrap_or_default();
+        let feature = gherkin::Feature::parse(text, env)
+            .map_err(|source| gherkin::ParseFileError::Parsing {
+                path: self.path.clone(),
+                error: None,
+                source,
+            })
+            .map_err(ParseError::from)
+            .and_then(|mut f| {
+                f.path = Some(self.path);
+                f.expand_examples().map_err(ParseError::from)
+            });
+
+        stream::iter(iter::once(feature))
+    }
+}
+
+impl Default for Text {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Text {
+    /// Creates a new [`Text`] [`Parser`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            language: None,
+            path: DEFAULT_PATH.into(),
+        }
+    }
+
+    /// Sets the provided language to parse [`gherkin`] text with instead of
+    /// the default one (English).
+    ///
+    /// # Errors
+    ///
+    /// If the provided language isn't supported.
+    pub fn language(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<Self, UnsupportedLanguageError> {
+        let name = name.into();
+        if !gherkin::is_language_supported(&name) {
+            return Err(UnsupportedLanguageError(name));
+        }
+        self.language = Some(name);
+        Ok(self)
+    }
+
+    /// Sets the path to report the parsed [`Feature`] with, instead of the
+    /// [`DEFAULT_PATH`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[must_use]
+    pub fn path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = path.into();
+        self
+    }
+}
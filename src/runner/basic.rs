@@ -11,35 +11,48 @@
 //! Default [`Runner`] implementation.
 
 use std::{
+    cell::{Cell, RefCell},
     cmp,
-    collections::HashMap,
+    collections::{hash_map::RandomState, BTreeMap, HashMap, HashSet},
     convert::identity,
-    fmt, mem,
+    env, fmt, fs,
+    hash::{BuildHasher as _, Hasher as _},
+    io::{self, Write as _},
+    mem,
     panic::{self, AssertUnwindSafe},
-    path::PathBuf,
+    path::{Path, PathBuf},
+    process,
+    rc::Rc,
     sync::{
         atomic::{AtomicBool, AtomicUsize, Ordering},
         Arc,
     },
+    task::Poll,
+    thread,
+    time::{Duration, Instant, SystemTime},
 };
 
 use futures::{
-    channel::mpsc,
+    channel::{mpsc, oneshot},
     future::{self, Either, LocalBoxFuture},
     lock::Mutex,
     pin_mut,
-    stream::{self, LocalBoxStream},
-    FutureExt as _, Stream, StreamExt as _, TryFutureExt as _,
+    stream::{self, FuturesUnordered, LocalBoxStream},
+    Future, FutureExt as _, Stream, StreamExt as _, TryFutureExt as _,
     TryStreamExt as _,
 };
+use futures_timer::Delay;
 use itertools::Itertools as _;
 use regex::{CaptureLocations, Regex};
 use structopt::StructOpt;
 
 use crate::{
-    event::{self, HookType, Info},
+    capture,
+    event::{self, GlobalHookType, HookType, Info},
     feature::Ext as _,
-    parser, step, Event, Runner, Step, World,
+    parser, step,
+    tag::{self, Ext as _},
+    Event, Runner, Step, World, WorldTeardown, WorldWithContext,
 };
 
 // Workaround for overwritten doc-comments.
@@ -49,12 +62,54 @@ use crate::{
     not(doc),
     allow(clippy::missing_docs_in_private_items, missing_docs)
 )]
-#[derive(Clone, Copy, Debug, StructOpt)]
+#[derive(Clone, Debug, StructOpt)]
 pub struct Cli {
-    /// Number of scenarios to run concurrently. If not specified, uses the
-    /// value configured in tests runner, or 64 by default.
+    /// Number of scenarios to run concurrently, with 0 meaning no limit. If
+    /// not specified, uses the value configured in tests runner, or 64 by
+    /// default.
     #[structopt(long, short, name = "int")]
+    #[cfg_attr(
+        feature = "config-file",
+        structopt(env = "CUCUMBER_CONCURRENCY")
+    )]
     pub concurrency: Option<usize>,
+
+    /// Number of times a failed scenario is retried before being reported as
+    /// failed. If not specified, uses the value configured in tests runner,
+    /// or 0 by default.
+    #[structopt(long, name = "retries")]
+    #[cfg_attr(feature = "config-file", structopt(env = "CUCUMBER_RETRIES"))]
+    pub retries: Option<usize>,
+
+    /// Tag expression to filter retried scenarios with. If not specified,
+    /// uses the value configured in tests runner, or retries all the failed
+    /// scenarios by default.
+    #[structopt(long, name = "retry-tagexpr")]
+    #[cfg_attr(
+        feature = "config-file",
+        structopt(env = "CUCUMBER_RETRY_TAG_FILTER")
+    )]
+    pub retry_tag_filter: Option<tag::Expression>,
+
+    /// Seed to shuffle scenarios of each feature with. If not specified, uses
+    /// the value configured in tests runner, or doesn't shuffle scenarios by
+    /// default.
+    #[structopt(long, name = "seed")]
+    #[cfg_attr(
+        feature = "config-file",
+        structopt(env = "CUCUMBER_SHUFFLE_SEED")
+    )]
+    pub shuffle_seed: Option<u64>,
+
+    /// Stops starting new scenarios and cancels the running ones once any step
+    /// or hook fails.
+    #[structopt(long)]
+    pub fail_fast: bool,
+
+    /// Only matches steps against step functions, without executing them or
+    /// constructing a world.
+    #[structopt(long)]
+    pub dry_run: bool,
 }
 
 /// Type determining whether [`Scenario`]s should run concurrently or
@@ -74,6 +129,28 @@ pub enum ScenarioType {
     Concurrent,
 }
 
+/// Behavior of the [`Scenario`]s being executed at the moment of a failure,
+/// once the [`Runner`] [fails fast][1].
+///
+/// [1]: Basic::fail_fast()
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum FailFast {
+    /// Let the [`Scenario`]s complete.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    Complete,
+
+    /// Cancel the [`Step`]s of the [`Scenario`]s, reporting them as
+    /// [skipped][1], but still running their [`HookType::After`] and
+    /// teardown.
+    ///
+    /// [1]: event::SkipReason::FailFast
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    Cancel,
+}
+
 /// Alias for [`fn`] used to determine whether a [`Scenario`] is [`Concurrent`]
 /// or a [`Serial`] one.
 ///
@@ -105,9 +182,53 @@ pub type AfterHookFn<World> = for<'a> fn(
     &'a gherkin::Feature,
     Option<&'a gherkin::Rule>,
     &'a gherkin::Scenario,
+    &'a event::ScenarioFinished,
     Option<&'a mut World>,
 ) -> LocalBoxFuture<'a, ()>;
 
+/// Alias for [`fn`] tearing down a [`World`] once its [`Scenario`] finishes.
+///
+/// [`Scenario`]: gherkin::Scenario
+pub type TeardownFn<World> =
+    fn(World) -> LocalBoxFuture<'static, Result<(), Info>>;
+
+/// Alias for a function constructing a new [`World`] for each [`Scenario`],
+/// returning its error already formatted.
+///
+/// [`Scenario`]: gherkin::Scenario
+pub type NewWorldFn<World> =
+    Rc<dyn Fn() -> LocalBoxFuture<'static, Result<World, String>>>;
+
+/// Alias for a function executed exactly once for the whole run, either
+/// before all [`Scenario`]s or after them.
+///
+/// [`Scenario`]: gherkin::Scenario
+pub type GlobalHookFn = Box<dyn FnOnce() -> LocalBoxFuture<'static, ()>>;
+
+/// Alias for [`fn`] selecting [`Scenario`]s to be executed as a warm-up.
+///
+/// [`Scenario`]: gherkin::Scenario
+pub type WarmUpFn =
+    fn(&gherkin::Feature, Option<&gherkin::Rule>, &gherkin::Scenario) -> bool;
+
+/// Alias for [`fn`] notified about a [`Scenario`] outcome.
+///
+/// [`Scenario`]: gherkin::Scenario
+pub type ScenarioOutcomeFn =
+    fn(&gherkin::Feature, Option<&gherkin::Rule>, &gherkin::Scenario);
+
+/// Alias for [`fn`] deriving a working directory of a [`Scenario`].
+///
+/// [`Scenario`]: gherkin::Scenario
+pub type WorkingDirFn = fn(
+    &gherkin::Feature,
+    Option<&gherkin::Rule>,
+    &gherkin::Scenario,
+) -> PathBuf;
+
+/// Default [`Basic::run_timeout_grace_period()`].
+const DEFAULT_RUN_TIMEOUT_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
 /// Default [`Runner`] implementation which follows [_order guarantees_][1] from
 /// the [`Runner`] trait docs.
 ///
@@ -115,7 +236,11 @@ pub type AfterHookFn<World> = for<'a> fn(
 /// returns [`ScenarioType`]. Also, can limit maximum number of concurrent
 /// [`Scenario`]s.
 ///
+/// [`Feature`]s of the same `.feature` file (like the one matched twice by
+/// overlapping globs) are executed only once, as the first one of them.
+///
 /// [1]: Runner#order-guarantees
+/// [`Feature`]: gherkin::Feature
 /// [`Scenario`]: gherkin::Scenario
 pub struct Basic<
     World,
@@ -123,11 +248,6 @@ pub struct Basic<
     Before = BeforeHookFn<World>,
     After = AfterHookFn<World>,
 > {
-    /// Optional number of concurrently executed [`Scenario`]s.
-    ///
-    /// [`Scenario`]: gherkin::Scenario
-    max_concurrent_scenarios: Option<usize>,
-
     /// [`Collection`] of functions to match [`Step`]s.
     ///
     /// [`Collection`]: step::Collection
@@ -155,6 +275,211 @@ pub struct Basic<
     /// [`Scenario`]: gherkin::Scenario
     /// [`Step`]: gherkin::Step
     after_hook: Option<After>,
+
+    /// [`Options`] of executing [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    options: Options<World>,
+}
+
+/// Options of executing [`Scenario`]s by a [`Basic`] [`Runner`], not
+/// depending on its hooks.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[allow(clippy::struct_excessive_bools)] // independent builder toggles
+struct Options<World> {
+    /// Optional number of concurrently executed [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    max_concurrent_scenarios: Option<usize>,
+
+    /// Function tearing down a [`World`] once its [`Scenario`] finishes.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    teardown: Option<TeardownFn<World>>,
+
+    /// Function constructing a new [`World`] for each [`Scenario`], instead of
+    /// [`World::new()`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    new_world: Option<NewWorldFn<World>>,
+
+    /// Indicator whether the rest of a [`Feature`]'s [`Scenario`]s should be
+    /// skipped once its [`Background`] [`Step`] fails.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    skip_feature_on_background_failure: bool,
+
+    /// Indicator whether a [`Step`] matching several [`Step`] functions fails
+    /// its [`Scenario`], rather than being executed with the first of them.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    fail_on_ambiguous: bool,
+
+    /// Interval of emitting [`Scenario::StepHeartbeat`] events while a
+    /// [`Step`] is still being executed, if any.
+    ///
+    /// [`Scenario::StepHeartbeat`]: event::Scenario::StepHeartbeat
+    /// [`Step`]: gherkin::Step
+    step_heartbeat: Option<Duration>,
+
+    /// Indicator whether the output of [`Step`]s written via
+    /// [`capture::stdout()`] and [`capture::stderr()`] is captured, to be
+    /// emitted as [`Step::Output`] events of the failed [`Step`]s only.
+    ///
+    /// [`capture::stderr()`]: crate::capture::stderr
+    /// [`capture::stdout()`]: crate::capture::stdout
+    /// [`Step::Output`]: event::Step::Output
+    /// [`Step`]: gherkin::Step
+    capture_output: bool,
+
+    /// Maximum wall-clock duration of the whole run, if any.
+    run_timeout: Option<Duration>,
+
+    /// Duration to wait for the in-flight [`Scenario`]s to finish once the
+    /// [`run_timeout`] is exceeded, before abandoning them.
+    ///
+    /// [`run_timeout`]: Self::run_timeout
+    /// [`Scenario`]: gherkin::Scenario
+    run_timeout_grace_period: Duration,
+
+    /// Function selecting [`Scenario`]s to be executed as a warm-up before the
+    /// actual run, if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    warmup: Option<WarmUpFn>,
+
+    /// Function, executed on each [`Scenario`] once all its [`Step`]s pass.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    on_scenario_passed: Option<ScenarioOutcomeFn>,
+
+    /// Function, executed on each [`Scenario`] once any of its [`Step`]s or
+    /// hooks fails.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    on_scenario_failed: Option<ScenarioOutcomeFn>,
+
+    /// Function deriving a working directory of each [`Scenario`], if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    working_dir: Option<WorkingDirFn>,
+
+    /// Number of times a failed [`Scenario`] is retried.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    retries: usize,
+
+    /// Tag expression selecting [`Scenario`]s to be retried, if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    retry_filter: Option<tag::Expression>,
+
+    /// [`tag::Inheritance`] policy of computing effective tags of
+    /// [`Scenario`]s for their `@retry(N)` and `@timeout(...)` tags and the
+    /// [`tag::Expression`] selecting [`Scenario`]s to be retried.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    tag_inheritance: tag::Inheritance,
+
+    /// Delay before retrying a failed [`Scenario`], if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    retry_after: Option<Duration>,
+
+    /// Maximum duration of a single [`Step`], if limited.
+    ///
+    /// [`Step`]: gherkin::Step
+    step_timeout: Option<Duration>,
+
+    /// Maximum duration of a whole [`Scenario`] (including its hooks), if
+    /// limited.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    scenario_timeout: Option<Duration>,
+
+    /// Seed to shuffle [`Scenario`]s of each [`Feature`] with, if shuffling
+    /// is enabled.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    shuffle_seed: Option<u64>,
+
+    /// Behavior of the [`Scenario`]s being executed, once any [`Step`] or
+    /// hook fails, if no more [`Scenario`]s should be started after that.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    fail_fast: Option<FailFast>,
+
+    /// Indicator whether [`Step`]s should only be matched against the
+    /// [`Collection`] without executing anything.
+    ///
+    /// [`Collection`]: step::Collection
+    /// [`Step`]: gherkin::Step
+    dry_run: bool,
+
+    /// Function, executed exactly once before any [`Scenario`] is started.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    before_all: Option<GlobalHookFn>,
+
+    /// Function, executed exactly once after all [`Scenario`]s are finished.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    after_all: Option<GlobalHookFn>,
+
+    /// Input to read a line from, once paused on a failed [`Step`] or hook,
+    /// if pausing is enabled.
+    ///
+    /// [`Step`]: gherkin::Step
+    pause_input: Option<Box<dyn io::BufRead + Send>>,
+
+    /// Indicator whether [`Scenario::ResourceUsage`] events should be emitted.
+    ///
+    /// [`Scenario::ResourceUsage`]: event::Scenario::ResourceUsage
+    #[cfg(feature = "resource-usage")]
+    resource_usage: bool,
+}
+
+impl<World> Default for Options<World> {
+    fn default() -> Self {
+        Self {
+            max_concurrent_scenarios: None,
+            teardown: None,
+            new_world: None,
+            skip_feature_on_background_failure: false,
+            fail_on_ambiguous: true,
+            step_heartbeat: None,
+            capture_output: false,
+            run_timeout: None,
+            run_timeout_grace_period: DEFAULT_RUN_TIMEOUT_GRACE_PERIOD,
+            warmup: None,
+            on_scenario_passed: None,
+            on_scenario_failed: None,
+            working_dir: None,
+            retries: 0,
+            retry_filter: None,
+            tag_inheritance: tag::Inheritance::Inherit,
+            retry_after: None,
+            step_timeout: None,
+            scenario_timeout: None,
+            shuffle_seed: None,
+            fail_fast: None,
+            dry_run: false,
+            before_all: None,
+            after_all: None,
+            pause_input: None,
+            #[cfg(feature = "resource-usage")]
+            resource_usage: false,
+        }
+    }
 }
 
 // Implemented manually to omit redundant trait bounds on `World` and to omit
@@ -162,7 +487,10 @@ pub struct Basic<
 impl<World, F, B, A> fmt::Debug for Basic<World, F, B, A> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Basic")
-            .field("max_concurrent_scenarios", &self.max_concurrent_scenarios)
+            .field(
+                "max_concurrent_scenarios",
+                &self.options.max_concurrent_scenarios,
+            )
             .field("steps", &self.steps)
             .finish_non_exhaustive()
     }
@@ -173,11 +501,11 @@ impl<World> Basic<World, ()> {
     #[must_use]
     pub fn custom() -> Self {
         Self {
-            max_concurrent_scenarios: None,
             steps: step::Collection::new(),
             which_scenario: (),
             before_hook: None,
             after_hook: None,
+            options: Options::default(),
         }
     }
 }
@@ -194,11 +522,14 @@ impl<World> Default for Basic<World> {
         };
 
         Self {
-            max_concurrent_scenarios: Some(64),
             steps: step::Collection::new(),
             which_scenario,
             before_hook: None,
             after_hook: None,
+            options: Options {
+                max_concurrent_scenarios: Some(64),
+                ..Options::default()
+            },
         }
     }
 }
@@ -207,16 +538,115 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
     /// If `max` is [`Some`], then number of concurrently executed [`Scenario`]s
     /// will be limited.
     ///
+    /// The limit applies across all the [`Feature`]s being executed, so
+    /// `Some(1)` executes [`Scenario`]s one by one, while [`None`] (or
+    /// `Some(0)`) executes them all at once. Default limit is `Some(64)`.
+    ///
+    /// The limit works like a semaphore: a new [`Scenario`] is started as soon
+    /// as any executed one finishes, so a single slow [`Scenario`] doesn't hold
+    /// back the others. As the [`World`] is created right before executing a
+    /// [`Scenario`], only `max` [`World`]s exist at the same time, which also
+    /// bounds the resources acquired in [`World::new()`] (like database
+    /// connections). However, the [`World::new()`] cost is paid inside the
+    /// slot, so expensive [`World`]s reduce the actual throughput.
+    ///
+    /// The order of the output isn't affected by the limit, as long as
+    /// a [`Normalized`] [`Writer`] is used, which outputs [`Scenario`]s in the
+    /// order they've been started.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Normalized`]: crate::writer::Normalized
     /// [`Scenario`]: gherkin::Scenario
+    /// [`World`]: crate::World
+    /// [`World::new()`]: crate::World::new()
+    /// [`Writer`]: crate::Writer
     #[must_use]
     pub fn max_concurrent_scenarios(
         mut self,
         max: impl Into<Option<usize>>,
     ) -> Self {
-        self.max_concurrent_scenarios = max.into();
+        self.options.max_concurrent_scenarios = max.into();
         self
     }
 
+    /// Returns the configured limit of concurrently executed [`Scenario`]s, if
+    /// any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn concurrency(&self) -> Option<usize> {
+        self.options.max_concurrent_scenarios
+    }
+
+    /// Indicates whether this [`Runner`] [pauses on failures][0], executing
+    /// [`Scenario`]s one at a time regardless of the [`concurrency()`].
+    ///
+    /// [0]: Basic::pause_on_failure()
+    /// [`concurrency()`]: Basic::concurrency()
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn pauses_on_failure(&self) -> bool {
+        self.options.pause_input.is_some()
+    }
+
+    /// Returns the configured number of times a failed [`Scenario`] is
+    /// retried.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn max_retries(&self) -> usize {
+        self.options.retries
+    }
+
+    /// Returns the configured delay before retrying a failed [`Scenario`], if
+    /// any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn retry_delay(&self) -> Option<Duration> {
+        self.options.retry_after
+    }
+
+    /// Returns the configured maximum duration of a single [`Step`], if
+    /// limited.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub const fn step_time_limit(&self) -> Option<Duration> {
+        self.options.step_timeout
+    }
+
+    /// Returns the configured maximum duration of a whole [`Scenario`], if
+    /// limited.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn scenario_time_limit(&self) -> Option<Duration> {
+        self.options.scenario_timeout
+    }
+
+    /// Returns the configured maximum duration of the whole run, if limited.
+    #[must_use]
+    pub const fn run_time_limit(&self) -> Option<Duration> {
+        self.options.run_timeout
+    }
+
+    /// Indicates whether this [`Runner`] [fails fast][0].
+    ///
+    /// [0]: Basic::fail_fast()
+    #[must_use]
+    pub const fn fails_fast(&self) -> bool {
+        self.options.fail_fast.is_some()
+    }
+
+    /// Indicates whether this [`Runner`] performs a [dry run][0].
+    ///
+    /// [0]: Basic::dry_run()
+    #[must_use]
+    pub const fn is_dry_run(&self) -> bool {
+        self.options.dry_run
+    }
+
     /// Function determining whether a [`Scenario`] is [`Concurrent`] or
     /// a [`Serial`] one.
     ///
@@ -234,18 +664,18 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
             + 'static,
     {
         let Self {
-            max_concurrent_scenarios,
             steps,
             before_hook,
             after_hook,
+            options,
             ..
         } = self;
         Basic {
-            max_concurrent_scenarios,
             steps,
             which_scenario: func,
             before_hook,
             after_hook,
+            options,
         }
     }
 
@@ -266,23 +696,28 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
         ) -> LocalBoxFuture<'a, ()>,
     {
         let Self {
-            max_concurrent_scenarios,
             steps,
             which_scenario,
             after_hook,
+            options,
             ..
         } = self;
         Basic {
-            max_concurrent_scenarios,
             steps,
             which_scenario,
             before_hook: Some(func),
             after_hook,
+            options,
         }
     }
 
     /// Sets hook, executed on each [`Scenario`] after running all its
-    /// [`Step`]s, even after [`Skipped`] of [`Failed`] ones.
+    /// [`Step`]s (including [`Background`] ones), even after [`Skipped`] or
+    /// [`Failed`] ones, or a failed [`before`] hook (in which case no
+    /// [`Step`]s are executed at all).
+    ///
+    /// The [`ScenarioFinished`] outcome of the [`Scenario`] is supplied to the
+    /// function, so it may do the appropriate cleanup.
     ///
     /// Last `World` argument is supplied to the function, in case it was
     /// initialized before by running [`before`] hook or any non-failed
@@ -291,9 +726,11 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
     /// additional [`Clone`] bounds on `World`, so the only option left is to
     /// pass [`None`] to the function.
     ///
+    /// [`Background`]: gherkin::Background
     /// [`before`]: Self::before()
     /// [`Failed`]: event::Step::Failed
     /// [`Scenario`]: gherkin::Scenario
+    /// [`ScenarioFinished`]: event::ScenarioFinished
     /// [`Skipped`]: event::Step::Skipped
     /// [`Step`]: gherkin::Step
     #[must_use]
@@ -303,90 +740,608 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
             &'a gherkin::Feature,
             Option<&'a gherkin::Rule>,
             &'a gherkin::Scenario,
+            &'a event::ScenarioFinished,
             Option<&'a mut World>,
         ) -> LocalBoxFuture<'a, ()>,
     {
         let Self {
-            max_concurrent_scenarios,
             steps,
             which_scenario,
             before_hook,
+            options,
             ..
         } = self;
         Basic {
-            max_concurrent_scenarios,
             steps,
             which_scenario,
             before_hook,
             after_hook: Some(func),
+            options,
         }
     }
 
-    /// Sets the given [`Collection`] of [`Step`]s to this [`Runner`].
+    /// Enables [`WorldTeardown`] of [`World`]s, awaited once their
+    /// [`Scenario`] finishes, after the [`after`] hook.
     ///
-    /// [`Collection`]: step::Collection
-    #[allow(clippy::missing_const_for_fn)] // false positive: drop in const
+    /// [`World`]s of failed [`Step`]s and hooks are torn down too, so they're
+    /// retained by this [`Runner`] instead of being passed into the failure
+    /// events. Error or panic in a teardown is reported as a failed
+    /// [`HookType::After`].
+    ///
+    /// [`after`]: Self::after()
+    /// [`Step`]: gherkin::Step
+    /// [`Scenario`]: gherkin::Scenario
     #[must_use]
-    pub fn steps(mut self, steps: step::Collection<World>) -> Self {
-        self.steps = steps;
+    pub fn teardown(mut self) -> Self
+    where
+        World: WorldTeardown,
+    {
+        self.options.teardown = Some(|world| {
+            WorldTeardown::drop(world)
+                .map(|res| res.map_err(|e| coerce_into_info(e.to_string())))
+                .boxed_local()
+        });
         self
     }
 
-    /// Adds a [Given] [`Step`] matching the given `regex`.
+    /// Makes this [`Runner`] construct [`World`]s via
+    /// [`WorldWithContext::new_with()`] instead of [`World::new()`], passing a
+    /// clone of the given `ctx` for each [`Scenario`].
     ///
-    /// [Given]: https://cucumber.io/docs/gherkin/reference/#given
+    /// [`Scenario`]: gherkin::Scenario
     #[must_use]
-    pub fn given(mut self, regex: Regex, step: Step<World>) -> Self {
-        self.steps = mem::take(&mut self.steps).given(None, regex, step);
+    pub fn with_context(mut self, ctx: World::Context) -> Self
+    where
+        World: WorldWithContext,
+    {
+        self.options.new_world = Some(Rc::new(move || {
+            World::new_with(ctx.clone())
+                .map(|res| res.map_err(|e| e.to_string()))
+                .boxed_local()
+        }));
         self
     }
 
-    /// Adds a [When] [`Step`] matching the given `regex`.
+    /// Makes this [`Runner`] skip the rest of a [`Feature`]'s [`Scenario`]s
+    /// once its [`Background`] [`Step`] fails, as they share the broken setup.
     ///
-    /// [When]: https://cucumber.io/docs/gherkin/reference/#given
+    /// Skipped [`Scenario`]s emit a [`Scenario::Skipped`] event referencing the
+    /// failed [`Background`] [`Step`]. [`Scenario`]s being already executed
+    /// concurrently at the moment of failure are not affected.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Scenario::Skipped`]: event::Scenario::Skipped
+    /// [`Step`]: gherkin::Step
     #[must_use]
-    pub fn when(mut self, regex: Regex, step: Step<World>) -> Self {
-        self.steps = mem::take(&mut self.steps).when(None, regex, step);
+    pub const fn skip_feature_on_background_failure(mut self) -> Self {
+        self.options.skip_feature_on_background_failure = true;
         self
     }
 
-    /// Adds a [Then] [`Step`] matching the given `regex`.
+    /// Makes this [`Runner`] emit [`Scenario::StepHeartbeat`] events every
+    /// `interval` while a [`Step`] is still being executed.
     ///
-    /// [Then]: https://cucumber.io/docs/gherkin/reference/#then
+    /// Useful for keeping CI jobs alive during legitimately slow [`Step`]s, as
+    /// [`Writer`]s may output such events as keepalive messages. If `interval`
+    /// is [`None`], then no heartbeats are emitted (default).
+    ///
+    /// [`Scenario::StepHeartbeat`]: event::Scenario::StepHeartbeat
+    /// [`Step`]: gherkin::Step
+    /// [`Writer`]: crate::Writer
     #[must_use]
-    pub fn then(mut self, regex: Regex, step: Step<World>) -> Self {
-        self.steps = mem::take(&mut self.steps).then(None, regex, step);
+    pub fn step_heartbeat(
+        mut self,
+        interval: impl Into<Option<Duration>>,
+    ) -> Self {
+        self.options.step_heartbeat = interval.into();
         self
     }
-}
 
-impl<W, Which, Before, After> Runner<W> for Basic<W, Which, Before, After>
-where
-    W: World,
-    Which: Fn(
-            &gherkin::Feature,
-            Option<&gherkin::Rule>,
-            &gherkin::Scenario,
-        ) -> ScenarioType
-        + 'static,
-    Before: for<'a> Fn(
-            &'a gherkin::Feature,
-            Option<&'a gherkin::Rule>,
-            &'a gherkin::Scenario,
-            &'a mut W,
-        ) -> LocalBoxFuture<'a, ()>
-        + 'static,
-    After: for<'a> Fn(
-            &'a gherkin::Feature,
-            Option<&'a gherkin::Rule>,
-            &'a gherkin::Scenario,
-            Option<&'a mut W>,
-        ) -> LocalBoxFuture<'a, ()>
-        + 'static,
-{
-    type Cli = Cli;
+    /// Makes this [`Runner`] [`capture`] the output of [`Step`]s written via
+    /// [`capture::stdout()`] and [`capture::stderr()`], emitting it as
+    /// [`Step::Output`] events of the failed [`Step`]s only, while discarding
+    /// it for the passed ones.
+    ///
+    /// The process' [`io::Stdout`] and [`io::Stderr`] aren't captured.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`io::Stderr`]: std::io::Stderr
+    /// [`io::Stdout`]: std::io::Stdout
+    /// [`Step`]: gherkin::Step
+    /// [`Step::Output`]: event::Step::Output
+    #[must_use]
+    pub const fn capture_output(mut self, enabled: bool) -> Self {
+        self.options.capture_output = enabled;
+        self
+    }
 
-    type EventStream =
+    /// Limits the total wall-clock duration of the whole run to the given
+    /// `timeout`. If [`None`], the run isn't limited (default).
+    ///
+    /// Once the `timeout` is exceeded, no more [`Scenario`]s are started, and
+    /// the in-flight ones are given a [grace period][1] to finish, after
+    /// which they're abandoned: their running [`Step`]s are cancelled, while
+    /// the [`after_hook`] and the [`teardown`] are still given another
+    /// [grace period][1] to clean up. Every [`Scenario`] not finished
+    /// normally is reported as [skipped][2], making the run fail.
+    ///
+    /// This is a top-level guard, independent of any finer timeouts applied
+    /// to [`Step`]s or [`Scenario`]s: whichever fires first wins.
+    ///
+    /// [1]: Basic::run_timeout_grace_period()
+    /// [2]: event::SkipReason::RunTimeout
+    /// [`after_hook`]: Basic::after()
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    /// [`teardown`]: Basic::teardown()
+    #[must_use]
+    pub fn run_timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+        self.options.run_timeout = timeout.into();
+        self
+    }
+
+    /// Sets the duration the in-flight [`Scenario`]s are waited for once the
+    /// [`Basic::run_timeout()`] is exceeded, before abandoning them.
+    ///
+    /// Default is 5 seconds.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn run_timeout_grace_period(mut self, period: Duration) -> Self {
+        self.options.run_timeout_grace_period = period;
+        self
+    }
+
+    /// Makes this [`Runner`] execute the [`Scenario`]s matching the given
+    /// `selector` once as a warm-up, before the actual run.
+    ///
+    /// Warm-up executions don't emit any events, so their outcomes are
+    /// excluded from the results. Useful for timing-sensitive [`Scenario`]s
+    /// affected by JIT or caching effects.
+    ///
+    /// If any warm-up [`Scenario`] fails, the environment is considered
+    /// broken, so the run is aborted with a [`Cucumber::GlobalHookFailed`]
+    /// event of the [`GlobalHookType::WarmUp`], carrying the failure.
+    ///
+    /// [`Cucumber::GlobalHookFailed`]: event::Cucumber::GlobalHookFailed
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn warmup(mut self, selector: WarmUpFn) -> Self {
+        self.options.warmup = Some(selector);
+        self
+    }
+
+    /// Sets a callback, executed on each [`Scenario`] once all its [`Step`]s
+    /// (including [`Background`] ones) and hooks pass.
+    ///
+    /// Unlike the [`after`] hook, it's not executed for failed or skipped
+    /// [`Scenario`]s. Panics inside it are ignored.
+    ///
+    /// [`after`]: Basic::after()
+    /// [`Background`]: gherkin::Background
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn on_scenario_passed(mut self, func: ScenarioOutcomeFn) -> Self {
+        self.options.on_scenario_passed = Some(func);
+        self
+    }
+
+    /// Sets a callback, executed on each [`Scenario`] once any of its
+    /// [`Step`]s (including [`Background`] ones) or hooks fails.
+    ///
+    /// Panics inside it are ignored.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn on_scenario_failed(mut self, func: ScenarioOutcomeFn) -> Self {
+        self.options.on_scenario_failed = Some(func);
+        self
+    }
+
+    /// Makes this [`Runner`] provide each [`Scenario`] with its own working
+    /// directory, derived by the given `func`, via [`step::Context`].
+    ///
+    /// The directory is created before the [`Scenario`] starts and is removed
+    /// with all its contents once it finishes (even if abandoned), so the
+    /// `func` should return a path unique to the [`Scenario`]. An already
+    /// existing directory is used as is and never removed. If the directory
+    /// cannot be created, the [`Scenario`] fails as if its [`before`] hook
+    /// did.
+    ///
+    /// The process-wide current directory is never changed, as it's shared
+    /// by all the concurrently executed [`Scenario`]s, so [`Step`]s should use
+    /// the [`Context::working_dir`] explicitly (via [`Command::current_dir()`],
+    /// for example).
+    ///
+    /// [`before`]: Basic::before()
+    /// [`Command::current_dir()`]: std::process::Command::current_dir()
+    /// [`Context::working_dir`]: step::Context::working_dir
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn working_dir(mut self, func: WorkingDirFn) -> Self {
+        self.options.working_dir = Some(func);
+        self
+    }
+
+    /// Makes this [`Runner`] provide each [`Scenario`] with its own temporary
+    /// working directory, created inside [`env::temp_dir()`].
+    ///
+    /// See [`Basic::working_dir()`] for details.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn temp_working_dir(self) -> Self {
+        self.working_dir(temp_working_dir)
+    }
+
+    /// Makes this [`Runner`] retry each failed [`Scenario`] up to the given
+    /// number of `times` (0 by default), reporting it as failed only if every
+    /// attempt fails.
+    ///
+    /// Each attempt is executed with a fresh [`World`] (re-running the
+    /// `Background` [`Step`]s too) and emits a [`Scenario::Retried`] event
+    /// before its [`Scenario::Started`] one, while events of the failed
+    /// attempts themselves are discarded. To retry only some [`Scenario`]s,
+    /// use [`Basic::retry_filter()`].
+    ///
+    /// A `@retry(N)` tag overrides the number of `times` (and the
+    /// [`Basic::retry_filter()`]) for the [`Scenario`] it's placed on, or for
+    /// all the [`Scenario`]s of the [`Rule`] or [`Feature`] it's placed on.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    /// [`Scenario::Retried`]: event::Scenario::Retried
+    /// [`Scenario::Started`]: event::Scenario::Started
+    #[must_use]
+    pub const fn retries(mut self, times: usize) -> Self {
+        self.options.retries = times;
+        self
+    }
+
+    /// Makes this [`Runner`] retry only the failed [`Scenario`]s whose
+    /// effective tags (see [`Cucumber::tag_inheritance()`]) match the given
+    /// `filter`, like `@flaky`.
+    ///
+    /// See [`Basic::retries()`] for details.
+    ///
+    /// [`Cucumber::tag_inheritance()`]: crate::Cucumber::tag_inheritance()
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn retry_filter(
+        mut self,
+        filter: impl Into<Option<tag::Expression>>,
+    ) -> Self {
+        self.options.retry_filter = filter.into();
+        self
+    }
+
+    /// Makes this [`Runner`] wait for the given `delay` before each
+    /// [retry][1] of a failed [`Scenario`]. If [`None`], retries immediately
+    /// (default).
+    ///
+    /// [1]: Basic::retries()
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn retry_after(mut self, delay: impl Into<Option<Duration>>) -> Self {
+        self.options.retry_after = delay.into();
+        self
+    }
+
+    /// Sets a hook, executed exactly once before any [`Scenario`] is started.
+    ///
+    /// The hook has no access to a [`World`], so is meant for setting up
+    /// process-wide state (like statics or external services). If it panics,
+    /// then no [`Scenario`] is executed at all, and the run fails with the
+    /// [`Cucumber::GlobalHookFailed`] event.
+    ///
+    /// [`Cucumber::GlobalHookFailed`]: event::Cucumber::GlobalHookFailed
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn before_all<F, Fut>(mut self, hook: F) -> Self
+    where
+        F: FnOnce() -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        self.options.before_all = Some(Box::new(move || hook().boxed_local()));
+        self
+    }
+
+    /// Sets a hook, executed exactly once after all [`Scenario`]s are
+    /// finished, just before the [`Cucumber::Finished`] event.
+    ///
+    /// If it panics, then the run fails with the
+    /// [`Cucumber::GlobalHookFailed`] event.
+    ///
+    /// [`Cucumber::Finished`]: event::Cucumber::Finished
+    /// [`Cucumber::GlobalHookFailed`]: event::Cucumber::GlobalHookFailed
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn after_all<F, Fut>(mut self, hook: F) -> Self
+    where
+        F: FnOnce() -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        self.options.after_all = Some(Box::new(move || hook().boxed_local()));
+        self
+    }
+
+    /// Limits the duration of each [`Step`] to the given `timeout`. If
+    /// [`None`], [`Step`]s aren't limited (default).
+    ///
+    /// Once the `timeout` is exceeded, the [`Step`] is cancelled (its future
+    /// is dropped along with the [`World`]) and reported as [failed][1] with
+    /// an [`event::StepError::TimedOut`], so the rest of its [`Scenario`]'s
+    /// [`Step`]s aren't executed, the same way as on a panic.
+    ///
+    /// A `@timeout(...)` tag (like `@timeout(30s)` or `@timeout(500ms)`, with
+    /// `ms`, `s`, `m` or `h` unit) overrides the `timeout` for the
+    /// [`Scenario`] it's placed on, or for all the [`Scenario`]s of the
+    /// [`Rule`] or [`Feature`] it's placed on.
+    ///
+    /// [1]: event::Step::Failed
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn step_timeout(
+        mut self,
+        timeout: impl Into<Option<Duration>>,
+    ) -> Self {
+        self.options.step_timeout = timeout.into();
+        self
+    }
+
+    /// Limits the duration of each [`Scenario`] (including all its [`Step`]s
+    /// and hooks) to the given `timeout`. If [`None`], [`Scenario`]s aren't
+    /// limited (default).
+    ///
+    /// Once the `timeout` is exceeded, the currently executed [`Step`] is
+    /// cancelled and reported the same way as on exceeding the
+    /// [`Basic::step_timeout()`], while a cancelled hook is reported as
+    /// [failed][1].
+    ///
+    /// [1]: event::Hook::Failed
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn scenario_timeout(
+        mut self,
+        timeout: impl Into<Option<Duration>>,
+    ) -> Self {
+        self.options.scenario_timeout = timeout.into();
+        self
+    }
+
+    /// Makes this [`Runner`] execute [`Scenario`]s of each [`Feature`] in
+    /// a random order, shuffled with the given `seed`, to surface [`Scenario`]s
+    /// depending on each other. If [`None`], a random `seed` is generated.
+    ///
+    /// The same `seed` always gives the same order, and is reported in the
+    /// [`Cucumber::ParsingFinished`] event, so a failed run can be replayed.
+    /// The order of [`Feature`]s themselves is kept intact.
+    ///
+    /// [`Cucumber::ParsingFinished`]: event::Cucumber::ParsingFinished
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn shuffle(mut self, seed: impl Into<Option<u64>>) -> Self {
+        self.options.shuffle_seed =
+            Some(seed.into().unwrap_or_else(random_seed));
+        self
+    }
+
+    /// Returns the seed [`Scenario`]s are shuffled with, if shuffling is
+    /// enabled.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn shuffle_seed(&self) -> Option<u64> {
+        self.options.shuffle_seed
+    }
+
+    /// If `enabled`, makes this [`Runner`] stop starting new [`Scenario`]s
+    /// once any [`Step`] or hook fails (after all the retries, if any), so a
+    /// fundamental breakage doesn't produce a flood of failures.
+    ///
+    /// [`Scenario`]s being executed at the moment of a failure are cancelled
+    /// and reported as [skipped][1] with [`SkipReason::FailFast`] (unless
+    /// [`Basic::fail_fast_complete()`] is used), and the [`Feature`]s and
+    /// [`Rule`]s they belong to are finished, so the emitted events remain
+    /// coherent, while the [`Scenario`]s not started are omitted entirely.
+    ///
+    /// [1]: event::Scenario::Skipped
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`SkipReason::FailFast`]: event::SkipReason::FailFast
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub const fn fail_fast(mut self, enabled: bool) -> Self {
+        self.options.fail_fast = if enabled {
+            Some(FailFast::Cancel)
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Makes this [`Runner`] [fail fast][1], but completing the [`Scenario`]s
+    /// being executed at the moment of a failure, instead of cancelling them.
+    ///
+    /// [1]: Basic::fail_fast()
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn fail_fast_complete(mut self) -> Self {
+        self.options.fail_fast = Some(FailFast::Complete);
+        self
+    }
+
+    /// Makes this [`Runner`] pause on each failed [`Step`] or hook until a
+    /// line is read from the given `input`, keeping the failed [`World`]
+    /// alive for inspection.
+    ///
+    /// Reading `q` aborts the run instead, as if [failing fast][1]. While
+    /// paused, the [`Step`], [`Scenario`] and [`run_timeout`]s are suspended.
+    /// [`Scenario`]s are executed one at a time, regardless of the
+    /// [`max_concurrent_scenarios()`] or `--concurrency` CLI option.
+    ///
+    /// The `input` is read on a separate thread, so doesn't block the
+    /// execution of other futures.
+    ///
+    /// [1]: Basic::fail_fast()
+    /// [`max_concurrent_scenarios()`]: Basic::max_concurrent_scenarios()
+    /// [`run_timeout`]: Basic::run_timeout()
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn pause_on_failure(
+        mut self,
+        input: impl io::BufRead + Send + 'static,
+    ) -> Self {
+        self.options.pause_input = Some(Box::new(input));
+        self
+    }
+
+    /// Makes this [`Runner`] only match [`Step`]s against the [`Collection`],
+    /// without executing them, constructing [`World`]s or running any hooks.
+    ///
+    /// Matched [`Step`]s are reported as [`Step::Matched`], the ones without a
+    /// matching function as [`Step::Skipped`], and the ones matching several
+    /// functions as [`Step::Failed`] with [`StepError::AmbiguousMatch`]. All
+    /// the [`Step`]s of a [`Scenario`] are matched, even after an unmatched
+    /// one.
+    ///
+    /// [`Collection`]: step::Collection
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    /// [`Step::Failed`]: event::Step::Failed
+    /// [`Step::Matched`]: event::Step::Matched
+    /// [`Step::Skipped`]: event::Step::Skipped
+    /// [`StepError::AmbiguousMatch`]: event::StepError::AmbiguousMatch
+    #[must_use]
+    pub const fn dry_run(mut self, enabled: bool) -> Self {
+        self.options.dry_run = enabled;
+        self
+    }
+
+    /// Sets whether a [`Step`] matching several [`Step`] functions fails its
+    /// [`Scenario`] with [`StepError::AmbiguousMatch`] (default), listing all
+    /// the matching [`Regex`]es along with their locations. This applies to
+    /// a [`dry_run()`] as well.
+    ///
+    /// If disabled, such [`Step`] is executed with the first (ordered by
+    /// [`Regex`] and location) of the matching functions, and once it passes,
+    /// is reported as a [`Step::SoftFailed`] one, so the ambiguity is warned
+    /// about without failing the [`Scenario`]. A [`dry_run()`] reports it the
+    /// same way.
+    ///
+    /// [`dry_run()`]: Self::dry_run()
+    /// [`Regex`]: regex::Regex
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    /// [`Step::SoftFailed`]: event::Step::SoftFailed
+    /// [`StepError::AmbiguousMatch`]: event::StepError::AmbiguousMatch
+    #[must_use]
+    pub const fn fail_on_ambiguous(mut self, enabled: bool) -> Self {
+        self.options.fail_on_ambiguous = enabled;
+        self
+    }
+
+    /// Makes this [`Runner`] sample the [`ResourceUsage`] of the process at
+    /// the start and the end of each [`Scenario`], emitting them as
+    /// [`Scenario::ResourceUsage`] event right before [`Scenario::Finished`].
+    ///
+    /// As samples are taken process-wide, they're attributable to a single
+    /// [`Scenario`] only if [`Scenario`]s are executed one at a time (see
+    /// [`Basic::max_concurrent_scenarios()`]).
+    ///
+    /// [`ResourceUsage`]: event::ResourceUsage
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Scenario::Finished`]: event::Scenario::Finished
+    /// [`Scenario::ResourceUsage`]: event::Scenario::ResourceUsage
+    #[cfg(feature = "resource-usage")]
+    #[must_use]
+    pub const fn resource_usage(mut self) -> Self {
+        self.options.resource_usage = true;
+        self
+    }
+
+    /// Sets the given [`Collection`] of [`Step`]s to this [`Runner`].
+    ///
+    /// [`Collection`]: step::Collection
+    #[allow(clippy::missing_const_for_fn)] // false positive: drop in const
+    #[must_use]
+    pub fn steps(mut self, steps: step::Collection<World>) -> Self {
+        self.steps = steps;
+        self
+    }
+
+    /// Adds a [Given] [`Step`] matching the given `regex`.
+    ///
+    /// [Given]: https://cucumber.io/docs/gherkin/reference/#given
+    #[must_use]
+    pub fn given(mut self, regex: Regex, step: Step<World>) -> Self {
+        self.steps = mem::take(&mut self.steps).given(None, regex, step);
+        self
+    }
+
+    /// Adds a [When] [`Step`] matching the given `regex`.
+    ///
+    /// [When]: https://cucumber.io/docs/gherkin/reference/#given
+    #[must_use]
+    pub fn when(mut self, regex: Regex, step: Step<World>) -> Self {
+        self.steps = mem::take(&mut self.steps).when(None, regex, step);
+        self
+    }
+
+    /// Adds a [Then] [`Step`] matching the given `regex`.
+    ///
+    /// [Then]: https://cucumber.io/docs/gherkin/reference/#then
+    #[must_use]
+    pub fn then(mut self, regex: Regex, step: Step<World>) -> Self {
+        self.steps = mem::take(&mut self.steps).then(None, regex, step);
+        self
+    }
+}
+
+impl<W, Which, Before, After> Runner<W> for Basic<W, Which, Before, After>
+where
+    W: World,
+    Which: Fn(
+            &gherkin::Feature,
+            Option<&gherkin::Rule>,
+            &gherkin::Scenario,
+        ) -> ScenarioType
+        + 'static,
+    Before: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a mut W,
+        ) -> LocalBoxFuture<'a, ()>
+        + 'static,
+    After: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a event::ScenarioFinished,
+            Option<&'a mut W>,
+        ) -> LocalBoxFuture<'a, ()>
+        + 'static,
+{
+    type Cli = Cli;
+
+    type EventStream =
         LocalBoxStream<'static, parser::Result<Event<event::Cucumber<W>>>>;
 
     fn run<S>(self, features: S, cli: Cli) -> Self::EventStream
@@ -394,29 +1349,49 @@ where
         S: Stream<Item = parser::Result<gherkin::Feature>> + 'static,
     {
         let Self {
-            max_concurrent_scenarios,
             steps,
             which_scenario,
             before_hook,
             after_hook,
+            mut options,
         } = self;
+        options.max_concurrent_scenarios =
+            cli.concurrency.or(options.max_concurrent_scenarios);
+        options.retries = cli.retries.unwrap_or(options.retries);
+        options.retry_filter = cli.retry_tag_filter.or(options.retry_filter);
+        options.shuffle_seed = cli.shuffle_seed.or(options.shuffle_seed);
+        options.fail_fast = options
+            .fail_fast
+            .or_else(|| cli.fail_fast.then(|| FailFast::Cancel));
+        options.dry_run |= cli.dry_run;
+        // Pausing on a failure of one `Scenario` shouldn't let others proceed.
+        if options.pause_input.is_some() {
+            options.max_concurrent_scenarios = Some(1);
+        }
 
         let buffer = Features::default();
         let (sender, receiver) = mpsc::unbounded();
+        let (parsed_sender, parsed_receiver) = oneshot::channel();
 
         let insert = insert_features(
             buffer.clone(),
             features,
             which_scenario,
+            (
+                options.shuffle_seed,
+                options.dry_run,
+                options.tag_inheritance,
+            ),
             sender.clone(),
+            parsed_sender,
         );
         let execute = execute(
             buffer,
-            cli.concurrency.or(max_concurrent_scenarios),
             steps,
+            (before_hook, after_hook),
+            options,
             sender,
-            before_hook,
-            after_hook,
+            parsed_receiver,
         );
 
         stream::select(
@@ -433,16 +1408,34 @@ where
         })
         .boxed_local()
     }
+
+    fn with_tag_inheritance(mut self, policy: tag::Inheritance) -> Self {
+        self.options.tag_inheritance = policy;
+        self
+    }
 }
 
-/// Stores [`Feature`]s for later use by [`execute()`].
+/// Stores [`Feature`]s for later use by [`execute()`], shuffling their
+/// [`Scenario`]s with the given `shuffle_seed`, if any.
+///
+/// Once all the [`Feature`]s are stored, passes the
+/// [`Cucumber::ParsingFinished`] event to the [`execute()`] via the given
+/// `parsed` channel.
 ///
+/// [`Cucumber::ParsingFinished`]: event::Cucumber::ParsingFinished
 /// [`Feature`]: gherkin::Feature
+/// [`Scenario`]: gherkin::Scenario
 async fn insert_features<W, S, F>(
     into: Features,
     features: S,
     which_scenario: F,
+    (shuffle_seed, dry_run, tag_inheritance): (
+        Option<u64>,
+        bool,
+        tag::Inheritance,
+    ),
     sender: mpsc::UnboundedSender<parser::Result<Event<event::Cucumber<W>>>>,
+    parsed: oneshot::Sender<event::Cucumber<W>>,
 ) where
     S: Stream<Item = parser::Result<gherkin::Feature>> + 'static,
     F: Fn(
@@ -452,13 +1445,48 @@ async fn insert_features<W, S, F>(
         ) -> ScenarioType
         + 'static,
 {
+    let (mut features_count, mut rules, mut scenarios, mut steps) =
+        (0, 0, 0, 0);
+    let mut parser_errors = 0;
+    // `Feature`s of the same file are indistinguishable in events, so only the
+    // first one of them is executed.
+    let mut inserted_paths = HashSet::new();
+    let mut rng = shuffle_seed.map(Rng::new);
+
     pin_mut!(features);
     while let Some(feat) = features.next().await {
         match feat {
-            Ok(f) => into.insert(f, &which_scenario).await,
+            Ok(f)
+                if f.path
+                    .as_ref()
+                    .map_or(false, |p| !inserted_paths.insert(p.clone())) => {}
+            Ok(f) => {
+                let bg_steps = |bg: &Option<gherkin::Background>| {
+                    bg.as_ref().map_or(0, |bg| bg.steps.len())
+                };
+                let feat_bg_steps = bg_steps(&f.background);
+
+                features_count += 1;
+                rules += f.rules.len();
+                scenarios += f.count_scenarios();
+                steps += f
+                    .scenarios
+                    .iter()
+                    .map(|sc| feat_bg_steps + sc.steps.len())
+                    .chain(f.rules.iter().flat_map(|r| {
+                        let rule_bg_steps = bg_steps(&r.background);
+                        r.scenarios.iter().map(move |sc| {
+                            feat_bg_steps + rule_bg_steps + sc.steps.len()
+                        })
+                    }))
+                    .sum::<usize>();
+
+                into.insert(f, &which_scenario, rng.as_mut()).await;
+            }
             // If the receiver end is dropped, then no one listens for events
             // so we can just stop from here.
             Err(e) => {
+                parser_errors += 1;
                 if sender.unbounded_send(Err(e)).is_err() {
                     break;
                 }
@@ -467,6 +1495,18 @@ async fn insert_features<W, S, F>(
     }
 
     into.finish();
+
+    // If the receiver end is dropped, then no one listens for events.
+    drop(parsed.send(event::Cucumber::ParsingFinished {
+        features: features_count,
+        rules,
+        scenarios,
+        steps,
+        parser_errors,
+        shuffle_seed,
+        dry_run,
+        tag_inheritance,
+    }));
 }
 
 /// Retrieves [`Feature`]s and executes them.
@@ -474,11 +1514,11 @@ async fn insert_features<W, S, F>(
 /// [`Feature`]: gherkin::Feature
 async fn execute<W, Before, After>(
     features: Features,
-    max_concurrent_scenarios: Option<usize>,
     collection: step::Collection<W>,
+    (before_hook, after_hook): (Option<Before>, Option<After>),
+    mut options: Options<W>,
     sender: mpsc::UnboundedSender<parser::Result<Event<event::Cucumber<W>>>>,
-    before_hook: Option<Before>,
-    after_hook: Option<After>,
+    parsed: oneshot::Receiver<event::Cucumber<W>>,
 ) where
     W: World,
     Before: 'static
@@ -493,6 +1533,7 @@ async fn execute<W, Before, After>(
             &'a gherkin::Feature,
             Option<&'a gherkin::Rule>,
             &'a gherkin::Scenario,
+            &'a event::ScenarioFinished,
             Option<&'a mut W>,
         ) -> LocalBoxFuture<'a, ()>,
 {
@@ -507,32 +1548,76 @@ async fn execute<W, Before, After>(
     let hook = panic::take_hook();
     panic::set_hook(Box::new(|_| {}));
 
+    let max_concurrent_scenarios = options.max_concurrent_scenarios;
+    let (run_timeout, grace_period) =
+        (options.run_timeout, options.run_timeout_grace_period);
+    // Neither global hooks, nor warm-up, are run when only matching `Step`s.
+    let (before_all, after_all, warmup) = if options.dry_run {
+        (None, None, None)
+    } else {
+        (
+            options.before_all.take(),
+            options.after_all.take(),
+            options.warmup,
+        )
+    };
+
     let mut executor =
-        Executor::new(collection, before_hook, after_hook, sender);
+        Executor::new(collection, before_hook, after_hook, options, sender);
 
     executor.send(event::Cucumber::Started);
 
-    loop {
-        let runnable = features.get(max_concurrent_scenarios).await;
-        if runnable.is_empty() {
-            if features.is_finished() {
-                break;
-            }
-            continue;
-        }
-
-        let started = executor.start_scenarios(&runnable);
-        executor.send_all(started);
+    // Waiting for all the `Feature`s to be parsed, so the total numbers of them
+    // are known before execution begins.
+    if let Ok(parsing_finished) = parsed.await {
+        executor.send(parsing_finished);
+    }
 
-        drop(
-            runnable
-                .into_iter()
-                .map(|(f, r, s)| executor.run_scenario(f, r, s))
-                .collect::<future::JoinAll<_>>()
-                .await,
-        );
+    // No `Scenario` should be executed against an environment, which failed
+    // to be set up.
+    let before_all_passed = match before_all {
+        Some(before) => {
+            executor
+                .run_global_hook(GlobalHookType::BeforeAll, before)
+                .await
+        }
+        None => true,
+    };
+
+    // Neither should be against an environment, which failed to warm up.
+    let warmed_up = match warmup.filter(|_| before_all_passed) {
+        Some(selector) => {
+            let selected = features.select(selector).await;
+            executor.warming_up.set(true);
+            executor
+                .execute_features(
+                    &selected,
+                    max_concurrent_scenarios,
+                    None,
+                    grace_period,
+                )
+                .await;
+            executor.finish_warm_up()
+        }
+        None => true,
+    };
+
+    if before_all_passed && warmed_up {
+        let deadline = run_timeout.map(|timeout| Instant::now() + timeout);
+        executor
+            .execute_features(
+                &features,
+                max_concurrent_scenarios,
+                deadline,
+                grace_period,
+            )
+            .await;
+    }
 
-        executor.cleanup_finished_rules_and_features();
+    if let Some(after) = after_all {
+        let _ = executor
+            .run_global_hook(GlobalHookType::AfterAll, after)
+            .await;
     }
 
     executor.send(event::Cucumber::Finished);
@@ -547,39 +1632,114 @@ async fn execute<W, Before, After>(
 struct Executor<W, Before, After> {
     /// Number of finished [`Scenario`]s of [`Feature`].
     ///
-    /// [`Feature`]: gherkin::Feature
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    features_scenarios_count:
+        RefCell<HashMap<Arc<gherkin::Feature>, AtomicUsize>>,
+
+    /// Number of finished [`Scenario`]s of [`Rule`].
+    ///
+    /// We also store path to `.feature` file so [`Rule`]s with same names and
+    /// spans in different files will have different hashes.
+    ///
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    #[allow(clippy::type_complexity)] // not really
+    rule_scenarios_count:
+        RefCell<HashMap<(Option<PathBuf>, Arc<gherkin::Rule>), AtomicUsize>>,
+
+    /// [`Step`]s [`Collection`].
+    ///
+    /// [`Collection`]: step::Collection
+    /// [`Step`]: step::Step
+    collection: step::Collection<W>,
+
+    /// Function, executed on each [`Scenario`] before running all [`Step`]s,
+    /// including [`Background`] ones.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    before_hook: Option<Before>,
+
+    /// Function, executed on each [`Scenario`] after running all [`Step`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    after_hook: Option<After>,
+
+    /// [`Options`] of executing [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    options: Options<W>,
+
+    /// Buffered events of the currently executed [`Scenario`]s' attempts,
+    /// which are going to be retried on failure.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    retryable_attempts:
+        RefCell<HashMap<Arc<gherkin::Scenario>, Vec<event::Cucumber<W>>>>,
+
+    /// Failed [`Background`] [`Step`]s of the currently executed [`Feature`]s.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Feature`]: gherkin::Feature
+    /// [`Step`]: gherkin::Step
+    failed_backgrounds:
+        RefCell<HashMap<Arc<gherkin::Feature>, Arc<gherkin::Step>>>,
+
+    /// Indicator whether the [`run_timeout`] has been exceeded, so no more
+    /// [`Scenario`]s should be started.
+    ///
+    /// [`run_timeout`]: Basic::run_timeout
+    /// [`Scenario`]: gherkin::Scenario
+    timed_out: Cell<bool>,
+
+    /// Indicator whether the run has been aborted on [failing fast][1], so no
+    /// more [`Scenario`]s should be started.
+    ///
     /// [`Scenario`]: gherkin::Scenario
-    features_scenarios_count: HashMap<Arc<gherkin::Feature>, AtomicUsize>,
+    /// [1]: Basic::fail_fast
+    aborted: Cell<bool>,
 
-    /// Number of finished [`Scenario`]s of [`Rule`].
+    /// Sender of the signal for the in-flight [`Scenario`]s to stop running
+    /// their [`Step`]s, which is dropped once they're abandoned.
     ///
-    /// We also store path to `.feature` file so [`Rule`]s with same names and
-    /// spans in different files will have different hashes.
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    abandon: RefCell<Option<oneshot::Sender<()>>>,
+
+    /// Signal for the in-flight [`Scenario`]s to stop running their
+    /// [`Step`]s, so only their [`HookType::After`] and teardown are executed.
     ///
-    /// [`Rule`]: gherkin::Rule
     /// [`Scenario`]: gherkin::Scenario
-    rule_scenarios_count:
-        HashMap<(Option<PathBuf>, Arc<gherkin::Rule>), AtomicUsize>,
+    /// [`Step`]: gherkin::Step
+    abandoned: future::Shared<oneshot::Receiver<()>>,
 
-    /// [`Step`]s [`Collection`].
+    /// Indicator whether the warm-up [`Scenario`]s are being executed, so no
+    /// events should be emitted.
     ///
-    /// [`Collection`]: step::Collection
-    /// [`Step`]: step::Step
-    collection: step::Collection<W>,
+    /// [`Scenario`]: gherkin::Scenario
+    warming_up: Cell<bool>,
 
-    /// Function, executed on each [`Scenario`] before running all [`Step`]s,
-    /// including [`Background`] ones.
+    /// Failure of the first failed warm-up [`Scenario`], if any.
     ///
-    /// [`Background`]: gherkin::Background
     /// [`Scenario`]: gherkin::Scenario
-    /// [`Step`]: gherkin::Step
-    before_hook: Option<Before>,
+    warm_up_failure: RefCell<Option<Info>>,
 
-    /// Function, executed on each [`Scenario`] after running all [`Step`]s.
+    /// Input to read a line from, once paused on a failure, if pausing is
+    /// enabled.
+    pause_input: Mutex<Option<Box<dyn io::BufRead + Send>>>,
+
+    /// Total duration of all the pauses on failures so far, which timeouts
+    /// are suspended for.
+    paused: Cell<Duration>,
+
+    /// Indicator whether the run has been aborted by the user while paused on
+    /// a failure, so the in-flight [`Scenario`]s should be cancelled.
     ///
     /// [`Scenario`]: gherkin::Scenario
-    /// [`Step`]: gherkin::Step
-    after_hook: Option<After>,
+    quit: Cell<bool>,
 
     /// Sender for notifying state of [`Feature`]s completion.
     ///
@@ -601,6 +1761,7 @@ where
             &'a gherkin::Feature,
             Option<&'a gherkin::Rule>,
             &'a gherkin::Scenario,
+            &'a event::ScenarioFinished,
             Option<&'a mut W>,
         ) -> LocalBoxFuture<'a, ()>,
 {
@@ -609,38 +1770,335 @@ where
         collection: step::Collection<W>,
         before_hook: Option<Before>,
         after_hook: Option<After>,
+        mut options: Options<W>,
         sender: mpsc::UnboundedSender<
             parser::Result<Event<event::Cucumber<W>>>,
         >,
     ) -> Self {
+        let (abandon, abandoned) = oneshot::channel();
+        let pause_input = Mutex::new(options.pause_input.take());
         Self {
-            features_scenarios_count: HashMap::new(),
-            rule_scenarios_count: HashMap::new(),
+            features_scenarios_count: RefCell::default(),
+            rule_scenarios_count: RefCell::default(),
             collection,
             before_hook,
             after_hook,
+            options,
+            retryable_attempts: RefCell::default(),
+            failed_backgrounds: RefCell::default(),
+            timed_out: Cell::new(false),
+            aborted: Cell::new(false),
+            abandon: RefCell::new(Some(abandon)),
+            abandoned: abandoned.shared(),
+            warming_up: Cell::new(false),
+            warm_up_failure: RefCell::default(),
+            pause_input,
+            paused: Cell::default(),
+            quit: Cell::new(false),
             sender,
         }
     }
 
-    /// Runs a [`Scenario`].
+    /// Executes all the given [`Features`], aborting them once the given
+    /// `deadline` (extended with the `grace_period`) is exceeded.
+    async fn execute_features(
+        &mut self,
+        features: &Features,
+        max_concurrent_scenarios: Option<usize>,
+        deadline: Option<Instant>,
+        grace_period: Duration,
+    ) {
+        let limit = max_concurrent_scenarios.filter(|max| *max > 0);
+        let mut in_flight = FuturesUnordered::new();
+        let mut running = RunningScenarios::new();
+        let mut serial_running = false;
+        let mut next_id = 0_usize;
+        loop {
+            // Start as many `Scenario`s as there are free slots, so a finished
+            // `Scenario` is replaced right away, without waiting for the ones
+            // started along with it.
+            if !serial_running
+                && !self.aborted.get()
+                && limit.map_or(true, |max| running.len() < max)
+            {
+                let free = limit.map(|max| max - running.len());
+                let (which, runnable) =
+                    features.get(free, !running.is_empty()).await;
+                if !runnable.is_empty() {
+                    serial_running = which == ScenarioType::Serial;
+                    self.send_all(self.start_scenarios(&runnable));
+                    for (f, r, s) in runnable {
+                        let id = next_id;
+                        next_id += 1;
+                        in_flight.push(
+                            self.run_scenario(
+                                Arc::clone(&f),
+                                r.clone(),
+                                Arc::clone(&s),
+                            )
+                            .map(move |()| id),
+                        );
+                        drop(running.insert(id, (f, r, s)));
+                    }
+                    continue;
+                }
+            }
+
+            if running.is_empty() {
+                if features.is_finished() || self.aborted.get() {
+                    break;
+                }
+                continue;
+            }
+
+            // The run isn't timed out while paused on failures.
+            let paused = self.paused.get();
+            let until = deadline.map(|d| {
+                if self.timed_out.get() {
+                    d + paused + grace_period
+                } else {
+                    d + paused
+                }
+            });
+            let timer = until.map_or_else(
+                || Either::Right(future::pending()),
+                |l| Either::Left(Delay::new(l - Instant::now().min(l))),
+            );
+            let finished = match future::select(in_flight.next(), timer).await {
+                Either::Left((Some(id), _)) => vec![id],
+                Either::Left((None, _)) => running.keys().copied().collect(),
+                Either::Right(_) if self.paused.get() > paused => continue,
+                Either::Right(_) if self.timed_out.get() => {
+                    self.abandon_scenarios(
+                        mem::take(&mut in_flight),
+                        mem::take(&mut running),
+                        event::SkipReason::RunTimeout,
+                        Some(grace_period),
+                    )
+                    .await;
+                    vec![]
+                }
+                Either::Right(_) => {
+                    self.timed_out.set(true);
+                    continue;
+                }
+            };
+            for id in finished {
+                drop(running.remove(&id));
+            }
+            if (self.options.fail_fast == Some(FailFast::Cancel)
+                || self.quit.get())
+                && self.aborted.get()
+                && !running.is_empty()
+            {
+                self.abandon_scenarios(
+                    mem::take(&mut in_flight),
+                    mem::take(&mut running),
+                    event::SkipReason::FailFast,
+                    None,
+                )
+                .await;
+            }
+            if running.is_empty() {
+                serial_running = false;
+            }
+
+            self.cleanup_finished_rules_and_features();
+        }
+
+        if self.aborted.get() {
+            self.finish_started_rules_and_features();
+        }
+    }
+
+    /// Forgets all the state left from executing the warm-up [`Scenario`]s,
+    /// so the actual run starts from scratch.
+    ///
+    /// Returns `false` if any of the warm-up [`Scenario`]s has failed, emitting
+    /// a [`Cucumber::GlobalHookFailed`] event with its failure.
+    ///
+    /// [`Cucumber::GlobalHookFailed`]: event::Cucumber::GlobalHookFailed
+    /// [`Scenario`]: gherkin::Scenario
+    fn finish_warm_up(&mut self) -> bool {
+        self.warming_up.set(false);
+        if let Some(info) = self.warm_up_failure.get_mut().take() {
+            self.send(event::Cucumber::GlobalHookFailed(
+                GlobalHookType::WarmUp,
+                info,
+            ));
+            return false;
+        }
+        self.features_scenarios_count.get_mut().clear();
+        self.rule_scenarios_count.get_mut().clear();
+        self.failed_backgrounds.get_mut().clear();
+        true
+    }
+
+    /// Runs a [`Scenario`], retrying it on failure, if configured.
     ///
     /// # Events
     ///
-    /// - Emits all [`Scenario`] events.
+    /// - Emits all [`Scenario`] events of its last attempt, preceded by a
+    ///   [`Scenario::Retried`] event for each failed one.
     /// - If [`Scenario`] was last for particular [`Rule`] or [`Feature`], also
     ///   emits finishing events for them.
     ///
     /// [`Feature`]: gherkin::Feature
     /// [`Rule`]: gherkin::Rule
     /// [`Scenario`]: gherkin::Scenario
-    #[allow(clippy::too_many_lines)]
+    /// [`Scenario::Retried`]: event::Scenario::Retried
     async fn run_scenario(
         &self,
         feature: Arc<gherkin::Feature>,
         rule: Option<Arc<gherkin::Rule>>,
         scenario: Arc<gherkin::Scenario>,
     ) {
+        #[cfg(feature = "resource-usage")]
+        let resources_at_start = self
+            .options
+            .resource_usage
+            .then(event::ResourceUsage::sample);
+
+        if self.options.dry_run {
+            self.match_scenario(&feature, rule.as_ref(), &scenario);
+            self.scenario_finished(feature, rule, scenario);
+            return;
+        }
+
+        let retries = self.retries_of(&feature, rule.as_deref(), &scenario);
+        for attempt in 0..=retries {
+            // Events of an attempt, which may be retried, are buffered, so
+            // the failed attempts don't affect `Writer`s.
+            let retryable = attempt < retries;
+            if retryable {
+                drop(
+                    self.retryable_attempts
+                        .borrow_mut()
+                        .insert(Arc::clone(&scenario), Vec::new()),
+                );
+            }
+
+            self.run_scenario_attempt(
+                Arc::clone(&feature),
+                rule.clone(),
+                Arc::clone(&scenario),
+            )
+            .await;
+
+            if retryable {
+                let events = self.take_retryable(&scenario);
+                if let Some(err) = events.iter().find_map(failure_of) {
+                    self.send(event::Cucumber::scenario(
+                        Arc::clone(&feature),
+                        rule.clone(),
+                        Arc::clone(&scenario),
+                        event::Scenario::retried(attempt + 1, retries, err),
+                    ));
+                    if let Some(delay) = self.options.retry_after {
+                        Delay::new(delay).await;
+                    }
+                    continue;
+                }
+                self.send_all(events.into_iter());
+            }
+            break;
+        }
+
+        #[cfg(feature = "resource-usage")]
+        if let Some(start) = resources_at_start {
+            self.send(event::Cucumber::scenario(
+                Arc::clone(&feature),
+                rule.clone(),
+                Arc::clone(&scenario),
+                event::Scenario::ResourceUsage {
+                    start,
+                    end: event::ResourceUsage::sample(),
+                },
+            ));
+        }
+
+        self.scenario_finished(feature, rule, scenario);
+    }
+
+    /// Matches all the [`Step`]s of a [`Scenario`] (including [`Background`]
+    /// ones) against the [`Collection`] in a [dry-run][1] mode.
+    ///
+    /// [1]: Basic::dry_run()
+    /// [`Background`]: gherkin::Background
+    /// [`Collection`]: step::Collection
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    fn match_scenario(
+        &self,
+        feature: &Arc<gherkin::Feature>,
+        rule: Option<&Arc<gherkin::Rule>>,
+        scenario: &Arc<gherkin::Scenario>,
+    ) {
+        let send = |ev| {
+            self.send(event::Cucumber::scenario(
+                Arc::clone(feature),
+                rule.cloned(),
+                Arc::clone(scenario),
+                ev,
+            ));
+        };
+
+        send(event::Scenario::Started);
+
+        let background = feature
+            .background
+            .iter()
+            .chain(rule.and_then(|r| r.background.as_ref()))
+            .flat_map(|bg| bg.steps.iter().map(|st| (st, true)));
+        let steps = scenario.steps.iter().map(|st| (st, false));
+        for (step, is_background) in background.chain(steps) {
+            let step = Arc::new(step.clone());
+            let outcome = match self.collection.find_first(&step) {
+                (Some((_, captures, _)), Some(e))
+                    if !self.options.fail_on_ambiguous =>
+                {
+                    event::Step::SoftFailed(
+                        Some(captures),
+                        event::StepError::AmbiguousMatch(e),
+                    )
+                }
+                (_, Some(e)) => event::Step::Failed(
+                    None,
+                    None,
+                    event::StepError::AmbiguousMatch(e),
+                ),
+                (Some((_, captures, _)), None) => {
+                    event::Step::Matched(captures)
+                }
+                (None, None) => event::Step::Skipped,
+            };
+            for ev in [event::Step::Started, outcome] {
+                send(if is_background {
+                    event::Scenario::Background(Arc::clone(&step), ev)
+                } else {
+                    event::Scenario::Step(Arc::clone(&step), ev)
+                });
+            }
+        }
+    }
+
+    /// Runs a single attempt of a [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[allow(clippy::too_many_lines)]
+    async fn run_scenario_attempt(
+        &self,
+        feature: Arc<gherkin::Feature>,
+        rule: Option<Arc<gherkin::Rule>>,
+        scenario: Arc<gherkin::Scenario>,
+    ) {
+        let retryable = self.is_retryable_attempt(&scenario);
+        let deadline = self
+            .options
+            .scenario_timeout
+            .map(|t| (Instant::now() + t, t));
+        let step_timeout =
+            self.step_timeout_of(&feature, rule.as_deref(), &scenario);
         let ok = |e: fn(_) -> event::Scenario<W>| {
             let (f, r, s) = (&feature, &rule, &scenario);
             move |step| {
@@ -655,29 +2113,86 @@ where
                 event::Cucumber::scenario(f, r, s, e(step, captures))
             }
         };
+        // Failed `Step`s and skipped ones (before any `World` is created) are
+        // indistinguishable by their results, so failures are tracked here.
+        let failure = RefCell::new(None);
         let err = |e: fn(_, _, _, _) -> event::Scenario<W>| {
             let (f, r, s) = (&feature, &rule, &scenario);
-            move |step, captures, w, info| {
+            let failure = &failure;
+            move |step, captures: Option<CaptureLocations>, w, info| {
+                *failure.borrow_mut() =
+                    Some(event::ScenarioFinished::StepFailed(
+                        captures.clone(),
+                        Clone::clone(&info),
+                    ));
                 let (f, r, s) = (Arc::clone(f), r.clone(), Arc::clone(s));
                 event::Cucumber::scenario(f, r, s, e(step, captures, w, info))
             }
         };
 
-        let compose = |started, passed, skipped, failed| {
-            (ok(started), ok_capt(passed), ok(skipped), err(failed))
+        let soft_err = |e: fn(_, _, _) -> event::Scenario<W>| {
+            let (f, r, s) = (&feature, &rule, &scenario);
+            move |step, captures, info| {
+                let (f, r, s) = (Arc::clone(f), r.clone(), Arc::clone(s));
+                event::Cucumber::scenario(f, r, s, e(step, captures, info))
+            }
         };
+        let out = |e: fn(_, _) -> event::Scenario<W>| {
+            let (f, r, s) = (&feature, &rule, &scenario);
+            move |step, output| {
+                let (f, r, s) = (Arc::clone(f), r.clone(), Arc::clone(s));
+                event::Cucumber::scenario(f, r, s, e(step, output))
+            }
+        };
+
+        let compose =
+            |started, passed, skipped, failed, soft_failed, output| {
+                (
+                    ok(started),
+                    ok_capt(passed),
+                    ok(skipped),
+                    err(failed),
+                    soft_err(soft_failed),
+                    out(output),
+                )
+            };
         let into_bg_step_ev = compose(
             event::Scenario::background_step_started,
             event::Scenario::background_step_passed,
             event::Scenario::background_step_skipped,
             event::Scenario::background_step_failed,
+            event::Scenario::background_step_soft_failed,
+            event::Scenario::background_step_output,
         );
+        let into_feature_bg_step_ev = {
+            let (started, passed, skipped, failed, soft_failed, output) =
+                into_bg_step_ev;
+            let feat = &feature;
+            let failed = move |step: Arc<_>, captures, world, info| {
+                // Retried attempt may pass, so shouldn't skip the `Feature`.
+                if !retryable {
+                    self.background_failed(feat, &step);
+                }
+                failed(step, captures, world, info)
+            };
+            (started, passed, skipped, failed, soft_failed, output)
+        };
         let into_step_ev = compose(
             event::Scenario::step_started,
             event::Scenario::step_passed,
             event::Scenario::step_skipped,
             event::Scenario::step_failed,
+            event::Scenario::step_soft_failed,
+            event::Scenario::step_output,
         );
+        let heartbeat = |step, elapsed| {
+            event::Cucumber::scenario(
+                Arc::clone(&feature),
+                rule.clone(),
+                Arc::clone(&scenario),
+                event::Scenario::StepHeartbeat(step, elapsed),
+            )
+        };
 
         self.send(event::Cucumber::scenario(
             Arc::clone(&feature),
@@ -686,63 +2201,332 @@ where
             event::Scenario::Started,
         ));
 
-        let world = async {
-            let before_hook = self
-                .run_before_hook(&feature, rule.as_ref(), &scenario)
-                .await
-                .map_err(|_unit| None)?;
-
-            let feature_background = feature
-                .background
-                .as_ref()
-                .map(|b| b.steps.iter().map(|s| Arc::new(s.clone())))
-                .into_iter()
-                .flatten();
-
-            let feature_background = stream::iter(feature_background)
-                .map(Ok)
-                .try_fold(before_hook, |world, bg_step| {
-                    self.run_step(world, bg_step, into_bg_step_ev).map_ok(Some)
-                })
-                .await?;
-
-            let rule_background = rule
-                .as_ref()
-                .map(|r| {
-                    r.background
-                        .as_ref()
-                        .map(|b| b.steps.iter().map(|s| Arc::new(s.clone())))
-                        .into_iter()
-                        .flatten()
+        let skip_reason = if self.timed_out.get() {
+            Some(event::SkipReason::RunTimeout)
+        } else {
+            self.failed_backgrounds
+                .borrow()
+                .get(&feature)
+                .map(|st| event::SkipReason::BackgroundFailed(Arc::clone(st)))
+        };
+        if let Some(reason) = skip_reason {
+            self.send(event::Cucumber::scenario(
+                Arc::clone(&feature),
+                rule.clone(),
+                Arc::clone(&scenario),
+                event::Scenario::Skipped(reason),
+            ));
+        } else {
+            let vars = step::Variables::default();
+            let working_dir = self
+                .options
+                .working_dir
+                .map(|f| {
+                    WorkingDir::create(f(&feature, rule.as_deref(), &scenario))
                 })
-                .into_iter()
-                .flatten();
+                .transpose();
+            let scope = StepScope {
+                vars: &vars,
+                working_dir: working_dir
+                    .as_ref()
+                    .ok()
+                    .and_then(Option::as_ref)
+                    .map(|dir| dir.path.as_path()),
+                step_timeout,
+                scenario_deadline: deadline,
+                paused_before: self.paused.get(),
+                retryable,
+            };
 
-            let rule_background = stream::iter(rule_background)
-                .map(Ok)
-                .try_fold(feature_background, |world, bg_step| {
-                    self.run_step(world, bg_step, into_bg_step_ev).map_ok(Some)
-                })
-                .await?;
+            let world = async {
+                if let Err(e) = &working_dir {
+                    let info = coerce_into_info(format!(
+                        "failed to create working directory: {}",
+                        e,
+                    ));
+                    *failure.borrow_mut() =
+                        Some(event::ScenarioFinished::BeforeHookFailed(
+                            Arc::clone(&info),
+                        ));
+                    for ev in [
+                        event::Scenario::hook_started(HookType::Before),
+                        event::Scenario::hook_failed(
+                            HookType::Before,
+                            None,
+                            info,
+                        ),
+                    ] {
+                        self.send(event::Cucumber::scenario(
+                            Arc::clone(&feature),
+                            rule.clone(),
+                            Arc::clone(&scenario),
+                            ev,
+                        ));
+                    }
+                    return Err(None);
+                }
 
-            stream::iter(scenario.steps.iter().map(|s| Arc::new(s.clone())))
-                .map(Ok)
-                .try_fold(rule_background, |world, step| {
-                    self.run_step(world, step, into_step_ev).map_ok(Some)
-                })
-                .await
+                let before_hook = self
+                    .run_before_hook(
+                        &feature,
+                        rule.as_ref(),
+                        &scenario,
+                        self.scenario_deadline(&scope),
+                    )
+                    .await
+                    .map_err(|(info, world)| {
+                        *failure.borrow_mut() = Some(
+                            event::ScenarioFinished::BeforeHookFailed(info),
+                        );
+                        world
+                    })?;
+
+                let feature_background = feature
+                    .background
+                    .as_ref()
+                    .map(|b| b.steps.iter().map(|s| Arc::new(s.clone())))
+                    .into_iter()
+                    .flatten();
+
+                let feature_background = stream::iter(feature_background)
+                    .map(Ok)
+                    .try_fold(before_hook, |world, bg_step| {
+                        self.run_step(
+                            world,
+                            bg_step,
+                            &scope,
+                            into_feature_bg_step_ev,
+                            &heartbeat,
+                        )
+                        .map_ok(Some)
+                    })
+                    .await?;
+
+                let rule_background = rule
+                    .as_ref()
+                    .map(|r| {
+                        r.background
+                            .as_ref()
+                            .map(|b| {
+                                b.steps.iter().map(|s| Arc::new(s.clone()))
+                            })
+                            .into_iter()
+                            .flatten()
+                    })
+                    .into_iter()
+                    .flatten();
+
+                let rule_background = stream::iter(rule_background)
+                    .map(Ok)
+                    .try_fold(feature_background, |world, bg_step| {
+                        self.run_step(
+                            world,
+                            bg_step,
+                            &scope,
+                            into_bg_step_ev,
+                            &heartbeat,
+                        )
+                        .map_ok(Some)
+                    })
+                    .await?;
+
+                stream::iter(scenario.steps.iter().map(|s| Arc::new(s.clone())))
+                    .map(Ok)
+                    .try_fold(rule_background, |world, step| {
+                        self.run_step(
+                            world,
+                            step,
+                            &scope,
+                            into_step_ev,
+                            &heartbeat,
+                        )
+                        .map_ok(Some)
+                    })
+                    .await
+            }
+            .await;
+            let passed = world.is_ok();
+            let world = world.unwrap_or_else(identity);
+            let failed = failure.borrow().is_some();
+            let finished = failure.take().unwrap_or(if passed {
+                event::ScenarioFinished::StepPassed
+            } else {
+                event::ScenarioFinished::StepSkipped
+            });
+
+            let world = self
+                .run_after_hook(
+                    world,
+                    &feature,
+                    rule.as_ref(),
+                    &scenario,
+                    &finished,
+                    self.scenario_deadline(&scope),
+                )
+                .await;
+            // `World` is torn down regardless of the `Scenario` result.
+            let world = match world {
+                Ok(Some(world)) => {
+                    self.run_teardown(world, &feature, rule.as_ref(), &scenario)
+                        .await
+                }
+                Err(Some(world)) => self
+                    .run_teardown(world, &feature, rule.as_ref(), &scenario)
+                    .await
+                    .and(Err(())),
+                Ok(None) => Ok(()),
+                Err(None) => Err(()),
+            };
+
+            let on_outcome = if failed || world.is_err() {
+                // Failed attempt is going to be retried, so has no outcome yet.
+                if retryable {
+                    None
+                } else {
+                    self.options.on_scenario_failed
+                }
+            } else if passed {
+                self.options.on_scenario_passed
+            } else {
+                None
+            };
+            if let Some(f) = on_outcome.filter(|_| !self.warming_up.get()) {
+                // Panics in outcome callbacks are ignored, as the `Scenario`
+                // has already finished at this point.
+                drop(panic::catch_unwind(AssertUnwindSafe(|| {
+                    f(&feature, rule.as_deref(), &scenario);
+                })));
+            }
         }
-        .await
-        .unwrap_or_else(identity);
+    }
 
-        self.run_after_hook(world, &feature, rule.as_ref(), &scenario)
-            .await
-            .map_or((), drop);
+    /// Abandons the given in-flight [`Scenario`]s due to the [`run_timeout`]
+    /// being exceeded, or on [failing fast][1], reporting them as
+    /// [skipped][0].
+    ///
+    /// Abandoned [`Scenario`]s stop running their [`Step`]s, but still execute
+    /// their [`HookType::After`] and teardown, awaited for the given
+    /// `grace_period`, if any. The ones not finished by then are dropped.
+    ///
+    /// [0]: event::Scenario::Skipped
+    /// [1]: Basic::fail_fast
+    /// [`run_timeout`]: Basic::run_timeout
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    async fn abandon_scenarios<Fut: Future<Output = usize>>(
+        &self,
+        mut in_flight: FuturesUnordered<Fut>,
+        mut running: RunningScenarios,
+        reason: event::SkipReason,
+        grace_period: Option<Duration>,
+    ) {
+        drop(self.abandon.borrow_mut().take());
+        for (_, _, scenario) in running.values() {
+            // Abandoned attempt won't be retried, so its events are reported.
+            self.send_all(self.take_retryable(scenario).into_iter());
+        }
+        for (f, r, s) in running.values() {
+            self.send(event::Cucumber::scenario(
+                Arc::clone(f),
+                r.clone(),
+                Arc::clone(s),
+                event::Scenario::Skipped(reason.clone()),
+            ));
+        }
+
+        let deadline = grace_period.map(|period| Instant::now() + period);
+        loop {
+            let next = in_flight.next();
+            let finished = match deadline {
+                Some(at) => until(next, at).await.flatten(),
+                None => next.await,
+            };
+            match finished {
+                Some(id) => drop(running.remove(&id)),
+                None => break,
+            }
+        }
+
+        for (f, r, s) in running.into_values() {
+            self.scenario_finished(f, r, s);
+        }
+    }
 
+    /// Indicates whether the in-flight [`Scenario`]s have been
+    /// [abandoned][`Executor::abandon_scenarios()`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn is_abandoned(&self) -> bool {
+        self.abandoned.peek().is_some()
+    }
+
+    /// Returns the deadline of the [`Scenario`] executed in the given `scope`,
+    /// extended by the pauses on failures since it has started.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn scenario_deadline(
+        &self,
+        scope: &StepScope<'_>,
+    ) -> Option<(Instant, Duration)> {
+        let paused = self.paused.get().saturating_sub(scope.paused_before);
+        scope
+            .scenario_deadline
+            .map(|(at, timeout)| (at + paused, timeout))
+    }
+
+    /// Pauses on a failure until a line is read from the input, if
+    /// [pausing][0] is enabled, aborting the run if `q` is read.
+    ///
+    /// Failures of `retryable` attempts and warm-up [`Scenario`]s aren't
+    /// paused on, as they aren't reported.
+    ///
+    /// [0]: Basic::pause_on_failure()
+    /// [`Scenario`]: gherkin::Scenario
+    async fn pause_on_failure(&self, retryable: bool) {
+        if retryable || self.warming_up.get() {
+            return;
+        }
+        let mut input = self.pause_input.lock().await;
+        let taken = match input.take() {
+            Some(taken) => taken,
+            None => return,
+        };
+
+        // Lets the failure be outputted before the prompt.
+        yield_now().await;
+        drop(writeln!(
+            io::stderr(),
+            "Paused on failure. Press Enter to continue, or `q` and Enter to \
+             abort.",
+        ));
+
+        let started_at = Instant::now();
+        let (taken, line) = read_line(taken).await;
+        self.paused.set(self.paused.get() + started_at.elapsed());
+        *input = taken;
+
+        if line.trim() == "q" {
+            self.quit.set(true);
+            self.aborted.set(true);
+        }
+    }
+
+    /// Emits [`Scenario::Finished`] event, along with the finishing events of
+    /// its [`Rule`] and [`Feature`], if it was the last one in them.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario::Finished`]: event::Scenario::Finished
+    fn scenario_finished(
+        &self,
+        feature: Arc<gherkin::Feature>,
+        rule: Option<Arc<gherkin::Rule>>,
+        scenario: Arc<gherkin::Scenario>,
+    ) {
         self.send(event::Cucumber::scenario(
             Arc::clone(&feature),
             rule.clone(),
-            Arc::clone(&scenario),
+            scenario,
             event::Scenario::Finished,
         ));
 
@@ -760,14 +2544,18 @@ where
     }
 
     /// Executes [`HookType::Before`], if present.
+    ///
+    /// Returns the [`Info`] of its failure, if it fails, along with the
+    /// [`World`] retained for its [teardown][`Basic::teardown`].
     async fn run_before_hook(
         &self,
         feature: &Arc<gherkin::Feature>,
         rule: Option<&Arc<gherkin::Rule>>,
         scenario: &Arc<gherkin::Scenario>,
-    ) -> Result<Option<W>, ()> {
+        deadline: Option<(Instant, Duration)>,
+    ) -> Result<Option<W>, (Info, Option<W>)> {
         let init_world = async {
-            AssertUnwindSafe(W::new())
+            AssertUnwindSafe(self.new_world())
                 .catch_unwind()
                 .await
                 .map_err(Info::from)
@@ -803,7 +2591,13 @@ where
                 }
             });
 
-            match fut.await {
+            let res = match deadline {
+                Some((at, timeout)) => until(fut, at)
+                    .await
+                    .unwrap_or_else(|| Err((timed_out(timeout), None))),
+                None => fut.await,
+            };
+            match res {
                 Ok(world) => {
                     self.send(event::Cucumber::scenario(
                         Arc::clone(feature),
@@ -814,17 +2608,21 @@ where
                     Ok(Some(world))
                 }
                 Err((info, world)) => {
+                    let (world, failed_world) = self.failed_world(world);
                     self.send(event::Cucumber::scenario(
                         Arc::clone(feature),
                         rule.map(Arc::clone),
                         Arc::clone(scenario),
                         event::Scenario::hook_failed(
                             HookType::Before,
-                            world.map(Arc::new),
-                            info,
+                            failed_world.clone(),
+                            Arc::clone(&info),
                         ),
                     ));
-                    Err(())
+                    self.pause_on_failure(self.is_retryable_attempt(scenario))
+                        .await;
+                    drop(failed_world);
+                    Err((info, world))
                 }
             }
         } else {
@@ -833,13 +2631,17 @@ where
     }
 
     /// Executes [`HookType::After`], if present.
+    ///
+    /// Returns the [`World`] back, unless it was passed into a failure event.
     async fn run_after_hook(
         &self,
         mut world: Option<W>,
         feature: &Arc<gherkin::Feature>,
         rule: Option<&Arc<gherkin::Rule>>,
         scenario: &Arc<gherkin::Scenario>,
-    ) -> Result<Option<W>, ()> {
+        finished: &event::ScenarioFinished,
+        deadline: Option<(Instant, Duration)>,
+    ) -> Result<Option<W>, Option<W>> {
         if let Some(hook) = self.after_hook.as_ref() {
             self.send(event::Cucumber::scenario(
                 Arc::clone(feature),
@@ -848,22 +2650,32 @@ where
                 event::Scenario::hook_started(HookType::After),
             ));
 
-            let fut = async {
-                let fut = (hook)(
-                    feature.as_ref(),
-                    rule.as_ref().map(AsRef::as_ref),
-                    scenario.as_ref(),
-                    world.as_mut(),
-                );
-                match AssertUnwindSafe(fut).catch_unwind().await {
-                    Ok(()) => Ok(world),
-                    Err(info) => Err((info, world)),
+            // `World` is kept outside the timed future, so isn't lost for
+            // its teardown once the hook times out.
+            let (res, timed_out_world) = {
+                let fut = async {
+                    let fut = (hook)(
+                        feature.as_ref(),
+                        rule.as_ref().map(AsRef::as_ref),
+                        scenario.as_ref(),
+                        finished,
+                        world.as_mut(),
+                    );
+                    AssertUnwindSafe(fut)
+                        .catch_unwind()
+                        .await
+                        .map_err(Info::from)
+                };
+                match deadline {
+                    Some((at, timeout)) => until(fut, at).await.map_or_else(
+                        || (Err(timed_out(timeout)), true),
+                        |r| (r, false),
+                    ),
+                    None => (fut.await, false),
                 }
             };
-
-            #[allow(clippy::shadow_unrelated)]
-            match fut.await {
-                Ok(world) => {
+            match res {
+                Ok(()) => {
                     self.send(event::Cucumber::scenario(
                         Arc::clone(feature),
                         rule.map(Arc::clone),
@@ -872,39 +2684,118 @@ where
                     ));
                     Ok(world)
                 }
-                Err((info, world)) => {
+                Err(info) => {
+                    let (world, failed_world) = if timed_out_world {
+                        (self.options.teardown.and(world), None)
+                    } else {
+                        self.failed_world(world)
+                    };
                     self.send(event::Cucumber::scenario(
                         Arc::clone(feature),
                         rule.map(Arc::clone),
                         Arc::clone(scenario),
                         event::Scenario::hook_failed(
                             HookType::After,
-                            world.map(Arc::new),
-                            info.into(),
+                            failed_world.clone(),
+                            info,
                         ),
                     ));
-                    Err(())
+                    self.pause_on_failure(self.is_retryable_attempt(scenario))
+                        .await;
+                    drop(failed_world);
+                    Err(world)
                 }
             }
         } else {
-            Ok(None)
+            Ok(world)
+        }
+    }
+
+    /// Splits the [`World`] of a failure into the one retained for its
+    /// [teardown][`Basic::teardown`] and the one passed into the failure
+    /// event.
+    ///
+    /// The [`World`] is retained only if the teardown is enabled, as it can't
+    /// be taken back out of the event, once emitted.
+    fn failed_world(&self, world: Option<W>) -> (Option<W>, Option<Arc<W>>) {
+        if self.options.teardown.is_some() {
+            (world, None)
+        } else {
+            (None, world.map(Arc::new))
         }
     }
 
+    /// Creates a new [`World`] either via the [`NewWorldFn`], if any, or via
+    /// [`World::new()`].
+    fn new_world(&self) -> LocalBoxFuture<'static, Result<W, String>> {
+        self.options.new_world.as_ref().map_or_else(
+            || {
+                W::new()
+                    .map(|res| res.map_err(|e| e.to_string()))
+                    .boxed_local()
+            },
+            |new_world| new_world(),
+        )
+    }
+
+    /// Tears down the given [`World`] via [`WorldTeardown`].
+    ///
+    /// Its error or panic is reported as a failed [`HookType::After`], as the
+    /// [`Scenario`] can't be considered passed with resources not released.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    async fn run_teardown(
+        &self,
+        world: W,
+        feature: &Arc<gherkin::Feature>,
+        rule: Option<&Arc<gherkin::Rule>>,
+        scenario: &Arc<gherkin::Scenario>,
+    ) -> Result<(), ()> {
+        let teardown = match self.options.teardown {
+            Some(teardown) => teardown,
+            None => return Ok(()),
+        };
+        let res = match AssertUnwindSafe(teardown(world)).catch_unwind().await {
+            Ok(res) => res,
+            Err(info) => Err(Info::from(info)),
+        };
+        res.map_err(|info| {
+            self.send(event::Cucumber::scenario(
+                Arc::clone(feature),
+                rule.map(Arc::clone),
+                Arc::clone(scenario),
+                event::Scenario::hook_failed(HookType::After, None, info),
+            ));
+        })
+    }
+
     /// Runs a [`Step`].
     ///
     /// # Events
     ///
     /// - Emits all [`Step`] events.
+    /// - Emits [`Scenario::StepHeartbeat`] events periodically, if configured.
     ///
+    /// [`Scenario::StepHeartbeat`]: event::Scenario::StepHeartbeat
     /// [`Step`]: gherkin::Step
-    async fn run_step<St, Ps, Sk, F>(
+    #[allow(clippy::too_many_lines)]
+    async fn run_step<St, Ps, Sk, F, Sf, Out, Hb>(
         &self,
         world: Option<W>,
         step: Arc<gherkin::Step>,
-        (started, passed, skipped, failed): (St, Ps, Sk, F),
+        scope: &StepScope<'_>,
+        (started, passed, skipped, failed, soft_failed, output): (
+            St,
+            Ps,
+            Sk,
+            F,
+            Sf,
+            Out,
+        ),
+        heartbeat: &Hb,
     ) -> Result<W, Option<W>>
     where
+        Hb: Fn(Arc<gherkin::Step>, Duration) -> event::Cucumber<W>,
         St: FnOnce(Arc<gherkin::Step>) -> event::Cucumber<W>,
         Ps: FnOnce(Arc<gherkin::Step>, CaptureLocations) -> event::Cucumber<W>,
         Sk: FnOnce(Arc<gherkin::Step>) -> event::Cucumber<W>,
@@ -914,62 +2805,162 @@ where
             Option<Arc<W>>,
             event::StepError,
         ) -> event::Cucumber<W>,
+        Sf: FnOnce(
+            Arc<gherkin::Step>,
+            Option<CaptureLocations>,
+            event::StepError,
+        ) -> event::Cucumber<W>,
+        Out: FnOnce(Arc<gherkin::Step>, String) -> event::Cucumber<W>,
     {
+        if self.is_abandoned() {
+            return Err(world);
+        }
         self.send(started(Arc::clone(&step)));
 
+        // `World` is kept outside the timed future, so isn't lost for its
+        // teardown once the `Step` times out.
+        let mut world = world;
         let run = async {
-            let (step_fn, captures, ctx) = match self.collection.find(&step) {
-                Ok(Some(f)) => f,
-                Ok(None) => return Ok((None, world)),
-                Err(e) => {
+            let (found, ambiguous) = self.collection.find_first(&step);
+            let ambiguous = match ambiguous {
+                Some(e) if self.options.fail_on_ambiguous => {
                     let e = event::StepError::AmbiguousMatch(e);
-                    return Err((e, None, world));
+                    return Err((e, None, false));
                 }
+                ambiguous => ambiguous,
             };
+            let (step_fn, captures, mut ctx) = match found {
+                Some(f) => f,
+                None => return Ok(None),
+            };
+
+            ctx.vars = scope.vars.clone();
+            ctx.working_dir = scope.working_dir.map(Path::to_path_buf);
+            let soft = ctx.soft;
 
-            let mut world = if let Some(w) = world {
+            let w = if let Some(w) = world.take() {
                 w
             } else {
-                match AssertUnwindSafe(W::new()).catch_unwind().await {
+                match AssertUnwindSafe(self.new_world()).catch_unwind().await {
                     Ok(Ok(w)) => w,
                     Ok(Err(e)) => {
                         let e = event::StepError::Panic(coerce_into_info(
                             format!("failed to initialize World: {}", e),
                         ));
-                        return Err((e, None, None));
+                        return Err((e, None, false));
                     }
                     Err(e) => {
                         let e = event::StepError::Panic(e.into());
-                        return Err((e, None, None));
+                        return Err((e, None, false));
                     }
                 }
             };
-
-            match AssertUnwindSafe(step_fn(&mut world, ctx))
-                .catch_unwind()
-                .await
-            {
-                Ok(()) => Ok((Some(captures), Some(world))),
+            let w = world.insert(w);
+
+            match AssertUnwindSafe(step_fn(w, ctx)).catch_unwind().await {
+                // Ambiguous `Step` is reported once it passes, as it has been
+                // executed with the first of the matching functions.
+                Ok(()) => match ambiguous {
+                    Some(e) => {
+                        let e = event::StepError::AmbiguousMatch(e);
+                        Err((e, Some(captures), true))
+                    }
+                    None => Ok(Some(captures)),
+                },
                 Err(e) => {
                     let e = event::StepError::Panic(e.into());
-                    Err((e, Some(captures), Some(world)))
+                    Err((e, Some(captures), soft))
+                }
+            }
+        };
+
+        let run = async {
+            if let Some(interval) = self.options.step_heartbeat {
+                let started_at = Instant::now();
+                pin_mut!(run);
+                loop {
+                    let tick = Delay::new(interval);
+                    match future::select(run.as_mut(), tick).await {
+                        Either::Left((res, _)) => break res,
+                        Either::Right(_) => self.send(heartbeat(
+                            Arc::clone(&step),
+                            started_at.elapsed(),
+                        )),
+                    }
                 }
+            } else {
+                run.await
+            }
+        };
+
+        // Whichever of the `Step` and `Scenario` timeouts expires first wins.
+        let deadline = scope
+            .step_timeout
+            .map(|t| (Instant::now() + t, t))
+            .into_iter()
+            .chain(self.scenario_deadline(scope))
+            .min_by_key(|(at, _)| *at);
+        let run = async {
+            if let Some((at, timeout)) = deadline {
+                until(run, at).await.unwrap_or_else(|| {
+                    Err((event::StepError::TimedOut(timeout), None, false))
+                })
+            } else {
+                run.await
+            }
+        };
+        let run = async {
+            if self.options.capture_output {
+                capture::captured(run).await
+            } else {
+                (run.await, String::new())
             }
         };
+        // Abandoned `Step` is cancelled without being reported, as its
+        // `Scenario` is reported as skipped instead.
+        let res =
+            match future::select(run.boxed_local(), self.abandoned.clone())
+                .await
+            {
+                Either::Left((res, _)) => Some(res),
+                Either::Right(_) => None,
+            };
+        let (res, step_output) = match res {
+            Some(res) => res,
+            None => return Err(world),
+        };
+        // Output of passed `Step`s is discarded.
+        if res.is_err() && !step_output.is_empty() {
+            self.send(output(Arc::clone(&step), step_output));
+        }
 
         #[allow(clippy::shadow_unrelated)]
-        match run.await {
-            Ok((Some(captures), Some(world))) => {
+        match (res, world) {
+            (Ok(Some(captures)), Some(world)) => {
                 self.send(passed(step, captures));
                 Ok(world)
             }
-            Ok((_, world)) => {
+            (Ok(_), world) => {
                 self.send(skipped(step));
                 Err(world)
             }
-            Err((err, captures, world)) => {
-                self.send(failed(step, captures, world.map(Arc::new), err));
-                Err(None)
+            (Err((err, captures, true)), Some(world)) => {
+                self.send(soft_failed(step, captures, err));
+                Ok(world)
+            }
+            (Err((err, captures, _)), world) => {
+                let (world, failed_world) =
+                    if matches!(err, event::StepError::TimedOut(_)) {
+                        (self.options.teardown.and(world), None)
+                    } else {
+                        self.failed_world(world)
+                    };
+                // Keeps the failed `World` alive while paused.
+                let alive = failed_world.clone();
+                self.send(failed(step, captures, failed_world, err));
+                self.pause_on_failure(scope.retryable).await;
+                drop(alive);
+                Err(world)
             }
         }
     }
@@ -987,6 +2978,7 @@ where
     ) -> Option<event::Cucumber<W>> {
         let finished_scenarios = self
             .rule_scenarios_count
+            .borrow()
             .get(&(feature.path.clone(), Arc::clone(&rule)))
             .unwrap_or_else(|| panic!("No Rule {}", rule.name))
             .fetch_add(1, Ordering::SeqCst)
@@ -1007,6 +2999,7 @@ where
     ) -> Option<event::Cucumber<W>> {
         let finished_scenarios = self
             .features_scenarios_count
+            .borrow()
             .get(&feature)
             .unwrap_or_else(|| panic!("No Feature {}", feature.name))
             .fetch_add(1, Ordering::SeqCst)
@@ -1026,7 +3019,7 @@ where
     /// [`Rule::Started`]: event::Rule::Started
     /// [`Scenario`]: gherkin::Scenario
     fn start_scenarios(
-        &mut self,
+        &self,
         runnable: impl AsRef<
             [(
                 Arc<gherkin::Feature>,
@@ -1041,6 +3034,7 @@ where
         for feature in runnable.iter().map(|(f, ..)| Arc::clone(f)).dedup() {
             let _ = self
                 .features_scenarios_count
+                .borrow_mut()
                 .entry(Arc::clone(&feature))
                 .or_insert_with(|| {
                     started_features.push(feature);
@@ -1058,6 +3052,7 @@ where
         {
             let _ = self
                 .rule_scenarios_count
+                .borrow_mut()
                 .entry((feat.path.clone(), Arc::clone(&rule)))
                 .or_insert_with(|| {
                     started_rules.push((feat, rule));
@@ -1075,33 +3070,112 @@ where
             )
     }
 
+    /// Emits finishing events of all the [`Rule`]s and [`Feature`]s, which
+    /// have been started, but won't be finished, as the run was aborted on
+    /// [failing fast][1].
+    ///
+    /// [1]: Basic::fail_fast
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    fn finish_started_rules_and_features(&self) {
+        let features =
+            mem::take(&mut *self.features_scenarios_count.borrow_mut())
+                .into_keys()
+                .collect::<Vec<_>>();
+        let rules = mem::take(&mut *self.rule_scenarios_count.borrow_mut());
+
+        for (path, rule) in rules.into_keys() {
+            let feature = features
+                .iter()
+                .find(|f| f.path == path && f.rules.contains(&rule));
+            if let Some(f) = feature {
+                self.send(event::Cucumber::rule_finished(Arc::clone(f), rule));
+            }
+        }
+        for f in features {
+            self.send(event::Cucumber::feature_finished(f));
+        }
+    }
+
     /// Removes all finished [`Rule`]s and [`Feature`]s as all their events are
     /// emitted already.
     ///
     /// [`Feature`]: gherkin::Feature
     /// [`Rule`]: gherkin::Rule
-    fn cleanup_finished_rules_and_features(&mut self) {
-        self.features_scenarios_count = self
-            .features_scenarios_count
-            .drain()
-            .filter(|(f, count)| {
-                f.count_scenarios() != count.load(Ordering::SeqCst)
-            })
-            .collect();
-
-        self.rule_scenarios_count = self
-            .rule_scenarios_count
-            .drain()
-            .filter(|((_, r), count)| {
+    fn cleanup_finished_rules_and_features(&self) {
+        let mut features = self.features_scenarios_count.borrow_mut();
+        features.retain(|f, count| {
+            f.count_scenarios() != count.load(Ordering::SeqCst)
+        });
+
+        self.rule_scenarios_count
+            .borrow_mut()
+            .retain(|(_, r), count| {
                 r.scenarios.len() != count.load(Ordering::SeqCst)
-            })
-            .collect();
+            });
+
+        self.failed_backgrounds
+            .borrow_mut()
+            .retain(|f, _| features.contains_key(f));
+    }
+
+    /// Remembers the failed [`Background`] [`Step`] of the given [`Feature`],
+    /// if the rest of its [`Scenario`]s should be skipped.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    fn background_failed(
+        &self,
+        feature: &Arc<gherkin::Feature>,
+        step: &Arc<gherkin::Step>,
+    ) {
+        if self.options.skip_feature_on_background_failure {
+            let _ = self
+                .failed_backgrounds
+                .borrow_mut()
+                .entry(Arc::clone(feature))
+                .or_insert_with(|| Arc::clone(step));
+        }
+    }
+
+    /// Runs the provided [`GlobalHookFn`], notifying about its failure, if
+    /// any.
+    ///
+    /// Returns `false` if the hook has failed.
+    async fn run_global_hook(
+        &self,
+        ty: GlobalHookType,
+        hook: GlobalHookFn,
+    ) -> bool {
+        match AssertUnwindSafe(hook()).catch_unwind().await {
+            Ok(()) => true,
+            Err(info) => {
+                self.send(event::Cucumber::GlobalHookFailed(
+                    ty,
+                    Info::from(info),
+                ));
+                false
+            }
+        }
     }
 
     /// Notifies with the given [`Cucumber`] event.
     ///
     /// [`Cucumber`]: event::Cucumber
     fn send(&self, event: event::Cucumber<W>) {
+        let event = match self.buffer_retryable(event) {
+            Some(ev) => ev,
+            None => return,
+        };
+        if self.warming_up.get() {
+            self.check_warm_up(&event);
+            return;
+        }
+        if self.options.fail_fast.is_some() && failure_of(&event).is_some() {
+            self.aborted.set(true);
+        }
         // If the receiver end is dropped, then no one listens for events
         // so we can just ignore it.
         drop(self.sender.unbounded_send(Ok(Event::new(event))));
@@ -1111,6 +3185,10 @@ where
     ///
     /// [`Cucumber`]: event::Cucumber
     fn send_all(&self, events: impl Iterator<Item = event::Cucumber<W>>) {
+        if self.warming_up.get() {
+            events.for_each(|ev| self.check_warm_up(&ev));
+            return;
+        }
         for v in events {
             // If the receiver end is dropped, then no one listens for events
             // so we can just stop from here.
@@ -1119,6 +3197,195 @@ where
             }
         }
     }
+
+    /// Checks whether the given [`Cucumber`] event of a warm-up [`Scenario`]
+    /// reports its failure, aborting the run, if so, as the environment is
+    /// considered broken.
+    ///
+    /// [`Cucumber`]: event::Cucumber
+    /// [`Scenario`]: gherkin::Scenario
+    fn check_warm_up(&self, event: &event::Cucumber<W>) {
+        if let Some(err) = failure_of(event) {
+            self.aborted.set(true);
+            let mut failure = self.warm_up_failure.borrow_mut();
+            if failure.is_none() {
+                *failure = Some(coerce_into_info(err.to_string()));
+            }
+        }
+    }
+
+    /// Returns the number of times the given [`Scenario`] should be retried
+    /// on failure, preferring the most specific `@retry(N)` tag, if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn retries_of(
+        &self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+    ) -> usize {
+        let tags = self.options.tag_inheritance.tags(feature, rule, scenario);
+        if let Some(times) = tags.iter().rev().find_map(|t| {
+            t.strip_prefix("retry(")?.strip_suffix(')')?.parse().ok()
+        }) {
+            return times;
+        }
+
+        let matches = self
+            .options
+            .retry_filter
+            .as_ref()
+            .map_or(true, |filter| filter.eval(tags));
+        if matches {
+            self.options.retries
+        } else {
+            0
+        }
+    }
+
+    /// Returns the maximum duration of each [`Step`] of the given [`Scenario`],
+    /// preferring the most specific `@timeout(...)` tag, if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    fn step_timeout_of(
+        &self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+    ) -> Option<Duration> {
+        self.options
+            .tag_inheritance
+            .tags(feature, rule, scenario)
+            .iter()
+            .rev()
+            .find_map(|t| {
+                parse_duration(t.strip_prefix("timeout(")?.strip_suffix(')')?)
+            })
+            .or(self.options.step_timeout)
+    }
+
+    /// Indicates whether the current attempt of the given [`Scenario`] is
+    /// going to be retried on failure.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn is_retryable_attempt(&self, scenario: &Arc<gherkin::Scenario>) -> bool {
+        self.retryable_attempts.borrow().contains_key(scenario)
+    }
+
+    /// Buffers the given [`Cucumber`] event, if it belongs to a retryable
+    /// attempt of a [`Scenario`], or returns it back otherwise.
+    ///
+    /// [`Cucumber`]: event::Cucumber
+    /// [`Scenario`]: gherkin::Scenario
+    fn buffer_retryable(
+        &self,
+        event: event::Cucumber<W>,
+    ) -> Option<event::Cucumber<W>> {
+        use event::{Cucumber, Feature, Rule};
+
+        let mut attempts = self.retryable_attempts.borrow_mut();
+        let buffer = if let Cucumber::Feature(
+            _,
+            Feature::Scenario(sc, _) | Feature::Rule(_, Rule::Scenario(sc, _)),
+        ) = &event
+        {
+            attempts.get_mut(sc)
+        } else {
+            None
+        };
+        match buffer {
+            Some(events) => {
+                events.push(event);
+                None
+            }
+            None => Some(event),
+        }
+    }
+
+    /// Stops buffering events of the given [`Scenario`]'s attempt, returning
+    /// the buffered ones.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn take_retryable(
+        &self,
+        scenario: &Arc<gherkin::Scenario>,
+    ) -> Vec<event::Cucumber<W>> {
+        self.retryable_attempts
+            .borrow_mut()
+            .remove(scenario)
+            .unwrap_or_default()
+    }
+}
+
+/// Scope of a [`Scenario`]'s attempt, shared by all its [`Step`]s.
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+struct StepScope<'a> {
+    /// [`step::Variables`] of the [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    vars: &'a step::Variables,
+
+    /// Working directory of the [`Scenario`], if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    working_dir: Option<&'a Path>,
+
+    /// Maximum duration of each [`Step`], if limited.
+    ///
+    /// [`Step`]: gherkin::Step
+    step_timeout: Option<Duration>,
+
+    /// Deadline of the whole [`Scenario`] along with its timeout, if limited.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    scenario_deadline: Option<(Instant, Duration)>,
+
+    /// Total duration of the pauses on failures before the [`Scenario`] has
+    /// started, so its [`scenario_deadline`] is extended by the ones after.
+    ///
+    /// [`scenario_deadline`]: Self::scenario_deadline
+    /// [`Scenario`]: gherkin::Scenario
+    paused_before: Duration,
+
+    /// Indicator whether the attempt of the [`Scenario`] is going to be
+    /// retried on failure.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    retryable: bool,
+}
+
+/// Returns the error the given [`Cucumber`] event reports a [`Scenario`]'s
+/// failure with, if any.
+///
+/// Failed hooks are represented with a [`StepError::Panic`].
+///
+/// [`Cucumber`]: event::Cucumber
+/// [`Scenario`]: gherkin::Scenario
+/// [`StepError::Panic`]: event::StepError::Panic
+fn failure_of<W>(event: &event::Cucumber<W>) -> Option<event::StepError> {
+    use event::{Cucumber, Feature, Hook, Rule, Scenario, Step};
+
+    let ev = if let Cucumber::Feature(
+        _,
+        Feature::Scenario(_, ev) | Feature::Rule(_, Rule::Scenario(_, ev)),
+    ) = event
+    {
+        ev
+    } else {
+        return None;
+    };
+    if let Scenario::Step(_, Step::Failed(_, _, err))
+    | Scenario::Background(_, Step::Failed(_, _, err)) = ev
+    {
+        return Some(err.clone());
+    }
+    if let Scenario::Hook(_, Hook::Failed(_, info)) = ev {
+        return Some(event::StepError::Panic(Arc::clone(info)));
+    }
+    None
 }
 
 /// [`Scenario`]s storage.
@@ -1133,6 +3400,18 @@ type Scenarios = HashMap<
     )>,
 >;
 
+/// In-flight [`Scenario`]s by their IDs.
+///
+/// [`Scenario`]: gherkin::Scenario
+type RunningScenarios = BTreeMap<
+    usize,
+    (
+        Arc<gherkin::Feature>,
+        Option<Arc<gherkin::Rule>>,
+        Arc<gherkin::Scenario>,
+    ),
+>;
+
 /// Storage sorted by [`ScenarioType`] [`Feature`]'s [`Scenario`]s.
 ///
 /// [`Feature`]: gherkin::Feature
@@ -1158,6 +3437,7 @@ impl Features {
         &self,
         feature: gherkin::Feature,
         which_scenario: &Which,
+        shuffle: Option<&mut Rng>,
     ) where
         Which: Fn(
                 &gherkin::Feature,
@@ -1166,7 +3446,7 @@ impl Features {
             ) -> ScenarioType
             + 'static,
     {
-        let local = feature
+        let mut all = feature
             .scenarios
             .iter()
             .map(|s| (&feature, None, s))
@@ -1183,9 +3463,13 @@ impl Features {
                     Arc::new(scenario.clone()),
                 )
             })
-            .into_group_map_by(|(f, r, s)| {
-                which_scenario(f, r.as_ref().map(AsRef::as_ref), s)
-            });
+            .collect::<Vec<_>>();
+        if let Some(rng) = shuffle {
+            rng.shuffle(&mut all);
+        }
+        let local = all.into_iter().into_group_map_by(|(f, r, s)| {
+            which_scenario(f, r.as_ref().map(AsRef::as_ref), s)
+        });
 
         let mut scenarios = self.scenarios.lock().await;
         if local.get(&ScenarioType::Serial).is_none() {
@@ -1207,33 +3491,74 @@ impl Features {
         }
     }
 
-    /// Returns [`Scenario`]s which are ready to run.
+    /// Returns [`Scenario`]s which are ready to run, up to the `free` number
+    /// of them, if limited, along with their [`ScenarioType`].
     ///
+    /// [`Serial`] [`Scenario`]s are preferred, but returned only once nothing
+    /// is `running`, while [`Concurrent`] ones are held back until then, so
+    /// the [`Serial`] ones won't starve.
+    ///
+    /// [`Concurrent`]: ScenarioType::Concurrent
     /// [`Scenario`]: gherkin::Scenario
+    /// [`Serial`]: ScenarioType::Serial
     async fn get(
         &self,
-        max_concurrent_scenarios: Option<usize>,
-    ) -> Vec<(
-        Arc<gherkin::Feature>,
-        Option<Arc<gherkin::Rule>>,
-        Arc<gherkin::Scenario>,
-    )> {
+        free: Option<usize>,
+        running: bool,
+    ) -> (
+        ScenarioType,
+        Vec<(
+            Arc<gherkin::Feature>,
+            Option<Arc<gherkin::Rule>>,
+            Arc<gherkin::Scenario>,
+        )>,
+    ) {
         let mut scenarios = self.scenarios.lock().await;
-        scenarios
+        if let Some(serial) = scenarios
             .get_mut(&ScenarioType::Serial)
-            .and_then(|s| (!s.is_empty()).then(|| vec![s.remove(0)]))
-            .or_else(|| {
-                scenarios.get_mut(&ScenarioType::Concurrent).and_then(|s| {
-                    (!s.is_empty()).then(|| {
-                        let end = cmp::min(
-                            s.len(),
-                            max_concurrent_scenarios.unwrap_or(s.len()),
-                        );
-                        s.drain(0..end).collect()
-                    })
-                })
+            .filter(|s| !s.is_empty())
+        {
+            let runnable = if running {
+                vec![]
+            } else {
+                vec![serial.remove(0)]
+            };
+            return (ScenarioType::Serial, runnable);
+        }
+        let runnable = scenarios
+            .get_mut(&ScenarioType::Concurrent)
+            .map(|s| {
+                let end = free.map_or(s.len(), |free| cmp::min(s.len(), free));
+                s.drain(0..end).collect()
             })
-            .unwrap_or_default()
+            .unwrap_or_default();
+        (ScenarioType::Concurrent, runnable)
+    }
+
+    /// Returns new [`Features`] containing only the stored [`Scenario`]s
+    /// matching the given `selector`, without removing them from these ones.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    async fn select(&self, selector: WarmUpFn) -> Self {
+        let selected = self
+            .scenarios
+            .lock()
+            .await
+            .iter()
+            .map(|(which, scenarios)| {
+                let matching = scenarios
+                    .iter()
+                    .filter(|(f, r, s)| selector(f, r.as_deref(), s))
+                    .cloned()
+                    .collect();
+                (*which, matching)
+            })
+            .collect();
+
+        Self {
+            scenarios: Arc::new(Mutex::new(selected)),
+            finished: Arc::new(AtomicBool::new(true)),
+        }
     }
 
     /// Marks that there will be no more [`Feature`]s to execute.
@@ -1251,7 +3576,188 @@ impl Features {
     }
 }
 
+/// Working directory of a [`Scenario`], removed with all its contents once
+/// dropped, if it has been created by the [`Runner`].
+///
+/// Being removed on drop, it's cleaned up even if the [`Scenario`] is
+/// abandoned.
+///
+/// [`Scenario`]: gherkin::Scenario
+struct WorkingDir {
+    /// Path of this [`WorkingDir`].
+    path: PathBuf,
+
+    /// Indicator whether this [`WorkingDir`] has been created by the
+    /// [`Runner`], so should be removed once dropped.
+    created: bool,
+}
+
+impl WorkingDir {
+    /// Creates a new [`WorkingDir`] at the given `path`, unless it exists
+    /// already.
+    ///
+    /// # Errors
+    ///
+    /// If the directory cannot be created.
+    fn create(path: PathBuf) -> io::Result<Self> {
+        let created = !path.exists();
+        fs::create_dir_all(&path)?;
+        Ok(Self { path, created })
+    }
+}
+
+impl Drop for WorkingDir {
+    fn drop(&mut self) {
+        if self.created {
+            drop(fs::remove_dir_all(&self.path));
+        }
+    }
+}
+
+/// Default [`WorkingDirFn`], returning a unique directory inside
+/// [`env::temp_dir()`].
+fn temp_working_dir(
+    _: &gherkin::Feature,
+    _: Option<&gherkin::Rule>,
+    _: &gherkin::Scenario,
+) -> PathBuf {
+    /// Number of temporary working directories returned so far.
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    env::temp_dir().join(format!(
+        "cucumber-{}-{}",
+        process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed),
+    ))
+}
+
 /// Coerces the given `value` into a type-erased [`Info`].
 fn coerce_into_info<T: std::any::Any + Send + 'static>(val: T) -> Info {
     Arc::new(val)
 }
+
+/// Parses a [`Duration`] out of the given `s`tring consisting of a number and
+/// an `ms`, `s`, `m` or `h` unit, like `30s` or `500ms`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let unit_at = s.find(|c: char| !c.is_ascii_digit())?;
+    let (num, unit) = s.split_at(unit_at);
+    let num = num.parse::<u64>().ok()?;
+    match unit {
+        "ms" => Some(Duration::from_millis(num)),
+        "s" => Some(Duration::from_secs(num)),
+        "m" => num.checked_mul(60).map(Duration::from_secs),
+        "h" => num.checked_mul(60 * 60).map(Duration::from_secs),
+        _ => None,
+    }
+}
+
+/// Reads a line from the given `input` on a separate thread, so the current
+/// one isn't blocked.
+///
+/// Returns the `input` back, unless reading it panics, along with the read
+/// line.
+async fn read_line(
+    mut input: Box<dyn io::BufRead + Send>,
+) -> (Option<Box<dyn io::BufRead + Send>>, String) {
+    let (tx, rx) = oneshot::channel();
+    drop(thread::spawn(move || {
+        let mut line = String::new();
+        drop(input.read_line(&mut line));
+        drop(tx.send((input, line)));
+    }));
+    rx.await.map_or_else(
+        |_| (None, String::new()),
+        |(taken, line)| (Some(taken), line),
+    )
+}
+
+/// Yields execution back to the caller once, so other futures polled along
+/// with the current one may make progress.
+async fn yield_now() {
+    let mut yielded = false;
+    future::poll_fn(|cx| {
+        if yielded {
+            return Poll::Ready(());
+        }
+        yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    })
+    .await;
+}
+
+/// Awaits the given `fut`ure until the given `deadline`, returning [`None`]
+/// once it's exceeded, so the `fut`ure is dropped (cancelled).
+async fn until<F: Future>(fut: F, deadline: Instant) -> Option<F::Output> {
+    pin_mut!(fut);
+    let timer = Delay::new(deadline.saturating_duration_since(Instant::now()));
+    match future::select(fut, timer).await {
+        Either::Left((out, _)) => Some(out),
+        Either::Right(_) => None,
+    }
+}
+
+/// Constructs an [`Info`] of a hook cancelled on exceeding the [`Scenario`]'s
+/// `timeout`.
+///
+/// [`Scenario`]: gherkin::Scenario
+fn timed_out(timeout: Duration) -> Info {
+    coerce_into_info(format!(
+        "Scenario timed out after {:.3}s",
+        timeout.as_secs_f64(),
+    ))
+}
+
+/// Generates a random seed for shuffling [`Scenario`]s.
+///
+/// [`Scenario`]: gherkin::Scenario
+fn random_seed() -> u64 {
+    // `RandomState` is randomly keyed for each process, so there is no need
+    // in pulling an additional dependency.
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    hasher.finish()
+}
+
+/// Simple [SplitMix64][0] pseudorandom number generator, reproducibly
+/// shuffling [`Scenario`]s with the same seed on every platform.
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [0]: https://prng.di.unimi.it/splitmix64.c
+#[derive(Clone, Copy, Debug)]
+struct Rng(u64);
+
+impl Rng {
+    /// Creates a new [`Rng`] out of the given `seed`.
+    const fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Returns the next pseudorandom number.
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Shuffles the given `items` in place using the [Fisher-Yates][0]
+    /// algorithm.
+    ///
+    /// [0]: https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            // Conversions never fail, as `usize` is never wider than 64 bits
+            // on supported platforms.
+            let bound = u64::try_from(i).map_or(u64::MAX, |i| i + 1);
+            let j = usize::try_from(self.next() % bound).unwrap_or(i);
+            items.swap(i, j);
+        }
+    }
+}
@@ -19,7 +19,7 @@ pub mod basic;
 use futures::Stream;
 use structopt::StructOptInternal;
 
-use crate::{event, parser, Event};
+use crate::{event, parser, tag, Event};
 
 #[doc(inline)]
 pub use self::basic::{Basic, ScenarioType};
@@ -85,4 +85,22 @@ pub trait Runner<World> {
     fn run<S>(self, features: S, cli: Self::Cli) -> Self::EventStream
     where
         S: Stream<Item = parser::Result<gherkin::Feature>> + 'static;
+
+    /// Sets the [`tag::Inheritance`] policy of computing effective tags of
+    /// [`Scenario`]s, as configured via [`Cucumber::tag_inheritance()`].
+    ///
+    /// Called by [`Cucumber`] right before [running][0] this [`Runner`]. Does
+    /// nothing by default.
+    ///
+    /// [0]: Runner::run()
+    /// [`Cucumber`]: crate::Cucumber
+    /// [`Cucumber::tag_inheritance()`]: crate::Cucumber::tag_inheritance()
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn with_tag_inheritance(self, _: tag::Inheritance) -> Self
+    where
+        Self: Sized,
+    {
+        self
+    }
 }
@@ -14,11 +14,16 @@
 //! [`Step`]: gherkin::Step
 
 use std::{
+    any::{self, Any},
+    borrow,
+    cell::RefCell,
     cmp::Ordering,
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt,
     hash::{Hash, Hasher},
     iter,
+    path::PathBuf,
+    rc::Rc,
 };
 
 use derive_more::{Deref, DerefMut, Display, Error};
@@ -53,6 +58,9 @@ pub struct Collection<World> {
     ///
     /// [Then]: https://cucumber.io/docs/gherkin/reference/#then
     then: HashMap<(HashableRegex, Option<Location>), Step<World>>,
+
+    /// [Soft][`Collection::soft()`] [`Step`]s.
+    soft: HashSet<(StepType, HashableRegex, Option<Location>)>,
 }
 
 impl<World> fmt::Debug for Collection<World> {
@@ -82,6 +90,7 @@ impl<World> fmt::Debug for Collection<World> {
                     .map(|(re, step)| (re, format!("{:p}", step)))
                     .collect::<HashMap<_, _>>(),
             )
+            .field("soft", &self.soft)
             .finish()
     }
 }
@@ -92,6 +101,7 @@ impl<World> Default for Collection<World> {
             given: HashMap::new(),
             when: HashMap::new(),
             then: HashMap::new(),
+            soft: HashSet::new(),
         }
     }
 }
@@ -145,6 +155,31 @@ impl<World> Collection<World> {
         self
     }
 
+    /// Marks the [`Step`] of the given type, matching the given `regex`, as a
+    /// soft (informational) one.
+    ///
+    /// Failures of soft [`Step`]s are reported as [`Step::SoftFailed`] events,
+    /// but don't fail their [`Scenario`], so the following [`Step`]s are
+    /// executed as usual. Useful for [`Step`]s performing pure
+    /// logging/observation.
+    ///
+    /// Only panics of the [`Step`] function itself are considered soft,
+    /// while ambiguous matches and [`World`] initialization errors are not.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step::SoftFailed`]: crate::event::Step::SoftFailed
+    /// [`World`]: crate::World
+    #[must_use]
+    pub fn soft(
+        mut self,
+        ty: StepType,
+        loc: Option<Location>,
+        regex: Regex,
+    ) -> Self {
+        let _ = self.soft.insert((ty, regex.into(), loc));
+        self
+    }
+
     /// Returns a [`Step`] function matching the given [`gherkin::Step`], if
     /// any.
     ///
@@ -155,6 +190,19 @@ impl<World> Collection<World> {
         &self,
         step: &gherkin::Step,
     ) -> Result<Option<WithContext<'_, World>>, AmbiguousMatchError> {
+        match self.find_first(step) {
+            (_, Some(e)) => Err(e),
+            (found, None) => Ok(found),
+        }
+    }
+
+    /// Returns the first (ordered by [`Regex`] and [`Location`]) [`Step`]
+    /// function matching the given [`gherkin::Step`], if any, along with an
+    /// [`AmbiguousMatchError`] if it matches multiple [`Regex`]es.
+    pub(crate) fn find_first(
+        &self,
+        step: &gherkin::Step,
+    ) -> (Option<WithContext<'_, World>>, Option<AmbiguousMatchError>) {
         let collection = match step.ty {
             StepType::Given => &self.given,
             StepType::When => &self.when,
@@ -169,20 +217,21 @@ impl<World> Collection<World> {
                     .map(|m| (re, loc, m, captures, step_fn))
             })
             .collect::<Vec<_>>();
-
-        let (_, _, whole_match, captures, step_fn) = match captures.len() {
-            0 => return Ok(None),
-            // Instead of `.unwrap()` to avoid documenting `# Panics` section.
-            1 => captures.pop().unwrap_or_else(|| unreachable!()),
-            _ => {
-                return Err(AmbiguousMatchError {
-                    possible_matches: captures
-                        .into_iter()
-                        .map(|(re, loc, ..)| (re.clone(), *loc))
-                        .collect(),
-                })
-            }
-        };
+        captures.sort_by(|(l, l_loc, ..), (r, r_loc, ..)| {
+            (l, l_loc).cmp(&(r, r_loc))
+        });
+
+        let ambiguous = (captures.len() > 1).then(|| AmbiguousMatchError {
+            possible_matches: captures
+                .iter()
+                .map(|(re, loc, ..)| ((*re).clone(), **loc))
+                .collect(),
+        });
+        let (re, loc, whole_match, captures, step_fn) =
+            match captures.into_iter().next() {
+                Some(found) => found,
+                None => return (None, ambiguous),
+            };
 
         let matches = iter::once(whole_match.as_str().to_owned())
             .chain((1..captures.len()).map(|group_id| {
@@ -193,15 +242,100 @@ impl<World> Collection<World> {
             }))
             .collect();
 
-        Ok(Some((
+        let found = (
             step_fn,
             captures,
             Context {
                 step: step.clone(),
                 matches,
+                vars: Variables::default(),
+                soft: self.soft.contains(&(step.ty, re.clone(), *loc)),
+                working_dir: None,
             },
-        )))
+        );
+        (Some(found), ambiguous)
+    }
+}
+
+/// Generates a stub of a [`Step`] function to be implemented for the given
+/// undefined [`gherkin::Step`], ready to be pasted.
+///
+/// Numbers in the [`gherkin::Step`] text are replaced with `(\d+)` capturing
+/// groups of a [`Regex`], passed into the stub as [`u64`] arguments. If there
+/// are none, the text is matched literally.
+#[must_use]
+pub fn snippet<World>(step: &gherkin::Step) -> String {
+    let attr = match step.ty {
+        StepType::Given => "given",
+        StepType::When => "when",
+        StepType::Then => "then",
+    };
+
+    let (mut pattern, mut literal, mut args) =
+        ("^".to_owned(), String::new(), 0);
+    let mut chars = step.value.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_ascii_digit() {
+            while chars.next_if(char::is_ascii_digit).is_some() {}
+            pattern.push_str(&regex::escape(&literal));
+            pattern.push_str(r"(\d+)");
+            literal.clear();
+            args += 1;
+        } else {
+            literal.push(c);
+        }
+    }
+    pattern.push_str(&regex::escape(&literal));
+    pattern.push('$');
+
+    let matcher = if args == 0 {
+        format!("{:?}", step.value)
+    } else {
+        // Enough `#`s to not terminate the raw string literal early.
+        let hashes = pattern
+            .split('"')
+            .skip(1)
+            .map(|s| s.chars().take_while(|c| *c == '#').count() + 1)
+            .max()
+            .map_or_else(String::new, |n| "#".repeat(n));
+        format!("regex = r{}\"{}\"{}", hashes, pattern, hashes)
+    };
+
+    let mut name = step
+        .value
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            if w.chars().all(|c| c.is_ascii_digit()) {
+                "n".to_owned()
+            } else {
+                w.to_lowercase()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("_");
+    if !name.starts_with(char::is_alphabetic) {
+        name.insert_str(0, "step_");
     }
+    let world = any::type_name::<World>()
+        .rsplit("::")
+        .next()
+        .unwrap_or("World");
+    let args = (1..=args)
+        .map(|i| format!(", n{}: u64", i))
+        .collect::<Vec<_>>()
+        .concat();
+    let step_arg = if step.docstring.is_some() || step.table.is_some() {
+        ", #[step] step: &Step"
+    } else {
+        ""
+    };
+
+    format!(
+        "#[{}({})]\n\
+         fn {}(world: &mut {}{}{}) {{\n    todo!()\n}}",
+        attr, matcher, name, world, args, step_arg,
+    )
 }
 
 /// Context for a [`Step`] function execution.
@@ -216,19 +350,149 @@ pub struct Context {
     ///
     /// [`Step::value`]: gherkin::Step::value
     pub matches: Vec<String>,
+
+    /// [`Variables`] of the [`Scenario`] this [`Step`] belongs to.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub vars: Variables,
+
+    /// Indicator whether the matched [`Step`] function is a [soft] one.
+    ///
+    /// [soft]: Collection::soft()
+    pub soft: bool,
+
+    /// Working directory of the [`Scenario`] this [`Step`] belongs to, if the
+    /// [`Runner`] provides one.
+    ///
+    /// The process-wide current directory is left untouched, so this path
+    /// should be passed explicitly to whatever depends on it (for example,
+    /// via [`Command::current_dir()`]).
+    ///
+    /// [`Command::current_dir()`]: std::process::Command::current_dir()
+    /// [`Runner`]: crate::runner::Basic::working_dir()
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub working_dir: Option<PathBuf>,
+}
+
+impl Context {
+    /// Sets the variable with the given `name` to the provided `value` for
+    /// all the following [`Step`]s of the current [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub fn set<T: Any>(&self, name: impl Into<String>, value: T) {
+        self.vars.set(name, value);
+    }
+
+    /// Returns a value of the variable with the given `name`, previously
+    /// [`set`] by some [`Step`] of the current [`Scenario`].
+    ///
+    /// # Errors
+    ///
+    /// - If there is no variable with the given `name`.
+    /// - If the variable has a type different from `T`.
+    ///
+    /// [`set`]: Context::set()
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub fn get<T: Any + Clone>(&self, name: &str) -> Result<T, VariableError> {
+        self.vars.get(name)
+    }
+}
+
+impl borrow::Borrow<gherkin::Step> for Context {
+    fn borrow(&self) -> &gherkin::Step {
+        &self.step
+    }
+}
+
+/// Typed key/value storage of variables, shared between all the [`Step`]s of a
+/// single [`Scenario`].
+///
+/// Cloning it produces a handle to the same storage.
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Default)]
+pub struct Variables(Rc<RefCell<HashMap<String, Box<dyn Any>>>>);
+
+impl Variables {
+    /// Sets the variable with the given `name` to the provided `value`,
+    /// replacing the previous one, if any.
+    pub fn set<T: Any>(&self, name: impl Into<String>, value: T) {
+        let _ = self.0.borrow_mut().insert(name.into(), Box::new(value));
+    }
+
+    /// Returns a value of the variable with the given `name`.
+    ///
+    /// # Errors
+    ///
+    /// - If there is no variable with the given `name`.
+    /// - If the variable has a type different from `T`.
+    pub fn get<T: Any + Clone>(&self, name: &str) -> Result<T, VariableError> {
+        self.0
+            .borrow()
+            .get(name)
+            .ok_or_else(|| VariableError::NotFound(name.to_owned()))?
+            .downcast_ref::<T>()
+            .cloned()
+            .ok_or_else(|| VariableError::TypeMismatch {
+                name: name.to_owned(),
+                expected: any::type_name::<T>(),
+            })
+    }
+}
+
+impl fmt::Debug for Variables {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.0.borrow().keys()).finish()
+    }
+}
+
+/// Error of retrieving a variable from [`Variables`].
+#[derive(Clone, Debug, Display, Error)]
+pub enum VariableError {
+    /// There is no variable with such name.
+    #[display(fmt = "No variable `{}` is set", _0)]
+    NotFound(#[error(not(source))] String),
+
+    /// Variable has a different type than the requested one.
+    #[display(fmt = "Variable `{}` is not of type `{}`", name, expected)]
+    TypeMismatch {
+        /// Name of the variable.
+        name: String,
+
+        /// Name of the requested type.
+        expected: &'static str,
+    },
 }
 
 /// Error of a [`gherkin::Step`] matching multiple [`Step`] [`Regex`]es inside a
 /// [`Collection`].
-#[derive(Clone, Debug, Display, Error)]
-#[display(fmt = "Possible matches: {:#?}", possible_matches)]
+#[derive(Clone, Debug, Error)]
 pub struct AmbiguousMatchError {
     /// Possible [`Regex`]es the [`gherkin::Step`] matches.
     pub possible_matches: Vec<(HashableRegex, Option<Location>)>,
 }
 
+impl fmt::Display for AmbiguousMatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Possible matches:")?;
+        for (re, loc) in &self.possible_matches {
+            write!(f, "\n  {}", re.as_str())?;
+            if let Some(loc) = loc {
+                write!(f, " --> {}", loc)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Location of a [`Step`] [`fn`] automatically filled by a proc macro.
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[display(fmt = "{}:{}:{}", path, line, column)]
 pub struct Location {
     /// Path to the file where [`Step`] [`fn`] is located.
     pub path: &'static str,
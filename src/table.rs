@@ -0,0 +1,366 @@
+// Copyright (c) 2018-2021  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Typed deserialization of [`gherkin::Step`] [data tables][0] via [`serde`].
+//!
+//! [0]: https://cucumber.io/docs/gherkin/reference/#data-tables
+
+use derive_more::{Display, Error};
+use sealed::sealed;
+use serde::de::{
+    self, value::MapAccessDeserializer, Deserialize, DeserializeSeed,
+    Deserializer, IntoDeserializer, MapAccess, Unexpected, Visitor,
+};
+
+/// Helper methods to access a [data table][0] of a [`gherkin::Step`].
+///
+/// [0]: https://cucumber.io/docs/gherkin/reference/#data-tables
+#[sealed]
+pub trait StepExt {
+    /// Returns the [data table][0] of this [`gherkin::Step`].
+    ///
+    /// # Errors
+    ///
+    /// If this [`gherkin::Step`] has no [data table][0].
+    ///
+    /// [0]: https://cucumber.io/docs/gherkin/reference/#data-tables
+    fn data_table(&self) -> Result<&gherkin::Table, Error>;
+
+    /// Deserializes each row of the [data table][0] of this [`gherkin::Step`]
+    /// into a `T`, treating the first row as a header naming `T`'s fields.
+    ///
+    /// Shortcut for [`StepExt::data_table()`] and [`Ext::rows_as()`].
+    ///
+    /// # Errors
+    ///
+    /// - If this [`gherkin::Step`] has no [data table][0].
+    /// - If any row cannot be deserialized into a `T`.
+    ///
+    /// [0]: https://cucumber.io/docs/gherkin/reference/#data-tables
+    fn table_rows<'de, T: Deserialize<'de>>(&'de self)
+        -> Result<Vec<T>, Error>;
+}
+
+#[sealed]
+impl StepExt for gherkin::Step {
+    fn data_table(&self) -> Result<&gherkin::Table, Error> {
+        self.table.as_ref().ok_or(Error::NoTable)
+    }
+
+    fn table_rows<'de, T: Deserialize<'de>>(
+        &'de self,
+    ) -> Result<Vec<T>, Error> {
+        self.data_table()?.rows_as()
+    }
+}
+
+/// Helper methods to deserialize a [`gherkin::Table`] into user types.
+///
+/// Cells are deserialized via their [`FromStr`] implementation for primitive
+/// types, while empty cells are deserialized as [`None`] for [`Option`]al
+/// fields.
+///
+/// [`FromStr`]: std::str::FromStr
+#[sealed]
+pub trait Ext {
+    /// Deserializes each row of this [`gherkin::Table`] into a `T` ("records"
+    /// mode), treating the first row as a header naming `T`'s fields.
+    ///
+    /// So this table:
+    /// ```gherkin
+    /// | name    | age |
+    /// | Alice   | 30  |
+    /// | Bob     | 25  |
+    /// ```
+    ///
+    /// Is deserialized into two `T`s with `name` and `age` fields.
+    ///
+    /// # Errors
+    ///
+    /// If any row cannot be deserialized into a `T`.
+    fn rows_as<'de, T: Deserialize<'de>>(&'de self) -> Result<Vec<T>, Error>;
+
+    /// Deserializes this two-column [`gherkin::Table`] into a single `T`
+    /// ("vertical" mode), treating the first column as `T`'s field names and
+    /// the second one as their values.
+    ///
+    /// So this table:
+    /// ```gherkin
+    /// | name | Alice |
+    /// | age  | 30    |
+    /// ```
+    ///
+    /// Is deserialized into a single `T` with `name` and `age` fields.
+    ///
+    /// # Errors
+    ///
+    /// - If this [`gherkin::Table`] doesn't have exactly 2 columns.
+    /// - If this [`gherkin::Table`] cannot be deserialized into a `T`.
+    fn vertical_as<'de, T: Deserialize<'de>>(&'de self) -> Result<T, Error>;
+}
+
+#[sealed]
+impl Ext for gherkin::Table {
+    fn rows_as<'de, T: Deserialize<'de>>(&'de self) -> Result<Vec<T>, Error> {
+        let (header, rows) = match self.rows.split_first() {
+            Some(split) => split,
+            None => return Ok(Vec::new()),
+        };
+
+        rows.iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let cells =
+                    header.iter().zip(row).map(|(k, v)| (Cell(k), Cell(v)));
+                deserialize_map(cells).map_err(|(column, source)| {
+                    Error::Row {
+                        // Counting from 1 and including the header row.
+                        row: i + 2,
+                        column: column.map(ToOwned::to_owned),
+                        source,
+                    }
+                })
+            })
+            .collect()
+    }
+
+    fn vertical_as<'de, T: Deserialize<'de>>(&'de self) -> Result<T, Error> {
+        let width = self.row_width();
+        if width != 2 {
+            return Err(Error::NotVertical(width));
+        }
+
+        let cells = self.rows.iter().map(|r| (Cell(&r[0]), Cell(&r[1])));
+        deserialize_map(cells).map_err(|(field, source)| Error::Vertical {
+            field: field.map(ToOwned::to_owned),
+            source,
+        })
+    }
+}
+
+/// Deserializes a `T` from the given key-value pairs of [`Cell`]s.
+///
+/// # Errors
+///
+/// If a `T` cannot be deserialized, along with the key of the [`Cell`] failed
+/// to be deserialized, if any.
+fn deserialize_map<'de, T, I>(
+    cells: I,
+) -> Result<T, (Option<&'de str>, de::value::Error)>
+where
+    T: Deserialize<'de>,
+    I: Iterator<Item = (Cell<'de>, Cell<'de>)>,
+{
+    let mut cells = Cells {
+        iter: cells,
+        key: None,
+        value: None,
+        failed: None,
+    };
+    T::deserialize(MapAccessDeserializer::new(&mut cells))
+        .map_err(|e| (cells.failed, e))
+}
+
+/// [`MapAccess`] over key-value pairs of [`Cell`]s, remembering the key of the
+/// value failed to be deserialized.
+struct Cells<'de, I> {
+    /// Key-value pairs of [`Cell`]s.
+    iter: I,
+
+    /// Key of the currently deserialized value.
+    key: Option<&'de str>,
+
+    /// Value to be deserialized next.
+    value: Option<Cell<'de>>,
+
+    /// Key of the value failed to be deserialized.
+    failed: Option<&'de str>,
+}
+
+impl<'de, I> MapAccess<'de> for Cells<'de, I>
+where
+    I: Iterator<Item = (Cell<'de>, Cell<'de>)>,
+{
+    type Error = de::value::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        self.iter
+            .next()
+            .map(|(k, v)| {
+                self.key = Some(k.0);
+                self.value = Some(v);
+                seed.deserialize(k)
+            })
+            .transpose()
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let value = self
+            .value
+            .take()
+            .ok_or_else(|| de::Error::custom("value is missing"))?;
+        seed.deserialize(value).map_err(|e| {
+            self.failed = self.key;
+            e
+        })
+    }
+}
+
+/// Error of deserializing a [`gherkin::Table`].
+#[derive(Clone, Debug, Display, Error)]
+pub enum Error {
+    /// [`gherkin::Step`] has no [data table][0].
+    ///
+    /// [0]: https://cucumber.io/docs/gherkin/reference/#data-tables
+    #[display(fmt = "Step has no data table")]
+    NoTable,
+
+    /// [`gherkin::Table`] deserialized in "vertical" mode doesn't have exactly
+    /// 2 columns.
+    #[display(
+        fmt = "Vertical data table should have 2 columns, but has {}",
+        _0
+    )]
+    NotVertical(#[error(not(source))] usize),
+
+    /// Row of a [`gherkin::Table`] cannot be deserialized in "records" mode.
+    #[display(
+        fmt = "Failed to deserialize data table row {}{}: {}",
+        row,
+        "column.as_ref().map(|c| format!(\", column `{}`\", c)).unwrap_or_default()",
+        source
+    )]
+    Row {
+        /// Number of the row in the [`gherkin::Table`], counting from 1 and
+        /// including the header row.
+        row: usize,
+
+        /// Name of the column failed to be deserialized, if the failure is
+        /// caused by a single cell.
+        column: Option<String>,
+
+        /// Deserialization error.
+        source: de::value::Error,
+    },
+
+    /// [`gherkin::Table`] cannot be deserialized in "vertical" mode.
+    #[display(
+        fmt = "Failed to deserialize vertical data table{}: {}",
+        "field.as_ref().map(|f| format!(\" field `{}`\", f)).unwrap_or_default()",
+        source
+    )]
+    Vertical {
+        /// Name of the field failed to be deserialized, if the failure is
+        /// caused by a single cell.
+        field: Option<String>,
+
+        /// Deserialization error.
+        source: de::value::Error,
+    },
+}
+
+/// [`Deserializer`] of a single [`gherkin::Table`] cell.
+#[derive(Clone, Copy, Debug)]
+struct Cell<'de>(&'de str);
+
+impl<'de> IntoDeserializer<'de, de::value::Error> for Cell<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+/// Generates [`Deserializer`] methods parsing a [`Cell`] via [`FromStr`].
+///
+/// [`FromStr`]: std::str::FromStr
+macro_rules! parse_cell {
+    ($($method:ident => $visit:ident),* $(,)?) => {$(
+        fn $method<V: Visitor<'de>>(
+            self,
+            visitor: V,
+        ) -> Result<V::Value, Self::Error> {
+            match self.0.parse() {
+                Ok(v) => visitor.$visit(v),
+                Err(_) => Err(de::Error::invalid_value(
+                    Unexpected::Str(self.0),
+                    &visitor,
+                )),
+            }
+        }
+    )*};
+}
+
+impl<'de> Deserializer<'de> for Cell<'de> {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_borrowed_str(self.0)
+    }
+
+    parse_cell! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        if self.0.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0
+            .into_deserializer()
+            .deserialize_enum(name, variants, visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        i128 u128 str string bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
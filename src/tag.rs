@@ -8,8 +8,12 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! Extension of a [`TagOperation`].
+//! Extension of a [`TagOperation`], extended tag [`Expression`]s and tags
+//! [`Inheritance`] policy.
 
+use std::{cmp::Ordering, fmt, iter::Peekable, str::FromStr, vec};
+
+use derive_more::{Display, Error};
 use gherkin::tagexpr::TagOperation;
 use sealed::sealed;
 
@@ -39,3 +43,480 @@ impl Ext for TagOperation {
         }
     }
 }
+
+#[sealed]
+impl Ext for Expression {
+    fn eval<I, S>(&self, tags: I) -> bool
+    where
+        S: AsRef<str>,
+        I: IntoIterator<Item = S> + Clone,
+    {
+        match self {
+            Self::And(l, r) => l.eval(tags.clone()) & r.eval(tags),
+            Self::Or(l, r) => l.eval(tags.clone()) | r.eval(tags),
+            Self::Not(e) => !e.eval(tags),
+            Self::Tag(t) => tags.into_iter().any(|tag| tag.as_ref() == t),
+            Self::Compare(key, op, value) => tags.into_iter().any(|tag| {
+                tag.as_ref()
+                    .split_once(':')
+                    .filter(|(k, _)| k == key)
+                    .map_or(false, |(_, v)| op.compare(v, value))
+            }),
+        }
+    }
+}
+
+/// [Tag expression][1] extended with comparisons of `@key:value` tags.
+///
+/// # Grammar
+///
+/// ```text
+/// expression := or
+/// or         := and ("or" and)*
+/// and        := not ("and" not)*
+/// not        := "not" not | primary
+/// primary    := "(" expression ")" | comparison | tag
+/// comparison := ["@"] key operator value
+/// tag        := "@" name
+/// operator   := "==" | "=" | "!=" | "<" | "<=" | ">" | ">="
+/// ```
+///
+/// `not` binds tighter than `and`, which binds tighter than `or`. Whitespace
+/// and the `@()\<>=!` characters may be used inside a name, key or value only
+/// being escaped with `\`.
+///
+/// A `key operator value` comparison holds if any of the tags is a
+/// `@key:actual` one with `actual operator value` being `true`. Values are
+/// compared as numbers if both of them are ones, or as strings otherwise. So,
+/// `priority >= 2` matches `@priority:2` and `@priority:10`, but neither
+/// `@priority:1` nor `@priority`.
+///
+/// ```rust
+/// # use cucumber::tag::{Expression, Ext as _};
+/// #
+/// let expr: Expression = "@smoke and not priority < 2".parse().unwrap();
+///
+/// assert!(expr.eval(["smoke", "priority:3"]));
+/// assert!(!expr.eval(["smoke", "priority:1"]));
+/// assert!(!expr.eval(["priority:3"]));
+/// ```
+///
+/// [1]: https://cucumber.io/docs/cucumber/api#tag-expressions
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Expression {
+    /// Both [`Expression`]s should hold.
+    And(Box<Self>, Box<Self>),
+
+    /// Any of [`Expression`]s should hold.
+    Or(Box<Self>, Box<Self>),
+
+    /// [`Expression`] should not hold.
+    Not(Box<Self>),
+
+    /// Tag (without the leading `@`) should be present.
+    Tag(String),
+
+    /// Value of a `@key:value` tag with the given key should satisfy the
+    /// [`Comparison`] with the given value.
+    Compare(String, Comparison, String),
+}
+
+impl From<TagOperation> for Expression {
+    fn from(op: TagOperation) -> Self {
+        match op {
+            TagOperation::And(l, r) => {
+                Self::And(Box::new((*l).into()), Box::new((*r).into()))
+            }
+            TagOperation::Or(l, r) => {
+                Self::Or(Box::new((*l).into()), Box::new((*r).into()))
+            }
+            TagOperation::Not(t) => Self::Not(Box::new((*t).into())),
+            TagOperation::Tag(t) => Self::Tag(t),
+        }
+    }
+}
+
+impl FromStr for Expression {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = tokenize(s)?.into_iter().peekable();
+        let expr = parse_or(&mut tokens)?;
+        tokens.next().map_or(Ok(expr), |t| {
+            Err(ParseError::UnexpectedToken(t.to_string()))
+        })
+    }
+}
+
+impl fmt::Display for Expression {
+    /// Formats this [`Expression`] in a way parseable back, parenthesizing
+    /// only the operands binding weaker than their operator.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        /// Writes the given `expr`, parenthesized if it binds weaker than the
+        /// given `min` precedence.
+        fn operand(
+            f: &mut fmt::Formatter<'_>,
+            expr: &Expression,
+            min: u8,
+        ) -> fmt::Result {
+            if expr.precedence() < min {
+                write!(f, "({})", expr)
+            } else {
+                write!(f, "{}", expr)
+            }
+        }
+
+        match self {
+            Self::Or(l, r) => {
+                operand(f, l, 0)?;
+                write!(f, " or ")?;
+                operand(f, r, 1)
+            }
+            Self::And(l, r) => {
+                operand(f, l, 1)?;
+                write!(f, " and ")?;
+                operand(f, r, 2)
+            }
+            Self::Not(e) => {
+                write!(f, "not ")?;
+                operand(f, e, 2)
+            }
+            Self::Tag(t) => write!(f, "@{}", escape(t)),
+            Self::Compare(k, op, v) => {
+                write!(f, "{} {} {}", escape(k), op, escape(v))
+            }
+        }
+    }
+}
+
+impl Expression {
+    /// Returns the precedence of this [`Expression`]'s operator, the higher
+    /// the tighter it binds.
+    const fn precedence(&self) -> u8 {
+        match self {
+            Self::Or(..) => 0,
+            Self::And(..) => 1,
+            Self::Not(_) => 2,
+            Self::Tag(_) | Self::Compare(..) => 3,
+        }
+    }
+}
+
+/// Comparison operator of an [`Expression::Compare`].
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum Comparison {
+    /// Values are equal (`==` or `=`).
+    #[display(fmt = "==")]
+    Eq,
+
+    /// Values are not equal (`!=`).
+    #[display(fmt = "!=")]
+    Ne,
+
+    /// Actual value is less than the expected one (`<`).
+    #[display(fmt = "<")]
+    Lt,
+
+    /// Actual value is less than or equal to the expected one (`<=`).
+    #[display(fmt = "<=")]
+    Le,
+
+    /// Actual value is greater than the expected one (`>`).
+    #[display(fmt = ">")]
+    Gt,
+
+    /// Actual value is greater than or equal to the expected one (`>=`).
+    #[display(fmt = ">=")]
+    Ge,
+}
+
+impl Comparison {
+    /// Compares the `actual` value with the `expected` one, numerically if
+    /// both of them are numbers, or lexicographically otherwise.
+    #[must_use]
+    pub fn compare(self, actual: &str, expected: &str) -> bool {
+        let ord = match (actual.parse::<f64>(), expected.parse::<f64>()) {
+            (Ok(a), Ok(e)) => a.partial_cmp(&e),
+            _ => Some(actual.cmp(expected)),
+        };
+        ord.map_or(false, |ord| match self {
+            Self::Eq => ord == Ordering::Equal,
+            Self::Ne => ord != Ordering::Equal,
+            Self::Lt => ord == Ordering::Less,
+            Self::Le => ord != Ordering::Greater,
+            Self::Gt => ord == Ordering::Greater,
+            Self::Ge => ord != Ordering::Less,
+        })
+    }
+}
+
+/// Error of parsing an [`Expression`].
+#[derive(Clone, Debug, Display, Eq, Error, PartialEq)]
+pub enum ParseError {
+    /// [`Expression`] ends while more is expected.
+    #[display(fmt = "Unexpected end of tag expression")]
+    UnexpectedEnd,
+
+    /// [`Expression`] contains a token in a wrong place.
+    #[display(fmt = "Unexpected `{}` in tag expression", _0)]
+    UnexpectedToken(#[error(not(source))] String),
+
+    /// [`Expression`] contains an escaped non-reserved character.
+    #[display(fmt = "Invalid escape `\\{}` in tag expression", _0)]
+    InvalidEscape(#[error(not(source))] char),
+}
+
+/// Token of an [`Expression`].
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
+enum Token {
+    /// Opening parenthesis.
+    #[display(fmt = "(")]
+    Open,
+
+    /// Closing parenthesis.
+    #[display(fmt = ")")]
+    Close,
+
+    /// [`Comparison`] operator.
+    #[display(fmt = "{}", _0)]
+    Op(Comparison),
+
+    /// Tag (without the leading `@`).
+    #[display(fmt = "@{}", _0)]
+    Tag(String),
+
+    /// Keyword, key or value.
+    #[display(fmt = "{}", _0)]
+    Word(String),
+}
+
+/// Characters reserved by the [`Expression`] grammar, which should be escaped
+/// to be used inside names, keys or values.
+const RESERVED: &str = "@()\\<>=!";
+
+/// Escapes whitespace and [`RESERVED`] characters of the given name, key or
+/// value.
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c.is_whitespace() || RESERVED.contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Splits the given [`Expression`] string into [`Token`]s.
+fn tokenize(s: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            _ if c.is_whitespace() => continue,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '<' | '>' | '=' | '!' => {
+                let eq = chars.next_if_eq(&'=').is_some();
+                Token::Op(match (c, eq) {
+                    ('<', false) => Comparison::Lt,
+                    ('<', true) => Comparison::Le,
+                    ('>', false) => Comparison::Gt,
+                    ('>', true) => Comparison::Ge,
+                    ('=', _) => Comparison::Eq,
+                    ('!', true) => Comparison::Ne,
+                    _ => return Err(ParseError::UnexpectedToken(c.into())),
+                })
+            }
+            _ => {
+                let is_tag = c == '@';
+                let mut word = String::new();
+                let mut next = (!is_tag).then(|| c);
+                loop {
+                    match next {
+                        Some('\\') => match chars.next() {
+                            Some(e)
+                                if e.is_whitespace()
+                                    || RESERVED.contains(e) =>
+                            {
+                                word.push(e);
+                            }
+                            Some(e) => {
+                                return Err(ParseError::InvalidEscape(e))
+                            }
+                            None => return Err(ParseError::UnexpectedEnd),
+                        },
+                        Some(n) => word.push(n),
+                        None => {}
+                    }
+                    next = chars.next_if(|n| {
+                        !n.is_whitespace()
+                            && (*n == '\\' || !RESERVED.contains(*n))
+                    });
+                    if next.is_none() {
+                        break;
+                    }
+                }
+                if word.is_empty() {
+                    return Err(ParseError::UnexpectedToken(c.into()));
+                }
+                if is_tag {
+                    Token::Tag(word)
+                } else {
+                    Token::Word(word)
+                }
+            }
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+/// Shortcut for a stream of [`Token`]s being parsed.
+type Tokens = Peekable<vec::IntoIter<Token>>;
+
+/// Parses `or` rule of an [`Expression`] grammar.
+fn parse_or(tokens: &mut Tokens) -> Result<Expression, ParseError> {
+    let mut expr = parse_and(tokens)?;
+    while tokens
+        .next_if(|t| matches!(t, Token::Word(w) if w == "or"))
+        .is_some()
+    {
+        expr = Expression::Or(Box::new(expr), Box::new(parse_and(tokens)?));
+    }
+    Ok(expr)
+}
+
+/// Parses `and` rule of an [`Expression`] grammar.
+fn parse_and(tokens: &mut Tokens) -> Result<Expression, ParseError> {
+    let mut expr = parse_not(tokens)?;
+    while tokens
+        .next_if(|t| matches!(t, Token::Word(w) if w == "and"))
+        .is_some()
+    {
+        expr = Expression::And(Box::new(expr), Box::new(parse_not(tokens)?));
+    }
+    Ok(expr)
+}
+
+/// Parses `not` rule of an [`Expression`] grammar.
+fn parse_not(tokens: &mut Tokens) -> Result<Expression, ParseError> {
+    if tokens
+        .next_if(|t| matches!(t, Token::Word(w) if w == "not"))
+        .is_some()
+    {
+        return Ok(Expression::Not(Box::new(parse_not(tokens)?)));
+    }
+    parse_primary(tokens)
+}
+
+/// Parses `primary` rule of an [`Expression`] grammar.
+fn parse_primary(tokens: &mut Tokens) -> Result<Expression, ParseError> {
+    let key = match tokens.next().ok_or(ParseError::UnexpectedEnd)? {
+        Token::Open => {
+            let expr = parse_or(tokens)?;
+            return match tokens.next() {
+                Some(Token::Close) => Ok(expr),
+                Some(t) => Err(ParseError::UnexpectedToken(t.to_string())),
+                None => Err(ParseError::UnexpectedEnd),
+            };
+        }
+        Token::Tag(tag) => {
+            if !matches!(tokens.peek(), Some(Token::Op(_))) {
+                return Ok(Expression::Tag(tag));
+            }
+            tag
+        }
+        // Misplaced keyword is reported right away, rather than as a missing
+        // comparison operator after it.
+        Token::Word(kw) if kw == "and" || kw == "or" => {
+            return Err(ParseError::UnexpectedToken(kw));
+        }
+        Token::Word(key) => key,
+        t @ (Token::Close | Token::Op(_)) => {
+            return Err(ParseError::UnexpectedToken(t.to_string()));
+        }
+    };
+    let op = match tokens.next() {
+        Some(Token::Op(op)) => op,
+        Some(t) => return Err(ParseError::UnexpectedToken(t.to_string())),
+        None => return Err(ParseError::UnexpectedEnd),
+    };
+    match tokens.next() {
+        Some(Token::Word(value)) => Ok(Expression::Compare(key, op, value)),
+        Some(t) => Err(ParseError::UnexpectedToken(t.to_string())),
+        None => Err(ParseError::UnexpectedEnd),
+    }
+}
+
+/// Policy of computing effective tags of a [`Scenario`] out of the tags of its
+/// parent [`Feature`] and [`Rule`].
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+#[cfg_attr(feature = "output-json", derive(serde::Serialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Inheritance {
+    /// [`Scenario`] inherits tags of its [`Feature`] and [`Rule`], as
+    /// described in the [Gherkin reference][1].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [1]: https://cucumber.io/docs/cucumber/api#tag-inheritance
+    Inherit,
+
+    /// Tags of the most nested tagged item replace the inherited ones.
+    ///
+    /// So, if a [`Scenario`] has any tags, only they are considered. Otherwise,
+    /// tags of its [`Rule`] are considered, and if it has none, then tags of
+    /// its [`Feature`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    Override,
+}
+
+impl Default for Inheritance {
+    fn default() -> Self {
+        Self::Inherit
+    }
+}
+
+impl Inheritance {
+    /// Returns effective tags of the given [`Scenario`] according to this
+    /// [`Inheritance`] policy.
+    ///
+    /// The order `Feature` -> `Rule` -> `Scenario` is preserved.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn tags<'a>(
+        self,
+        feature: &'a gherkin::Feature,
+        rule: Option<&'a gherkin::Rule>,
+        scenario: &'a gherkin::Scenario,
+    ) -> Vec<&'a str> {
+        let rule_tags = rule.map(|r| r.tags.as_slice()).unwrap_or_default();
+        let levels =
+            [feature.tags.as_slice(), rule_tags, scenario.tags.as_slice()];
+
+        match self {
+            Self::Inherit => levels
+                .iter()
+                .flat_map(|t| t.iter())
+                .map(String::as_str)
+                .collect(),
+            Self::Override => levels
+                .iter()
+                .rev()
+                .find(|t| !t.is_empty())
+                .into_iter()
+                .flat_map(|t| t.iter())
+                .map(String::as_str)
+                .collect(),
+        }
+    }
+}
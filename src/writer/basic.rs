@@ -13,11 +13,17 @@
 use std::{
     borrow::Cow,
     cmp,
+    collections::HashSet,
+    env,
     fmt::{Debug, Display},
-    io,
+    io, mem,
     str::FromStr,
+    time::Duration,
 };
 
+#[cfg(feature = "timestamps")]
+use std::time::SystemTime;
+
 use async_trait::async_trait;
 use derive_more::{Deref, DerefMut};
 use itertools::Itertools as _;
@@ -26,10 +32,10 @@ use structopt::StructOpt;
 
 use crate::{
     event::{self, Info},
-    parser,
+    parser, step,
     writer::{
         self,
-        out::{Styles, WriteStrExt as _},
+        out::{Styles, Theme, WriteStrExt as _},
         Ext as _,
     },
     Event, World, Writer,
@@ -52,13 +58,25 @@ pub struct Cli {
     /// Coloring policy for a console output.
     #[structopt(long, name = "auto|always|never", default_value = "auto")]
     pub color: Coloring,
+
+    /// Outputs a step function snippet for each undefined step, to be
+    /// implemented.
+    #[structopt(long)]
+    pub snippets: bool,
+
+    /// Outputs how long each step has been executed for.
+    #[cfg(feature = "timestamps")]
+    #[structopt(long)]
+    pub show_timings: bool,
 }
 
 /// Possible policies of a [`console`] output coloring.
 #[derive(Clone, Copy, Debug)]
 pub enum Coloring {
     /// Letting [`console::colors_enabled()`] to decide, whether output should
-    /// be colored.
+    /// be colored, unless the [`NO_COLOR`] environment variable is set.
+    ///
+    /// [`NO_COLOR`]: https://no-color.org
     Auto,
 
     /// Forcing of a colored output.
@@ -96,6 +114,7 @@ impl FromStr for Coloring {
 /// [`Normalized`]: writer::Normalized
 /// [`Runner`]: crate::runner::Runner
 /// [`Scenario`]: gherkin::Scenario
+#[allow(clippy::struct_excessive_bools)] // independent builder toggles
 #[derive(Debug, Deref, DerefMut)]
 pub struct Basic<Out: io::Write = io::Stdout> {
     /// [`io::Write`] implementor to write the output into.
@@ -117,6 +136,62 @@ pub struct Basic<Out: io::Write = io::Stdout> {
     ///
     /// [1]: gherkin::Step::docstring
     verbose: bool,
+
+    /// Indicator whether a step function snippet should be outputted for each
+    /// [undefined][1] [`Step`].
+    ///
+    /// [1]: event::Step::Skipped
+    /// [`Step`]: gherkin::Step
+    snippets: bool,
+
+    /// Indicator whether an execution duration should be outputted for each
+    /// finished [`Step`].
+    ///
+    /// [`Step`]: gherkin::Step
+    #[cfg(feature = "timestamps")]
+    show_timings: bool,
+
+    /// Time the currently executed [`Step`] has been started at.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[cfg(feature = "timestamps")]
+    step_started_at: Option<SystemTime>,
+
+    /// Execution duration of the just finished [`Step`], to be outputted.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[cfg(feature = "timestamps")]
+    step_duration: Option<Duration>,
+
+    /// Indicator whether this [`Basic`] [`Writer`] produces a
+    /// [deterministic][1] output.
+    ///
+    /// [1]: Basic::deterministic()
+    deterministic: bool,
+
+    /// Positions (line and column) of [`Background`] [`Step`]s passed in the
+    /// current [`Feature`], if they should be [collapsed][1].
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Feature`]: gherkin::Feature
+    /// [`Step`]: gherkin::Step
+    /// [1]: Basic::collapse_background()
+    passed_background: Option<HashSet<(usize, usize)>>,
+
+    /// Indicator whether any [`Background`] [`Step`] of the current
+    /// [`Scenario`] has been collapsed already.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    background_collapsed: bool,
+
+    /// [Captured output][1] of the currently executed [`Step`], to be output
+    /// along with its failure.
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [1]: event::Step::Output
+    step_output: Option<String>,
 }
 
 #[async_trait(?Send)]
@@ -136,16 +211,30 @@ where
         use event::{Cucumber, Feature};
 
         self.apply_cli(*cli);
+        #[cfg(feature = "timestamps")]
+        if let Ok(ev) = &ev {
+            self.measure_step(ev);
+        }
 
         match ev.map(Event::into_inner) {
             Err(err) => self.parsing_failed(&err),
-            Ok(Cucumber::Started | Cucumber::Finished) => Ok(()),
+            Ok(Cucumber::Started) => Ok(()),
+            Ok(Cucumber::Finished) => self.output.flush(),
+            Ok(Cucumber::ParsingFinished {
+                features,
+                scenarios,
+                shuffle_seed,
+                ..
+            }) => self.parsing_finished(features, scenarios, shuffle_seed),
             Ok(Cucumber::Feature(f, ev)) => match ev {
                 Feature::Started => self.feature_started(&f),
                 Feature::Scenario(sc, ev) => self.scenario(&f, &sc, &ev),
                 Feature::Rule(r, ev) => self.rule(&f, &r, ev),
                 Feature::Finished => Ok(()),
             },
+            Ok(Cucumber::GlobalHookFailed(which, info)) => {
+                self.global_hook_failed(which, &info)
+            }
         }
         .unwrap_or_else(|e| panic!("Failed to write into terminal: {}", e));
     }
@@ -163,7 +252,10 @@ where
     where
         'val: 'async_trait,
     {
+        // Flushing here too, as arbitrary output (like a summary) is usually
+        // written after the `Cucumber::Finished` event.
         self.write_line(val.as_ref())
+            .and_then(|()| self.output.flush())
             .unwrap_or_else(|e| panic!("Failed to write: {}", e));
     }
 }
@@ -211,16 +303,100 @@ impl<Out: io::Write> Basic<Out> {
             indent: 0,
             lines_to_clear: 0,
             verbose: false,
+            snippets: false,
+            #[cfg(feature = "timestamps")]
+            show_timings: false,
+            #[cfg(feature = "timestamps")]
+            step_started_at: None,
+            #[cfg(feature = "timestamps")]
+            step_duration: None,
+            deterministic: false,
+            passed_background: None,
+            background_collapsed: false,
+            step_output: None,
         };
-        basic.apply_cli(Cli { verbose, color });
+        basic.apply_cli(Cli {
+            verbose,
+            color,
+            snippets: false,
+            #[cfg(feature = "timestamps")]
+            show_timings: false,
+        });
         basic
     }
 
+    /// Makes this [`Basic`] [`Writer`] to produce a deterministic output,
+    /// which stays byte-stable across runs and machines.
+    ///
+    /// This disables any coloring (regardless of the [`Cli`] options) and
+    /// outputs [`Feature`] paths relatively to the current working directory
+    /// (or `CARGO_MANIFEST_DIR`) with `/` as a separator. Anything depending
+    /// on the run itself is omitted: the seed [`Scenario`]s are shuffled with,
+    /// timings and [heartbeats][0] of [`Step`]s, and [resource usage][1] of
+    /// [`Scenario`]s.
+    ///
+    /// Useful for snapshot testing of an output.
+    ///
+    /// [0]: event::Scenario::StepHeartbeat
+    /// [1]: event::Scenario::ResourceUsage
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn deterministic(mut self) -> Self {
+        self.deterministic = true;
+        self.styles.is_present = false;
+        #[cfg(feature = "timestamps")]
+        {
+            self.show_timings = false;
+        }
+        self
+    }
+
+    /// Makes this [`Basic`] [`Writer`] output [`Background`] [`Step`]s only for
+    /// the first [`Scenario`] of a [`Feature`] or [`Rule`], collapsing them
+    /// into a single `(background)` line for the following [`Scenario`]s, as
+    /// long as they pass the same way.
+    ///
+    /// Failed or skipped [`Background`] [`Step`]s are always outputted fully.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn collapse_background(mut self) -> Self {
+        self.passed_background = Some(HashSet::new());
+        self
+    }
+
+    /// Makes this [`Basic`] [`Writer`] to output with the given color
+    /// [`Theme`].
+    ///
+    /// The [`Theme`] only remaps colors, while whether the output is colored
+    /// at all is still decided by the [`Coloring`] policy.
+    #[must_use]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.styles.apply_theme(theme);
+        self
+    }
+
     /// Applies the given [`Cli`] options to this [`Basic`] [`Writer`].
     pub fn apply_cli(&mut self, cli: Cli) {
         if cli.verbose {
             self.verbose = true;
         }
+        if cli.snippets {
+            self.snippets = true;
+        }
+        if self.deterministic {
+            return;
+        }
+        #[cfg(feature = "timestamps")]
+        if cli.show_timings {
+            self.show_timings = true;
+        }
         match cli.color {
             Coloring::Auto => {}
             Coloring::Always => self.styles.is_present = true,
@@ -228,6 +404,104 @@ impl<Out: io::Write> Basic<Out> {
         }
     }
 
+    /// Returns a path of the given [`Feature`] for outputting, or its name if
+    /// it has no path.
+    ///
+    /// In a [deterministic][1] mode the path is made relative to the current
+    /// working directory (or `CARGO_MANIFEST_DIR`).
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [1]: Basic::deterministic()
+    fn feature_path<'f>(&self, feat: &'f gherkin::Feature) -> Cow<'f, str> {
+        let path = match feat.path.as_ref() {
+            Some(p) => p,
+            None => return Cow::Borrowed(&feat.name),
+        };
+
+        if !self.deterministic {
+            return path
+                .to_str()
+                .map_or_else(|| Cow::Borrowed(feat.name.as_str()), Cow::from);
+        }
+
+        env::current_dir()
+            .ok()
+            .and_then(|dir| path.strip_prefix(dir).ok())
+            .or_else(|| {
+                env::var_os("CARGO_MANIFEST_DIR")
+                    .and_then(|dir| path.strip_prefix(dir).ok())
+            })
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/")
+            .into()
+    }
+
+    /// Measures execution duration of a [`Step`] (or a [`Background`] one)
+    /// finished by the given event, if [timings][1] should be outputted.
+    ///
+    /// Durations are measured between the timestamps of the events, so they
+    /// don't depend on when the events are received.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    /// [1]: Cli::show_timings
+    #[cfg(feature = "timestamps")]
+    fn measure_step<W>(&mut self, ev: &Event<event::Cucumber<W>>) {
+        use event::{Cucumber, Feature, Rule, Scenario, Step};
+
+        if !self.show_timings {
+            return;
+        }
+        if let Cucumber::Feature(
+            _,
+            Feature::Scenario(
+                _,
+                Scenario::Step(_, st) | Scenario::Background(_, st),
+            )
+            | Feature::Rule(
+                _,
+                Rule::Scenario(
+                    _,
+                    Scenario::Step(_, st) | Scenario::Background(_, st),
+                ),
+            ),
+        ) = &ev.value
+        {
+            match st {
+                Step::Started => self.step_started_at = Some(ev.at),
+                Step::Passed(_) | Step::Failed(..) | Step::SoftFailed(..) => {
+                    self.step_duration = self
+                        .step_started_at
+                        .take()
+                        .and_then(|started| ev.at.duration_since(started).ok());
+                }
+                Step::Skipped | Step::Matched(_) => {
+                    self.step_started_at = None;
+                }
+                Step::Output(_) => {}
+            }
+        }
+    }
+
+    /// Returns the formatted execution duration of the just finished [`Step`],
+    /// if it has been [measured][1].
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [1]: Cli::show_timings
+    #[allow(clippy::unused_self)] // `timestamps` feature is disabled
+    fn step_timing(&mut self) -> String {
+        #[cfg(feature = "timestamps")]
+        if let Some(elapsed) = self.step_duration.take() {
+            return if elapsed < Duration::from_secs(1) {
+                format!(" ({}ms)", elapsed.as_millis())
+            } else {
+                format!(" ({:.3}s)", elapsed.as_secs_f64())
+            };
+        }
+        String::new()
+    }
+
     /// Clears last `n` lines if [`Coloring`] is enabled.
     fn clear_last_lines_if_term_present(&mut self) -> io::Result<()> {
         if self.styles.is_present && self.lines_to_clear > 0 {
@@ -248,6 +522,37 @@ impl<Out: io::Write> Basic<Out> {
             .write_line(&self.styles.err(format!("Failed to parse: {}", error)))
     }
 
+    /// Outputs the numbers of [`Scenario`]s and [`Feature`]s to be run, along
+    /// with the seed they're shuffled with (if any), once
+    /// [parsing is finished][1].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [1]: event::Cucumber::ParsingFinished
+    pub(crate) fn parsing_finished(
+        &mut self,
+        features: usize,
+        scenarios: usize,
+        shuffle_seed: Option<u64>,
+    ) -> io::Result<()> {
+        let plural = |n: usize| if n == 1 { "" } else { "s" };
+        self.output.write_line(self.styles.bold(format!(
+            "Running {} scenario{} across {} feature{}",
+            scenarios,
+            plural(scenarios),
+            features,
+            plural(features),
+        )))?;
+        if let Some(seed) = shuffle_seed.filter(|_| !self.deterministic) {
+            self.output.write_line(self.styles.bold(format!(
+                "Shuffling scenarios with seed {} (replay with \
+                 `--shuffle-seed {}`)",
+                seed, seed,
+            )))?;
+        }
+        Ok(())
+    }
+
     /// Outputs the [started] [`Feature`].
     ///
     /// [started]: event::Feature::Started
@@ -257,6 +562,9 @@ impl<Out: io::Write> Basic<Out> {
         feature: &gherkin::Feature,
     ) -> io::Result<()> {
         self.lines_to_clear = 1;
+        if let Some(passed) = self.passed_background.as_mut() {
+            passed.clear();
+        }
         self.output.write_line(
             &self
                 .styles
@@ -345,10 +653,163 @@ impl<Out: io::Write> Basic<Out> {
                 self.step(feat, st, ev)?;
             }
             Scenario::Finished => self.indent = self.indent.saturating_sub(2),
+            Scenario::Retried {
+                attempt,
+                retries,
+                previous_error,
+            } => {
+                self.scenario_retried(
+                    scenario,
+                    *attempt,
+                    *retries,
+                    previous_error,
+                )?;
+            }
+            Scenario::Skipped(reason) => {
+                self.scenario_skipped(feat, reason)?;
+            }
+            Scenario::StepHeartbeat(st, elapsed) => {
+                self.step_heartbeat(st, *elapsed)?;
+            }
+            Scenario::ResourceUsage { start, end } => {
+                self.resource_usage(start, end)?;
+            }
         }
         Ok(())
     }
 
+    /// Outputs the [skipped] [`Scenario`] along with its [`SkipReason`].
+    ///
+    /// [skipped]: event::Scenario::Skipped
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`SkipReason`]: event::SkipReason
+    pub(crate) fn scenario_skipped(
+        &mut self,
+        feat: &gherkin::Feature,
+        reason: &event::SkipReason,
+    ) -> io::Result<()> {
+        let indent = " ".repeat(self.indent + 1);
+        match reason {
+            event::SkipReason::BackgroundFailed(step) => {
+                self.output.write_line(self.styles.skipped(format!(
+                    "{indent}?  Scenario skipped, as Background step failed \
+                     earlier\n\
+                     {indent}   {} {}: {}:{}:{}",
+                    step.keyword,
+                    step.value,
+                    self.feature_path(feat),
+                    step.position.line,
+                    step.position.col,
+                    indent = indent,
+                )))
+            }
+            event::SkipReason::RunTimeout => {
+                self.output.write_line(self.styles.err(format!(
+                    "{}?  Scenario interrupted: run timeout",
+                    indent,
+                )))
+            }
+            event::SkipReason::FailFast => {
+                self.output.write_line(self.styles.skipped(format!(
+                    "{}?  Scenario interrupted: run aborted on failure",
+                    indent,
+                )))
+            }
+            event::SkipReason::FilteredOut => self.output.write_line(
+                self.styles
+                    .skipped(format!("{}?  Scenario filtered out", indent)),
+            ),
+        }
+    }
+
+    /// Outputs the [retried] [`Scenario`].
+    ///
+    /// [retried]: event::Scenario::Retried
+    /// [`Scenario`]: gherkin::Scenario
+    pub(crate) fn scenario_retried(
+        &mut self,
+        scenario: &gherkin::Scenario,
+        attempt: usize,
+        retries: usize,
+        previous_error: &event::StepError,
+    ) -> io::Result<()> {
+        self.output.write_line(self.styles.skipped(format!(
+            "{indent}\u{21bb} {}: {} (retried {}/{} after: {})",
+            scenario.keyword,
+            scenario.name,
+            attempt,
+            retries,
+            previous_error,
+            indent = " ".repeat(self.indent + 2),
+        )))
+    }
+
+    /// Outputs the [heartbeat] of a still running [`Step`].
+    ///
+    /// [heartbeat]: event::Scenario::StepHeartbeat
+    /// [`Step`]: gherkin::Step
+    pub(crate) fn step_heartbeat(
+        &mut self,
+        step: &gherkin::Step,
+        elapsed: Duration,
+    ) -> io::Result<()> {
+        if self.deterministic {
+            return Ok(());
+        }
+        if self.styles.is_present {
+            self.lines_to_clear += 1;
+        }
+        self.output.write_line(self.styles.skipped(format!(
+            "{indent}\u{2026} {} {} is still running after {:.1}s",
+            step.keyword,
+            step.value,
+            elapsed.as_secs_f64(),
+            indent = " ".repeat(self.indent + 1),
+        )))
+    }
+
+    /// Outputs the deltas of a [`Scenario`]'s [resource usage][0], if any of
+    /// them has grown.
+    ///
+    /// [0]: event::Scenario::ResourceUsage
+    /// [`Scenario`]: gherkin::Scenario
+    pub(crate) fn resource_usage(
+        &mut self,
+        start: &event::ResourceUsage,
+        end: &event::ResourceUsage,
+    ) -> io::Result<()> {
+        /// Returns the growth of the given value, if it has grown.
+        fn growth(start: Option<u64>, end: Option<u64>) -> Option<u64> {
+            start
+                .zip(end)
+                .map(|(s, e)| e.saturating_sub(s))
+                .filter(|d| *d > 0)
+        }
+
+        if self.deterministic {
+            return Ok(());
+        }
+
+        let deltas = [
+            growth(start.rss, end.rss)
+                .map(|d| format!("RSS +{} KiB", d / 1024)),
+            growth(start.open_fds, end.open_fds)
+                .map(|d| format!("open FDs +{}", d)),
+        ]
+        .into_iter()
+        .flatten()
+        .join(", ");
+        if deltas.is_empty() {
+            return Ok(());
+        }
+
+        self.output.write_line(self.styles.skipped(format!(
+            "{indent}\u{2191} Resource usage grew: {}",
+            deltas,
+            indent = " ".repeat(self.indent + 1),
+        )))
+    }
+
     /// Outputs the [failed] [`Scenario`]'s hook.
     ///
     /// [failed]: event::Hook::Failed
@@ -367,10 +828,7 @@ impl<Out: io::Write> Basic<Out> {
             "{indent}\u{2718}  Scenario's {} hook failed {}:{}:{}\n\
              {indent}   Captured output: {}{}",
             which,
-            feat.path
-                .as_ref()
-                .and_then(|p| p.to_str())
-                .unwrap_or(&feat.name),
+            self.feature_path(feat),
             sc.position.line,
             sc.position.col,
             coerce_error(info),
@@ -384,6 +842,24 @@ impl<Out: io::Write> Basic<Out> {
         )))
     }
 
+    /// Outputs the [failed] hook, executed exactly once for the whole run.
+    ///
+    /// [failed]: event::Cucumber::GlobalHookFailed
+    pub(crate) fn global_hook_failed(
+        &mut self,
+        which: event::GlobalHookType,
+        info: &Info,
+    ) -> io::Result<()> {
+        self.clear_last_lines_if_term_present()?;
+
+        self.output.write_line(&self.styles.err(format!(
+            "\u{2718}  {} hook failed, aborting the run\n   \
+             Captured output: {}",
+            which,
+            coerce_error(info),
+        )))
+    }
+
     /// Outputs the [started] [`Scenario`].
     ///
     /// [started]: event::Scenario::Started
@@ -394,6 +870,7 @@ impl<Out: io::Write> Basic<Out> {
     ) -> io::Result<()> {
         self.lines_to_clear = 1;
         self.indent += 2;
+        self.background_collapsed = false;
         self.output.write_line(&self.styles.ok(format!(
             "{}{}: {}",
             " ".repeat(self.indent),
@@ -426,13 +903,24 @@ impl<Out: io::Write> Basic<Out> {
                 self.indent = self.indent.saturating_sub(4);
             }
             Step::Skipped => {
-                self.step_skipped(feat, step)?;
+                self.step_skipped::<W>(feat, step)?;
+                self.indent = self.indent.saturating_sub(4);
+            }
+            Step::Matched(captures) => {
+                self.step_matched(step, " ", captures)?;
                 self.indent = self.indent.saturating_sub(4);
             }
             Step::Failed(c, w, i) => {
                 self.step_failed(feat, step, c.as_ref(), w.as_ref(), i)?;
+                self.step_output()?;
+                self.indent = self.indent.saturating_sub(4);
+            }
+            Step::SoftFailed(c, i) => {
+                self.step_soft_failed(feat, step, "", c.as_ref(), i)?;
+                self.step_output()?;
                 self.indent = self.indent.saturating_sub(4);
             }
+            Step::Output(out) => self.step_output = Some(out.clone()),
         }
         Ok(())
     }
@@ -488,6 +976,7 @@ impl<Out: io::Write> Basic<Out> {
         captures: &CaptureLocations,
     ) -> io::Result<()> {
         self.clear_last_lines_if_term_present()?;
+        let timing = self.step_timing();
 
         let step_keyword =
             self.styles.ok(format!("\u{2714}  {}", step.keyword));
@@ -516,20 +1005,49 @@ impl<Out: io::Write> Basic<Out> {
             .unwrap_or_default());
 
         self.output.write_line(&self.styles.ok(format!(
-            "{indent}{} {}{}{}",
+            "{indent}{} {}{}{}{}",
             step_keyword,
             step_value,
+            timing,
             doc_str,
             step_table,
             indent = " ".repeat(self.indent.saturating_sub(3)),
         )))
     }
 
-    /// Outputs the [skipped] [`Step`].
+    /// Outputs the [matched] [`Step`] (or [`Background`] one, depending on
+    /// the `prefix`).
+    ///
+    /// [matched]: event::Step::Matched
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    pub(crate) fn step_matched(
+        &mut self,
+        step: &gherkin::Step,
+        prefix: &str,
+        captures: &CaptureLocations,
+    ) -> io::Result<()> {
+        self.clear_last_lines_if_term_present()?;
+
+        let step_value = format_captures(
+            &step.value,
+            captures,
+            |v| self.styles.skipped(v),
+            |v| self.styles.skipped(self.styles.bold(v)),
+        );
+        self.output.write_line(&format!(
+            "{indent}{} {}",
+            self.styles.skipped(format!("-{} {}", prefix, step.keyword)),
+            step_value,
+            indent = " ".repeat(self.indent.saturating_sub(3)),
+        ))
+    }
+
+    /// Outputs the [skipped] [`Step`], which has no matching step function.
     ///
     /// [skipped]: event::Step::Skipped
     /// [`Step`]: gherkin::Step
-    pub(crate) fn step_skipped(
+    pub(crate) fn step_skipped<W>(
         &mut self,
         feat: &gherkin::Feature,
         step: &gherkin::Step,
@@ -537,7 +1055,7 @@ impl<Out: io::Write> Basic<Out> {
         self.clear_last_lines_if_term_present()?;
         self.output.write_line(&self.styles.skipped(format!(
             "{indent}?  {} {}{}{}\n\
-             {indent}   Step skipped: {}:{}:{}",
+             {indent}   Step undefined: {}:{}:{}{}",
             step.keyword,
             step.value,
             step.docstring
@@ -551,12 +1069,15 @@ impl<Out: io::Write> Basic<Out> {
                 .as_ref()
                 .map(|t| format_table(t, self.indent))
                 .unwrap_or_default(),
-            feat.path
-                .as_ref()
-                .and_then(|p| p.to_str())
-                .unwrap_or(&feat.name),
+            self.feature_path(feat),
             step.position.line,
             step.position.col,
+            self.snippets
+                .then(|| format_str_with_indent(
+                    format_snippet::<W>(step),
+                    self.indent.saturating_sub(3) + 3,
+                ))
+                .unwrap_or_default(),
             indent = " ".repeat(self.indent.saturating_sub(3)),
         )))
     }
@@ -574,6 +1095,7 @@ impl<Out: io::Write> Basic<Out> {
         err: &event::StepError,
     ) -> io::Result<()> {
         self.clear_last_lines_if_term_present()?;
+        let timing = self.step_timing();
 
         let step_keyword = self.styles.err(format!(
             "{indent}\u{2718}  {}",
@@ -594,9 +1116,10 @@ impl<Out: io::Write> Basic<Out> {
         );
 
         let diagnostics = self.styles.err(format!(
-            "{}{}\n\
+            "{}{}{}\n\
              {indent}   Step failed: {}:{}:{}\n\
              {indent}   Captured output: {}{}",
+            timing,
             step.docstring
                 .as_ref()
                 .and_then(|doc| self.verbose.then(|| format_str_with_indent(
@@ -608,10 +1131,7 @@ impl<Out: io::Write> Basic<Out> {
                 .as_ref()
                 .map(|t| format_table(t, self.indent))
                 .unwrap_or_default(),
-            feat.path
-                .as_ref()
-                .and_then(|p| p.to_str())
-                .unwrap_or(&feat.name),
+            self.feature_path(feat),
             step.position.line,
             step.position.col,
             format_str_with_indent(
@@ -633,6 +1153,73 @@ impl<Out: io::Write> Basic<Out> {
         ))
     }
 
+    /// Outputs the [captured output][1] of the just failed [`Step`], if any.
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [1]: event::Step::Output
+    fn step_output(&mut self) -> io::Result<()> {
+        if let Some(out) = self.step_output.take() {
+            let indent = self.indent.saturating_sub(3);
+            let out = self.styles.err(format!(
+                "{}   Step output:{}",
+                " ".repeat(indent),
+                format_str_with_indent(out, indent + 3),
+            ));
+            self.write_line(&out)?;
+        }
+        Ok(())
+    }
+
+    /// Outputs the failed [soft] [`Step`] (or a [`Background`] one, if the
+    /// `marker` is `>`).
+    ///
+    /// [soft]: event::Step::SoftFailed
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    pub(crate) fn step_soft_failed(
+        &mut self,
+        feat: &gherkin::Feature,
+        step: &gherkin::Step,
+        marker: &str,
+        captures: Option<&CaptureLocations>,
+        err: &event::StepError,
+    ) -> io::Result<()> {
+        self.clear_last_lines_if_term_present()?;
+        let timing = self.step_timing();
+
+        let step_value = captures.map_or_else(
+            || step.value.clone(),
+            |capts| {
+                format_captures(
+                    &step.value,
+                    capts,
+                    |v| self.styles.skipped(v),
+                    |v| self.styles.skipped(self.styles.bold(v)),
+                )
+            },
+        );
+
+        let output = self.styles.skipped(format!(
+            "{indent}\u{26a0}{:2}{} {}{}\n\
+             {indent}   Soft step failed: {}:{}:{}\n\
+             {indent}   Captured output: {}",
+            marker,
+            step.keyword,
+            step_value,
+            timing,
+            self.feature_path(feat),
+            step.position.line,
+            step.position.col,
+            format_str_with_indent(
+                format!("{}", err),
+                self.indent.saturating_sub(3) + 3,
+            ),
+            indent = " ".repeat(self.indent.saturating_sub(3)),
+        ));
+
+        self.write_line(&output)
+    }
+
     /// Outputs the [`Background`] [`Step`]'s
     /// [started]/[passed]/[skipped]/[failed] event.
     ///
@@ -654,18 +1241,36 @@ impl<Out: io::Write> Basic<Out> {
             Step::Started => {
                 self.bg_step_started(bg)?;
             }
+            Step::Passed(_) if self.is_collapsible(bg) => {
+                self.bg_step_collapsed()?;
+                self.indent = self.indent.saturating_sub(4);
+            }
             Step::Passed(captures) => {
+                if let Some(passed) = self.passed_background.as_mut() {
+                    let _ = passed.insert((bg.position.line, bg.position.col));
+                }
                 self.bg_step_passed(bg, captures)?;
                 self.indent = self.indent.saturating_sub(4);
             }
             Step::Skipped => {
-                self.bg_step_skipped(feat, bg)?;
+                self.bg_step_skipped::<W>(feat, bg)?;
+                self.indent = self.indent.saturating_sub(4);
+            }
+            Step::Matched(captures) => {
+                self.step_matched(bg, ">", captures)?;
                 self.indent = self.indent.saturating_sub(4);
             }
             Step::Failed(c, w, i) => {
                 self.bg_step_failed(feat, bg, c.as_ref(), w.as_ref(), i)?;
+                self.step_output()?;
                 self.indent = self.indent.saturating_sub(4);
             }
+            Step::SoftFailed(c, i) => {
+                self.step_soft_failed(feat, bg, ">", c.as_ref(), i)?;
+                self.step_output()?;
+                self.indent = self.indent.saturating_sub(4);
+            }
+            Step::Output(out) => self.step_output = Some(out.clone()),
         }
         Ok(())
     }
@@ -686,6 +1291,12 @@ impl<Out: io::Write> Basic<Out> {
         step: &gherkin::Step,
     ) -> io::Result<()> {
         self.indent += 4;
+        if self.is_collapsible(step) {
+            // Nothing is outputted until the `Step` finishes, so there is
+            // nothing to overwrite either.
+            self.lines_to_clear = 0;
+            return Ok(());
+        }
         if self.styles.is_present {
             let output = format!(
                 "{indent}> {} {}{}{}",
@@ -723,49 +1334,84 @@ impl<Out: io::Write> Basic<Out> {
         captures: &CaptureLocations,
     ) -> io::Result<()> {
         self.clear_last_lines_if_term_present()?;
+        let timing = self.step_timing();
 
-        let step_keyword =
-            self.styles.ok(format!("\u{2714}> {}", step.keyword));
+        let step_keyword = self
+            .styles
+            .background(format!("\u{2714}> {}", step.keyword));
         let step_value = format_captures(
             &step.value,
             captures,
-            |v| self.styles.ok(v),
-            |v| self.styles.ok(self.styles.bold(v)),
+            |v| self.styles.background(v),
+            |v| self.styles.background(self.styles.bold(v)),
         );
-        let doc_str = self.styles.ok(step
-            .docstring
-            .as_ref()
-            .and_then(|doc| {
-                self.verbose.then(|| {
-                    format_str_with_indent(
-                        doc,
-                        self.indent.saturating_sub(3) + 3,
-                    )
+        let doc_str = self.styles.background(
+            step.docstring
+                .as_ref()
+                .and_then(|doc| {
+                    self.verbose.then(|| {
+                        format_str_with_indent(
+                            doc,
+                            self.indent.saturating_sub(3) + 3,
+                        )
+                    })
                 })
-            })
-            .unwrap_or_default());
-        let step_table = self.styles.ok(step
-            .table
-            .as_ref()
-            .map(|t| format_table(t, self.indent))
-            .unwrap_or_default());
+                .unwrap_or_default(),
+        );
+        let step_table = self.styles.background(
+            step.table
+                .as_ref()
+                .map(|t| format_table(t, self.indent))
+                .unwrap_or_default(),
+        );
 
-        self.output.write_line(&self.styles.ok(format!(
-            "{indent}{} {}{}{}",
+        self.output.write_line(&self.styles.background(format!(
+            "{indent}{} {}{}{}{}",
             step_keyword,
             step_value,
+            timing,
             doc_str,
             step_table,
             indent = " ".repeat(self.indent.saturating_sub(3)),
         )))
     }
 
+    /// Indicates whether the given [`Background`] [`Step`] has already passed
+    /// in the current [`Feature`] and should be [collapsed][1] on passing.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Feature`]: gherkin::Feature
+    /// [`Step`]: gherkin::Step
+    /// [1]: Basic::collapse_background()
+    fn is_collapsible(&self, step: &gherkin::Step) -> bool {
+        self.passed_background.as_ref().map_or(false, |passed| {
+            passed.contains(&(step.position.line, step.position.col))
+        })
+    }
+
+    /// Outputs the `(background)` line instead of the [collapsed][1] passed
+    /// [`Background`] [`Step`], once per [`Scenario`].
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    /// [1]: Basic::collapse_background()
+    fn bg_step_collapsed(&mut self) -> io::Result<()> {
+        if mem::replace(&mut self.background_collapsed, true) {
+            return Ok(());
+        }
+        self.output.write_line(self.styles.background(format!(
+            "{}\u{2714}> (background)",
+            " ".repeat(self.indent.saturating_sub(3)),
+        )))
+    }
+
     /// Outputs the [skipped] [`Background`] [`Step`].
     ///
     /// [skipped]: event::Step::Skipped
     /// [`Background`]: gherkin::Background
     /// [`Step`]: gherkin::Step
-    pub(crate) fn bg_step_skipped(
+    pub(crate) fn bg_step_skipped<W>(
         &mut self,
         feat: &gherkin::Feature,
         step: &gherkin::Step,
@@ -773,7 +1419,7 @@ impl<Out: io::Write> Basic<Out> {
         self.clear_last_lines_if_term_present()?;
         self.output.write_line(&self.styles.skipped(format!(
             "{indent}?> {} {}{}{}\n\
-             {indent}   Background step failed: {}:{}:{}",
+             {indent}   Background step undefined: {}:{}:{}{}",
             step.keyword,
             step.value,
             step.docstring
@@ -787,12 +1433,15 @@ impl<Out: io::Write> Basic<Out> {
                 .as_ref()
                 .map(|t| format_table(t, self.indent))
                 .unwrap_or_default(),
-            feat.path
-                .as_ref()
-                .and_then(|p| p.to_str())
-                .unwrap_or(&feat.name),
+            self.feature_path(feat),
             step.position.line,
             step.position.col,
+            self.snippets
+                .then(|| format_str_with_indent(
+                    format_snippet::<W>(step),
+                    self.indent.saturating_sub(3) + 3,
+                ))
+                .unwrap_or_default(),
             indent = " ".repeat(self.indent.saturating_sub(3)),
         )))
     }
@@ -811,6 +1460,7 @@ impl<Out: io::Write> Basic<Out> {
         err: &event::StepError,
     ) -> io::Result<()> {
         self.clear_last_lines_if_term_present()?;
+        let timing = self.step_timing();
 
         let step_keyword = self.styles.err(format!(
             "{indent}\u{2718}> {}{}",
@@ -831,9 +1481,10 @@ impl<Out: io::Write> Basic<Out> {
         );
 
         let diagnostics = self.styles.err(format!(
-            "{}{}\n\
+            "{}{}{}\n\
              {indent}   Step failed: {}:{}:{}\n\
              {indent}   Captured output: {}{}",
+            timing,
             step.docstring
                 .as_ref()
                 .and_then(|doc| self.verbose.then(|| format_str_with_indent(
@@ -845,10 +1496,7 @@ impl<Out: io::Write> Basic<Out> {
                 .as_ref()
                 .map(|t| format_table(t, self.indent))
                 .unwrap_or_default(),
-            feat.path
-                .as_ref()
-                .and_then(|p| p.to_str())
-                .unwrap_or(&feat.name),
+            self.feature_path(feat),
             step.position.line,
             step.position.col,
             format_str_with_indent(
@@ -898,6 +1546,17 @@ fn format_str_with_indent(str: impl AsRef<str>, indent: usize) -> String {
         .unwrap_or_default()
 }
 
+/// Formats a step function snippet to be implemented for the given
+/// [undefined][1] [`gherkin::Step`].
+///
+/// [1]: event::Step::Skipped
+fn format_snippet<W>(step: &gherkin::Step) -> String {
+    format!(
+        "You can implement it with the following snippet:\n{}",
+        step::snippet::<W>(step),
+    )
+}
+
 /// Formats the given [`gherkin::Table`] and adds `indent`s to each line to
 /// prettify the output.
 fn format_table(table: &gherkin::Table, indent: usize) -> String {
@@ -67,6 +67,22 @@ impl<W: World, Wr: writer::Failure<W> + ?Sized> writer::Failure<W>
     fn hook_errors(&self) -> usize {
         self.0.hook_errors()
     }
+
+    fn skipped_steps(&self) -> usize {
+        self.0.skipped_steps()
+    }
+
+    fn passed_steps(&self) -> usize {
+        self.0.passed_steps()
+    }
+
+    fn not_executed_steps(&self) -> usize {
+        self.0.not_executed_steps()
+    }
+
+    fn timed_out_scenarios(&self) -> usize {
+        self.0.timed_out_scenarios()
+    }
 }
 
 impl<Wr: writer::Normalized> writer::Normalized for Arbitrary<Wr> {}
@@ -45,8 +45,12 @@ pub struct FailOnSkipped<W, F = SkipFn> {
 ///
 /// [`Failed`]: event::Step::Failed
 /// [`Skipped`]: event::Step::Skipped
-pub type SkipFn =
-    fn(&gherkin::Feature, Option<&gherkin::Rule>, &gherkin::Scenario) -> bool;
+pub type SkipFn = fn(
+    &gherkin::Feature,
+    Option<&gherkin::Rule>,
+    &gherkin::Scenario,
+    &gherkin::Step,
+) -> bool;
 
 #[async_trait(?Send)]
 impl<W, Wr, F> Writer<W> for FailOnSkipped<Wr, F>
@@ -56,6 +60,7 @@ where
         &gherkin::Feature,
         Option<&gherkin::Rule>,
         &gherkin::Scenario,
+        &gherkin::Step,
     ) -> bool,
     Wr: for<'val> writer::Arbitrary<'val, W, String>,
 {
@@ -70,14 +75,23 @@ where
             Cucumber, Feature, Rule, Scenario, Step, StepError::Panic,
         };
 
-        let map_failed = |f: Arc<_>, r: Option<Arc<_>>, sc: Arc<_>, st: _| {
-            let ev = if (self.should_fail)(&f, r.as_deref(), &sc) {
+        let map_failed = |f: Arc<_>,
+                          r: Option<Arc<_>>,
+                          sc: Arc<_>,
+                          st: Arc<_>,
+                          is_background: bool| {
+            let ev = if (self.should_fail)(&f, r.as_deref(), &sc, &st) {
                 Step::Failed(None, None, Panic(Arc::new("not allowed to skip")))
             } else {
                 Step::Skipped
             };
+            let ev = if is_background {
+                Scenario::Background(st, ev)
+            } else {
+                Scenario::Step(st, ev)
+            };
 
-            Cucumber::scenario(f, r, sc, Scenario::Step(st, ev))
+            Cucumber::scenario(f, r, sc, ev)
         };
 
         let event = event.map(|outer| {
@@ -88,13 +102,32 @@ where
                         r,
                         Rule::Scenario(sc, Scenario::Step(st, Step::Skipped)),
                     ),
-                ) => map_failed(f, Some(r), sc, st),
+                ) => map_failed(f, Some(r), sc, st, false),
+                Cucumber::Feature(
+                    f,
+                    Feature::Rule(
+                        r,
+                        Rule::Scenario(
+                            sc,
+                            Scenario::Background(st, Step::Skipped),
+                        ),
+                    ),
+                ) => map_failed(f, Some(r), sc, st, true),
                 Cucumber::Feature(
                     f,
                     Feature::Scenario(sc, Scenario::Step(st, Step::Skipped)),
-                ) => map_failed(f, None, sc, st),
+                ) => map_failed(f, None, sc, st, false),
+                Cucumber::Feature(
+                    f,
+                    Feature::Scenario(
+                        sc,
+                        Scenario::Background(st, Step::Skipped),
+                    ),
+                ) => map_failed(f, None, sc, st, true),
                 Cucumber::Started
+                | Cucumber::ParsingFinished { .. }
                 | Cucumber::Feature(..)
+                | Cucumber::GlobalHookFailed(..)
                 | Cucumber::Finished => ev,
             })
         });
@@ -136,6 +169,22 @@ where
     fn hook_errors(&self) -> usize {
         self.writer.hook_errors()
     }
+
+    fn skipped_steps(&self) -> usize {
+        self.writer.skipped_steps()
+    }
+
+    fn passed_steps(&self) -> usize {
+        self.writer.passed_steps()
+    }
+
+    fn not_executed_steps(&self) -> usize {
+        self.writer.not_executed_steps()
+    }
+
+    fn timed_out_scenarios(&self) -> usize {
+        self.writer.timed_out_scenarios()
+    }
 }
 
 impl<Wr: writer::Normalized, F> writer::Normalized for FailOnSkipped<Wr, F> {}
@@ -144,7 +193,7 @@ impl<Writer> From<Writer> for FailOnSkipped<Writer> {
     fn from(writer: Writer) -> Self {
         Self {
             writer,
-            should_fail: |_, _, sc| {
+            should_fail: |_, _, sc, _| {
                 !sc.tags.iter().any(|t| t == "allow.skipped")
             },
         }
@@ -172,6 +221,7 @@ impl<Writer> FailOnSkipped<Writer> {
             &gherkin::Feature,
             Option<&gherkin::Rule>,
             &gherkin::Scenario,
+            &gherkin::Step,
         ) -> bool,
     {
         FailOnSkipped {
@@ -0,0 +1,287 @@
+// Copyright (c) 2018-2021  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Writer`] selecting one of the built-in output formats at runtime.
+
+use std::{fmt::Debug, io, str::FromStr};
+
+use async_trait::async_trait;
+use derive_more::{Display, Error};
+use structopt::StructOpt;
+
+use crate::{event, parser, writer, Event, World, Writer};
+
+// Workaround for overwritten doc-comments.
+// https://github.com/TeXitoi/structopt/issues/333#issuecomment-712265332
+#[cfg_attr(doc, doc = "CLI options of a [`Format`] [`Writer`].")]
+#[cfg_attr(
+    not(doc),
+    allow(missing_docs, clippy::missing_docs_in_private_items)
+)]
+#[derive(Clone, Copy, Debug, StructOpt)]
+pub struct Cli {
+    /// Output format: `pretty`, `progress`, `tap`, `teamcity`, `markdown`,
+    /// `html`, `junit` or `json` (the latter three require the corresponding
+    /// crate features). Can be set via `CUCUMBER_FORMAT` environment variable
+    /// as well.
+    #[structopt(
+        long,
+        name = "pretty|progress|tap|teamcity|markdown|html|junit|json",
+        env = "CUCUMBER_FORMAT"
+    )]
+    pub format: Option<Name>,
+
+    /// CLI options of the `pretty` output format.
+    #[structopt(flatten)]
+    pub pretty: writer::basic::Cli,
+}
+
+/// Name of a built-in output format.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Name {
+    /// Human-readable output of a [`writer::Basic`].
+    Pretty,
+
+    /// Compact progress line of a [`writer::Progress`].
+    Progress,
+
+    /// [TAP version 13][1] output of a [`writer::Tap`].
+    ///
+    /// [1]: https://testanything.org/tap-version-13-specification.html
+    Tap,
+
+    /// [TeamCity service messages][1] of a [`writer::TeamCity`].
+    ///
+    /// [1]: https://www.jetbrains.com/help/teamcity/service-messages.html
+    TeamCity,
+
+    /// Markdown report of a [`writer::Markdown`].
+    Markdown,
+
+    /// Self-contained HTML report of a [`writer::Html`].
+    #[cfg(feature = "output-html")]
+    Html,
+
+    /// [JUnit XML report][1] of a [`writer::JUnit`].
+    ///
+    /// [1]: https://llg.cubic.org/docs/junit
+    #[cfg(feature = "output-junit")]
+    JUnit,
+
+    /// [Cucumber JSON format][1] of a [`writer::Json`].
+    ///
+    /// [1]: https://github.com/cucumber/cucumber-json-schema
+    #[cfg(feature = "output-json")]
+    Json,
+}
+
+impl FromStr for Name {
+    type Err = UnknownFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "pretty" => Ok(Self::Pretty),
+            "progress" => Ok(Self::Progress),
+            "tap" => Ok(Self::Tap),
+            "teamcity" => Ok(Self::TeamCity),
+            "markdown" => Ok(Self::Markdown),
+            #[cfg(feature = "output-html")]
+            "html" => Ok(Self::Html),
+            #[cfg(feature = "output-junit")]
+            "junit" => Ok(Self::JUnit),
+            #[cfg(feature = "output-json")]
+            "json" => Ok(Self::Json),
+            _ => Err(UnknownFormatError(s.to_owned())),
+        }
+    }
+}
+
+/// Error of parsing an unknown (or disabled by crate features) output format
+/// [`Name`].
+#[derive(Clone, Debug, Display, Error)]
+#[display(fmt = "Unknown or disabled output format: {}", _0)]
+pub struct UnknownFormatError(#[error(not(source))] pub String);
+
+/// [`Writer`] outputting to [`io::Stdout`] in a format chosen at runtime via
+/// `--format` CLI option (or `CUCUMBER_FORMAT` environment variable), so the
+/// same binary may be used across different CI configurations.
+///
+/// The chosen [`Writer`] is constructed on the first event, falling back to the
+/// [`Format`]'s default [`Name`] if the format isn't specified.
+///
+/// [`Format`] is [`Normalized`] already, and may be [`Summarize`]d, while the
+/// summary is outputted in the `pretty` format only, to keep reports valid.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::convert::Infallible;
+/// #
+/// # use async_trait::async_trait;
+/// # use cucumber::{writer, WorldInit, WriterExt as _};
+/// #
+/// # #[derive(Debug, WorldInit)]
+/// # struct MyWorld;
+/// #
+/// # #[async_trait(?Send)]
+/// # impl cucumber::World for MyWorld {
+/// #     type Error = Infallible;
+/// #
+/// #     async fn new() -> Result<Self, Self::Error> {
+/// #         Ok(Self)
+/// #     }
+/// # }
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// MyWorld::cucumber()
+///     .with_writer(writer::Format::default().summarized())
+///     .run("tests/features/readme")
+///     .await;
+/// # }
+/// ```
+///
+/// [`Normalized`]: writer::Normalized
+/// [`Summarize`]: writer::Summarize
+#[derive(Debug)]
+pub struct Format<W> {
+    /// [`Name`] of the format to use if none is specified via CLI.
+    default: Name,
+
+    /// [`Writer`] of the chosen format, once constructed.
+    writer: Option<Selected<W>>,
+}
+
+impl<W> Default for Format<W> {
+    fn default() -> Self {
+        Self::new(Name::Pretty)
+    }
+}
+
+impl<W> Format<W> {
+    /// Creates a new [`Format`] [`Writer`] falling back to the provided
+    /// `default` format, if none is specified via CLI.
+    #[must_use]
+    pub const fn new(default: Name) -> Self {
+        Self {
+            default,
+            writer: None,
+        }
+    }
+}
+
+/// Built-in [`Writer`] chosen by a [`Format`].
+#[derive(Debug)]
+enum Selected<W> {
+    /// [`Name::Pretty`] format.
+    Pretty(writer::Normalize<W, writer::Basic<io::Stdout>>),
+
+    /// [`Name::Progress`] format.
+    Progress(writer::Progress<io::Stdout>),
+
+    /// [`Name::Tap`] format.
+    Tap(writer::Normalize<W, writer::Tap<io::Stdout>>),
+
+    /// [`Name::TeamCity`] format.
+    TeamCity(writer::Normalize<W, writer::TeamCity<io::Stdout>>),
+
+    /// [`Name::Markdown`] format.
+    Markdown(writer::Normalize<W, writer::Markdown<io::Stdout>>),
+
+    /// [`Name::Html`] format.
+    #[cfg(feature = "output-html")]
+    Html(writer::Normalize<W, writer::Html<io::Stdout>>),
+
+    /// [`Name::JUnit`] format.
+    #[cfg(feature = "output-junit")]
+    JUnit(writer::Normalize<W, writer::JUnit<W, io::Stdout>>),
+
+    /// [`Name::Json`] format.
+    #[cfg(feature = "output-json")]
+    Json(writer::Normalize<W, writer::Json<io::Stdout>>),
+}
+
+impl<W: World + Debug> Selected<W> {
+    /// Constructs the [`Writer`] of the given format.
+    fn new(name: Name) -> Self {
+        match name {
+            Name::Pretty => Self::Pretty(writer::Basic::stdout()),
+            Name::Progress => Self::Progress(writer::Progress::stdout()),
+            Name::Tap => Self::Tap(writer::Tap::new(io::stdout())),
+            Name::TeamCity => {
+                Self::TeamCity(writer::TeamCity::new(io::stdout()))
+            }
+            Name::Markdown => {
+                Self::Markdown(writer::Markdown::new(io::stdout()))
+            }
+            #[cfg(feature = "output-html")]
+            Name::Html => Self::Html(writer::Html::new(io::stdout())),
+            #[cfg(feature = "output-junit")]
+            Name::JUnit => Self::JUnit(writer::JUnit::new(io::stdout())),
+            #[cfg(feature = "output-json")]
+            Name::Json => Self::Json(writer::Json::new(io::stdout())),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<W: World + Debug> Writer<W> for Format<W> {
+    type Cli = Cli;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+        cli: &Self::Cli,
+    ) {
+        let name = cli.format.unwrap_or(self.default);
+        let empty = crate::cli::Empty::default();
+        match self.writer.get_or_insert_with(|| Selected::new(name)) {
+            Selected::Pretty(w) => w.handle_event(ev, &cli.pretty).await,
+            Selected::Progress(w) => w.handle_event(ev, &empty).await,
+            Selected::Tap(w) => w.handle_event(ev, &empty).await,
+            Selected::TeamCity(w) => w.handle_event(ev, &empty).await,
+            Selected::Markdown(w) => w.handle_event(ev, &empty).await,
+            #[cfg(feature = "output-html")]
+            Selected::Html(w) => w.handle_event(ev, &empty).await,
+            #[cfg(feature = "output-junit")]
+            Selected::JUnit(w) => w.handle_event(ev, &empty).await,
+            #[cfg(feature = "output-json")]
+            Selected::Json(w) => w.handle_event(ev, &empty).await,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'val, W> writer::Arbitrary<'val, W, String> for Format<W>
+where
+    W: World + Debug,
+{
+    async fn write(&mut self, val: String)
+    where
+        'val: 'async_trait,
+    {
+        // Arbitrary output would break machine-readable reports, while the
+        // textual ones render it in their own way (like TAP comments).
+        match &mut self.writer {
+            Some(Selected::Pretty(w)) => w.write(val).await,
+            Some(Selected::Progress(w)) => {
+                writer::Arbitrary::<W, _>::write(w, val).await;
+            }
+            Some(Selected::Tap(w)) => w.write(val).await,
+            Some(Selected::TeamCity(w)) => w.write(val).await,
+            Some(Selected::Markdown(w)) => w.write(val).await,
+            _ => {}
+        }
+    }
+}
+
+impl<W> writer::Normalized for Format<W> {}
+
+impl<W> writer::NonTransforming for Format<W> {}
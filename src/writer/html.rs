@@ -0,0 +1,851 @@
+// Copyright (c) 2018-2021  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Self-contained HTML report [`Writer`] implementation.
+
+use std::{
+    fmt::{self, Debug, Write as _},
+    fs, io,
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+use async_trait::async_trait;
+
+use crate::{
+    cli, event, parser,
+    writer::{self, basic::coerce_error, Ext as _},
+    Event, World, Writer,
+};
+
+/// Inline CSS of the report.
+const STYLE: &str = "\
+body{font-family:sans-serif;margin:2em;color:#212121}\
+details{margin:.25em 0 .25em 1em;border-left:4px solid #9e9e9e;padding-left:.5em}\
+summary{cursor:pointer;padding:.2em 0}\
+.passed{border-color:#2e7d32}.failed{border-color:#c62828}\
+.skipped{border-color:#f9a825}\
+.step.passed .status{color:#2e7d32}.step.failed .status{color:#c62828}\
+.step.skipped .status{color:#f9a825}.step.soft-failed .status{color:#ef6c00}\
+.steps{list-style:none;margin:.25em 0 .5em 1em;padding:0}\
+.step{padding:.1em 0}.background{font-style:italic}\
+.keyword{font-weight:bold}.tags{color:#6a1b9a}\
+.duration,.uri{color:#757575;font-size:.85em}\
+pre{background:#f5f5f5;padding:.5em;margin:.25em 0 .25em 1.5em}\
+pre.error{background:#ffebee}\
+table{border-collapse:collapse;margin:.25em 0 .25em 1.5em}\
+td{border:1px solid #bdbdbd;padding:.1em .5em}";
+
+/// Inline JS of the report, expanding/collapsing all the [`Feature`]s,
+/// [`Rule`]s and [`Scenario`]s at once.
+const SCRIPT: &str = "\
+function toggleAll(open){\
+document.querySelectorAll('details').forEach(function(d){d.open=open;});}";
+
+/// Self-contained HTML report [`Writer`] implementation outputting a single
+/// HTML page (with inline CSS and JS, and no external assets) to an
+/// [`io::Write`] implementor.
+///
+/// [`Feature`]s, [`Rule`]s and [`Scenario`]s are rendered as collapsible
+/// blocks with color-coded statuses and durations, while data tables and doc
+/// strings of [`Step`]s are rendered as `<table>` and `<pre>` blocks.
+///
+/// # Ordering
+///
+/// This [`Writer`] isn't [`Normalized`] by itself, so should be wrapped into
+/// a [`writer::Normalize`], otherwise will panic in runtime as won't be able to
+/// match events with their [`Scenario`]s.
+///
+/// [`Normalized`]: writer::Normalized
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+#[derive(Debug)]
+pub struct Html<Out: io::Write> {
+    /// [`io::Write`] implementor to output the report into.
+    output: Out,
+
+    /// Collected [`Feature`]s to render.
+    features: Vec<Feature>,
+
+    /// Collected parsing errors to render.
+    parsing_errors: Vec<String>,
+
+    /// [`SystemTime`] when the current [`Step`] has started.
+    ///
+    /// [`Step`]: gherkin::Step
+    started: Option<SystemTime>,
+
+    /// Indicator whether the report has been written already, so any further
+    /// events (like re-outputted by a [`writer::Repeat`]) should be ignored.
+    written: bool,
+}
+
+/// Collected [`gherkin::Feature`].
+#[derive(Debug)]
+struct Feature {
+    /// [`gherkin::Feature::keyword`].
+    keyword: String,
+
+    /// [`gherkin::Feature::name`].
+    name: String,
+
+    /// [`gherkin::Feature::path`], if any.
+    path: Option<String>,
+
+    /// [`gherkin::Feature::tags`].
+    tags: Vec<String>,
+
+    /// [`Item`]s of this [`Feature`], in their execution order.
+    items: Vec<Item>,
+}
+
+/// Item of a [`Feature`].
+#[derive(Debug)]
+enum Item {
+    /// [`Rule`] of a [`Feature`].
+    Rule(Rule),
+
+    /// [`Scenario`] of a [`Feature`] outside any [`Rule`].
+    Scenario(Scenario),
+}
+
+/// Collected [`gherkin::Rule`].
+#[derive(Debug)]
+struct Rule {
+    /// [`gherkin::Rule::keyword`].
+    keyword: String,
+
+    /// [`gherkin::Rule::name`].
+    name: String,
+
+    /// [`gherkin::Rule::position`].
+    position: gherkin::LineCol,
+
+    /// [`gherkin::Rule::tags`].
+    tags: Vec<String>,
+
+    /// [`Scenario`]s of this [`Rule`], in their execution order.
+    scenarios: Vec<Scenario>,
+}
+
+/// Collected [`gherkin::Scenario`].
+#[derive(Debug)]
+struct Scenario {
+    /// [`gherkin::Scenario::keyword`].
+    keyword: String,
+
+    /// [`gherkin::Scenario::name`].
+    name: String,
+
+    /// [`gherkin::Scenario::position`].
+    position: gherkin::LineCol,
+
+    /// [`gherkin::Scenario::tags`].
+    tags: Vec<String>,
+
+    /// [`SystemTime`] when this [`Scenario`] has started.
+    started: Option<SystemTime>,
+
+    /// Duration of this [`Scenario`], once it has finished.
+    duration: Option<Duration>,
+
+    /// Errors of this [`Scenario`] not related to any [`Step`], like failed
+    /// hooks.
+    errors: Vec<String>,
+
+    /// [`event::SkipReason`] of this [`Scenario`] being skipped without
+    /// execution, if any.
+    skipped: Option<event::SkipReason>,
+
+    /// Executed [`Step`]s of this [`Scenario`], including [`Background`] ones.
+    ///
+    /// [`Background`]: gherkin::Background
+    steps: Vec<Step>,
+}
+
+/// Executed [`gherkin::Step`].
+#[derive(Debug)]
+struct Step {
+    /// [`gherkin::Step::keyword`].
+    keyword: String,
+
+    /// [`gherkin::Step::value`].
+    text: String,
+
+    /// Whether this [`Step`] belongs to a [`gherkin::Background`].
+    background: bool,
+
+    /// [`gherkin::Step::docstring`], if any.
+    docstring: Option<String>,
+
+    /// Rows of the [`gherkin::Step::table`], if any.
+    table: Vec<Vec<String>>,
+
+    /// [`Status`] of this [`Step`].
+    status: Status,
+
+    /// Duration of this [`Step`].
+    duration: Duration,
+
+    /// Error of this [`Step`], if any.
+    error: Option<String>,
+}
+
+/// Status of an executed [`Step`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Status {
+    /// [`event::Step::Passed`].
+    Passed,
+
+    /// [`event::Step::SoftFailed`].
+    SoftFailed,
+
+    /// [`event::Step::Failed`].
+    Failed,
+
+    /// [`event::Step::Skipped`].
+    Skipped,
+}
+
+impl Status {
+    /// Returns a CSS class of this [`Status`].
+    const fn class(self) -> &'static str {
+        match self {
+            Self::Passed => "passed",
+            Self::SoftFailed => "soft-failed",
+            Self::Failed => "failed",
+            Self::Skipped => "skipped",
+        }
+    }
+
+    /// Returns a symbol representing this [`Status`].
+    const fn symbol(self) -> &'static str {
+        match self {
+            Self::Passed => "\u{2714}",
+            Self::SoftFailed => "\u{26a0}",
+            Self::Failed => "\u{2718}",
+            Self::Skipped => "?",
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<W: World + Debug, Out: io::Write> Writer<W> for Html<Out> {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        event: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule};
+
+        if self.written {
+            return;
+        }
+
+        match event.map(Event::split) {
+            Err(err) => self.parsing_errors.push(err.to_string()),
+            Ok((Cucumber::Feature(f, Feature::Started), _)) => {
+                self.features.push(self::Feature {
+                    keyword: f.keyword.clone(),
+                    name: f.name.clone(),
+                    path: f.path.as_ref().map(|p| p.display().to_string()),
+                    tags: f.tags.clone(),
+                    items: Vec::new(),
+                });
+            }
+            Ok((Cucumber::Feature(_, Feature::Rule(r, Rule::Started)), _)) => {
+                self.feature().items.push(Item::Rule(self::Rule {
+                    keyword: r.keyword.clone(),
+                    name: r.name.clone(),
+                    position: r.position,
+                    tags: r.tags.clone(),
+                    scenarios: Vec::new(),
+                }));
+            }
+            Ok((Cucumber::Feature(_, Feature::Scenario(sc, ev)), meta)) => {
+                self.handle_scenario_event(None, &sc, ev, meta.at);
+            }
+            Ok((
+                Cucumber::Feature(_, Feature::Rule(r, Rule::Scenario(sc, ev))),
+                meta,
+            )) => {
+                self.handle_scenario_event(Some(&r), &sc, ev, meta.at);
+            }
+            Ok((Cucumber::Finished, _)) => {
+                self.written = true;
+                let report = self.render().unwrap_or_else(|e| {
+                    panic!("Failed to render HTML report: {}", e)
+                });
+                self.output
+                    .write_all(report.as_bytes())
+                    .and_then(|()| self.output.flush())
+                    .unwrap_or_else(|e| {
+                        panic!("Failed to write HTML report: {}", e)
+                    });
+            }
+            Ok((
+                Cucumber::Started
+                | Cucumber::ParsingFinished { .. }
+                | Cucumber::GlobalHookFailed(..)
+                | Cucumber::Feature(
+                    _,
+                    Feature::Finished | Feature::Rule(_, Rule::Finished),
+                ),
+                _,
+            )) => {}
+        }
+    }
+}
+
+impl<W, Out> writer::Failure<W> for Html<Out>
+where
+    W: World + Debug,
+    Out: io::Write,
+{
+    fn failed_steps(&self) -> usize {
+        self.steps_with(Status::Failed)
+    }
+
+    fn parsing_errors(&self) -> usize {
+        self.parsing_errors.len()
+    }
+
+    fn hook_errors(&self) -> usize {
+        self.scenarios().map(|sc| sc.errors.len()).sum()
+    }
+
+    fn skipped_steps(&self) -> usize {
+        self.steps_with(Status::Skipped)
+    }
+
+    fn timed_out_scenarios(&self) -> usize {
+        self.scenarios()
+            .filter(|sc| {
+                matches!(sc.skipped, Some(event::SkipReason::RunTimeout))
+            })
+            .count()
+    }
+}
+
+impl<O: io::Write> writer::NonTransforming for Html<O> {}
+
+impl Html<fs::File> {
+    /// Creates a new [`Normalized`] [`Html`] [`Writer`] outputting the report
+    /// into a file at the given `path`, creating or truncating it.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be created.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    pub fn create<W: Debug + World>(
+        path: impl AsRef<Path>,
+    ) -> io::Result<writer::Normalize<W, Self>> {
+        fs::File::create(path).map(Self::new)
+    }
+}
+
+impl<Out: io::Write> Html<Out> {
+    /// Creates a new [`Normalized`] [`Html`] [`Writer`] outputting the report
+    /// into the given `output`.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    #[must_use]
+    pub fn new<W: Debug + World>(output: Out) -> writer::Normalize<W, Self> {
+        Self::raw(output).normalized()
+    }
+
+    /// Creates a new raw and non-[`Normalized`] [`Html`] [`Writer`] outputting
+    /// the report into the given `output`.
+    ///
+    /// Use it only if you know what you're doing. Otherwise, consider using
+    /// [`Html::new()`] which creates an already [`Normalized`] version of
+    /// [`Html`] [`Writer`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    #[must_use]
+    pub const fn raw(output: Out) -> Self {
+        Self {
+            output,
+            features: Vec::new(),
+            parsing_errors: Vec::new(),
+            started: None,
+            written: false,
+        }
+    }
+
+    /// Returns the [`Feature`] being currently executed.
+    ///
+    /// # Panics
+    ///
+    /// If no [`Feature`] has been started yet.
+    fn feature(&mut self) -> &mut Feature {
+        self.features
+            .last_mut()
+            .unwrap_or_else(|| panic!("No `Started` event for `Feature`"))
+    }
+
+    /// Returns an iterator over all the collected [`Scenario`]s.
+    fn scenarios(&self) -> impl Iterator<Item = &Scenario> {
+        self.features.iter().flat_map(|f| &f.items).flat_map(|i| {
+            let (rule, scenario) = match i {
+                Item::Rule(r) => (Some(&r.scenarios), None),
+                Item::Scenario(sc) => (None, Some(sc)),
+            };
+            rule.into_iter().flatten().chain(scenario)
+        })
+    }
+
+    /// Returns number of the collected [`Step`]s with the given [`Status`].
+    fn steps_with(&self, status: Status) -> usize {
+        self.scenarios()
+            .flat_map(|sc| &sc.steps)
+            .filter(|st| st.status == status)
+            .count()
+    }
+
+    /// Handles the given [`event::Scenario`].
+    fn handle_scenario_event<W>(
+        &mut self,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+        ev: event::Scenario<W>,
+        at: SystemTime,
+    ) {
+        use event::{Hook, Scenario};
+
+        if matches!(ev, Scenario::Started) {
+            let new = self::Scenario {
+                keyword: scenario.keyword.clone(),
+                name: scenario.name.clone(),
+                position: scenario.position,
+                tags: scenario.tags.clone(),
+                started: Some(at),
+                duration: None,
+                errors: Vec::new(),
+                skipped: None,
+                steps: Vec::new(),
+            };
+            let feature = self.feature();
+            if let Some(r) = rule {
+                let scenarios = feature
+                    .items
+                    .iter_mut()
+                    .rev()
+                    .find_map(|i| match i {
+                        Item::Rule(rl) if rl.position == r.position => {
+                            Some(&mut rl.scenarios)
+                        }
+                        Item::Rule(_) | Item::Scenario(_) => None,
+                    })
+                    .unwrap_or_else(|| {
+                        panic!("No `Started` event for `Rule` '{}'", r.name)
+                    });
+                // Only the last attempt of a retried `Scenario` is reported.
+                match scenarios.last_mut() {
+                    Some(last) if last.position == new.position => *last = new,
+                    Some(_) | None => scenarios.push(new),
+                }
+            } else {
+                match feature.items.last_mut() {
+                    Some(Item::Scenario(last))
+                        if last.position == new.position =>
+                    {
+                        *last = new;
+                    }
+                    Some(_) | None => feature.items.push(Item::Scenario(new)),
+                }
+            }
+            return;
+        }
+
+        let started = &mut self.started;
+        let sc = Self::scenario(&mut self.features, rule, scenario);
+        match ev {
+            Scenario::Hook(ty, Hook::Failed(_, info)) => {
+                sc.errors.push(format!(
+                    "{} hook failed: {}",
+                    ty,
+                    coerce_error(&info)
+                ));
+            }
+            Scenario::Background(st, ev) => {
+                Self::handle_step_event(started, sc, &st, true, ev, at);
+            }
+            Scenario::Step(st, ev) => {
+                Self::handle_step_event(started, sc, &st, false, ev, at);
+            }
+            Scenario::Skipped(reason) => sc.skipped = Some(reason),
+            Scenario::Finished => {
+                sc.duration = sc
+                    .started
+                    .and_then(|s| at.duration_since(s).ok())
+                    .or(Some(Duration::ZERO));
+            }
+            Scenario::Started
+            | Scenario::Hook(..)
+            | Scenario::Retried { .. }
+            | Scenario::StepHeartbeat(..)
+            | Scenario::ResourceUsage { .. } => {}
+        }
+    }
+
+    /// Returns the collected [`Scenario`] of the given [`gherkin::Scenario`].
+    ///
+    /// # Panics
+    ///
+    /// If the given [`gherkin::Scenario`] hasn't been started yet.
+    fn scenario<'f>(
+        features: &'f mut [Feature],
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+    ) -> &'f mut Scenario {
+        let mut items = features
+            .last_mut()
+            .map(|f| f.items.iter_mut().rev())
+            .into_iter()
+            .flatten();
+        let found = if let Some(r) = rule {
+            items
+                .find_map(|i| match i {
+                    Item::Rule(rl) if rl.position == r.position => Some(rl),
+                    Item::Rule(_) | Item::Scenario(_) => None,
+                })
+                .and_then(|rl| {
+                    rl.scenarios
+                        .iter_mut()
+                        .rev()
+                        .find(|sc| sc.position == scenario.position)
+                })
+        } else {
+            items.find_map(|i| match i {
+                Item::Scenario(sc) if sc.position == scenario.position => {
+                    Some(sc)
+                }
+                Item::Rule(_) | Item::Scenario(_) => None,
+            })
+        };
+        found.unwrap_or_else(|| {
+            panic!("No `Started` event for `Scenario` '{}'", scenario.name)
+        })
+    }
+
+    /// Handles the given [`event::Step`] of the given [`Scenario`].
+    fn handle_step_event<W>(
+        started: &mut Option<SystemTime>,
+        scenario: &mut Scenario,
+        step: &gherkin::Step,
+        background: bool,
+        ev: event::Step<W>,
+        at: SystemTime,
+    ) {
+        let (status, error) = match ev {
+            event::Step::Started => {
+                *started = Some(at);
+                return;
+            }
+            event::Step::Output(_) => return,
+            event::Step::Passed(_) => (Status::Passed, None),
+            event::Step::SoftFailed(_, err) => {
+                (Status::SoftFailed, Some(err.to_string()))
+            }
+            event::Step::Failed(_, _, err) => (
+                Status::Failed,
+                Some(match err {
+                    e @ (event::StepError::AmbiguousMatch(_)
+                    | event::StepError::TimedOut(_)) => e.to_string(),
+                    event::StepError::Panic(info) => {
+                        coerce_error(&info).into_owned()
+                    }
+                }),
+            ),
+            event::Step::Skipped | event::Step::Matched(_) => {
+                (Status::Skipped, None)
+            }
+        };
+
+        scenario.steps.push(Step {
+            keyword: step.keyword.clone(),
+            text: step.value.clone(),
+            background,
+            docstring: step.docstring.clone(),
+            table: step
+                .table
+                .as_ref()
+                .map(|t| t.rows.clone())
+                .unwrap_or_default(),
+            status,
+            duration: started
+                .take()
+                .and_then(|s| at.duration_since(s).ok())
+                .unwrap_or_default(),
+            error,
+        });
+    }
+
+    /// Renders the collected events into an HTML page.
+    fn render(&self) -> Result<String, fmt::Error> {
+        let scenarios = self.scenarios().collect::<Vec<_>>();
+        let failed = scenarios
+            .iter()
+            .filter(|sc| sc.status() == Status::Failed)
+            .count();
+        let skipped = scenarios
+            .iter()
+            .filter(|sc| sc.status() == Status::Skipped)
+            .count();
+
+        let mut out = String::new();
+        write!(
+            out,
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n\
+             <meta charset=\"utf-8\">\n<title>Cucumber report</title>\n\
+             <style>{}</style>\n</head>\n<body>\n\
+             <h1>Cucumber report</h1>\n\
+             <p class=\"summary\">{} features, {} scenarios \
+             ({} passed, {} failed, {} skipped), {} steps, \
+             {} parsing errors</p>\n\
+             <button onclick=\"toggleAll(true)\">Expand all</button>\n\
+             <button onclick=\"toggleAll(false)\">Collapse all</button>\n",
+            STYLE,
+            self.features.len(),
+            scenarios.len(),
+            scenarios.len() - failed - skipped,
+            failed,
+            skipped,
+            scenarios.iter().map(|sc| sc.steps.len()).sum::<usize>(),
+            self.parsing_errors.len(),
+        )?;
+        for err in &self.parsing_errors {
+            writeln!(out, "<pre class=\"error\">{}</pre>", escape(err))?;
+        }
+        for f in &self.features {
+            f.render(&mut out)?;
+        }
+        write!(out, "<script>{}</script>\n</body>\n</html>\n", SCRIPT)?;
+        Ok(out)
+    }
+}
+
+impl Feature {
+    /// Renders this [`Feature`] as a collapsible HTML block.
+    fn render(&self, out: &mut String) -> fmt::Result {
+        let statuses = self.items.iter().flat_map(|i| match i {
+            Item::Rule(r) => r.scenarios.iter().map(Scenario::status).collect(),
+            Item::Scenario(sc) => vec![sc.status()],
+        });
+        let duration = self
+            .items
+            .iter()
+            .flat_map(|i| match i {
+                Item::Rule(r) => r.scenarios.iter().collect(),
+                Item::Scenario(sc) => vec![sc],
+            })
+            .filter_map(|sc| sc.duration)
+            .sum();
+
+        write!(
+            out,
+            "<details class=\"feature {}\" open>\n<summary>{}\
+             <span class=\"keyword\">{}:</span> {} {}</summary>\n",
+            worst(statuses).class(),
+            tags(&self.tags),
+            escape(&self.keyword),
+            escape(&self.name),
+            format_duration(duration),
+        )?;
+        if let Some(path) = &self.path {
+            writeln!(out, "<p class=\"uri\">{}</p>", escape(path))?;
+        }
+        for item in &self.items {
+            match item {
+                Item::Rule(r) => r.render(out)?,
+                Item::Scenario(sc) => sc.render(out)?,
+            }
+        }
+        out.push_str("</details>\n");
+        Ok(())
+    }
+}
+
+impl Rule {
+    /// Renders this [`Rule`] as a collapsible HTML block.
+    fn render(&self, out: &mut String) -> fmt::Result {
+        write!(
+            out,
+            "<details class=\"rule {}\" open>\n<summary>{}\
+             <span class=\"keyword\">{}:</span> {}</summary>\n",
+            worst(self.scenarios.iter().map(Scenario::status)).class(),
+            tags(&self.tags),
+            escape(&self.keyword),
+            escape(&self.name),
+        )?;
+        for sc in &self.scenarios {
+            sc.render(out)?;
+        }
+        out.push_str("</details>\n");
+        Ok(())
+    }
+}
+
+impl Scenario {
+    /// Returns the overall [`Status`] of this [`Scenario`].
+    fn status(&self) -> Status {
+        if !self.errors.is_empty() {
+            Status::Failed
+        } else if self.skipped.is_some() {
+            Status::Skipped
+        } else {
+            worst(self.steps.iter().map(|st| st.status))
+        }
+    }
+
+    /// Renders this [`Scenario`] as a collapsible HTML block, expanded only if
+    /// it hasn't passed.
+    fn render(&self, out: &mut String) -> fmt::Result {
+        let status = self.status();
+        write!(
+            out,
+            "<details class=\"scenario {}\"{}>\n<summary>{}\
+             <span class=\"keyword\">{}:</span> {} {}</summary>\n\
+             <ul class=\"steps\">\n",
+            status.class(),
+            if status == Status::Passed {
+                ""
+            } else {
+                " open"
+            },
+            tags(&self.tags),
+            escape(&self.keyword),
+            escape(&self.name),
+            format_duration(self.duration.unwrap_or_default()),
+        )?;
+        for st in &self.steps {
+            st.render(out)?;
+        }
+        out.push_str("</ul>\n");
+        match &self.skipped {
+            Some(event::SkipReason::BackgroundFailed(st)) => writeln!(
+                out,
+                "<pre class=\"error\">Skipped: background step failed: \
+                 {} {}</pre>",
+                escape(&st.keyword),
+                escape(&st.value),
+            )?,
+            Some(event::SkipReason::RunTimeout) => out.push_str(
+                "<pre class=\"error\">Skipped: run timeout exceeded</pre>\n",
+            ),
+            Some(event::SkipReason::FailFast) => out.push_str(
+                "<pre class=\"error\">Skipped: run aborted on failure</pre>\n",
+            ),
+            Some(event::SkipReason::FilteredOut) => out
+                .push_str("<pre class=\"error\">Skipped: filtered out</pre>\n"),
+            None => {}
+        }
+        for err in &self.errors {
+            writeln!(out, "<pre class=\"error\">{}</pre>", escape(err))?;
+        }
+        out.push_str("</details>\n");
+        Ok(())
+    }
+}
+
+impl Step {
+    /// Renders this [`Step`] as an HTML list item.
+    fn render(&self, out: &mut String) -> fmt::Result {
+        write!(
+            out,
+            "<li class=\"step {}{}\"><span class=\"status\">{}</span> \
+             <span class=\"keyword\">{}</span> {} {}",
+            self.status.class(),
+            if self.background { " background" } else { "" },
+            self.status.symbol(),
+            escape(&self.keyword),
+            escape(&self.text),
+            format_duration(self.duration),
+        )?;
+        if let Some(doc) = &self.docstring {
+            write!(out, "<pre>{}</pre>", escape(doc.trim_matches('\n')))?;
+        }
+        if !self.table.is_empty() {
+            out.push_str("<table>");
+            for row in &self.table {
+                out.push_str("<tr>");
+                for cell in row {
+                    write!(out, "<td>{}</td>", escape(cell))?;
+                }
+                out.push_str("</tr>");
+            }
+            out.push_str("</table>");
+        }
+        if let Some(err) = &self.error {
+            write!(out, "<pre class=\"error\">{}</pre>", escape(err))?;
+        }
+        out.push_str("</li>\n");
+        Ok(())
+    }
+}
+
+/// Returns the worst of the given [`Status`]es, or [`Status::Passed`] if there
+/// are none.
+fn worst(statuses: impl IntoIterator<Item = Status>) -> Status {
+    statuses
+        .into_iter()
+        .fold(Status::Passed, |acc, s| match (acc, s) {
+            (Status::Failed, _) | (_, Status::Failed) => Status::Failed,
+            (Status::Skipped, _) | (_, Status::Skipped) => Status::Skipped,
+            (Status::SoftFailed, _) | (_, Status::SoftFailed) => {
+                Status::SoftFailed
+            }
+            (Status::Passed, Status::Passed) => Status::Passed,
+        })
+}
+
+/// Renders the given `tags` as an HTML `<span>`, if there are any.
+fn tags(tags: &[String]) -> String {
+    if tags.is_empty() {
+        return String::new();
+    }
+    let tags = tags
+        .iter()
+        .map(|t| format!("@{}", escape(t)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!("<span class=\"tags\">{}</span> ", tags)
+}
+
+/// Renders the given [`Duration`] as an HTML `<span>`.
+fn format_duration(duration: Duration) -> String {
+    format!(
+        "<span class=\"duration\">{}.{:03}s</span>",
+        duration.as_secs(),
+        duration.subsec_millis(),
+    )
+}
+
+/// Escapes the given `text` for using inside HTML.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
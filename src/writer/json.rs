@@ -12,7 +12,7 @@
 //!
 //! [1]: https://github.com/cucumber/cucumber-json-schema
 
-use std::{fmt::Debug, io, time::SystemTime};
+use std::{fmt::Debug, io, mem, time::SystemTime};
 
 use async_trait::async_trait;
 use inflector::Inflector as _;
@@ -54,6 +54,41 @@ pub struct Json<Out: io::Write> {
     /// [`Scenario`]: gherkin::Scenario
     /// [`Hook`]: event::Hook
     started: Option<SystemTime>,
+
+    /// Indicator whether each [`Feature`] should be written into the
+    /// [`Json::output`] as soon as it finishes, rather than all at once in the
+    /// end.
+    streaming: bool,
+
+    /// Number of [`Feature`]s written into the [`Json::output`] already, when
+    /// [`Json::streaming`].
+    streamed: usize,
+
+    /// Number of [`Failed`] [`Step`]s.
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`Step`]: gherkin::Step
+    failed_steps: usize,
+
+    /// Number of [`Skipped`] [`Step`]s.
+    ///
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`Step`]: gherkin::Step
+    skipped_steps: usize,
+
+    /// Number of parsing errors.
+    parsing_errors: usize,
+
+    /// Number of failed [`Scenario`] hooks.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    hook_errors: usize,
+
+    /// Number of [`Scenario`]s skipped due to the [`Cucumber::run_timeout()`].
+    ///
+    /// [`Cucumber::run_timeout()`]: crate::Cucumber::run_timeout()
+    /// [`Scenario`]: gherkin::Scenario
+    timed_out_scenarios: usize,
 }
 
 #[async_trait(?Send)]
@@ -69,10 +104,12 @@ impl<W: World + Debug, Out: io::Write> Writer<W> for Json<Out> {
 
         match event.map(event::Event::split) {
             Err(parser::Error::Parsing(e)) => {
+                self.parsing_errors += 1;
                 let feature = Feature::parsing_err(&e);
                 self.features.push(feature);
             }
             Err(parser::Error::ExampleExpansion(e)) => {
+                self.parsing_errors += 1;
                 let feature = Feature::example_expansion_err(&e);
                 self.features.push(feature);
             }
@@ -91,6 +128,21 @@ impl<W: World + Debug, Out: io::Write> Writer<W> for Json<Out> {
             )) => {
                 self.handle_scenario_event(&f, Some(&r), &sc, ev, meta);
             }
+            Ok((Cucumber::Feature(f, event::Feature::Finished), _))
+                if self.streaming =>
+            {
+                self.stream_finished(&f);
+            }
+            Ok((Cucumber::Finished, _)) if self.streaming => {
+                for f in mem::take(&mut self.features) {
+                    self.stream_feature(&f);
+                }
+                let framing = if self.streamed == 0 { "[]\n" } else { "]\n" };
+                self.output
+                    .write_all(framing.as_bytes())
+                    .and_then(|()| self.output.flush())
+                    .unwrap_or_else(|e| panic!("Failed to write JSON: {}", e));
+            }
             Ok((Cucumber::Finished, _)) => {
                 self.output
                     .write_all(
@@ -100,6 +152,8 @@ impl<W: World + Debug, Out: io::Write> Writer<W> for Json<Out> {
                             })
                             .as_bytes(),
                     )
+                    .and_then(|()| self.output.write_all(b"\n"))
+                    .and_then(|()| self.output.flush())
                     .unwrap_or_else(|e| panic!("Failed to write JSON: {}", e));
             }
             _ => {}
@@ -107,6 +161,32 @@ impl<W: World + Debug, Out: io::Write> Writer<W> for Json<Out> {
     }
 }
 
+impl<W, Out> writer::Failure<W> for Json<Out>
+where
+    W: World + Debug,
+    Out: io::Write,
+{
+    fn failed_steps(&self) -> usize {
+        self.failed_steps
+    }
+
+    fn parsing_errors(&self) -> usize {
+        self.parsing_errors
+    }
+
+    fn hook_errors(&self) -> usize {
+        self.hook_errors
+    }
+
+    fn skipped_steps(&self) -> usize {
+        self.skipped_steps
+    }
+
+    fn timed_out_scenarios(&self) -> usize {
+        self.timed_out_scenarios
+    }
+}
+
 impl<O: io::Write> writer::NonTransforming for Json<O> {}
 
 impl<Out: io::Write> Json<Out> {
@@ -120,6 +200,26 @@ impl<Out: io::Write> Json<Out> {
         Self::raw(output).normalized()
     }
 
+    /// Creates a new [`Normalized`] [`Json`] [`Writer`] outputting [JSON][1]
+    /// into the given `output` incrementally, writing each [`Feature`] as soon
+    /// as it finishes.
+    ///
+    /// This way a crash in the middle of a run preserves the already finished
+    /// [`Feature`]s in the `output`, while the final [JSON][1] is the same as
+    /// the one of [`Json::new()`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Normalized`]: writer::Normalized
+    /// [1]: https://github.com/cucumber/cucumber-json-schema
+    #[must_use]
+    pub fn streaming<W: Debug + World>(
+        output: Out,
+    ) -> writer::Normalize<W, Self> {
+        let mut json = Self::raw(output);
+        json.streaming = true;
+        json.normalized()
+    }
+
     /// Creates a new non-[`Normalized`] [`Json`] [`Writer`] outputting
     /// [JSON][1] into the given `output`, and suitable for feeding into
     /// [`tee()`].
@@ -151,9 +251,44 @@ impl<Out: io::Write> Json<Out> {
             output,
             features: Vec::new(),
             started: None,
+            streaming: false,
+            streamed: 0,
+            failed_steps: 0,
+            skipped_steps: 0,
+            parsing_errors: 0,
+            hook_errors: 0,
+            timed_out_scenarios: 0,
+        }
+    }
+
+    /// Writes the finished [`gherkin::Feature`] into the [`Json::output`],
+    /// along with all the [`Feature`]s preceding it.
+    fn stream_finished(&mut self, feature: &gherkin::Feature) {
+        if let Some(pos) = self.features.iter().position(|f| f == feature) {
+            let finished = self.features.drain(..=pos).collect::<Vec<_>>();
+            for f in &finished {
+                self.stream_feature(f);
+            }
         }
     }
 
+    /// Writes the given [`Feature`] into the [`Json::output`] as the next
+    /// element of a [JSON][1] array.
+    ///
+    /// [1]: https://github.com/cucumber/cucumber-json-schema
+    fn stream_feature(&mut self, feature: &Feature) {
+        let framing = if self.streamed == 0 { "[" } else { "," };
+        self.streamed += 1;
+
+        let json = serde_json::to_string(feature)
+            .unwrap_or_else(|e| panic!("Failed to serialize JSON: {}", e));
+        self.output
+            .write_all(framing.as_bytes())
+            .and_then(|()| self.output.write_all(json.as_bytes()))
+            .and_then(|()| self.output.flush())
+            .unwrap_or_else(|e| panic!("Failed to write JSON: {}", e));
+    }
+
     /// Handles the given [`event::Scenario`].
     fn handle_scenario_event<W>(
         &mut self,
@@ -185,7 +320,15 @@ impl<Out: io::Write> Json<Out> {
                     feature, rule, scenario, "scenario", &st, ev, meta,
                 );
             }
-            Scenario::Started | Scenario::Finished => {}
+            Scenario::Skipped(event::SkipReason::RunTimeout) => {
+                self.timed_out_scenarios += 1;
+            }
+            Scenario::Started
+            | Scenario::Finished
+            | Scenario::Retried { .. }
+            | Scenario::Skipped(_)
+            | Scenario::StepHeartbeat(..)
+            | Scenario::ResourceUsage { .. } => {}
         }
     }
 
@@ -228,13 +371,16 @@ impl<Out: io::Write> Json<Out> {
                     error_message: None,
                 },
             },
-            Hook::Failed(_, info) => HookResult {
-                result: RunResult {
-                    status: Status::Failed,
-                    duration: duration(),
-                    error_message: Some(coerce_error(&info).into_owned()),
-                },
-            },
+            Hook::Failed(_, info) => {
+                self.hook_errors += 1;
+                HookResult {
+                    result: RunResult {
+                        status: Status::Failed,
+                        duration: duration(),
+                        error_message: Some(coerce_error(&info).into_owned()),
+                    },
+                }
+            }
         };
 
         let el =
@@ -278,24 +424,46 @@ impl<Out: io::Write> Json<Out> {
                 let _ = self.mut_or_insert_element(feature, rule, scenario, ty);
                 return;
             }
+            event::Step::Output(_) => return,
             event::Step::Passed(..) => RunResult {
                 status: Status::Passed,
                 duration: duration(),
                 error_message: None,
             },
-            event::Step::Failed(_, _, err) => match err {
-                event::StepError::AmbiguousMatch(err) => RunResult {
-                    status: Status::Ambiguous,
-                    duration: duration(),
-                    error_message: Some(err.to_string()),
-                },
-                event::StepError::Panic(info) => RunResult {
-                    status: Status::Failed,
-                    duration: duration(),
-                    error_message: Some(coerce_error(&info).into_owned()),
-                },
+            event::Step::Failed(_, _, err) => {
+                self.failed_steps += 1;
+                match err {
+                    event::StepError::AmbiguousMatch(err) => RunResult {
+                        status: Status::Ambiguous,
+                        duration: duration(),
+                        error_message: Some(err.to_string()),
+                    },
+                    event::StepError::Panic(info) => RunResult {
+                        status: Status::Failed,
+                        duration: duration(),
+                        error_message: Some(coerce_error(&info).into_owned()),
+                    },
+                    event::StepError::TimedOut(_) => RunResult {
+                        status: Status::Failed,
+                        duration: duration(),
+                        error_message: Some(err.to_string()),
+                    },
+                }
+            }
+            event::Step::SoftFailed(_, err) => RunResult {
+                status: Status::Passed,
+                duration: duration(),
+                error_message: Some(err.to_string()),
             },
-            event::Step::Skipped => RunResult {
+            event::Step::Skipped => {
+                self.skipped_steps += 1;
+                RunResult {
+                    status: Status::Skipped,
+                    duration: duration(),
+                    error_message: None,
+                }
+            }
+            event::Step::Matched(_) => RunResult {
                 status: Status::Skipped,
                 duration: duration(),
                 error_message: None,
@@ -308,6 +476,18 @@ impl<Out: io::Write> Json<Out> {
             line: step.position.line,
             name: step.value.clone(),
             hidden: false,
+            doc_string: step.docstring.as_ref().map(|value| DocString {
+                value: value.clone(),
+                line: step.position.line + 1,
+            }),
+            rows: step
+                .table
+                .iter()
+                .flat_map(|t| &t.rows)
+                .map(|cells| Row {
+                    cells: cells.clone(),
+                })
+                .collect(),
             result,
         });
     }
@@ -375,7 +555,8 @@ pub enum Status {
     /// [`event::Step::Passed`].
     Passed,
 
-    /// [`event::Step::Failed`] with an [`event::StepError::Panic`].
+    /// [`event::Step::Failed`] with an [`event::StepError::Panic`] or
+    /// [`event::StepError::TimedOut`].
     Failed,
 
     /// [`event::Step::Skipped`].
@@ -431,10 +612,38 @@ pub struct Step {
     #[serde(skip_serializing_if = "std::ops::Not::not")]
     pub hidden: bool,
 
+    /// [`DocString`] argument of this [`Step`], if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doc_string: Option<DocString>,
+
+    /// [`Row`]s of the data table argument of this [`Step`], if any.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rows: Vec<Row>,
+
     /// [`RunResult`] of this [`Step`].
     pub result: RunResult,
 }
 
+/// [`Serialize`]able [`gherkin::Step::docstring`].
+#[derive(Clone, Debug, Serialize)]
+pub struct DocString {
+    /// Content of this [`DocString`].
+    pub value: String,
+
+    /// Line number of this [`DocString`] in a `.feature` file.
+    ///
+    /// As [`gherkin`] parser omits this info, it's assumed to start right
+    /// after its [`gherkin::Step`].
+    pub line: usize,
+}
+
+/// [`Serialize`]able row of a [`gherkin::Step::table`].
+#[derive(Clone, Debug, Serialize)]
+pub struct Row {
+    /// Cells of this [`Row`].
+    pub cells: Vec<String>,
+}
+
 /// [`Serialize`]able result of running a [`Before`] or [`After`] hook.
 ///
 /// [`Before`]: event::HookType::Before
@@ -493,6 +702,13 @@ pub struct Element {
     /// [`gherkin::Scenario::tags`].
     pub tags: Vec<Tag>,
 
+    /// [`Example`] this [`gherkin::Scenario`] was expanded from, in case it
+    /// comes from a [Scenario Outline][1].
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference/#scenario-outline
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub example: Option<Example>,
+
     /// [`gherkin::Scenario`]'s [`Step`]s.
     pub steps: Vec<Step>,
 }
@@ -535,11 +751,42 @@ impl Element {
                     line: scenario.position.line,
                 })
                 .collect(),
+            example: (ty == "scenario")
+                .then(|| scenario.examples.first().and_then(Example::new))
+                .flatten(),
             steps: Vec::new(),
         }
     }
 }
 
+/// [`Serialize`]able row of [`gherkin::Examples`] a [`gherkin::Scenario`] was
+/// expanded from.
+#[derive(Clone, Debug, Serialize)]
+pub struct Example {
+    /// Header of the [`gherkin::Examples`] table.
+    pub header: Vec<String>,
+
+    /// Row of the [`gherkin::Examples`] table.
+    pub row: Vec<String>,
+}
+
+impl Example {
+    /// Creates a new [`Example`] out of the given [`gherkin::Examples`], if
+    /// they consist of a header and a single row only, as [expanded] ones
+    /// do.
+    ///
+    /// [expanded]: crate::feature::Ext::expand_examples()
+    fn new(examples: &gherkin::Examples) -> Option<Self> {
+        match examples.table.rows.as_slice() {
+            [header, row] => Some(Self {
+                header: header.clone(),
+                row: row.clone(),
+            }),
+            _ => None,
+        }
+    }
+}
+
 /// [`Serialize`]able [`gherkin::Feature`].
 #[derive(Clone, Debug, Serialize)]
 pub struct Feature {
@@ -608,11 +855,14 @@ impl Feature {
                 line: 0,
                 name: String::new(),
                 tags: Vec::new(),
+                example: None,
                 steps: vec![Step {
                     keyword: String::new(),
                     line: err.pos.line,
                     name: "scenario".into(),
                     hidden: false,
+                    doc_string: None,
+                    rows: Vec::new(),
                     result: RunResult {
                         status: Status::Failed,
                         duration: 0,
@@ -649,11 +899,14 @@ impl Feature {
                 line: 0,
                 name: String::new(),
                 tags: Vec::new(),
+                example: None,
                 steps: vec![Step {
                     keyword: String::new(),
                     line: 0,
                     name: "scenario".into(),
                     hidden: false,
+                    doc_string: None,
+                    rows: Vec::new(),
                     result: RunResult {
                         status: Status::Failed,
                         duration: 0,
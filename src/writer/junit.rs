@@ -12,7 +12,7 @@
 //!
 //! [1]: https://llg.cubic.org/docs/junit
 
-use std::{fmt::Debug, io, mem, path::Path, time::SystemTime};
+use std::{borrow::Cow, fmt::Debug, io, mem, path::Path, time::SystemTime};
 
 use async_trait::async_trait;
 use junit_report::{
@@ -39,13 +39,30 @@ const WRAP_ADVICE: &str = "Consider wrapping `Writer` into `writer::Normalize`";
 /// [JUnit XML report][1] [`Writer`] implementation outputting XML to an
 /// [`io::Write`] implementor.
 ///
+/// Every [`Feature`] is output as a `<testsuite>` and every [`Scenario`] as a
+/// `<testcase>` inside it, with the [`Scenario`]'s wall-clock duration in its
+/// `time` attribute. A [`Failed`] [`Step`] or hook produces a `<failure>`
+/// element with the panic message in its `message` attribute and the whole
+/// [`Scenario`]'s output as its content, while a [`Skipped`] [`Step`] produces
+/// a `<skipped>` element.
+///
+/// # Limitations
+///
+/// Panics are caught without capturing their backtraces, so no backtrace is
+/// included into `<failure>` elements.
+///
 /// # Ordering
 ///
 /// This [`Writer`] isn't [`Normalized`] by itself, so should be wrapped into
 /// a [`writer::Normalize`], otherwise will panic in runtime as won't be able to
 /// form correct [JUnit `testsuite`s][1].
 ///
+/// [`Failed`]: event::Step::Failed
+/// [`Feature`]: gherkin::Feature
 /// [`Normalized`]: writer::Normalized
+/// [`Scenario`]: gherkin::Scenario
+/// [`Skipped`]: event::Step::Skipped
+/// [`Step`]: gherkin::Step
 /// [1]: https://llg.cubic.org/docs/junit
 #[derive(Debug)]
 pub struct JUnit<W, Out: io::Write> {
@@ -92,11 +109,23 @@ where
 
         match ev.map(Event::split) {
             Err(err) => self.handle_error(&err),
-            Ok((Cucumber::Started, _)) => {}
+            Ok((Cucumber::GlobalHookFailed(which, info), _)) => {
+                self.report.add_testsuite(
+                    TestSuiteBuilder::new("Errors")
+                        .add_testcase(TestCase::failure(
+                            &format!("{} hook", which),
+                            Duration::ZERO,
+                            "Hook Error",
+                            &xml_text(&coerce_error(&info)),
+                        ))
+                        .build(),
+                );
+            }
+            Ok((Cucumber::Started | Cucumber::ParsingFinished { .. }, _)) => {}
             Ok((Cucumber::Feature(feat, ev), meta)) => match ev {
                 Feature::Started => {
                     self.suit = Some(
-                        TestSuiteBuilder::new(&format!(
+                        TestSuiteBuilder::new(&xml_text(&format!(
                             "Feature: {}{}",
                             &feat.name,
                             feat.path
@@ -104,7 +133,7 @@ where
                                 .and_then(Path::to_str)
                                 .map(|path| format!(": {}", path))
                                 .unwrap_or_default(),
-                        ))
+                        )))
                         .set_timestamp(meta.at.into())
                         .build(),
                     );
@@ -130,6 +159,10 @@ where
                 self.report
                     .write_xml(&mut self.output)
                     .unwrap_or_else(|e| panic!("Failed to write XML: {}", e));
+                self.output
+                    .write_all(b"\n")
+                    .and_then(|()| self.output.flush())
+                    .unwrap_or_else(|e| panic!("Failed to write XML: {}", e));
             }
         }
     }
@@ -218,10 +251,10 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
         self.report.add_testsuite(
             TestSuiteBuilder::new("Errors")
                 .add_testcase(TestCase::failure(
-                    &name,
+                    &xml_text(&name),
                     Duration::ZERO,
                     ty,
-                    &format!("{}", err),
+                    &xml_text(&err.to_string()),
                 ))
                 .build(),
         );
@@ -245,9 +278,14 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
             }
             Scenario::Hook(..)
             | Scenario::Background(..)
-            | Scenario::Step(..) => {
+            | Scenario::Step(..)
+            | Scenario::Skipped(_) => {
                 self.events.push(ev);
             }
+            // Only the last attempt of a retried `Scenario` is reported.
+            Scenario::Retried { .. }
+            | Scenario::StepHeartbeat(..)
+            | Scenario::ResourceUsage { .. } => {}
             Scenario::Finished => {
                 let dur = self.scenario_duration(meta.at, sc);
                 let events = mem::take(&mut self.events);
@@ -295,7 +333,7 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
                 )
             });
 
-        let case_name = format!(
+        let case_name = xml_text(&format!(
             "{}Scenario: {}: {}{}:{}",
             rule.map(|r| format!("Rule: {}: ", r.name))
                 .unwrap_or_default(),
@@ -307,33 +345,55 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
                 .unwrap_or_default(),
             sc.position.line,
             sc.position.col,
-        );
+        ))
+        .into_owned();
 
         let mut case = match last_event {
             Scenario::Started
+            | Scenario::Retried { .. }
+            | Scenario::StepHeartbeat(..)
             | Scenario::Hook(_, Hook::Started | Hook::Passed)
-            | Scenario::Background(_, Step::Started | Step::Passed(_))
-            | Scenario::Step(_, Step::Started | Step::Passed(_)) => {
+            | Scenario::Background(
+                _,
+                Step::Started
+                | Step::Passed(_)
+                | Step::SoftFailed(..)
+                | Step::Output(_),
+            )
+            | Scenario::Step(
+                _,
+                Step::Started
+                | Step::Passed(_)
+                | Step::SoftFailed(..)
+                | Step::Output(_),
+            )
+            | Scenario::ResourceUsage { .. } => {
                 TestCaseBuilder::success(&case_name, duration).build()
             }
-            Scenario::Background(_, Step::Skipped)
-            | Scenario::Step(_, Step::Skipped) => {
+            Scenario::Background(_, Step::Skipped | Step::Matched(_))
+            | Scenario::Step(_, Step::Skipped | Step::Matched(_))
+            | Scenario::Skipped(_) => {
                 TestCaseBuilder::skipped(&case_name).build()
             }
             Scenario::Hook(_, Hook::Failed(_, e)) => TestCaseBuilder::failure(
                 &case_name,
                 duration,
                 "Hook Panicked",
-                coerce_error(e).as_ref(),
+                &xml_text(&coerce_error(e)),
             )
             .build(),
             Scenario::Background(_, Step::Failed(_, _, e))
             | Scenario::Step(_, Step::Failed(_, _, e)) => {
+                let ty = if let event::StepError::TimedOut(_) = e {
+                    "Step Timed Out"
+                } else {
+                    "Step Panicked"
+                };
                 TestCaseBuilder::failure(
                     &case_name,
                     duration,
-                    "Step Panicked",
-                    &e.to_string(),
+                    ty,
+                    &xml_text(&e.to_string()),
                 )
                 .build()
             }
@@ -363,7 +423,7 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
                 panic!("Failed to write with `writer::Basic`: {}", e)
             });
 
-        case.set_system_out(&output);
+        case.set_system_out(&xml_cdata(&output));
 
         case
     }
@@ -398,3 +458,42 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
         })
     }
 }
+
+/// Strips ANSI escape codes and characters not allowed in [XML 1.0][1] (like
+/// control ones) from the given `text`, so it may be safely used either in
+/// an attribute or in a text node, which are escaped by the [`Report`] itself.
+///
+/// [1]: https://www.w3.org/TR/xml/#charsets
+fn xml_text(text: &str) -> Cow<'_, str> {
+    let is_allowed = |c: char| {
+        matches!(c, '\t' | '\n' | '\r')
+            || !(c.is_control() || matches!(c, '\u{FFFE}' | '\u{FFFF}'))
+    };
+
+    let text = console::strip_ansi_codes(text);
+    if text.chars().all(is_allowed) {
+        text
+    } else {
+        text.chars()
+            .filter(|c| is_allowed(*c))
+            .collect::<String>()
+            .into()
+    }
+}
+
+/// Prepares the given `text` for being put into a [CDATA section][1] by the
+/// [`Report`], which doesn't escape it at all.
+///
+/// In addition to [`xml_text()`], splits every `]]>` sequence between two
+/// adjacent [CDATA sections][1], as otherwise it would end the section
+/// prematurely.
+///
+/// [1]: https://www.w3.org/TR/xml/#sec-cdata-sect
+fn xml_cdata(text: &str) -> Cow<'_, str> {
+    let text = xml_text(text);
+    if text.contains("]]>") {
+        text.replace("]]>", "]]]]><![CDATA[>").into()
+    } else {
+        text
+    }
+}
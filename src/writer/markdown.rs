@@ -0,0 +1,502 @@
+// Copyright (c) 2018-2021  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [GitHub Flavored Markdown][1] summary [`Writer`] implementation.
+//!
+//! [1]: https://github.github.com/gfm
+
+use std::{
+    fmt::{self, Debug, Write as _},
+    io, mem,
+};
+
+use async_trait::async_trait;
+
+use crate::{
+    cli, event, parser,
+    writer::{self, basic::coerce_error, Ext as _},
+    Event, World, Writer,
+};
+
+/// [GitHub Flavored Markdown][1] summary [`Writer`] implementation outputting
+/// a report suitable for posting as a pull request comment to an
+/// [`io::Write`] implementor.
+///
+/// The report consists of a table with numbers of passed, failed and skipped
+/// [`Scenario`]s per [`Feature`], an overall pass rate line, and a collapsible
+/// `<details>` block for each failed [`Scenario`] with its failed [`Step`] (or
+/// hook) and its panic message. Parsing errors and failed global hooks are
+/// reported as failures too.
+///
+/// The report is buffered and written at once on [`Cucumber::Finished`] event,
+/// while arbitrary output (like a summary) is written after it as a fenced
+/// code block, so this [`Writer`] may be [`Summarize`]d without breaking the
+/// report.
+///
+/// # Ordering
+///
+/// This [`Writer`] isn't [`Normalized`] by itself, so should be wrapped into
+/// a [`writer::Normalize`], otherwise will attribute outcomes to wrong
+/// [`Scenario`]s.
+///
+/// [`Cucumber::Finished`]: event::Cucumber::Finished
+/// [`Feature`]: gherkin::Feature
+/// [`Normalized`]: writer::Normalized
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+/// [`Summarize`]: writer::Summarize
+/// [1]: https://github.github.com/gfm
+#[derive(Clone, Debug)]
+pub struct Markdown<Out: io::Write> {
+    /// [`io::Write`] implementor to output the report into.
+    output: Out,
+
+    /// Collected [`Feature`]s to render the results table of.
+    features: Vec<Feature>,
+
+    /// Collected [`Failure`]s to render, in their occurrence order.
+    failures: Vec<Failure>,
+
+    /// Number of parsing errors.
+    parsing_errors: usize,
+
+    /// Number of failed global hooks.
+    failed_global_hooks: usize,
+
+    /// [`Attempt`] of the currently executed [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    attempt: Attempt,
+
+    /// Indicator whether the report has been written already, so any further
+    /// events (like re-outputted by a [`writer::Repeat`]) should be ignored.
+    written: bool,
+}
+
+/// Results of a single [`gherkin::Feature`].
+#[derive(Clone, Debug)]
+struct Feature {
+    /// [`gherkin::Feature::name`].
+    name: String,
+
+    /// Number of passed [`gherkin::Scenario`]s.
+    passed: usize,
+
+    /// Number of failed [`gherkin::Scenario`]s.
+    failed: usize,
+
+    /// Number of skipped [`gherkin::Scenario`]s.
+    skipped: usize,
+
+    /// Number of failed [`gherkin::Step`]s.
+    failed_steps: usize,
+
+    /// Number of skipped [`gherkin::Step`]s.
+    skipped_steps: usize,
+
+    /// Number of failed [`gherkin::Scenario`] hooks.
+    failed_hooks: usize,
+
+    /// Number of [`gherkin::Scenario`]s skipped due to the run timeout.
+    timed_out: usize,
+}
+
+/// Failure to be rendered as a `<details>` block.
+#[derive(Clone, Debug)]
+struct Failure {
+    /// Title of the failed item.
+    title: String,
+
+    /// Failed [`gherkin::Step`] (or hook).
+    step: String,
+
+    /// Location of the failure, if any.
+    at: Option<String>,
+
+    /// Panic message of the failure.
+    message: String,
+}
+
+/// Outcome of the current attempt of executing a [`gherkin::Scenario`].
+///
+/// Only the last attempt of a retried [`gherkin::Scenario`] is reported.
+#[derive(Clone, Debug, Default)]
+struct Attempt {
+    /// First [`Failure`] of the [`gherkin::Scenario`], if any.
+    failure: Option<Failure>,
+
+    /// Indicator whether the [`gherkin::Scenario`] has been skipped.
+    skipped: bool,
+
+    /// Number of failed [`gherkin::Step`]s.
+    failed_steps: usize,
+
+    /// Number of skipped [`gherkin::Step`]s.
+    skipped_steps: usize,
+
+    /// Number of failed [`gherkin::Scenario`] hooks.
+    failed_hooks: usize,
+
+    /// Indicator whether the [`gherkin::Scenario`] has been skipped due to
+    /// the run timeout.
+    timed_out: bool,
+}
+
+#[async_trait(?Send)]
+impl<W: World + Debug, Out: io::Write> Writer<W> for Markdown<Out> {
+    type Cli = cli::Empty;
+
+    #[allow(clippy::unused_async)] // false positive: #[async_trait]
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule};
+
+        if self.written {
+            return;
+        }
+
+        match ev.map(Event::split) {
+            Err(err) => {
+                self.parsing_errors += 1;
+                self.failures.push(Failure {
+                    title: "Parsing error".to_owned(),
+                    step: "Parsing error".to_owned(),
+                    at: None,
+                    message: err.to_string(),
+                });
+            }
+            Ok((Cucumber::Feature(f, ev), _)) => match ev {
+                Feature::Started => self.features.push(self::Feature {
+                    name: f.name.clone(),
+                    passed: 0,
+                    failed: 0,
+                    skipped: 0,
+                    failed_steps: 0,
+                    skipped_steps: 0,
+                    failed_hooks: 0,
+                    timed_out: 0,
+                }),
+                Feature::Scenario(sc, ev)
+                | Feature::Rule(_, Rule::Scenario(sc, ev)) => {
+                    self.scenario(&f, &sc, ev);
+                }
+                Feature::Finished
+                | Feature::Rule(_, Rule::Started | Rule::Finished) => {}
+            },
+            Ok((Cucumber::GlobalHookFailed(which, info), _)) => {
+                self.failed_global_hooks += 1;
+                self.failures.push(Failure {
+                    title: format!("{} hook", which),
+                    step: format!("{} hook", which),
+                    at: None,
+                    message: coerce_error(&info).into_owned(),
+                });
+            }
+            Ok((Cucumber::Finished, _)) => {
+                self.written = true;
+                let report = self.render().unwrap_or_else(|e| {
+                    panic!("Failed to render Markdown report: {}", e)
+                });
+                self.emit(&report);
+            }
+            Ok((Cucumber::Started | Cucumber::ParsingFinished { .. }, _)) => {}
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'val, W, Val, Out> writer::Arbitrary<'val, W, Val> for Markdown<Out>
+where
+    W: World + Debug,
+    Val: AsRef<str> + 'val,
+    Out: io::Write,
+{
+    #[allow(clippy::unused_async)] // false positive: #[async_trait]
+    async fn write(&mut self, val: Val)
+    where
+        'val: 'async_trait,
+    {
+        // Arbitrary output is usually written after the `Cucumber::Finished`
+        // event, so should be flushed on its own.
+        let block = fenced(&console::strip_ansi_codes(val.as_ref()), "");
+        self.emit(&format!("\n{}", block));
+    }
+}
+
+impl<W, Out> writer::Failure<W> for Markdown<Out>
+where
+    W: World + Debug,
+    Out: io::Write,
+{
+    fn failed_steps(&self) -> usize {
+        self.features.iter().map(|f| f.failed_steps).sum()
+    }
+
+    fn parsing_errors(&self) -> usize {
+        self.parsing_errors
+    }
+
+    fn hook_errors(&self) -> usize {
+        self.failed_global_hooks
+            + self.features.iter().map(|f| f.failed_hooks).sum::<usize>()
+    }
+
+    fn skipped_steps(&self) -> usize {
+        self.features.iter().map(|f| f.skipped_steps).sum()
+    }
+
+    fn timed_out_scenarios(&self) -> usize {
+        self.features.iter().map(|f| f.timed_out).sum()
+    }
+}
+
+impl<O: io::Write> writer::NonTransforming for Markdown<O> {}
+
+impl<Out: io::Write> Markdown<Out> {
+    /// Creates a new [`Normalized`] [`Markdown`] [`Writer`] outputting the
+    /// report into the given `output`.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    #[must_use]
+    pub fn new<W: Debug + World>(output: Out) -> writer::Normalize<W, Self> {
+        Self::raw(output).normalized()
+    }
+
+    /// Creates a new raw and non-[`Normalized`] [`Markdown`] [`Writer`]
+    /// outputting the report into the given `output`.
+    ///
+    /// Use it only if you know what you're doing. Otherwise, consider using
+    /// [`Markdown::new()`] which creates an already [`Normalized`] version of
+    /// [`Markdown`] [`Writer`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    #[must_use]
+    pub fn raw(output: Out) -> Self {
+        Self {
+            output,
+            features: Vec::new(),
+            failures: Vec::new(),
+            parsing_errors: 0,
+            failed_global_hooks: 0,
+            attempt: Attempt::default(),
+            written: false,
+        }
+    }
+
+    /// Handles the given [`event::Scenario`], counting its outcome once the
+    /// [`Scenario`] is finished.
+    ///
+    /// # Panics
+    ///
+    /// If no [`Feature`] has been started yet.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    fn scenario<W>(
+        &mut self,
+        feat: &gherkin::Feature,
+        sc: &gherkin::Scenario,
+        ev: event::Scenario<W>,
+    ) {
+        use event::{Hook, Scenario, SkipReason, Step};
+
+        match ev {
+            Scenario::Started => self.attempt = Attempt::default(),
+            Scenario::Finished => {
+                let attempt = mem::take(&mut self.attempt);
+                let feature = self.features.last_mut().unwrap_or_else(|| {
+                    panic!("No `Started` event for `Feature`")
+                });
+                feature.failed_steps += attempt.failed_steps;
+                feature.skipped_steps += attempt.skipped_steps;
+                feature.failed_hooks += attempt.failed_hooks;
+                feature.timed_out += usize::from(attempt.timed_out);
+                if let Some(mut failure) = attempt.failure {
+                    feature.failed += 1;
+                    failure.title =
+                        format!("{} \u{203a} {}", feat.name, sc.name);
+                    self.failures.push(failure);
+                } else if attempt.skipped {
+                    feature.skipped += 1;
+                } else {
+                    feature.passed += 1;
+                }
+            }
+            Scenario::Step(st, Step::Failed(_, _, e))
+            | Scenario::Background(st, Step::Failed(_, _, e)) => {
+                self.attempt.failed_steps += 1;
+                self.attempt.fail(Failure {
+                    title: String::new(),
+                    step: format!("{} {}", st.keyword, st.value),
+                    at: feat.path.as_ref().map(|p| {
+                        format!(
+                            "{}:{}:{}",
+                            p.display(),
+                            st.position.line,
+                            st.position.col,
+                        )
+                    }),
+                    message: e.to_string(),
+                });
+            }
+            Scenario::Hook(which, Hook::Failed(_, info)) => {
+                self.attempt.failed_hooks += 1;
+                self.attempt.fail(Failure {
+                    title: String::new(),
+                    step: format!("{} hook", which),
+                    at: None,
+                    message: coerce_error(&info).into_owned(),
+                });
+            }
+            Scenario::Step(_, Step::Skipped)
+            | Scenario::Background(_, Step::Skipped) => {
+                self.attempt.skipped_steps += 1;
+                self.attempt.skipped = true;
+            }
+            Scenario::Skipped(reason) => {
+                self.attempt.skipped = true;
+                self.attempt.timed_out =
+                    matches!(reason, SkipReason::RunTimeout);
+            }
+            Scenario::Step(..)
+            | Scenario::Background(..)
+            | Scenario::Hook(..)
+            | Scenario::Retried { .. }
+            | Scenario::StepHeartbeat(..)
+            | Scenario::ResourceUsage { .. } => {}
+        }
+    }
+
+    /// Renders the whole report.
+    fn render(&self) -> Result<String, fmt::Error> {
+        let mut out = String::new();
+
+        writeln!(out, "## Cucumber results\n")?;
+        writeln!(out, "| Feature | Passed | Failed | Skipped |")?;
+        writeln!(out, "| :--- | ---: | ---: | ---: |")?;
+        for f in &self.features {
+            writeln!(
+                out,
+                "| {} | {} | {} | {} |",
+                table_cell(&f.name),
+                f.passed,
+                f.failed,
+                f.skipped,
+            )?;
+        }
+
+        let passed = self.features.iter().map(|f| f.passed).sum::<usize>();
+        let total = self
+            .features
+            .iter()
+            .map(|f| f.passed + f.failed + f.skipped)
+            .sum::<usize>()
+            + self.parsing_errors;
+        writeln!(out)?;
+        // Computed in basis points to avoid floating point rounding.
+        if let Some(rate) = (passed * 10_000).checked_div(total) {
+            writeln!(
+                out,
+                "**Pass rate:** {}.{:02}% ({} of {} scenarios passed)",
+                rate / 100,
+                rate % 100,
+                passed,
+                total,
+            )?;
+        } else {
+            writeln!(out, "**Pass rate:** no scenarios were run")?;
+        }
+
+        if !self.failures.is_empty() {
+            writeln!(out, "\n### Failures")?;
+        }
+        for f in &self.failures {
+            writeln!(out, "\n<details>")?;
+            writeln!(out, "<summary>{}</summary>\n", html_text(&f.title))?;
+            write!(out, "**Failed:** `{}`", code_span(&f.step))?;
+            if let Some(at) = &f.at {
+                write!(out, " at `{}`", code_span(at))?;
+            }
+            writeln!(out, "\n")?;
+            write!(
+                out,
+                "{}",
+                fenced(&console::strip_ansi_codes(&f.message), "text"),
+            )?;
+            writeln!(out, "\n</details>")?;
+        }
+
+        Ok(out)
+    }
+
+    /// Writes the given `text` into the [`Markdown::output`] and flushes it.
+    fn emit(&mut self, text: &str) {
+        self.output
+            .write_all(text.as_bytes())
+            .and_then(|()| self.output.flush())
+            .unwrap_or_else(|e| {
+                panic!("Failed to write Markdown report: {}", e)
+            });
+    }
+}
+
+impl Attempt {
+    /// Records the given [`Failure`], unless there is one already, as the
+    /// first failure is the most relevant one.
+    fn fail(&mut self, failure: Failure) {
+        if self.failure.is_none() {
+            self.failure = Some(failure);
+        }
+    }
+}
+
+/// Escapes the given `s` to be placed into a [GFM table][1] cell.
+///
+/// [1]: https://github.github.com/gfm/#tables-extension-
+fn table_cell(s: &str) -> String {
+    html_text(s).replace('|', "\\|").replace('\n', " ")
+}
+
+/// Escapes the given `s` to be placed as text into an HTML element.
+fn html_text(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Makes the given `s` suitable for a single-line [code span][1].
+///
+/// [1]: https://github.github.com/gfm/#code-spans
+fn code_span(s: &str) -> String {
+    s.replace('`', "'").replace('\n', " ")
+}
+
+/// Renders the given `s` as a [fenced code block][1] with the given `info`
+/// string, using a fence longer than any backtick run inside `s`.
+///
+/// [1]: https://github.github.com/gfm/#fenced-code-blocks
+fn fenced(s: &str, info: &str) -> String {
+    let longest = s
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+    let fence = "`".repeat(longest.max(2) + 1);
+    format!(
+        "{}{}\n{}\n{}\n",
+        fence,
+        info,
+        s.trim_end_matches('\n'),
+        fence,
+    )
+}
@@ -0,0 +1,899 @@
+// Copyright (c) 2018-2021  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [Cucumber Messages][1] [`Writer`] implementation, outputting them as
+//! [NDJSON][2].
+//!
+//! [1]: https://github.com/cucumber/messages
+//! [2]: http://ndjson.org
+
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Debug,
+    io,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+use crate::{
+    cli, event,
+    writer::{self, basic::coerce_error, Ext as _},
+    Event, World, Writer,
+};
+
+/// Version of the [Cucumber Messages][1] protocol being output.
+///
+/// [1]: https://github.com/cucumber/messages
+const PROTOCOL_VERSION: &str = "17.1.1";
+
+/// Key of a [`gherkin::Scenario`] being unique across a run: URI of its
+/// [`gherkin::Feature`] along with its position in there.
+type ScenarioKey = (String, gherkin::LineCol);
+
+/// [Cucumber Messages][1] [`Writer`] implementation outputting each message as
+/// a separate line of [JSON][2] (so called [NDJSON][3]) to an [`io::Write`]
+/// implementor.
+///
+/// Every [`Feature`] is output as a `gherkinDocument` message followed by a
+/// `pickle` message for each of its [`Scenario`]s, once the [`Feature`] is
+/// started. Then each [`Scenario`] is reported with `testCase`,
+/// `testCaseStarted`, `testStepStarted`, `testStepFinished` and
+/// `testCaseFinished` messages. Identifiers are allocated sequentially and
+/// reused for all the messages referring to the same node.
+///
+/// # Limitations
+///
+/// - [`gherkin`] parser omits comments, original language and tag positions,
+///   so `comments` are always empty, `language` is always `en`, and tags are
+///   located at their owner.
+/// - [Scenario Outline][4]s are already expanded, so each example is reported
+///   as a separate `scenario` node.
+/// - Hooks are not reported as test steps, so their failures are reflected
+///   only in the `success` of the `testRunFinished` message.
+///
+/// # Ordering
+///
+/// This [`Writer`] isn't [`Normalized`] by itself, so should be wrapped into
+/// a [`writer::Normalize`], otherwise will panic in runtime as won't be able to
+/// match steps with their [`Scenario`]s.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Normalized`]: writer::Normalized
+/// [`Scenario`]: gherkin::Scenario
+/// [1]: https://github.com/cucumber/messages
+/// [2]: https://www.json.org
+/// [3]: http://ndjson.org
+/// [4]: https://cucumber.io/docs/gherkin/reference/#scenario-outline
+#[derive(Debug)]
+pub struct NdJson<Out: io::Write> {
+    /// [`io::Write`] implementor to output messages into.
+    output: Out,
+
+    /// Next identifier to be allocated.
+    next_id: usize,
+
+    /// `pickle` messages already output, by their [`gherkin::Scenario`]s.
+    pickles: HashMap<ScenarioKey, Pickle>,
+
+    /// Number of [`Failed`] [`Step`]s.
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`Step`]: gherkin::Step
+    failed_steps: usize,
+
+    /// Number of [`Skipped`] [`Step`]s.
+    ///
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`Step`]: gherkin::Step
+    skipped_steps: usize,
+
+    /// Number of parsing errors.
+    parsing_errors: usize,
+
+    /// Number of failed [`Scenario`] hooks.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    hook_errors: usize,
+
+    /// Number of [`Scenario`]s skipped due to the [`Cucumber::run_timeout()`].
+    ///
+    /// [`Cucumber::run_timeout()`]: crate::Cucumber::run_timeout()
+    /// [`Scenario`]: gherkin::Scenario
+    timed_out_scenarios: usize,
+}
+
+/// Already output `pickle` message of a [`gherkin::Scenario`].
+#[derive(Debug)]
+struct Pickle {
+    /// Identifier of this [`Pickle`].
+    id: String,
+
+    /// Identifiers of this [`Pickle`] steps, along with positions of their
+    /// [`gherkin::Step`]s.
+    steps: Vec<(gherkin::LineCol, String)>,
+
+    /// [`TestCase`] of this [`Pickle`], once its [`gherkin::Scenario`] has
+    /// started.
+    test_case: Option<TestCase>,
+}
+
+/// Already output `testCase` message of a [`Pickle`].
+#[derive(Debug)]
+struct TestCase {
+    /// Identifier of this [`TestCase`].
+    id: String,
+
+    /// Identifiers of this [`TestCase`] steps, along with positions of their
+    /// [`gherkin::Step`]s.
+    steps: Vec<(gherkin::LineCol, String)>,
+
+    /// Identifier of the current attempt of this [`TestCase`].
+    started_id: String,
+
+    /// Number of the current attempt of this [`TestCase`], starting from `0`.
+    attempt: usize,
+
+    /// Indices of the [`TestCase::steps`] finished during the current attempt.
+    finished: Vec<usize>,
+
+    /// [`SystemTime`] when the current step has started.
+    step_started: Option<SystemTime>,
+}
+
+#[async_trait(?Send)]
+impl<W: World + Debug, Out: io::Write> Writer<W> for NdJson<Out> {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        event: crate::parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule};
+
+        let (ev, meta) = match event.map(Event::split) {
+            Ok(ev) => ev,
+            Err(err) => {
+                self.parsing_errors += 1;
+                self.parsing_err(&err);
+                return;
+            }
+        };
+
+        match ev {
+            Cucumber::Started => {
+                self.emit(&json!({ "meta": meta_message() }));
+                self.emit(&json!({
+                    "testRunStarted": { "timestamp": timestamp(meta.at) },
+                }));
+            }
+            Cucumber::Feature(f, Feature::Started) => self.feature_started(&f),
+            Cucumber::Feature(
+                f,
+                Feature::Scenario(sc, ev)
+                | Feature::Rule(_, Rule::Scenario(sc, ev)),
+            ) => self.handle_scenario_event(&f, &sc, ev, meta.at),
+            Cucumber::Finished => {
+                let success = self.failed_steps == 0
+                    && self.parsing_errors == 0
+                    && self.hook_errors == 0
+                    && self.timed_out_scenarios == 0;
+                self.emit(&json!({
+                    "testRunFinished": {
+                        "success": success,
+                        "timestamp": timestamp(meta.at),
+                    },
+                }));
+                self.output.flush().unwrap_or_else(|e| {
+                    panic!("Failed to write NDJSON: {}", e)
+                });
+            }
+            Cucumber::GlobalHookFailed(..) => self.hook_errors += 1,
+            Cucumber::ParsingFinished { .. }
+            | Cucumber::Feature(
+                _,
+                Feature::Finished
+                | Feature::Rule(_, Rule::Started | Rule::Finished),
+            ) => {}
+        }
+    }
+}
+
+impl<W, Out> writer::Failure<W> for NdJson<Out>
+where
+    W: World + Debug,
+    Out: io::Write,
+{
+    fn failed_steps(&self) -> usize {
+        self.failed_steps
+    }
+
+    fn parsing_errors(&self) -> usize {
+        self.parsing_errors
+    }
+
+    fn hook_errors(&self) -> usize {
+        self.hook_errors
+    }
+
+    fn skipped_steps(&self) -> usize {
+        self.skipped_steps
+    }
+
+    fn timed_out_scenarios(&self) -> usize {
+        self.timed_out_scenarios
+    }
+}
+
+impl<O: io::Write> writer::NonTransforming for NdJson<O> {}
+
+impl<Out: io::Write> NdJson<Out> {
+    /// Creates a new [`Normalized`] [`NdJson`] [`Writer`] outputting
+    /// [Cucumber Messages][1] into the given `output`.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [1]: https://github.com/cucumber/messages
+    #[must_use]
+    pub fn new<W: Debug + World>(output: Out) -> writer::Normalize<W, Self> {
+        Self::raw(output).normalized()
+    }
+
+    /// Creates a new raw and non-[`Normalized`] [`NdJson`] [`Writer`]
+    /// outputting [Cucumber Messages][1] into the given `output`.
+    ///
+    /// Use it only if you know what you're doing. Otherwise, consider using
+    /// [`NdJson::new()`] which creates an already [`Normalized`] version of
+    /// [`NdJson`] [`Writer`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [1]: https://github.com/cucumber/messages
+    #[must_use]
+    pub fn raw(output: Out) -> Self {
+        Self {
+            output,
+            next_id: 0,
+            pickles: HashMap::new(),
+            failed_steps: 0,
+            skipped_steps: 0,
+            parsing_errors: 0,
+            hook_errors: 0,
+            timed_out_scenarios: 0,
+        }
+    }
+
+    /// Writes the given `message` into the [`NdJson::output`] as a single
+    /// line.
+    fn emit(&mut self, message: &Value) {
+        serde_json::to_writer(&mut self.output, message)
+            .map_err(io::Error::from)
+            .and_then(|()| self.output.write_all(b"\n"))
+            .unwrap_or_else(|e| panic!("Failed to write NDJSON: {}", e));
+    }
+
+    /// Allocates a new unique identifier.
+    fn next_id(&mut self) -> String {
+        let id = self.next_id.to_string();
+        self.next_id += 1;
+        id
+    }
+
+    /// Outputs a `parseError` message for the given [`parser::Error`].
+    ///
+    /// [`parser::Error`]: crate::parser::Error
+    fn parsing_err(&mut self, err: &crate::parser::Error) {
+        let path = match err {
+            crate::parser::Error::Parsing(e) => match &**e {
+                gherkin::ParseFileError::Reading { path, .. }
+                | gherkin::ParseFileError::Parsing { path, .. } => Some(path),
+            },
+            crate::parser::Error::ExampleExpansion(e) => e.path.as_ref(),
+        };
+        self.emit(&json!({
+            "parseError": {
+                "source": {
+                    "uri": path.map(|p| p.to_string_lossy()),
+                },
+                "message": err.to_string(),
+            },
+        }));
+    }
+
+    /// Outputs `gherkinDocument` and `pickle` messages of the started
+    /// [`gherkin::Feature`].
+    fn feature_started(&mut self, feature: &gherkin::Feature) {
+        let uri = uri(feature);
+
+        let mut children = Vec::new();
+        let mut pickles = Vec::new();
+        let feature_bg = feature.background.as_ref().map(|bg| {
+            let (node, steps) = self.background_node(bg);
+            children.push(node);
+            steps
+        });
+        let feature_tags = self.tag_nodes(&feature.tags, feature.position);
+        for sc in &feature.scenarios {
+            let (node, pickle) = self.scenario_node(
+                &uri,
+                sc,
+                &[feature_tags.as_slice()],
+                feature_bg.iter().collect(),
+            );
+            children.push(node);
+            pickles.push(pickle);
+        }
+        for r in &feature.rules {
+            let mut rule_children = Vec::new();
+            let rule_bg = r.background.as_ref().map(|bg| {
+                let (node, steps) = self.background_node(bg);
+                rule_children.push(node);
+                steps
+            });
+            let rule_tags = self.tag_nodes(&r.tags, r.position);
+            for sc in &r.scenarios {
+                let (node, pickle) = self.scenario_node(
+                    &uri,
+                    sc,
+                    &[&feature_tags, &rule_tags],
+                    feature_bg.iter().chain(rule_bg.iter()).collect(),
+                );
+                rule_children.push(node);
+                pickles.push(pickle);
+            }
+            children.push(json!({
+                "rule": {
+                    "id": self.next_id(),
+                    "location": location(r.position),
+                    "tags": rule_tags,
+                    "keyword": r.keyword,
+                    "name": r.name,
+                    "description": "",
+                    "children": rule_children,
+                },
+            }));
+        }
+
+        self.emit(&json!({
+            "gherkinDocument": {
+                "uri": uri,
+                "feature": {
+                    "location": location(feature.position),
+                    "tags": feature_tags,
+                    "language": "en",
+                    "keyword": feature.keyword,
+                    "name": feature.name,
+                    "description": feature.description.as_deref()
+                        .unwrap_or_default(),
+                    "children": children,
+                },
+                "comments": [],
+            },
+        }));
+        for (key, message, pickle) in pickles {
+            self.emit(&message);
+            drop(self.pickles.insert(key, pickle));
+        }
+    }
+
+    /// Builds a `background` node of a `gherkinDocument` message, returning
+    /// it along with the built nodes of its [`gherkin::Step`]s.
+    fn background_node<'bg>(
+        &mut self,
+        bg: &'bg gherkin::Background,
+    ) -> (Value, Vec<(String, &'bg gherkin::Step)>) {
+        let steps = bg
+            .steps
+            .iter()
+            .map(|st| (self.next_id(), st))
+            .collect::<Vec<_>>();
+        let node = json!({
+            "background": {
+                "id": self.next_id(),
+                "location": location(bg.position),
+                "keyword": bg.keyword,
+                "name": "",
+                "description": "",
+                "steps": steps
+                    .iter()
+                    .map(|(id, st)| step_node(id, st))
+                    .collect::<Vec<_>>(),
+            },
+        });
+        (node, steps)
+    }
+
+    /// Builds a `scenario` node of a `gherkinDocument` message, returning it
+    /// along with the `pickle` message of the given [`gherkin::Scenario`].
+    ///
+    /// The `pickle` includes the given inherited `tags` and `backgrounds`
+    /// steps.
+    fn scenario_node(
+        &mut self,
+        uri: &str,
+        scenario: &gherkin::Scenario,
+        tags: &[&[Value]],
+        backgrounds: Vec<&Vec<(String, &gherkin::Step)>>,
+    ) -> (Value, (ScenarioKey, Value, Pickle)) {
+        let own_tags = self.tag_nodes(&scenario.tags, scenario.position);
+        let steps = scenario
+            .steps
+            .iter()
+            .map(|st| (self.next_id(), st))
+            .collect::<Vec<_>>();
+        let node_id = self.next_id();
+        let node = json!({
+            "scenario": {
+                "id": node_id,
+                "location": location(scenario.position),
+                "tags": own_tags,
+                "keyword": scenario.keyword,
+                "name": scenario.name,
+                "description": "",
+                "steps": steps
+                    .iter()
+                    .map(|(id, st)| step_node(id, st))
+                    .collect::<Vec<_>>(),
+                "examples": [],
+            },
+        });
+
+        let pickle_steps = backgrounds
+            .into_iter()
+            .flatten()
+            .chain(&steps)
+            .map(|(ast_id, st)| (st.position, self.next_id(), *st, ast_id))
+            .collect::<Vec<_>>();
+        let pickle = Pickle {
+            id: self.next_id(),
+            steps: pickle_steps
+                .iter()
+                .map(|(pos, id, ..)| (*pos, id.clone()))
+                .collect(),
+            test_case: None,
+        };
+        let message = json!({
+            "pickle": {
+                "id": pickle.id,
+                "uri": uri,
+                "name": scenario.name,
+                "language": "en",
+                "astNodeIds": [node_id],
+                "tags": tags
+                    .iter()
+                    .copied()
+                    .flatten()
+                    .chain(&own_tags)
+                    .map(|t| json!({
+                        "name": t["name"],
+                        "astNodeId": t["id"],
+                    }))
+                    .collect::<Vec<_>>(),
+                "steps": pickle_steps
+                    .iter()
+                    .map(|(_, id, st, ast_id)| {
+                        let mut step = json!({
+                            "id": id,
+                            "text": st.value,
+                            "type": step_type(st.ty),
+                            "astNodeIds": [ast_id],
+                        });
+                        let argument = st
+                            .docstring
+                            .as_ref()
+                            .map(|doc| json!({
+                                "docString": { "content": doc },
+                            }))
+                            .or_else(|| st.table.as_ref().map(|t| json!({
+                                "dataTable": {
+                                    "rows": t.rows.iter().map(|r| json!({
+                                        "cells": r.iter().map(|c| json!({
+                                            "value": c,
+                                        })).collect::<Vec<_>>(),
+                                    })).collect::<Vec<_>>(),
+                                },
+                            })));
+                        if let Some(arg) = argument {
+                            step["argument"] = arg;
+                        }
+                        step
+                    })
+                    .collect::<Vec<_>>(),
+            },
+        });
+
+        (node, ((uri.to_owned(), scenario.position), message, pickle))
+    }
+
+    /// Builds `tag` nodes of a `gherkinDocument` message.
+    fn tag_nodes(
+        &mut self,
+        tags: &[String],
+        owner: gherkin::LineCol,
+    ) -> Vec<Value> {
+        tags.iter()
+            .map(|t| {
+                json!({
+                    "location": location(owner),
+                    "name": format!("@{}", t),
+                    "id": self.next_id(),
+                })
+            })
+            .collect()
+    }
+
+    /// Handles the given [`event::Scenario`].
+    fn handle_scenario_event<W>(
+        &mut self,
+        feature: &gherkin::Feature,
+        scenario: &gherkin::Scenario,
+        ev: event::Scenario<W>,
+        at: SystemTime,
+    ) {
+        use event::{Hook, Scenario};
+
+        match ev {
+            Scenario::Started => self.scenario_started(feature, scenario, at),
+            Scenario::Background(st, ev) | Scenario::Step(st, ev) => {
+                self.handle_step_event(feature, scenario, &st, ev, at);
+            }
+            Scenario::Hook(_, Hook::Failed(..)) => self.hook_errors += 1,
+            Scenario::Skipped(event::SkipReason::RunTimeout) => {
+                self.timed_out_scenarios += 1;
+            }
+            Scenario::Finished => {
+                self.scenario_finished(feature, scenario, at);
+            }
+            Scenario::Hook(..)
+            | Scenario::Retried { .. }
+            | Scenario::Skipped(_)
+            | Scenario::StepHeartbeat(..)
+            | Scenario::ResourceUsage { .. } => {}
+        }
+    }
+
+    /// Outputs `testCase` (on the first attempt only) and `testCaseStarted`
+    /// messages of the started [`gherkin::Scenario`].
+    fn scenario_started(
+        &mut self,
+        feature: &gherkin::Feature,
+        scenario: &gherkin::Scenario,
+        at: SystemTime,
+    ) {
+        let key = (uri(feature), scenario.position);
+        let (pickle_id, pickle_steps, has_case) =
+            self.pickles.get(&key).map_or_else(
+                || panic!("No `pickle` for `Scenario` '{}'", scenario.name),
+                |p| (p.id.clone(), p.steps.clone(), p.test_case.is_some()),
+            );
+
+        let new_case = (!has_case).then(|| {
+            let case = TestCase {
+                id: self.next_id(),
+                steps: pickle_steps
+                    .iter()
+                    .map(|(pos, _)| (*pos, self.next_id()))
+                    .collect(),
+                started_id: String::new(),
+                attempt: 0,
+                finished: Vec::new(),
+                step_started: None,
+            };
+            let message = json!({
+                "testCase": {
+                    "id": case.id,
+                    "pickleId": pickle_id,
+                    "testSteps": pickle_steps
+                        .iter()
+                        .zip(&case.steps)
+                        .map(|((_, pickle_step), (_, id))| json!({
+                            "id": id,
+                            "pickleStepId": pickle_step,
+                            "stepDefinitionIds": [],
+                        }))
+                        .collect::<Vec<_>>(),
+                },
+            });
+            self.emit(&message);
+            case
+        });
+        let started_id = self.next_id();
+
+        let pickle = self.pickles.get_mut(&key).unwrap_or_else(|| {
+            unreachable!("`pickle` has been checked to exist already")
+        });
+        let case = if let Some(case) = new_case {
+            pickle.test_case.insert(case)
+        } else {
+            let case = pickle
+                .test_case
+                .as_mut()
+                .unwrap_or_else(|| unreachable!("`testCase` exists already"));
+            case.attempt += 1;
+            case.finished.clear();
+            case
+        };
+        case.started_id = started_id;
+
+        let message = json!({
+            "testCaseStarted": {
+                "id": case.started_id,
+                "testCaseId": case.id,
+                "attempt": case.attempt,
+                "timestamp": timestamp(at),
+            },
+        });
+        self.emit(&message);
+    }
+
+    /// Outputs `testStepStarted` or `testStepFinished` message of the given
+    /// [`event::Step`].
+    fn handle_step_event<W>(
+        &mut self,
+        feature: &gherkin::Feature,
+        scenario: &gherkin::Scenario,
+        step: &gherkin::Step,
+        ev: event::Step<W>,
+        at: SystemTime,
+    ) {
+        let (status, message) = match ev {
+            event::Step::Started => {
+                let case = self.test_case(feature, scenario);
+                case.step_started = Some(at);
+                let (_, id) = case.step(step);
+                let message = json!({
+                    "testStepStarted": {
+                        "testCaseStartedId": case.started_id,
+                        "testStepId": id,
+                        "timestamp": timestamp(at),
+                    },
+                });
+                self.emit(&message);
+                return;
+            }
+            event::Step::Output(_) => return,
+            event::Step::Passed(_) => ("PASSED", None),
+            event::Step::SoftFailed(_, err) => {
+                ("PASSED", Some(err.to_string()))
+            }
+            event::Step::Skipped => {
+                self.skipped_steps += 1;
+                ("UNDEFINED", None)
+            }
+            event::Step::Matched(_) => ("SKIPPED", None),
+            event::Step::Failed(_, _, err) => {
+                self.failed_steps += 1;
+                match err {
+                    event::StepError::AmbiguousMatch(e) => {
+                        ("AMBIGUOUS", Some(e.to_string()))
+                    }
+                    event::StepError::Panic(info) => {
+                        ("FAILED", Some(coerce_error(&info).into_owned()))
+                    }
+                    event::StepError::TimedOut(_) => {
+                        ("FAILED", Some(err.to_string()))
+                    }
+                }
+            }
+        };
+
+        let case = self.test_case(feature, scenario);
+        let started = case.step_started.take().unwrap_or_else(|| {
+            panic!("No `Started` event for `Step` '{}'", step.value)
+        });
+        let (idx, id) = case.step(step);
+        let id = id.to_owned();
+        case.finished.push(idx);
+        let message = step_finished(
+            &case.started_id,
+            &id,
+            status,
+            at.duration_since(started).unwrap_or_default(),
+            message,
+            at,
+        );
+        self.emit(&message);
+    }
+
+    /// Outputs `testStepFinished` messages of the [`gherkin::Step`]s not
+    /// executed during the current attempt as `SKIPPED`, and then a
+    /// `testCaseFinished` message of the finished [`gherkin::Scenario`].
+    fn scenario_finished(
+        &mut self,
+        feature: &gherkin::Feature,
+        scenario: &gherkin::Scenario,
+        at: SystemTime,
+    ) {
+        let case = self.test_case(feature, scenario);
+        let mut messages = case
+            .steps
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !case.finished.contains(idx))
+            .map(|(_, (_, id))| {
+                step_finished(
+                    &case.started_id,
+                    id,
+                    "SKIPPED",
+                    Duration::ZERO,
+                    None,
+                    at,
+                )
+            })
+            .collect::<Vec<_>>();
+        messages.push(json!({
+            "testCaseFinished": {
+                "testCaseStartedId": case.started_id,
+                "timestamp": timestamp(at),
+                "willBeRetried": false,
+            },
+        }));
+
+        for message in &messages {
+            self.emit(message);
+        }
+    }
+
+    /// Returns the started [`TestCase`] of the given [`gherkin::Scenario`].
+    ///
+    /// # Panics
+    ///
+    /// If the given [`gherkin::Scenario`] hasn't been started yet.
+    fn test_case(
+        &mut self,
+        feature: &gherkin::Feature,
+        scenario: &gherkin::Scenario,
+    ) -> &mut TestCase {
+        self.pickles
+            .get_mut(&(uri(feature), scenario.position))
+            .and_then(|p| p.test_case.as_mut())
+            .unwrap_or_else(|| {
+                panic!("No `Started` event for `Scenario` '{}'", scenario.name)
+            })
+    }
+}
+
+impl TestCase {
+    /// Returns the index and the identifier of the test step of the given
+    /// [`gherkin::Step`].
+    ///
+    /// # Panics
+    ///
+    /// If this [`TestCase`] doesn't contain the given [`gherkin::Step`].
+    fn step(&self, step: &gherkin::Step) -> (usize, &str) {
+        // Steps of a `Pickle` are positioned on different lines, so their
+        // positions identify them unambiguously.
+        self.steps
+            .iter()
+            .enumerate()
+            .find(|(_, (pos, _))| *pos == step.position)
+            .map_or_else(
+                || panic!("No test step for `Step` '{}'", step.value),
+                |(idx, (_, id))| (idx, id.as_str()),
+            )
+    }
+}
+
+/// Returns URI of the given [`gherkin::Feature`], falling back to its name if
+/// it has no path.
+fn uri(feature: &gherkin::Feature) -> String {
+    feature.path.as_ref().map_or_else(
+        || feature.name.clone(),
+        |p| p.to_string_lossy().into_owned(),
+    )
+}
+
+/// Builds a `step` node of a `gherkinDocument` message.
+fn step_node(id: &str, step: &gherkin::Step) -> Value {
+    let mut node = json!({
+        "id": id,
+        "location": location(step.position),
+        "keyword": format!("{} ", step.keyword),
+        "text": step.value,
+    });
+    if let Some(doc) = &step.docstring {
+        node["docString"] = json!({
+            "location": location(gherkin::LineCol {
+                line: step.position.line + 1,
+                col: step.position.col,
+            }),
+            "content": doc,
+            "delimiter": "\"\"\"",
+        });
+    }
+    if let Some(table) = &step.table {
+        node["dataTable"] = json!({
+            "location": location(table.position),
+            "rows": table.rows.iter().enumerate().map(|(i, r)| {
+                let pos = gherkin::LineCol {
+                    line: table.position.line + i,
+                    col: table.position.col,
+                };
+                json!({
+                    "id": format!("{}-{}", id, i),
+                    "location": location(pos),
+                    "cells": r.iter().map(|c| json!({
+                        "location": location(pos),
+                        "value": c,
+                    })).collect::<Vec<_>>(),
+                })
+            }).collect::<Vec<_>>(),
+        });
+    }
+    node
+}
+
+/// Builds a `testStepFinished` message.
+fn step_finished(
+    started_id: &str,
+    step_id: &str,
+    status: &str,
+    duration: Duration,
+    message: Option<String>,
+    at: SystemTime,
+) -> Value {
+    let mut result = json!({
+        "status": status,
+        "duration": {
+            "seconds": duration.as_secs(),
+            "nanos": duration.subsec_nanos(),
+        },
+    });
+    if let Some(msg) = message {
+        result["message"] = msg.into();
+    }
+    json!({
+        "testStepFinished": {
+            "testCaseStartedId": started_id,
+            "testStepId": step_id,
+            "testStepResult": result,
+            "timestamp": timestamp(at),
+        },
+    })
+}
+
+/// Builds a `meta` message describing this library and its environment.
+fn meta_message() -> Value {
+    json!({
+        "protocolVersion": PROTOCOL_VERSION,
+        "implementation": {
+            "name": "cucumber-rs",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "runtime": { "name": "rust" },
+        "os": { "name": env::consts::OS },
+        "cpu": { "name": env::consts::ARCH },
+    })
+}
+
+/// Converts the given [`gherkin::LineCol`] into a `location` message.
+fn location(pos: gherkin::LineCol) -> Value {
+    json!({ "line": pos.line, "column": pos.col })
+}
+
+/// Converts the given [`SystemTime`] into a `timestamp` message.
+fn timestamp(at: SystemTime) -> Value {
+    let since_epoch = at.duration_since(UNIX_EPOCH).unwrap_or_default();
+    json!({
+        "seconds": since_epoch.as_secs(),
+        "nanos": since_epoch.subsec_nanos(),
+    })
+}
+
+/// Returns the `type` of a `pickleStep` message for the given
+/// [`gherkin::StepType`].
+const fn step_type(ty: gherkin::StepType) -> &'static str {
+    match ty {
+        gherkin::StepType::Given => "Context",
+        gherkin::StepType::When => "Action",
+        gherkin::StepType::Then => "Outcome",
+    }
+}
@@ -15,15 +15,27 @@
 pub mod basic;
 pub mod discard;
 pub mod fail_on_skipped;
+pub mod format;
+#[cfg(feature = "output-html")]
+pub mod html;
 #[cfg(feature = "output-json")]
 pub mod json;
 #[cfg(feature = "output-junit")]
 pub mod junit;
+pub mod markdown;
+#[cfg(feature = "output-json")]
+pub mod messages;
 pub mod normalize;
 pub mod out;
+pub mod progress;
 pub mod repeat;
+pub mod route;
 pub mod summarize;
+pub mod tap;
+pub mod teamcity;
 pub mod tee;
+pub mod timed;
+pub mod to_file;
 
 use async_trait::async_trait;
 use sealed::sealed;
@@ -31,20 +43,34 @@ use structopt::StructOptInternal;
 
 use crate::{event, parser, Event};
 
+#[cfg(feature = "output-html")]
+#[doc(inline)]
+pub use self::html::Html;
 #[cfg(feature = "output-json")]
 #[doc(inline)]
 pub use self::json::Json;
 #[cfg(feature = "output-junit")]
 #[doc(inline)]
 pub use self::junit::JUnit;
+#[cfg(feature = "output-json")]
+#[doc(inline)]
+pub use self::messages::NdJson;
 #[doc(inline)]
 pub use self::{
     basic::{Basic, Coloring},
     fail_on_skipped::FailOnSkipped,
+    format::Format,
+    markdown::Markdown,
     normalize::{AssertNormalized, Normalize, Normalized},
+    progress::Progress,
     repeat::Repeat,
+    route::Route,
     summarize::{Summarizable, Summarize},
+    tap::Tap,
+    teamcity::TeamCity,
     tee::Tee,
+    timed::Timed,
+    to_file::ToFile,
 };
 
 /// Writer of [`Cucumber`] events to some output.
@@ -56,7 +82,12 @@ pub use self::{
 /// inspection after execution. See [`Summarize`] and
 /// [`Cucumber::run_and_exit()`] for examples.
 ///
+/// As the process may exit right after [`Cucumber::run()`] returns, any
+/// buffered output should be flushed on [`Cucumber::Finished`] event (and on
+/// everything written after it, like a summary).
+///
 /// [`Cucumber`]: crate::event::Cucumber
+/// [`Cucumber::Finished`]: crate::event::Cucumber::Finished
 /// [`Cucumber::run()`]: crate::Cucumber::run
 /// [`Cucumber::run_and_exit()`]: crate::Cucumber::run_and_exit
 /// [`Runner`]: crate::Runner
@@ -109,7 +140,9 @@ pub trait Failure<World>: Writer<World> {
     /// Indicates whether there were failures/errors during execution.
     #[must_use]
     fn execution_has_failed(&self) -> bool {
-        self.failed_steps() > 0 || self.parsing_errors() > 0
+        self.failed_steps() > 0
+            || self.parsing_errors() > 0
+            || self.timed_out_scenarios() > 0
     }
 
     /// Returns number of [`Failed`] [`Step`]s.
@@ -128,6 +161,65 @@ pub trait Failure<World>: Writer<World> {
     /// [`Scenario`]: gherkin::Scenario
     #[must_use]
     fn hook_errors(&self) -> usize;
+
+    /// Returns number of [`Skipped`] [`Step`]s, which means that no matching
+    /// [`Step`] function was found for them.
+    ///
+    /// Returns `0` by default, so should be overridden by [`Writer`]s, which
+    /// track [`Skipped`] [`Step`]s.
+    ///
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    fn skipped_steps(&self) -> usize {
+        0
+    }
+
+    /// Returns number of [`Passed`] [`Step`]s (including [soft failed][0]
+    /// and [matched][1] ones, not failing their [`Scenario`]).
+    ///
+    /// Returns `0` by default, so should be overridden by [`Writer`]s, which
+    /// track [`Passed`] [`Step`]s.
+    ///
+    /// [0]: event::Step::SoftFailed
+    /// [1]: event::Step::Matched
+    /// [`Passed`]: event::Step::Passed
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    fn passed_steps(&self) -> usize {
+        0
+    }
+
+    /// Returns number of [`Step`]s not executed at all, as a previous [`Step`]
+    /// or hook of their [`Scenario`] has failed or was [`Skipped`], or the
+    /// whole [`Scenario`] was [skipped][0].
+    ///
+    /// Returns `0` by default, so should be overridden by [`Writer`]s, which
+    /// track such [`Step`]s.
+    ///
+    /// [0]: event::Scenario::Skipped
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    fn not_executed_steps(&self) -> usize {
+        0
+    }
+
+    /// Returns number of [`Scenario`]s [skipped][0] or abandoned due to the
+    /// [`Cucumber::run_timeout()`] being exceeded.
+    ///
+    /// Returns `0` by default, so should be overridden by [`Writer`]s, which
+    /// track such [`Scenario`]s.
+    ///
+    /// [0]: event::SkipReason::RunTimeout
+    /// [`Cucumber::run_timeout()`]: crate::Cucumber::run_timeout()
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn timed_out_scenarios(&self) -> usize {
+        0
+    }
 }
 
 /// Extension of [`Writer`] allowing its normalization and summarization.
@@ -189,6 +281,7 @@ pub trait Ext: Sized {
             &gherkin::Feature,
             Option<&gherkin::Rule>,
             &gherkin::Scenario,
+            &gherkin::Step,
         ) -> bool;
 
     /// Wraps this [`Writer`] to re-output [`Skipped`] [`Step`]s at the end of
@@ -208,6 +301,14 @@ pub trait Ext: Sized {
     #[must_use]
     fn repeat_failed<W>(self) -> Repeat<W, Self>;
 
+    /// Wraps this [`Writer`] to re-output [`Scenario`]s, which have been
+    /// [retried][1] and eventually passed, at the end of an output.
+    ///
+    /// [1]: event::Scenario::Retried
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn repeat_retried<W>(self) -> Repeat<W, Self>;
+
     /// Wraps this [`Writer`] to re-output `filter`ed events at the end of an
     /// output.
     #[must_use]
@@ -216,10 +317,18 @@ pub trait Ext: Sized {
         F: Fn(&parser::Result<Event<event::Cucumber<W>>>) -> bool;
 
     /// Attaches the provided `other` [`Writer`] to the current one for passing
-    /// events to both of them simultaneously.
+    /// events to both of them, the current one first.
     #[must_use]
     fn tee<W, Wr: Writer<W>>(self, other: Wr) -> Tee<Self, Wr>;
 
+    /// Wraps this [`Writer`] to record durations of [`Scenario`]s and
+    /// [`Step`]s, available via [`Timed::timings()`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    fn timed(self) -> Timed<Self>;
+
     /// Wraps this [`Writer`] into a [`discard::Arbitrary`] one, providing a
     /// no-op [`ArbitraryWriter`] implementation.
     ///
@@ -267,6 +376,7 @@ impl<T> Ext for T {
             &gherkin::Feature,
             Option<&gherkin::Rule>,
             &gherkin::Scenario,
+            &gherkin::Step,
         ) -> bool,
     {
         FailOnSkipped::with(self, f)
@@ -280,6 +390,10 @@ impl<T> Ext for T {
         Repeat::failed(self)
     }
 
+    fn repeat_retried<W>(self) -> Repeat<W, Self> {
+        Repeat::retried(self)
+    }
+
     fn repeat_if<W, F>(self, filter: F) -> Repeat<W, Self, F>
     where
         F: Fn(&parser::Result<Event<event::Cucumber<W>>>) -> bool,
@@ -291,6 +405,10 @@ impl<T> Ext for T {
         Tee::new(self, other)
     }
 
+    fn timed(self) -> Timed<Self> {
+        Timed::new(self)
+    }
+
     fn discard_arbitrary_writes(self) -> discard::Arbitrary<Self> {
         discard::Arbitrary::wrap(self)
     }
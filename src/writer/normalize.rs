@@ -10,7 +10,14 @@
 
 //! [`Writer`]-wrapper for outputting events in a normalized readable order.
 
-use std::{hash::Hash, mem, sync::Arc};
+use std::{
+    fmt,
+    hash::Hash,
+    io::{self, Write as _},
+    mem,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use async_trait::async_trait;
 use derive_more::Deref;
@@ -35,11 +42,26 @@ use crate::{
 /// much easier to understand what is really happening in the running
 /// [`Feature`] while don't impose any restrictions on the running order.
 ///
+/// Events of the currently written [`Feature`] (and [`Scenario`]) are passed to
+/// the underlying [`Writer`] as soon as they happen, so only the events of the
+/// concurrently running ones are kept in memory until it's their turn. Use
+/// [`Normalize::eager()`] to not keep concurrently running [`Scenario`]s of a
+/// single running [`Feature`] in memory either.
+///
+/// [`Feature`]s, [`Rule`]s and [`Scenario`]s are written in the order they
+/// have started, which depends on scheduling. Use [`Normalize::sorted()`] to
+/// write them in the order of their paths and positions instead.
+///
+/// As a [`Feature`] never finishing (due to a bug in a custom [`Runner`], for
+/// example) stalls the output of all the other ones, a [watchdog][1] may be
+/// enabled to report such a [`Feature`].
+///
 /// [`Feature`]: gherkin::Feature
 /// [`Rule`]: gherkin::Rule
 /// [`Runner`]: crate::Runner
 /// [`Scenario`]: gherkin::Scenario
 /// [`Step`]: gherkin::Step
+/// [1]: Normalize::watchdog()
 #[derive(Debug, Deref)]
 pub struct Normalize<World, Writer> {
     /// Original [`Writer`] to normalize output of.
@@ -48,6 +70,26 @@ pub struct Normalize<World, Writer> {
 
     /// Normalization queue of happened events.
     queue: CucumberQueue<World>,
+
+    /// Indicator whether [`Feature`]s, [`Rule`]s and [`Scenario`]s are
+    /// written in the order of their paths and positions, rather than the
+    /// order they have started.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    sorted: bool,
+
+    /// [`Watchdog`] reporting a stuck [`Feature`], if enabled.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    watchdog: Option<Watchdog>,
+
+    /// Indicator whether events of a [`Feature`] are written as soon as they
+    /// happen, while it's the only one running.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    eager: bool,
 }
 
 impl<W, Writer> Normalize<W, Writer> {
@@ -58,8 +100,92 @@ impl<W, Writer> Normalize<W, Writer> {
         Self {
             writer,
             queue: CucumberQueue::new(Metadata::new(())),
+            sorted: false,
+            watchdog: None,
+            eager: false,
+        }
+    }
+
+    /// Creates a new [`Normalized`] wrapper, which will feed [`event`]s to the
+    /// given [`Writer`] ordering [`Feature`]s by their paths, and [`Rule`]s
+    /// and [`Scenario`]s by their positions in a [`Feature`], so the output
+    /// doesn't depend on scheduling.
+    ///
+    /// As the order isn't known before all the [`Feature`]s have started, no
+    /// [`Feature`] is written until the whole execution is finished.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn sorted(writer: Writer) -> Self {
+        Self {
+            sorted: true,
+            ..Self::new(writer)
+        }
+    }
+
+    /// Creates a new [`Normalized`] wrapper, which will feed [`event`]s of a
+    /// [`Feature`] to the given [`Writer`] as soon as they happen, while it's
+    /// the only [`Feature`] running, rather than queueing the events of its
+    /// concurrently running [`Rule`]s and [`Scenario`]s until it's their turn.
+    ///
+    /// This bounds memory of long-running [`Feature`]s, but interleaves their
+    /// [`Rule`]s and [`Scenario`]s in the output. Once several [`Feature`]s
+    /// are running at the same time, their events are queued, so each
+    /// [`Feature`] is still written uninterruptedly.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn eager(writer: Writer) -> Self {
+        Self {
+            eager: true,
+            ..Self::new(writer)
         }
     }
+
+    /// Enables a watchdog reporting the [`Feature`] currently holding the
+    /// output into [`io::Stderr`], once it hasn't received any event for the
+    /// given `idle` [`Duration`], while events of other [`Feature`]s keep
+    /// being queued behind it.
+    ///
+    /// See [`Normalize::watchdog_with()`] for details.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[must_use]
+    pub fn watchdog(self, idle: Duration) -> Self {
+        self.watchdog_with(idle, report_to_stderr)
+    }
+
+    /// Enables a watchdog reporting the [`Feature`] currently holding the
+    /// output via the given `on_stuck` function, once it hasn't received any
+    /// event for the given `idle` [`Duration`], while events of other
+    /// [`Feature`]s keep being queued behind it.
+    ///
+    /// As this [`Writer`] has no timer of its own, the idleness is checked on
+    /// receiving events of other [`Feature`]s only, so a [`Runner`] stuck
+    /// entirely isn't reported. A stuck [`Feature`] is reported only once,
+    /// unless it makes progress and gets stuck again.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Runner`]: crate::Runner
+    #[must_use]
+    pub fn watchdog_with(
+        mut self,
+        idle: Duration,
+        on_stuck: fn(&Stuck),
+    ) -> Self {
+        self.watchdog = Some(Watchdog {
+            idle,
+            on_stuck,
+            head: None,
+            progressed_at: Instant::now(),
+            reported: false,
+        });
+        self
+    }
 }
 
 #[async_trait(?Send)]
@@ -82,8 +208,19 @@ impl<World, Wr: Writer<World>> Writer<World> for Normalize<World, Wr> {
             return;
         }
 
+        let progressed = match event.as_deref() {
+            Ok(Cucumber::Feature(f, _)) => Some(Arc::clone(f)),
+            Ok(_) | Err(_) => None,
+        };
+
         match event.map(Event::split) {
-            res @ (Err(_) | Ok((Cucumber::Started, _))) => {
+            res @ (Err(_)
+            | Ok((
+                Cucumber::Started
+                | Cucumber::ParsingFinished { .. }
+                | Cucumber::GlobalHookFailed(..),
+                _,
+            ))) => {
                 self.writer
                     .handle_event(res.map(|(ev, meta)| meta.insert(ev)), cli)
                     .await;
@@ -117,6 +254,24 @@ impl<World, Wr: Writer<World>> Writer<World> for Normalize<World, Wr> {
             },
         }
 
+        if let Some(watchdog) = &mut self.watchdog {
+            watchdog.check(&self.queue, progressed.as_deref());
+        }
+
+        if self.sorted {
+            if !matches!(
+                self.queue.state,
+                FinishedState::FinishedButNotEmitted(_)
+            ) {
+                return;
+            }
+            self.queue.sort();
+        }
+
+        if self.eager && self.queue.queue.len() == 1 {
+            self.queue.emit_eagerly(&mut self.writer, cli).await;
+        }
+
         while let Some(feature_to_remove) =
             self.queue.emit((), &mut self.writer, cli).await
         {
@@ -161,6 +316,22 @@ where
     fn hook_errors(&self) -> usize {
         self.writer.hook_errors()
     }
+
+    fn skipped_steps(&self) -> usize {
+        self.writer.skipped_steps()
+    }
+
+    fn passed_steps(&self) -> usize {
+        self.writer.passed_steps()
+    }
+
+    fn not_executed_steps(&self) -> usize {
+        self.writer.not_executed_steps()
+    }
+
+    fn timed_out_scenarios(&self) -> usize {
+        self.writer.timed_out_scenarios()
+    }
 }
 
 impl<W, Wr: writer::NonTransforming> writer::NonTransforming
@@ -264,6 +435,22 @@ where
     fn hook_errors(&self) -> usize {
         self.0.hook_errors()
     }
+
+    fn skipped_steps(&self) -> usize {
+        self.0.skipped_steps()
+    }
+
+    fn passed_steps(&self) -> usize {
+        self.0.passed_steps()
+    }
+
+    fn not_executed_steps(&self) -> usize {
+        self.0.not_executed_steps()
+    }
+
+    fn timed_out_scenarios(&self) -> usize {
+        self.0.timed_out_scenarios()
+    }
 }
 
 impl<Wr: writer::NonTransforming> writer::NonTransforming
@@ -273,6 +460,161 @@ impl<Wr: writer::NonTransforming> writer::NonTransforming
 
 impl<Writer> Normalized for AssertNormalized<Writer> {}
 
+/// [`Feature`] holding the output of a [`Normalize`]d [`Writer`] without any
+/// progress, as reported by its [watchdog][1].
+///
+/// [`Feature`]: gherkin::Feature
+/// [1]: Normalize::watchdog()
+#[derive(Clone, Debug)]
+pub struct Stuck {
+    /// [`Feature`] holding the output.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    pub feature: Arc<gherkin::Feature>,
+
+    /// Started, but not finished yet [`Scenario`]s of the [`Feature`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    pub pending: Vec<Arc<gherkin::Scenario>>,
+
+    /// [`Duration`] the [`Feature`] hasn't received any event for.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    pub idle: Duration,
+
+    /// Number of other [`Feature`]s waiting for their output.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    pub waiting: usize,
+}
+
+impl fmt::Display for Stuck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} `{}`", self.feature.keyword, self.feature.name)?;
+        if let Some(path) = &self.feature.path {
+            write!(f, " ({})", path.display())?;
+        }
+        write!(
+            f,
+            " hasn't progressed for {:.3}s, holding the output of {} other \
+             {}",
+            self.idle.as_secs_f64(),
+            self.waiting,
+            if self.waiting == 1 {
+                "feature"
+            } else {
+                "features"
+            },
+        )?;
+        if self.pending.is_empty() {
+            return write!(f, ", with no pending scenarios");
+        }
+        write!(f, ", with pending scenarios:")?;
+        for sc in &self.pending {
+            write!(
+                f,
+                "\n  {}: {} ({}:{})",
+                sc.keyword, sc.name, sc.position.line, sc.position.col,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Reports the given [`Stuck`] [`Feature`] into [`io::Stderr`].
+///
+/// [`Feature`]: gherkin::Feature
+fn report_to_stderr(stuck: &Stuck) {
+    // Failing to output a diagnostic shouldn't fail the whole run.
+    drop(writeln!(io::stderr(), "[WARNING] {}", stuck));
+}
+
+/// Watchdog of the [`Feature`] holding the output of a [`Normalize`]d
+/// [`Writer`].
+///
+/// [`Feature`]: gherkin::Feature
+#[derive(Clone, Debug)]
+struct Watchdog {
+    /// [`Duration`] of no progress to report a [`Feature`] after.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    idle: Duration,
+
+    /// Function to report a [`Stuck`] [`Feature`] with.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    on_stuck: fn(&Stuck),
+
+    /// [`Feature`] holding the output, as of the last check.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    head: Option<Arc<gherkin::Feature>>,
+
+    /// [`Instant`] when the [`Watchdog::head`] has progressed last time.
+    progressed_at: Instant,
+
+    /// Indicator whether the [`Watchdog::head`] has been reported already
+    /// since its last progress.
+    reported: bool,
+}
+
+impl Watchdog {
+    /// Checks whether the first not finished [`Feature`] of the given
+    /// [`CucumberQueue`] has progressed, considering the `progressed` one has
+    /// just received an event, and reports it as [`Stuck`] otherwise, if it's
+    /// idle for too long.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    fn check<W>(
+        &mut self,
+        queue: &CucumberQueue<W>,
+        progressed: Option<&gherkin::Feature>,
+    ) {
+        // Finished `Feature`s are only waiting for their turn to be emitted.
+        let head = queue.queue.iter().find(|(_, events)| {
+            matches!(events.state, FinishedState::NotFinished)
+        });
+        let (head, events) = if let Some(current) = head {
+            current
+        } else {
+            self.head = None;
+            return;
+        };
+
+        if self.head.as_ref() != Some(head) || progressed == Some(&**head) {
+            self.head = Some(Arc::clone(head));
+            self.progressed_at = Instant::now();
+            self.reported = false;
+            return;
+        }
+
+        let idle = self.progressed_at.elapsed();
+        if self.reported || idle < self.idle {
+            return;
+        }
+        self.reported = true;
+
+        let pending = events
+            .queue
+            .iter()
+            .flat_map(|(item, events)| match (item, events) {
+                (Either::Left(_), Either::Left(rule)) => {
+                    rule.queue.keys().cloned().collect()
+                }
+                (Either::Right(sc), _) => vec![Arc::clone(sc)],
+                (Either::Left(_), Either::Right(_)) => unreachable!(),
+            })
+            .collect();
+        (self.on_stuck)(&Stuck {
+            feature: Arc::clone(head),
+            pending,
+            idle,
+            waiting: queue.queue.len() - 1,
+        });
+    }
+}
+
 /// Normalization queue for incoming events.
 ///
 /// We use [`LinkedHashMap`] everywhere throughout this module to ensure FIFO
@@ -326,6 +668,13 @@ impl<K: Eq + Hash, V> Queue<K, V> {
     fn remove(&mut self, key: &K) {
         drop(self.queue.remove(key));
     }
+
+    /// Reorders this [`Queue`] by the given `key` of its items.
+    fn sort_by_key<T: Ord>(&mut self, mut key: impl FnMut(&K) -> T) {
+        let mut items = self.queue.drain().collect::<Vec<_>>();
+        items.sort_by_key(|(k, _)| key(k));
+        self.queue.extend(items);
+    }
 }
 
 /// Finishing state of a [`Queue`].
@@ -425,10 +774,15 @@ type CucumberQueue<World> = Queue<Arc<gherkin::Feature>, FeatureQueue<World>>;
 impl<World> CucumberQueue<World> {
     /// Inserts a new [`Feature`] on [`event::Feature::Started`].
     ///
+    /// If an equal [`Feature`] is in the queue already, then its events are
+    /// kept, rather than being overwritten.
+    ///
     /// [`Feature`]: gherkin::Feature
     fn new_feature(&mut self, feat: Event<Arc<gherkin::Feature>>) {
         let (feat, meta) = feat.split();
-        drop(self.queue.insert(feat, FeatureQueue::new(meta)));
+        if !self.queue.contains_key(&feat) {
+            drop(self.queue.insert(feat, FeatureQueue::new(meta)));
+        }
     }
 
     /// Marks a [`Feature`] as finished on [`event::Feature::Finished`].
@@ -489,6 +843,64 @@ impl<World> CucumberQueue<World> {
             .unwrap_or_else(|| panic!("No Feature {}", feat.name))
             .insert_scenario_event(rule, scenario, event);
     }
+
+    /// Reorders [`Feature`]s by their paths, and their [`Rule`]s and
+    /// [`Scenario`]s by their positions.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    fn sort(&mut self) {
+        self.sort_by_key(|f| {
+            (
+                f.path.clone(),
+                f.position.line,
+                f.position.col,
+                f.name.clone(),
+            )
+        });
+        for (_, feature) in &mut self.queue {
+            feature.sort_by_key(|item| match item {
+                Either::Left(r) => (r.position.line, r.position.col),
+                Either::Right(sc) => (sc.position.line, sc.position.col),
+            });
+            for (_, item) in &mut feature.queue {
+                if let Either::Left(rule) = item {
+                    rule.sort_by_key(|sc| (sc.position.line, sc.position.col));
+                }
+            }
+        }
+    }
+
+    /// Passes all the queued events of the current [`Feature`] to the provided
+    /// [`Writer`], without waiting for its [`Rule`]s and [`Scenario`]s to be
+    /// finished one by one.
+    ///
+    /// Doesn't emit the [`Feature`] being finished, leaving it to the
+    /// [`Emitter::emit()`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    async fn emit_eagerly<W: Writer<World>>(
+        &mut self,
+        writer: &mut W,
+        cli: &W::Cli,
+    ) {
+        if let Some((f, events)) = self.current_item() {
+            if let Some(meta) = events.initial.take() {
+                writer
+                    .handle_event(
+                        Ok(meta.wrap(event::Cucumber::feature_started(
+                            Arc::clone(&f),
+                        ))),
+                        cli,
+                    )
+                    .await;
+            }
+            events.emit_eagerly(f, writer, cli).await;
+        }
+    }
 }
 
 #[async_trait(?Send)]
@@ -618,7 +1030,6 @@ impl<World> FeatureQueue<World> {
                     .queue
                     .entry(scenario)
                     .or_insert_with(ScenariosQueue::new)
-                    .0
                     .push(ev),
                 Either::Right(_) => unreachable!(),
             }
@@ -628,11 +1039,52 @@ impl<World> FeatureQueue<World> {
                 .entry(Either::Right(scenario))
                 .or_insert_with(|| Either::Right(ScenariosQueue::new()))
             {
-                Either::Right(events) => events.0.push(ev),
+                Either::Right(events) => events.push(ev),
                 Either::Left(_) => unreachable!(),
             }
         }
     }
+
+    /// Passes all the queued events of every [`Rule`] and [`Scenario`] of
+    /// this [`FeatureQueue`] to the provided [`Writer`], removing the finished
+    /// ones.
+    ///
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    async fn emit_eagerly<W: Writer<World>>(
+        &mut self,
+        feature: Arc<gherkin::Feature>,
+        writer: &mut W,
+        cli: &W::Cli,
+    ) {
+        let mut emitted = Vec::new();
+        for (item, events) in &mut self.queue {
+            let finished = match (item, events) {
+                (Either::Left(rule), Either::Left(events)) => events
+                    .emit_eagerly(
+                        (Arc::clone(&feature), Arc::clone(rule)),
+                        writer,
+                        cli,
+                    )
+                    .await
+                    .map(Either::Left),
+                (Either::Right(scenario), Either::Right(events)) => events
+                    .emit(
+                        (Arc::clone(&feature), None, Arc::clone(scenario)),
+                        writer,
+                        cli,
+                    )
+                    .await
+                    .map(Either::Right),
+                (Either::Left(_), Either::Right(_))
+                | (Either::Right(_), Either::Left(_)) => unreachable!(),
+            };
+            emitted.extend(finished);
+        }
+        for item in emitted {
+            self.remove(&item);
+        }
+    }
 }
 
 #[async_trait(?Send)]
@@ -677,6 +1129,45 @@ impl<'me, World> Emitter<World> for &'me mut FeatureQueue<World> {
 /// [`Rule`]: gherkin::Rule
 type RulesQueue<World> = Queue<Arc<gherkin::Scenario>, ScenariosQueue<World>>;
 
+impl<World> RulesQueue<World> {
+    /// Passes all the queued events of every [`Scenario`] of this
+    /// [`RulesQueue`] to the provided [`Writer`], removing the finished ones.
+    ///
+    /// If this method returns [`Some`], then all events of the [`Rule`] were
+    /// passed to the provided [`Writer`] and it should be [`remove`]d.
+    ///
+    /// [`remove`]: Queue::remove()
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    async fn emit_eagerly<W: Writer<World>>(
+        &mut self,
+        (feature, rule): (Arc<gherkin::Feature>, Arc<gherkin::Rule>),
+        writer: &mut W,
+        cli: &W::Cli,
+    ) -> Option<Arc<gherkin::Rule>> {
+        let emitted = self
+            .emit((Arc::clone(&feature), Arc::clone(&rule)), writer, cli)
+            .await;
+        if emitted.is_some() {
+            return emitted;
+        }
+
+        let mut finished = Vec::new();
+        for (scenario, events) in &mut self.queue {
+            let path = (
+                Arc::clone(&feature),
+                Some(Arc::clone(&rule)),
+                Arc::clone(scenario),
+            );
+            finished.extend(events.emit(path, writer, cli).await);
+        }
+        for scenario in finished {
+            self.remove(&scenario);
+        }
+        None
+    }
+}
+
 #[async_trait(?Send)]
 impl<'me, World> Emitter<World> for &'me mut RulesQueue<World> {
     type Current = (Arc<gherkin::Scenario>, &'me mut ScenariosQueue<World>);
@@ -751,6 +1242,37 @@ impl<World> ScenariosQueue<World> {
     const fn new() -> Self {
         Self(Vec::new())
     }
+
+    /// Pushes the given event into this [`ScenariosQueue`].
+    ///
+    /// [`Background`] [`Step`] events are placed before any not yet emitted
+    /// event following the [`Background`] in execution order (a regular
+    /// [`Step`], an [`After`] hook or finishing), so they're never emitted
+    /// after them.
+    ///
+    /// [`After`]: event::HookType::After
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    fn push(&mut self, ev: Event<event::Scenario<World>>) {
+        use event::{HookType, Scenario};
+
+        let pos = matches!(*ev, Scenario::Background(..))
+            .then(|| {
+                self.0.iter().position(|queued| {
+                    matches!(
+                        **queued,
+                        Scenario::Step(..)
+                            | Scenario::Hook(HookType::After, _)
+                            | Scenario::Finished,
+                    )
+                })
+            })
+            .flatten();
+        match pos {
+            Some(pos) => self.0.insert(pos, ev),
+            None => self.0.push(ev),
+        }
+    }
 }
 
 #[async_trait(?Send)]
@@ -10,7 +10,7 @@
 
 //! Tools for writing output.
 
-use std::{borrow::Cow, io, str};
+use std::{borrow::Cow, env, io, str};
 
 use console::Style;
 use derive_more::{Deref, DerefMut, Display, From, Into};
@@ -30,23 +30,35 @@ pub struct Styles {
     /// [`Style`] for rendering header.
     pub header: Style,
 
+    /// [`Style`] for rendering passed [`Background`] events.
+    ///
+    /// [`Background`]: gherkin::Background
+    pub background: Style,
+
     /// [`Style`] for rendering __bold__.
     pub bold: Style,
 
-    /// Indicates whether the terminal was detected.
+    /// Indicates whether the terminal was detected and the output should be
+    /// styled.
+    ///
+    /// Detection respects the [`NO_COLOR`] environment variable.
+    ///
+    /// [`NO_COLOR`]: https://no-color.org
     pub is_present: bool,
 }
 
 impl Default for Styles {
     fn default() -> Self {
         Self {
-            ok: Style::new().green(),
-            skipped: Style::new().cyan(),
-            err: Style::new().red(),
-            header: Style::new().blue(),
-            bold: Style::new().bold(),
+            ok: styled(Style::new().green()),
+            skipped: styled(Style::new().cyan()),
+            err: styled(Style::new().red()),
+            header: styled(Style::new().blue()),
+            background: styled(Style::new().green()),
+            bold: styled(Style::new().bold()),
             is_present: atty::is(atty::Stream::Stdout)
-                && console::colors_enabled(),
+                && console::colors_enabled()
+                && env::var_os("NO_COLOR").map_or(true, |v| v.is_empty()),
         }
     }
 }
@@ -102,6 +114,20 @@ impl Styles {
         }
     }
 
+    /// If terminal is present colors `input` with [`Styles::background`] color
+    /// or leaves "as is" otherwise.
+    #[must_use]
+    pub fn background<'a>(
+        &self,
+        input: impl Into<Cow<'a, str>>,
+    ) -> Cow<'a, str> {
+        if self.is_present {
+            self.background.apply_to(input.into()).to_string().into()
+        } else {
+            input.into()
+        }
+    }
+
     /// If terminal is present makes `input` __bold__ or leaves "as is"
     /// otherwise.
     #[must_use]
@@ -112,6 +138,106 @@ impl Styles {
             input.into()
         }
     }
+
+    /// Applies the given [`Theme`] to these [`Styles`].
+    pub fn apply_theme(&mut self, theme: Theme) {
+        self.ok = styled(Style::new().fg(theme.passed.into()));
+        self.err = styled(Style::new().fg(theme.failed.into()));
+        self.skipped = styled(Style::new().fg(theme.skipped.into()));
+        self.background = styled(Style::new().fg(theme.background.into()));
+        self.bold = styled(if theme.bold {
+            Style::new().bold()
+        } else {
+            Style::new()
+        });
+    }
+}
+
+/// Forces the given [`Style`] to be always applied, as whether the output
+/// should be styled is decided by [`Styles::is_present`] instead (so
+/// [`Coloring::Always`] works even when no terminal is detected).
+///
+/// [`Coloring::Always`]: crate::writer::Coloring::Always
+const fn styled(style: Style) -> Style {
+    style.force_styling(true)
+}
+
+/// Color theme of a terminal output, remapping the default colors of
+/// [`Styles`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Theme {
+    /// [`Color`] of passed events.
+    pub passed: Color,
+
+    /// [`Color`] of failed events and errors.
+    pub failed: Color,
+
+    /// [`Color`] of skipped events.
+    pub skipped: Color,
+
+    /// [`Color`] of passed [`Background`] events.
+    ///
+    /// [`Background`]: gherkin::Background
+    pub background: Color,
+
+    /// Indicator whether __bold__ text is allowed (like captured values of
+    /// steps).
+    pub bold: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            passed: Color::Basic(console::Color::Green),
+            failed: Color::Basic(console::Color::Red),
+            skipped: Color::Basic(console::Color::Cyan),
+            background: Color::Basic(console::Color::Green),
+            bold: true,
+        }
+    }
+}
+
+/// Color of a [`Theme`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Color {
+    /// One of the basic 8 ANSI colors.
+    Basic(console::Color),
+
+    /// One of the 256 ANSI colors.
+    Ansi256(u8),
+
+    /// 24-bit RGB color.
+    ///
+    /// As [`console`] doesn't support true colors, it's output as the closest
+    /// one of the 256 ANSI colors.
+    Rgb(u8, u8, u8),
+}
+
+impl From<Color> for console::Color {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Basic(c) => c,
+            Color::Ansi256(c) => Self::Color256(c),
+            Color::Rgb(r, g, b) => Self::Color256(rgb_to_ansi256(r, g, b)),
+        }
+    }
+}
+
+/// Approximates the given RGB color with the closest of the 256 ANSI colors:
+/// either from the 24 grayscale ramp or from the 6×6×6 color cube.
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let to_u8 = |v: u16| u8::try_from(v).unwrap_or(u8::MAX);
+
+    if r == g && g == b {
+        return match r {
+            0..=7 => 16,
+            249..=255 => 231,
+            _ => 232 + to_u8((u16::from(r) - 8) * 24 / 247),
+        };
+    }
+
+    let cube = |v: u8| (u16::from(v) * 5 + 127) / 255;
+    to_u8(16 + 36 * cube(r) + 6 * cube(g) + cube(b))
 }
 
 /// [`io::Write`] extension for easier manipulation with strings and special
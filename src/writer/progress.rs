@@ -0,0 +1,399 @@
+// Copyright (c) 2018-2021  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Progress line [`Writer`] implementation.
+
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+    io,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+
+use crate::{
+    cli, event, parser,
+    writer::{self, out::WriteStrExt as _},
+    Event, World, Writer,
+};
+
+/// Default interval between progress lines output by a [`Progress`]
+/// [`Writer`] in a plain (not live) mode.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// [`Writer`] implementation outputting only a single progress line, like
+/// `[43/120] features • 310/900 scenarios • 4 failed`, instead of the verbose
+/// output of each [`Step`], which is handy for large suites.
+///
+/// In a live mode (enabled by default for [`Progress::stdout()`] attached to
+/// a terminal) the line is redrawn in place as events arrive, and cleared on
+/// [`Cucumber::Finished`] event. Otherwise, the line is output as a separate
+/// one whenever the progress changes, but not more often than once per the
+/// [interval][1], and once more on [`Cucumber::Finished`] event.
+///
+/// Arbitrary output (like a summary) is written as is, so this [`Writer`]
+/// should be [`Summarize`]d to output the normal summary at the end:
+/// ```rust
+/// # use std::convert::Infallible;
+/// #
+/// # use async_trait::async_trait;
+/// # use cucumber::{writer, WorldInit, WriterExt as _};
+/// #
+/// # #[derive(Debug, WorldInit)]
+/// # struct MyWorld;
+/// #
+/// # #[async_trait(?Send)]
+/// # impl cucumber::World for MyWorld {
+/// #     type Error = Infallible;
+/// #
+/// #     async fn new() -> Result<Self, Self::Error> {
+/// #         Ok(Self)
+/// #     }
+/// # }
+/// #
+/// # let fut = async {
+/// MyWorld::cucumber()
+///     .with_writer(writer::Progress::stdout().summarized())
+///     .run_and_exit("tests/features/readme")
+///     .await;
+/// # };
+/// #
+/// # futures::executor::block_on(fut);
+/// ```
+///
+/// As only counts are tracked, events may be received in any order, so this
+/// [`Writer`] is [`Normalized`] by itself. However, it's not
+/// [`NonTransforming`], as it doesn't output the received events.
+///
+/// [`Cucumber::Finished`]: event::Cucumber::Finished
+/// [`NonTransforming`]: writer::NonTransforming
+/// [`Normalized`]: writer::Normalized
+/// [`Step`]: gherkin::Step
+/// [`Summarize`]: writer::Summarize
+/// [1]: Progress::interval()
+#[derive(Debug)]
+pub struct Progress<Out: io::Write> {
+    /// [`io::Write`] implementor to output the progress line into.
+    output: Out,
+
+    /// Indicator whether the progress line is redrawn in place.
+    live: bool,
+
+    /// Minimal interval between progress lines in a plain (not live) mode.
+    interval: Duration,
+
+    /// [`Instant`] when the last progress line has been output, if any.
+    output_at: Option<Instant>,
+
+    /// Last output progress line, to avoid outputting the same one again.
+    last_line: String,
+
+    /// Number of parsed [`Feature`]s, once parsing is finished.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    total_features: Option<usize>,
+
+    /// Number of parsed [`Scenario`]s, once parsing is finished.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    total_scenarios: Option<usize>,
+
+    /// Number of finished [`Feature`]s.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    features: usize,
+
+    /// Number of finished [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    scenarios: usize,
+
+    /// Number of failed [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    failed_scenarios: usize,
+
+    /// Number of failed [`Step`]s.
+    ///
+    /// [`Step`]: gherkin::Step
+    failed_steps: usize,
+
+    /// Number of skipped [`Step`]s.
+    ///
+    /// [`Step`]: gherkin::Step
+    skipped_steps: usize,
+
+    /// Number of parsing errors.
+    parsing_errors: usize,
+
+    /// Number of failed hooks.
+    failed_hooks: usize,
+
+    /// Number of [`Scenario`]s skipped due to the run timeout.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    timed_out_scenarios: usize,
+
+    /// Running [`Scenario`]s, which have failed already.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    failing: HashSet<Arc<gherkin::Scenario>>,
+}
+
+#[async_trait(?Send)]
+impl<W: World + Debug, Out: io::Write> Writer<W> for Progress<Out> {
+    type Cli = cli::Empty;
+
+    #[allow(clippy::unused_async)] // false positive: #[async_trait]
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule};
+
+        match ev.as_deref() {
+            Err(_) => self.parsing_errors += 1,
+            Ok(Cucumber::ParsingFinished {
+                features,
+                scenarios,
+                ..
+            }) => {
+                self.total_features = Some(*features);
+                self.total_scenarios = Some(*scenarios);
+            }
+            Ok(Cucumber::Feature(_, ev)) => match ev {
+                Feature::Finished => self.features += 1,
+                Feature::Scenario(sc, ev)
+                | Feature::Rule(_, Rule::Scenario(sc, ev)) => {
+                    self.scenario(sc, ev);
+                }
+                Feature::Started | Feature::Rule(..) => {}
+            },
+            Ok(Cucumber::GlobalHookFailed(..)) => self.failed_hooks += 1,
+            Ok(Cucumber::Finished) => {
+                self.finish();
+                return;
+            }
+            // Nothing is known about the progress yet.
+            Ok(Cucumber::Started) => return,
+        }
+
+        self.update();
+    }
+}
+
+#[async_trait(?Send)]
+impl<'val, W, Val, Out> writer::Arbitrary<'val, W, Val> for Progress<Out>
+where
+    W: World + Debug,
+    Val: AsRef<str> + 'val,
+    Out: io::Write,
+{
+    #[allow(clippy::unused_async)] // false positive: #[async_trait]
+    async fn write(&mut self, val: Val)
+    where
+        'val: 'async_trait,
+    {
+        // Flushing here too, as arbitrary output (like a summary) is usually
+        // written after the `Cucumber::Finished` event.
+        self.output
+            .write_line(val.as_ref())
+            .and_then(|()| self.output.flush())
+            .unwrap_or_else(|e| panic!("Failed to write: {}", e));
+    }
+}
+
+impl<W, Out> writer::Failure<W> for Progress<Out>
+where
+    W: World + Debug,
+    Out: io::Write,
+{
+    fn failed_steps(&self) -> usize {
+        self.failed_steps
+    }
+
+    fn parsing_errors(&self) -> usize {
+        self.parsing_errors
+    }
+
+    fn hook_errors(&self) -> usize {
+        self.failed_hooks
+    }
+
+    fn skipped_steps(&self) -> usize {
+        self.skipped_steps
+    }
+
+    fn timed_out_scenarios(&self) -> usize {
+        self.timed_out_scenarios
+    }
+}
+
+impl<O: io::Write> writer::Normalized for Progress<O> {}
+
+impl<O: io::Write> writer::Summarizable for Progress<O> {}
+
+impl Progress<io::Stdout> {
+    /// Creates a new [`Progress`] [`Writer`] outputting to [`io::Stdout`],
+    /// redrawing the progress line in place if it's attached to a terminal.
+    #[must_use]
+    pub fn stdout() -> Self {
+        Self::new(io::stdout()).live(atty::is(atty::Stream::Stdout))
+    }
+}
+
+impl<Out: io::Write> Progress<Out> {
+    /// Creates a new [`Progress`] [`Writer`] outputting plain progress lines
+    /// to the given `output`.
+    #[must_use]
+    pub fn new(output: Out) -> Self {
+        Self {
+            output,
+            live: false,
+            interval: DEFAULT_INTERVAL,
+            output_at: None,
+            last_line: String::new(),
+            total_features: None,
+            total_scenarios: None,
+            features: 0,
+            scenarios: 0,
+            failed_scenarios: 0,
+            failed_steps: 0,
+            skipped_steps: 0,
+            parsing_errors: 0,
+            failed_hooks: 0,
+            timed_out_scenarios: 0,
+            failing: HashSet::new(),
+        }
+    }
+
+    /// Sets whether the progress line should be redrawn in place (requires a
+    /// terminal), rather than output as separate lines.
+    #[must_use]
+    pub const fn live(mut self, live: bool) -> Self {
+        self.live = live;
+        self
+    }
+
+    /// Sets the minimal interval between progress lines output in a plain
+    /// (not live) mode, being [`DEFAULT_INTERVAL`] by default.
+    #[must_use]
+    pub const fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Keeps track of the given [`event::Scenario`].
+    fn scenario<W>(
+        &mut self,
+        sc: &Arc<gherkin::Scenario>,
+        ev: &event::Scenario<W>,
+    ) {
+        use event::{Hook, Scenario, SkipReason, Step};
+
+        match ev {
+            Scenario::Step(_, Step::Failed(..))
+            | Scenario::Background(_, Step::Failed(..)) => {
+                self.failed_steps += 1;
+                let _ = self.failing.insert(Arc::clone(sc));
+            }
+            Scenario::Hook(_, Hook::Failed(..)) => {
+                self.failed_hooks += 1;
+                let _ = self.failing.insert(Arc::clone(sc));
+            }
+            Scenario::Step(_, Step::Skipped)
+            | Scenario::Background(_, Step::Skipped) => {
+                self.skipped_steps += 1;
+            }
+            Scenario::Skipped(reason) => {
+                if matches!(reason, SkipReason::RunTimeout) {
+                    self.timed_out_scenarios += 1;
+                }
+            }
+            Scenario::Finished => {
+                self.scenarios += 1;
+                if self.failing.remove(sc) {
+                    self.failed_scenarios += 1;
+                }
+            }
+            // Events of failed attempts of a retried `Scenario` aren't
+            // emitted, so only its last attempt is counted.
+            Scenario::Started
+            | Scenario::Retried { .. }
+            | Scenario::Step(..)
+            | Scenario::Background(..)
+            | Scenario::Hook(..)
+            | Scenario::StepHeartbeat(..)
+            | Scenario::ResourceUsage { .. } => {}
+        }
+    }
+
+    /// Renders the current progress line.
+    fn line(&self) -> String {
+        let total = |n: Option<usize>| {
+            n.map_or_else(|| "?".to_owned(), |n| n.to_string())
+        };
+        format!(
+            "[{}/{}] features \u{2022} {}/{} scenarios \u{2022} {} failed",
+            self.features,
+            total(self.total_features),
+            self.scenarios,
+            total(self.total_scenarios),
+            self.failed_scenarios + self.parsing_errors,
+        )
+    }
+
+    /// Outputs the current progress line, if it has changed.
+    fn update(&mut self) {
+        let line = self.line();
+        if line == self.last_line {
+            return;
+        }
+        if !self.live
+            && self
+                .output_at
+                .map_or(false, |at| at.elapsed() < self.interval)
+        {
+            return;
+        }
+
+        self.output_at = Some(Instant::now());
+        let res = if self.live {
+            self.output
+                .clear_line()
+                .and_then(|()| self.output.write_str(&line))
+        } else {
+            self.output.write_line(&line)
+        };
+        res.and_then(|()| self.output.flush())
+            .unwrap_or_else(|e| panic!("Failed to write: {}", e));
+        self.last_line = line;
+    }
+
+    /// Clears the progress line in a live mode, or outputs the final one
+    /// otherwise.
+    fn finish(&mut self) {
+        let res = if self.live {
+            self.output.clear_line()
+        } else {
+            let line = self.line();
+            if line == self.last_line {
+                Ok(())
+            } else {
+                self.output.write_line(line)
+            }
+        };
+        res.and_then(|()| self.output.flush())
+            .unwrap_or_else(|e| panic!("Failed to write: {}", e));
+    }
+}
@@ -10,12 +10,23 @@
 
 //! [`Writer`]-wrapper for re-outputting events at the end of an output.
 
-use std::mem;
+use std::{
+    collections::{HashMap, HashSet},
+    mem,
+    path::PathBuf,
+    sync::Arc,
+};
 
 use async_trait::async_trait;
 use derive_more::Deref;
+use either::Either;
+use futures::future::LocalBoxFuture;
 
-use crate::{event, parser, writer, Event, World, Writer};
+use crate::{
+    event, parser,
+    writer::{self, basic::coerce_error},
+    Event, World, Writer,
+};
 
 /// Alias for a [`fn`] predicate deciding whether an event should be
 /// re-outputted or not.
@@ -29,6 +40,14 @@ pub type FilterEvent<W> =
 ///
 /// An underlying [`Writer`] has to be [`NonTransforming`].
 ///
+/// Repeated failures may be collapsed into a single re-outputted event via
+/// [`Repeat::deduplicate()`], while the same [`Step`] being buffered several
+/// times may be re-outputted only once via [`Repeat::unique()`].
+///
+/// The re-outputted events may be separated from the rest of an output with a
+/// header via [`Repeat::header()`], which requires the underlying [`Writer`]
+/// to be [`writer::Arbitrary`].
+///
 /// [failed]: crate::WriterExt::repeat_failed
 /// [skipped]: crate::WriterExt::repeat_skipped
 /// [`NonTransforming`]: writer::NonTransforming
@@ -44,8 +63,40 @@ pub struct Repeat<W, Wr, F = FilterEvent<W>> {
 
     /// Buffer of collected events for re-outputting.
     events: Vec<parser::Result<Event<event::Cucumber<W>>>>,
+
+    /// Indicator whether duplicate failure events should be collapsed before
+    /// re-outputting.
+    deduplicate: bool,
+
+    /// Indicator whether only the last buffered event of the same [`Step`] (or
+    /// hook) of the same [`Scenario`] should be re-outputted, discarding the
+    /// ones of the previous attempts of [retried][1] [`Scenario`]s.
+    ///
+    /// [1]: event::Scenario::Retried
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    unique: bool,
+
+    /// Buffered events of the currently running [retried][1] [`Scenario`]s,
+    /// if they should be re-outputted once passed.
+    ///
+    /// [1]: event::Scenario::Retried
+    /// [`Scenario`]: gherkin::Scenario
+    retried: Option<HashMap<Arc<gherkin::Scenario>, Vec<RepeatedEvent<W>>>>,
+
+    /// Header to output before the re-outputted events (and a line of the
+    /// same width after them), along with the function outputting it via the
+    /// [`writer::Arbitrary`] implementation of the original [`Writer`].
+    header: Option<(String, WriteHeader<Wr>)>,
 }
 
+/// Alias for a [`fn`] outputting a header of a [`Repeat`] [`Writer`] via the
+/// [`writer::Arbitrary`] implementation of its original [`Writer`].
+type WriteHeader<Wr> = for<'w> fn(&'w mut Wr, String) -> LocalBoxFuture<'w, ()>;
+
+/// Alias for an event re-outputted by a [`Repeat`] [`Writer`].
+type RepeatedEvent<W> = parser::Result<Event<event::Cucumber<W>>>;
+
 #[async_trait(?Send)]
 impl<W, Wr, F> Writer<W> for Repeat<W, Wr, F>
 where
@@ -60,9 +111,19 @@ where
         event: parser::Result<Event<event::Cucumber<W>>>,
         cli: &Self::Cli,
     ) {
+        if self.unique {
+            if let Some(retried) = retried_scenario(&event) {
+                self.events.retain(|ev| {
+                    scenario_identity(ev).as_ref() != Some(&retried)
+                });
+            }
+        }
         if (self.filter)(&event) {
             self.events.push(event.clone());
         }
+        if let Some(retried) = self.retried.as_mut() {
+            collect_retried(retried, &mut self.events, &event);
+        }
 
         let is_finished =
             matches!(event.as_deref(), Ok(event::Cucumber::Finished));
@@ -70,9 +131,24 @@ where
         self.writer.handle_event(event, cli).await;
 
         if is_finished {
-            for ev in mem::take(&mut self.events) {
+            let mut events = mem::take(&mut self.events);
+            if self.unique {
+                events = keep_unique(events);
+            }
+            if self.deduplicate {
+                events = deduplicate(events);
+            }
+            let header = self.header.as_ref().filter(|_| !events.is_empty());
+            if let Some((text, write)) = header {
+                write(&mut self.writer, text.clone()).await;
+            }
+            for ev in events {
                 self.writer.handle_event(ev, cli).await;
             }
+            if let Some((text, write)) = header {
+                let footer = "=".repeat(text.chars().count());
+                write(&mut self.writer, footer).await;
+            }
         }
     }
 }
@@ -109,6 +185,22 @@ where
     fn hook_errors(&self) -> usize {
         self.writer.hook_errors()
     }
+
+    fn skipped_steps(&self) -> usize {
+        self.writer.skipped_steps()
+    }
+
+    fn passed_steps(&self) -> usize {
+        self.writer.passed_steps()
+    }
+
+    fn not_executed_steps(&self) -> usize {
+        self.writer.not_executed_steps()
+    }
+
+    fn timed_out_scenarios(&self) -> usize {
+        self.writer.timed_out_scenarios()
+    }
 }
 
 impl<W, Wr: writer::Normalized, F> writer::Normalized for Repeat<W, Wr, F> {}
@@ -124,8 +216,64 @@ impl<W, Wr, F> Repeat<W, Wr, F> {
             writer,
             filter,
             events: Vec::new(),
+            deduplicate: false,
+            unique: false,
+            retried: None,
+            header: None,
         }
     }
+
+    /// Makes this [`Writer`] collapse duplicate failures (having the same
+    /// [`Step`] text and panic message) into a single re-outputted event,
+    /// with the number of occurrences appended to its message (`× 5`).
+    ///
+    /// Disabled by default.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub const fn deduplicate(mut self, enabled: bool) -> Self {
+        self.deduplicate = enabled;
+        self
+    }
+
+    /// Makes this [`Writer`] re-output only the last buffered event of the
+    /// same [`Step`] (or hook) of the same [`Scenario`], identified by their
+    /// positions in a [`Feature`] rather than their text, so distinct
+    /// [`Step`]s with the same wording are still re-outputted.
+    ///
+    /// Events buffered for the previous attempts of a [retried][1]
+    /// [`Scenario`] are discarded too, so only its final attempt is
+    /// re-outputted, even if a custom [`Runner`] emits the failed ones.
+    ///
+    /// Disabled by default.
+    ///
+    /// [1]: event::Scenario::Retried
+    /// [`Feature`]: gherkin::Feature
+    /// [`Runner`]: crate::Runner
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub const fn unique(mut self, enabled: bool) -> Self {
+        self.unique = enabled;
+        self
+    }
+
+    /// Makes this [`Writer`] output the given `header` before the
+    /// re-outputted events, and a line of `=` of the same width after them,
+    /// so it's clear where the repeated section begins and ends.
+    ///
+    /// Nothing is output if there are no events to re-output.
+    ///
+    /// The original [`Writer`] has to be [`writer::Arbitrary`] to output the
+    /// `header`, so this method doesn't compile for the ones that aren't.
+    #[must_use]
+    pub fn header(mut self, header: impl Into<String>) -> Self
+    where
+        Wr: for<'val> writer::Arbitrary<'val, W, String>,
+    {
+        self.header = Some((header.into(), |w, h| w.write(h)));
+        self
+    }
 }
 
 impl<W, Wr> Repeat<W, Wr> {
@@ -150,16 +298,22 @@ impl<W, Wr> Repeat<W, Wr> {
                                 _,
                                 Scenario::Step(_, Step::Skipped)
                                     | Scenario::Background(_, Step::Skipped)
+                                    | Scenario::Skipped(_)
                             )
                         ) | Feature::Scenario(
                             _,
                             Scenario::Step(_, Step::Skipped)
                                 | Scenario::Background(_, Step::Skipped)
+                                | Scenario::Skipped(_)
                         )
                     )),
                 )
             },
             events: Vec::new(),
+            deduplicate: false,
+            unique: false,
+            retried: None,
+            header: None,
         }
     }
 
@@ -197,6 +351,302 @@ impl<W, Wr> Repeat<W, Wr> {
                 )
             },
             events: Vec::new(),
+            deduplicate: false,
+            unique: false,
+            retried: None,
+            header: None,
+        }
+    }
+
+    /// Creates a [`Writer`] for re-outputting [`Failed`] events and [`Parser`]
+    /// errors at the end of an output, each [`Step`] (or hook) of a
+    /// [`Scenario`] only once.
+    ///
+    /// Shortcut for [`Repeat::failed()`] with [`Repeat::unique()`] enabled.
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`Parser`]: crate::Parser
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn failed_unique(writer: Wr) -> Self {
+        Self::failed(writer).unique(true)
+    }
+
+    /// Creates a [`Writer`] for re-outputting [`Failed`] events and [`Parser`]
+    /// errors at the end of an output, separated from the rest of it with the
+    /// given `header`.
+    ///
+    /// Shortcut for [`Repeat::failed()`] with [`Repeat::header()`] set, so
+    /// requires the given `writer` to be [`writer::Arbitrary`].
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`Parser`]: crate::Parser
+    #[must_use]
+    pub fn with_header(writer: Wr, header: impl Into<String>) -> Self
+    where
+        Wr: for<'val> writer::Arbitrary<'val, W, String>,
+    {
+        Self::failed(writer).header(header)
+    }
+
+    /// Creates a [`Writer`] for re-outputting [`Scenario`]s, which have been
+    /// [retried][1] and eventually passed, at the end of an output.
+    ///
+    /// All the events of such [`Scenario`] are re-outputted, starting from its
+    /// [retries][1] and up to its [`Scenario::Finished`].
+    ///
+    /// [1]: event::Scenario::Retried
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Scenario::Finished`]: event::Scenario::Finished
+    #[must_use]
+    pub fn retried(writer: Wr) -> Self {
+        Self {
+            writer,
+            filter: |_| false,
+            events: Vec::new(),
+            deduplicate: false,
+            unique: false,
+            retried: Some(HashMap::new()),
+            header: None,
+        }
+    }
+}
+
+/// Identity of a [`Scenario`] by the path of its [`Feature`] and its position.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Scenario`]: gherkin::Scenario
+type ScenarioIdentity = (Option<PathBuf>, usize, usize);
+
+/// Identity of a re-outputted event by its [`ScenarioIdentity`], and either
+/// the position of its [`Step`] (with an indicator whether it's a
+/// [`Background`] one) or the type of its hook.
+///
+/// [`Background`]: gherkin::Background
+/// [`Step`]: gherkin::Step
+type EventIdentity = (ScenarioIdentity, Either<(bool, usize, usize), String>);
+
+/// Returns the [`ScenarioIdentity`] of the given event, if it's a
+/// [`Scenario`] one.
+///
+/// [`Scenario`]: gherkin::Scenario
+fn scenario_identity<W>(event: &RepeatedEvent<W>) -> Option<ScenarioIdentity> {
+    use event::{Cucumber, Feature, Rule};
+
+    match event.as_deref() {
+        Ok(Cucumber::Feature(
+            f,
+            Feature::Rule(_, Rule::Scenario(sc, _)) | Feature::Scenario(sc, _),
+        )) => Some((f.path.clone(), sc.position.line, sc.position.col)),
+        _ => None,
+    }
+}
+
+/// Returns the [`ScenarioIdentity`] of the given event, if it's a
+/// [`Scenario::Retried`] one.
+///
+/// [`Scenario::Retried`]: event::Scenario::Retried
+fn retried_scenario<W>(event: &RepeatedEvent<W>) -> Option<ScenarioIdentity> {
+    use event::{Cucumber, Feature, Rule, Scenario};
+
+    match event.as_deref() {
+        Ok(Cucumber::Feature(
+            _,
+            Feature::Rule(_, Rule::Scenario(_, Scenario::Retried { .. }))
+            | Feature::Scenario(_, Scenario::Retried { .. }),
+        )) => scenario_identity(event),
+        _ => None,
+    }
+}
+
+/// Returns the [`EventIdentity`] of the given event, if it's a [`Step`] or a
+/// hook one.
+///
+/// [`Step`]: gherkin::Step
+fn event_identity<W>(event: &RepeatedEvent<W>) -> Option<EventIdentity> {
+    use event::{Cucumber, Feature, Rule, Scenario};
+
+    let item = match event.as_deref() {
+        Ok(Cucumber::Feature(
+            _,
+            Feature::Rule(_, Rule::Scenario(_, ev)) | Feature::Scenario(_, ev),
+        )) => match ev {
+            // Captured output precedes the failure of the same `Step`.
+            Scenario::Step(_, event::Step::Output(_))
+            | Scenario::Background(_, event::Step::Output(_))
+            | Scenario::Started
+            | Scenario::Finished
+            | Scenario::Retried { .. }
+            | Scenario::Skipped(_)
+            | Scenario::StepHeartbeat(..)
+            | Scenario::ResourceUsage { .. } => return None,
+            Scenario::Step(st, _) => {
+                Either::Left((false, st.position.line, st.position.col))
+            }
+            Scenario::Background(st, _) => {
+                Either::Left((true, st.position.line, st.position.col))
+            }
+            Scenario::Hook(ty, _) => Either::Right(ty.to_string()),
+        },
+        _ => return None,
+    };
+    Some((scenario_identity(event)?, item))
+}
+
+/// Keeps only the last one of the given events having the same
+/// [`EventIdentity`], preserving the order of the kept ones.
+fn keep_unique<W>(events: Vec<RepeatedEvent<W>>) -> Vec<RepeatedEvent<W>> {
+    let mut seen = HashSet::new();
+    let mut unique = events
+        .into_iter()
+        .rev()
+        .filter(|ev| event_identity(ev).map_or(true, |id| seen.insert(id)))
+        .collect::<Vec<_>>();
+    unique.reverse();
+    unique
+}
+
+/// Buffers the given `event` into the `scenarios` if it belongs to a
+/// [retried][1] [`Scenario`], moving all the buffered events of the
+/// [`Scenario`] into the `passed` ones once it finishes successfully.
+///
+/// As failed attempts are never emitted by a [`Runner`], all the events
+/// following the [retries][1] of a [`Scenario`] belong to its last attempt.
+///
+/// [1]: event::Scenario::Retried
+/// [`Runner`]: crate::Runner
+/// [`Scenario`]: gherkin::Scenario
+fn collect_retried<W>(
+    scenarios: &mut HashMap<Arc<gherkin::Scenario>, Vec<RepeatedEvent<W>>>,
+    passed: &mut Vec<RepeatedEvent<W>>,
+    event: &RepeatedEvent<W>,
+) {
+    use event::{Cucumber, Feature, Rule, Scenario};
+
+    let (sc, ev) = match event.as_deref() {
+        Ok(Cucumber::Feature(
+            _,
+            Feature::Rule(_, Rule::Scenario(sc, ev))
+            | Feature::Scenario(sc, ev),
+        )) => (sc, ev),
+        _ => return,
+    };
+
+    if matches!(ev, Scenario::Retried { .. }) {
+        scenarios
+            .entry(Arc::clone(sc))
+            .or_default()
+            .push(event.clone());
+        return;
+    }
+    let buffered = match scenarios.get_mut(sc) {
+        Some(buffered) => buffered,
+        None => return,
+    };
+    buffered.push(event.clone());
+
+    if matches!(ev, Scenario::Finished) {
+        let events = scenarios.remove(sc).unwrap_or_default();
+        let has_failed = events.iter().any(|e| {
+            matches!(
+                e.as_deref(),
+                Ok(Cucumber::Feature(
+                    _,
+                    Feature::Rule(_, Rule::Scenario(_, attempt_ev))
+                    | Feature::Scenario(_, attempt_ev),
+                )) if is_failure(attempt_ev),
+            )
+        });
+        if !has_failed {
+            passed.extend(events);
+        }
+    }
+}
+
+/// Checks whether the given [`event::Scenario`] fails its [`Scenario`].
+///
+/// [`Scenario`]: gherkin::Scenario
+const fn is_failure<W>(ev: &event::Scenario<W>) -> bool {
+    use event::{Hook, Scenario, Step};
+
+    matches!(
+        ev,
+        Scenario::Step(_, Step::Failed(..) | Step::Skipped)
+            | Scenario::Background(_, Step::Failed(..) | Step::Skipped)
+            | Scenario::Hook(_, Hook::Failed(..))
+            | Scenario::Skipped(_),
+    )
+}
+
+/// Collapses the given `events` having the same [`failure_of()`] key into the
+/// first one of them, appending the number of occurrences to its message.
+///
+/// Events without a [`failure_of()`] key are left intact.
+fn deduplicate<W>(
+    events: Vec<parser::Result<Event<event::Cucumber<W>>>>,
+) -> Vec<parser::Result<Event<event::Cucumber<W>>>> {
+    let mut unique = Vec::<(_, usize)>::with_capacity(events.len());
+    let mut seen = HashMap::<_, usize>::new();
+    for mut ev in events {
+        let key = ev.as_mut().ok().and_then(|ev| {
+            failure_of(ev).map(|(subject, info)| (subject, coerce_error(info)))
+        });
+        if let Some(key) = key {
+            if let Some(&i) = seen.get(&key) {
+                unique[i].1 += 1;
+                continue;
+            }
+            let _ = seen.insert(key, unique.len());
+        }
+        unique.push((ev, 1));
+    }
+
+    unique
+        .into_iter()
+        .map(|(mut ev, count)| {
+            if count > 1 {
+                if let Some((_, info)) =
+                    ev.as_mut().ok().and_then(|ev| failure_of(ev))
+                {
+                    *info =
+                        Arc::new(format!("{} × {}", coerce_error(info), count));
+                }
+            }
+            ev
+        })
+        .collect()
+}
+
+/// Extracts the failed [`Step`] text (or the failed hook type) along with its
+/// panic [`Info`] out of the given event, if it represents a failure.
+///
+/// [`Info`]: event::Info
+/// [`Step`]: gherkin::Step
+fn failure_of<W>(
+    ev: &mut event::Cucumber<W>,
+) -> Option<(String, &mut event::Info)> {
+    use event::{Cucumber, Feature, Hook, Rule, Scenario, Step, StepError};
+
+    let ev = if let Cucumber::Feature(
+        _,
+        Feature::Scenario(_, ev) | Feature::Rule(_, Rule::Scenario(_, ev)),
+    ) = ev
+    {
+        ev
+    } else {
+        return None;
+    };
+    if let Scenario::Step(st, ev) | Scenario::Background(st, ev) = ev {
+        if let Step::Failed(_, _, StepError::Panic(info))
+        | Step::SoftFailed(_, StepError::Panic(info)) = ev
+        {
+            return Some((st.value.clone(), info));
         }
+        None
+    } else if let Scenario::Hook(ty, Hook::Failed(_, info)) = ev {
+        Some((format!("{} hook", ty), info))
+    } else {
+        None
     }
 }
@@ -0,0 +1,228 @@
+// Copyright (c) 2018-2021  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Routing [`Scenario`] events to different [`Writer`]s by their tags.
+//!
+//! [`Scenario`]: gherkin::Scenario
+
+use async_trait::async_trait;
+
+use crate::{event, parser, tag, writer, Event, World, Writer};
+
+/// Wrapper routing [`Scenario`] events to different [`Writer`]s (like
+/// per-team report files) based on the [`Scenario`]'s tags.
+///
+/// Each [`Scenario`] is routed to the [`Writer`] of the first configured tag
+/// it has (including ones inherited from its [`Feature`] and [`Rule`], unless
+/// another [`tag::Inheritance`] policy is [configured][0] for the run), or to
+/// the default [`Writer`], if it has none of them. Parsing errors are passed
+/// to the default [`Writer`] only, while all other events are passed to every
+/// [`Writer`].
+///
+/// # Example
+///
+/// ```rust
+/// # use std::{convert::Infallible, fs};
+/// #
+/// # use async_trait::async_trait;
+/// # use cucumber::{writer, WorldInit, WriterExt as _};
+/// #
+/// # #[derive(Debug, WorldInit)]
+/// # struct MyWorld;
+/// #
+/// # #[async_trait(?Send)]
+/// # impl cucumber::World for MyWorld {
+/// #     type Error = Infallible;
+/// #
+/// #     async fn new() -> Result<Self, Self::Error> {
+/// #         Ok(Self)
+/// #     }
+/// # }
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> std::io::Result<()> {
+/// # let dir = tempfile::tempdir()?;
+/// # let file = |name| fs::File::create(dir.path().join(name));
+/// let basic = |out| writer::Basic::raw(out, writer::Coloring::Never, false);
+/// MyWorld::cucumber()
+///     .with_writer(
+///         writer::Route::new(basic(file("other.log")?))
+///             .route("cat", basic(file("cats.log")?))
+///             .route("dog", basic(file("dogs.log")?))
+///             .normalized(),
+///     )
+///     .run("tests/features/readme")
+///     .await;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [0]: crate::Cucumber::tag_inheritance()
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug)]
+pub struct Route<Wr> {
+    /// [`Writer`]s along with the tags routed to them, in the order of
+    /// priority.
+    routes: Vec<(String, Wr)>,
+
+    /// [`Writer`] receiving [`Scenario`]s having none of the routed tags.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    default: Wr,
+
+    /// [`tag::Inheritance`] policy of computing effective tags of
+    /// [`Scenario`]s to route them by, as received with the
+    /// [`Cucumber::ParsingFinished`] event.
+    ///
+    /// [`Cucumber::ParsingFinished`]: event::Cucumber::ParsingFinished
+    /// [`Scenario`]: gherkin::Scenario
+    tag_inheritance: tag::Inheritance,
+}
+
+impl<Wr> Route<Wr> {
+    /// Creates a new [`Route`] [`Writer`] passing all the [`Scenario`]s to the
+    /// `default` [`Writer`], until some routes are added via
+    /// [`Route::route()`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn new(default: Wr) -> Self {
+        Self {
+            routes: Vec::new(),
+            default,
+            tag_inheritance: tag::Inheritance::Inherit,
+        }
+    }
+
+    /// Routes [`Scenario`]s having the given `tag` (without a leading `@`) to
+    /// the provided [`Writer`].
+    ///
+    /// Routes added earlier take priority over the later ones.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn route(mut self, tag: impl Into<String>, writer: Wr) -> Self {
+        self.routes.push((tag.into(), writer));
+        self
+    }
+
+    /// Returns the [`Writer`] the given [`Scenario`] should be routed to.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn writer_for(
+        &mut self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+    ) -> &mut Wr {
+        let tags = self.tag_inheritance.tags(feature, rule, scenario);
+        let routed = self
+            .routes
+            .iter_mut()
+            .find(|(tag, _)| tags.contains(&tag.as_str()));
+        match routed {
+            Some((_, writer)) => writer,
+            None => &mut self.default,
+        }
+    }
+
+    /// Returns an [`Iterator`] over all the [`Writer`]s of this [`Route`],
+    /// including the default one.
+    fn writers(&self) -> impl Iterator<Item = &Wr> {
+        self.routes
+            .iter()
+            .map(|(_, writer)| writer)
+            .chain(Some(&self.default))
+    }
+}
+
+#[async_trait(?Send)]
+impl<W, Wr> Writer<W> for Route<Wr>
+where
+    W: World,
+    Wr: Writer<W>,
+{
+    type Cli = Wr::Cli;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+        cli: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule};
+
+        if let Ok(Cucumber::ParsingFinished {
+            tag_inheritance, ..
+        }) = ev.as_deref()
+        {
+            self.tag_inheritance = *tag_inheritance;
+        }
+
+        let writer = match ev.as_deref() {
+            Ok(Cucumber::Feature(f, Feature::Scenario(sc, _))) => {
+                self.writer_for(f, None, sc)
+            }
+            Ok(Cucumber::Feature(
+                f,
+                Feature::Rule(r, Rule::Scenario(sc, _)),
+            )) => self.writer_for(f, Some(r), sc),
+            Err(_) => &mut self.default,
+            Ok(_) => {
+                for (_, writer) in &mut self.routes {
+                    writer.handle_event(ev.clone(), cli).await;
+                }
+                &mut self.default
+            }
+        };
+        writer.handle_event(ev, cli).await;
+    }
+}
+
+impl<W, Wr> writer::Failure<W> for Route<Wr>
+where
+    Wr: writer::Failure<W>,
+    Self: Writer<W>,
+{
+    fn failed_steps(&self) -> usize {
+        self.writers().map(writer::Failure::failed_steps).sum()
+    }
+
+    fn parsing_errors(&self) -> usize {
+        self.writers().map(writer::Failure::parsing_errors).sum()
+    }
+
+    fn hook_errors(&self) -> usize {
+        self.writers().map(writer::Failure::hook_errors).sum()
+    }
+
+    fn skipped_steps(&self) -> usize {
+        self.writers().map(writer::Failure::skipped_steps).sum()
+    }
+
+    fn passed_steps(&self) -> usize {
+        self.writers().map(writer::Failure::passed_steps).sum()
+    }
+
+    fn not_executed_steps(&self) -> usize {
+        self.writers()
+            .map(writer::Failure::not_executed_steps)
+            .sum()
+    }
+
+    fn timed_out_scenarios(&self) -> usize {
+        self.writers()
+            .map(writer::Failure::timed_out_scenarios)
+            .sum()
+    }
+}
+
+impl<Wr: writer::Normalized> writer::Normalized for Route<Wr> {}
@@ -10,7 +10,11 @@
 
 //! [`Writer`]-wrapper for collecting a summary of execution.
 
-use std::{borrow::Cow, collections::HashMap, sync::Arc};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
 
 use async_trait::async_trait;
 use derive_more::Deref;
@@ -146,11 +150,84 @@ pub struct Summarize<Writer> {
     /// [`Parser`]: crate::Parser
     pub parsing_errors: usize,
 
-    /// Number of failed [`Scenario`] hooks.
+    /// Number of failed [`Scenario`] hooks, including the ones executed once
+    /// for the whole run (see [`Cucumber::before_all()`]).
     ///
+    /// [`Cucumber::before_all()`]: crate::Cucumber::before_all()
     /// [`Scenario`]: gherkin::Scenario
     pub failed_hooks: usize,
 
+    /// Number of [`Scenario`]s interrupted by the [`Cucumber::run_timeout()`].
+    ///
+    /// [`Cucumber::run_timeout()`]: crate::Cucumber::run_timeout()
+    /// [`Scenario`]: gherkin::Scenario
+    pub timed_out_scenarios: usize,
+
+    /// Number of [soft] [`Step`]s that failed.
+    ///
+    /// Such [`Step`]s are counted as passed in the [`Summarize::steps`], as
+    /// they don't fail their [`Scenario`].
+    ///
+    /// [soft]: crate::step::Collection::soft()
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub soft_failed_steps: usize,
+
+    /// Number of [`Scenario`]s not executed at all, as the run was aborted
+    /// early (see [`Cucumber::fail_fast()`]).
+    ///
+    /// Known only once the [`Cucumber::Finished`] event is encountered.
+    ///
+    /// [`Cucumber::Finished`]: event::Cucumber::Finished
+    /// [`Cucumber::fail_fast()`]: crate::Cucumber::fail_fast()
+    /// [`Scenario`]: gherkin::Scenario
+    pub not_executed_scenarios: usize,
+
+    /// Number of [`Step`]s not executed at all, as a previous [`Step`] or
+    /// hook of their [`Scenario`] has failed or was skipped, or the whole
+    /// [`Scenario`] was skipped.
+    ///
+    /// Such [`Step`]s aren't counted in the [`Summarize::steps`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub not_executed_steps: usize,
+
+    /// Seed the [`Scenario`]s were shuffled with, if they were (see
+    /// [`Cucumber::shuffle()`]).
+    ///
+    /// [`Cucumber::shuffle()`]: crate::Cucumber::shuffle()
+    /// [`Scenario`]: gherkin::Scenario
+    pub shuffle_seed: Option<u64>,
+
+    /// Indicator whether the run was a dry run (see [`Cucumber::dry_run()`]).
+    ///
+    /// During a dry run, every skipped (unmatched) [`Step`] counts as a
+    /// failed one in the [`writer::Failure`] implementation.
+    ///
+    /// [`Cucumber::dry_run()`]: crate::Cucumber::dry_run()
+    /// [`Step`]: gherkin::Step
+    pub dry_run: bool,
+
+    /// [`Scenario`]s [`Stats`] grouped by each of their tags (including the
+    /// ones inherited from their [`Feature`] and [`Rule`]), if enabled via
+    /// [`Summarize::tag_breakdown()`].
+    ///
+    /// Tags are stored without the leading `@`, and [`Scenario`]s without any
+    /// tags aren't counted here.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    pub tags: Option<BTreeMap<String, Stats>>,
+
+    /// Total number of [`Scenario`]s to be executed, as reported by the
+    /// [`Cucumber::ParsingFinished`] event.
+    ///
+    /// [`Cucumber::ParsingFinished`]: event::Cucumber::ParsingFinished
+    /// [`Scenario`]: gherkin::Scenario
+    parsed_scenarios: Option<usize>,
+
     /// Current [`State`] of this [`Writer`].
     state: State,
 
@@ -158,6 +235,14 @@ pub struct Summarize<Writer> {
     ///
     /// [`Scenario`]: gherkin::Scenario
     handled_scenarios: HashMap<Arc<gherkin::Scenario>, Indicator>,
+
+    /// Numbers of executed [`Step`]s of the [`Scenario`]s being run, to count
+    /// the [not executed][0] ones once they're finished.
+    ///
+    /// [0]: Summarize::not_executed_steps
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    executed_steps: HashMap<Arc<gherkin::Scenario>, usize>,
 }
 
 #[async_trait(?Send)]
@@ -182,20 +267,41 @@ where
         if let State::InProgress = self.state {
             match ev.as_deref() {
                 Err(_) => self.parsing_errors += 1,
-                Ok(Cucumber::Feature(_, ev)) => match ev {
+                Ok(Cucumber::Feature(f, ev)) => match ev {
                     Feature::Started => self.features += 1,
                     Feature::Rule(_, Rule::Started) => {
                         self.rules += 1;
                     }
-                    Feature::Rule(_, Rule::Scenario(sc, ev))
-                    | Feature::Scenario(sc, ev) => {
+                    Feature::Rule(r, Rule::Scenario(sc, ev)) => {
+                        self.handle_tags(f, Some(r), sc, ev);
+                        self.handle_executed_steps(f, Some(r), sc, ev);
+                        self.handle_scenario(sc, ev);
+                    }
+                    Feature::Scenario(sc, ev) => {
+                        self.handle_tags(f, None, sc, ev);
+                        self.handle_executed_steps(f, None, sc, ev);
                         self.handle_scenario(sc, ev);
                     }
                     Feature::Finished | Feature::Rule(..) => {}
                 },
                 Ok(Cucumber::Finished) => {
+                    self.not_executed_scenarios =
+                        self.parsed_scenarios.map_or(0, |n| {
+                            n.saturating_sub(self.scenarios.total())
+                        });
                     self.state = State::FinishedButNotOutput;
                 }
+                Ok(Cucumber::ParsingFinished {
+                    scenarios,
+                    shuffle_seed,
+                    dry_run,
+                    ..
+                }) => {
+                    self.parsed_scenarios = Some(*scenarios);
+                    self.shuffle_seed = *shuffle_seed;
+                    self.dry_run = *dry_run;
+                }
+                Ok(Cucumber::GlobalHookFailed(..)) => self.failed_hooks += 1,
                 Ok(Cucumber::Started) => {}
             };
         }
@@ -231,7 +337,12 @@ where
     Self: Writer<W>,
 {
     fn failed_steps(&self) -> usize {
-        self.steps.failed
+        // Unmatched steps are the only possible failures of a dry run.
+        if self.dry_run {
+            self.steps.failed + self.steps.skipped
+        } else {
+            self.steps.failed
+        }
     }
 
     fn parsing_errors(&self) -> usize {
@@ -241,6 +352,22 @@ where
     fn hook_errors(&self) -> usize {
         self.failed_hooks
     }
+
+    fn skipped_steps(&self) -> usize {
+        self.steps.skipped
+    }
+
+    fn passed_steps(&self) -> usize {
+        self.steps.passed
+    }
+
+    fn not_executed_steps(&self) -> usize {
+        self.not_executed_steps
+    }
+
+    fn timed_out_scenarios(&self) -> usize {
+        self.timed_out_scenarios
+    }
 }
 
 impl<Wr: writer::Normalized> writer::Normalized for Summarize<Wr> {}
@@ -265,8 +392,17 @@ impl<Writer> From<Writer> for Summarize<Writer> {
             },
             parsing_errors: 0,
             failed_hooks: 0,
+            timed_out_scenarios: 0,
+            soft_failed_steps: 0,
+            not_executed_scenarios: 0,
+            not_executed_steps: 0,
+            shuffle_seed: None,
+            dry_run: false,
+            tags: None,
+            parsed_scenarios: None,
             state: State::InProgress,
             handled_scenarios: HashMap::new(),
+            executed_steps: HashMap::new(),
         }
     }
 }
@@ -286,20 +422,42 @@ impl<Writer> Summarize<Writer> {
         };
 
         match ev {
-            Step::Started => {}
-            Step::Passed(_) => self.steps.passed += 1,
+            Step::Started | Step::Output(_) => {}
+            // Matched `Step`s of a dry run don't fail their `Scenario`.
+            Step::Passed(_) | Step::Matched(_) => self.steps.passed += 1,
+            Step::SoftFailed(..) => {
+                self.steps.passed += 1;
+                self.soft_failed_steps += 1;
+            }
+            // A dry run keeps matching `Step`s after an unmatched or ambiguous
+            // one, so a `Scenario` should be counted only once.
             Step::Skipped => {
                 self.steps.skipped += 1;
-                self.scenarios.skipped += 1;
-                let _ = self
-                    .handled_scenarios
-                    .insert(Arc::clone(scenario), Skipped);
+                if !self.handled_scenarios.contains_key(scenario) {
+                    self.scenarios.skipped += 1;
+                    let _ = self
+                        .handled_scenarios
+                        .insert(Arc::clone(scenario), Skipped);
+                }
             }
             Step::Failed(..) => {
                 self.steps.failed += 1;
-                self.scenarios.failed += 1;
-                let _ =
-                    self.handled_scenarios.insert(Arc::clone(scenario), Failed);
+                match self.handled_scenarios.get(scenario) {
+                    Some(Failed) => {}
+                    Some(Skipped) => {
+                        self.scenarios.skipped -= 1;
+                        self.scenarios.failed += 1;
+                        let _ = self
+                            .handled_scenarios
+                            .insert(Arc::clone(scenario), Failed);
+                    }
+                    None => {
+                        self.scenarios.failed += 1;
+                        let _ = self
+                            .handled_scenarios
+                            .insert(Arc::clone(scenario), Failed);
+                    }
+                }
             }
         }
     }
@@ -316,6 +474,9 @@ impl<Writer> Summarize<Writer> {
 
         match ev {
             Scenario::Started
+            | Scenario::Retried { .. }
+            | Scenario::StepHeartbeat(..)
+            | Scenario::ResourceUsage { .. }
             | Scenario::Hook(_, Hook::Passed | Hook::Started) => {}
             Scenario::Hook(_, Hook::Failed(..)) => {
                 // - If Scenario's last Step failed and then After Hook failed
@@ -342,6 +503,15 @@ impl<Writer> Summarize<Writer> {
             Scenario::Background(_, ev) | Scenario::Step(_, ev) => {
                 self.handle_step(scenario, ev);
             }
+            Scenario::Skipped(reason) => {
+                if matches!(reason, event::SkipReason::RunTimeout) {
+                    self.timed_out_scenarios += 1;
+                }
+                self.scenarios.skipped += 1;
+                let _ = self
+                    .handled_scenarios
+                    .insert(Arc::clone(scenario), Indicator::Skipped);
+            }
             Scenario::Finished => {
                 if self.handled_scenarios.remove(scenario).is_none() {
                     self.scenarios.passed += 1;
@@ -349,6 +519,93 @@ impl<Writer> Summarize<Writer> {
             }
         }
     }
+
+    /// Keeps track of the [`Step`]s of the [`Scenario`] not executed at all.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    fn handle_executed_steps<W>(
+        &mut self,
+        feature: &gherkin::Feature,
+        rule: Option<&Arc<gherkin::Rule>>,
+        scenario: &Arc<gherkin::Scenario>,
+        ev: &event::Scenario<W>,
+    ) {
+        use event::{Scenario, Step};
+
+        match ev {
+            Scenario::Background(_, Step::Started | Step::Output(_))
+            | Scenario::Step(_, Step::Started | Step::Output(_))
+            | Scenario::Started
+            | Scenario::Hook(..)
+            | Scenario::Skipped(_)
+            | Scenario::StepHeartbeat(..)
+            | Scenario::ResourceUsage { .. } => {}
+            Scenario::Background(..) | Scenario::Step(..) => {
+                *self
+                    .executed_steps
+                    .entry(Arc::clone(scenario))
+                    .or_default() += 1;
+            }
+            // Only the last attempt of a retried `Scenario` is counted.
+            Scenario::Retried { .. } => {
+                let _ = self.executed_steps.remove(scenario);
+            }
+            Scenario::Finished => {
+                let executed =
+                    self.executed_steps.remove(scenario).unwrap_or_default();
+                let total = feature
+                    .background
+                    .iter()
+                    .chain(rule.and_then(|r| r.background.as_ref()))
+                    .map(|bg| bg.steps.len())
+                    .sum::<usize>()
+                    + scenario.steps.len();
+                self.not_executed_steps += total.saturating_sub(executed);
+            }
+        }
+    }
+
+    /// Keeps track of [`Scenario`]'s [`Stats`] grouped by its tags, if
+    /// [enabled][1].
+    ///
+    /// Should be called before [`Summarize::handle_scenario()`], as the outcome
+    /// of a finished [`Scenario`] is taken from the handled ones.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [1]: Summarize::tag_breakdown()
+    fn handle_tags<W>(
+        &mut self,
+        feature: &gherkin::Feature,
+        rule: Option<&Arc<gherkin::Rule>>,
+        scenario: &Arc<gherkin::Scenario>,
+        ev: &event::Scenario<W>,
+    ) {
+        let tags = match self.tags.as_mut() {
+            Some(tags) if matches!(ev, event::Scenario::Finished) => tags,
+            _ => return,
+        };
+        let outcome = self.handled_scenarios.get(scenario).copied();
+
+        let inherited = feature
+            .tags
+            .iter()
+            .chain(rule.iter().flat_map(|r| &r.tags))
+            .chain(&scenario.tags)
+            .unique();
+        for tag in inherited {
+            let stats = tags.entry(tag.clone()).or_insert(Stats {
+                passed: 0,
+                skipped: 0,
+                failed: 0,
+            });
+            match outcome {
+                None => stats.passed += 1,
+                Some(Indicator::Skipped) => stats.skipped += 1,
+                Some(Indicator::Failed) => stats.failed += 1,
+            }
+        }
+    }
 }
 
 impl<Writer> Summarize<Writer> {
@@ -357,6 +614,22 @@ impl<Writer> Summarize<Writer> {
     pub fn new(writer: Writer) -> Self {
         Self::from(writer)
     }
+
+    /// Makes this [`Writer`] collect [`Scenario`]s [`Stats`] grouped by each
+    /// of their tags (including the ones inherited from their [`Feature`] and
+    /// [`Rule`]) into [`Summarize::tags`], and output them as a table in the
+    /// summary.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn tag_breakdown(mut self, enabled: bool) -> Self {
+        self.tags = enabled.then(BTreeMap::new);
+        self
+    }
 }
 
 /// Marker indicating that a [`Writer`] can be wrapped into a [`Summarize`].
@@ -463,6 +736,53 @@ impl Styles {
         let steps = self.maybe_plural("step", summary.steps.total());
         let steps_stats = self.format_stats(summary.steps);
 
+        let tags = summary
+            .tags
+            .as_ref()
+            .filter(|tags| !tags.is_empty())
+            .map(|tags| {
+                let width = tags
+                    .keys()
+                    .map(|t| t.chars().count())
+                    .max()
+                    .unwrap_or_default();
+                let rows = tags
+                    .iter()
+                    .map(|(tag, stats)| {
+                        format!(
+                            "  @{:width$} {}{}",
+                            tag,
+                            self.maybe_plural("scenario", stats.total()),
+                            self.format_stats(*stats),
+                            width = width,
+                        )
+                    })
+                    .join("\n");
+                format!("{}\n{}\n", self.bold("Scenarios by tag:"), rows)
+            })
+            .unwrap_or_default();
+
+        let soft_failed = (summary.soft_failed_steps > 0)
+            .then(|| {
+                format!(
+                    "{}\n",
+                    self.skipped(self.maybe_plural(
+                        "soft step failure",
+                        summary.soft_failed_steps,
+                    )),
+                )
+            })
+            .unwrap_or_default();
+
+        // Skipped `Step`s of a dry run are the ones without a step function.
+        let undefined = (summary.dry_run && summary.steps.skipped > 0)
+            .then(|| {
+                self.err(
+                    self.maybe_plural("undefined step", summary.steps.skipped),
+                )
+            })
+            .unwrap_or_default();
+
         let parsing_errors = (summary.parsing_errors > 0)
             .then(|| {
                 self.err(
@@ -477,12 +797,51 @@ impl Styles {
             })
             .unwrap_or_default();
 
-        let comma = (!parsing_errors.is_empty() && !hook_errors.is_empty())
-            .then(|| self.err(", "))
+        let timed_out = (summary.timed_out_scenarios > 0)
+            .then(|| {
+                self.err(format!(
+                    "{} interrupted: run timeout",
+                    self.maybe_plural("scenario", summary.timed_out_scenarios),
+                ))
+            })
+            .unwrap_or_default();
+
+        let not_executed = (summary.not_executed_scenarios > 0)
+            .then(|| {
+                self.err(format!(
+                    "{} not executed: run aborted early",
+                    self.maybe_plural(
+                        "scenario",
+                        summary.not_executed_scenarios,
+                    ),
+                ))
+            })
+            .unwrap_or_default();
+
+        let errors = [
+            undefined,
+            parsing_errors,
+            hook_errors,
+            timed_out,
+            not_executed,
+        ]
+        .into_iter()
+        .filter(|s| !s.is_empty())
+        .join(&self.err(", "));
+
+        // Repeated here, as the start of the output may be long gone.
+        let shuffle_seed = summary
+            .shuffle_seed
+            .map(|seed| {
+                format!(
+                    "\nShuffled with seed {} (replay with `--shuffle-seed {}`)",
+                    seed, seed,
+                )
+            })
             .unwrap_or_default();
 
         format!(
-            "{}\n{}\n{}{}{}\n{}{}\n{}{}{}",
+            "{}\n{}\n{}{}{}\n{}{}\n{}{}{}{}",
             self.bold(self.header("[Summary]")),
             features,
             rules,
@@ -490,9 +849,10 @@ impl Styles {
             scenarios_stats,
             steps,
             steps_stats,
-            parsing_errors,
-            comma,
-            hook_errors
+            tags,
+            soft_failed,
+            errors,
+            shuffle_seed,
         )
         .trim_end_matches('\n')
         .to_owned()
@@ -0,0 +1,448 @@
+// Copyright (c) 2018-2021  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [TAP version 13][1] [`Writer`] implementation.
+//!
+//! [1]: https://testanything.org/tap-version-13-specification.html
+
+use std::{
+    fmt::{Debug, Write as _},
+    io, mem,
+    path::Path,
+};
+
+use async_trait::async_trait;
+
+use crate::{
+    cli, event, parser,
+    writer::{self, basic::coerce_error, discard, Ext as _},
+    Event, World, Writer,
+};
+
+/// [TAP version 13][1] [`Writer`] implementation outputting a test point for
+/// each [`Scenario`] to an [`io::Write`] implementor.
+///
+/// The `1..N` plan is output once the total number of [`Scenario`]s is known
+/// (on [`Cucumber::ParsingFinished`] event), counting parsing errors as failed
+/// test points too. A failed [`Scenario`] is followed by a [YAML diagnostic
+/// block][2] with its failed [`Step`] (or hook) and its panic message, while a
+/// skipped one is marked with a `# SKIP` directive.
+///
+/// Arbitrary output (like a summary) is written as TAP comments, so this
+/// [`Writer`] may be [`Summarize`]d without breaking the report.
+///
+/// # Ordering
+///
+/// This [`Writer`] isn't [`Normalized`] by itself, so should be wrapped into
+/// a [`writer::Normalize`], otherwise will produce test points with mixed up
+/// outcomes.
+///
+/// [`Cucumber::ParsingFinished`]: event::Cucumber::ParsingFinished
+/// [`Normalized`]: writer::Normalized
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+/// [`Summarize`]: writer::Summarize
+/// [1]: https://testanything.org/tap-version-13-specification.html
+/// [2]: https://testanything.org/tap-version-13-specification.html#yaml-blocks
+#[derive(Clone, Debug)]
+pub struct Tap<Out: io::Write> {
+    /// [`io::Write`] implementor to output [TAP][1] into.
+    ///
+    /// [1]: https://testanything.org/tap-version-13-specification.html
+    output: Out,
+
+    /// Indicator whether the `TAP version 13` header has been output already.
+    header_written: bool,
+
+    /// Indicator whether the `1..N` plan has been output already.
+    plan_written: bool,
+
+    /// Number of test points output so far.
+    test_points: usize,
+
+    /// [`Outcome`] of the currently executed [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    outcome: Outcome,
+}
+
+/// Outcome of a [`Scenario`] being reported as a single test point.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug)]
+enum Outcome {
+    /// [`Scenario`] has passed so far.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    Passed,
+
+    /// [`Scenario`] has been skipped for the given reason.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    Skipped(String),
+
+    /// [`Scenario`] has failed.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    Failed(Diagnostic),
+}
+
+/// [YAML diagnostic][1] of a failed test point.
+///
+/// [1]: https://testanything.org/tap-version-13-specification.html#yaml-blocks
+#[derive(Clone, Debug)]
+struct Diagnostic {
+    /// Failed [`Step`] (or hook) text.
+    ///
+    /// [`Step`]: gherkin::Step
+    step: String,
+
+    /// Location of the failed [`Step`], if any.
+    ///
+    /// [`Step`]: gherkin::Step
+    at: Option<String>,
+
+    /// Panic message of the failure.
+    message: String,
+}
+
+#[async_trait(?Send)]
+impl<W: World + Debug, Out: io::Write> Writer<W> for Tap<Out> {
+    type Cli = cli::Empty;
+
+    #[allow(clippy::unused_async)] // false positive: #[async_trait]
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule};
+
+        match ev.map(Event::split) {
+            Err(err) => self.parsing_error(&err),
+            Ok((Cucumber::Started, _)) => self.header(),
+            Ok((
+                Cucumber::ParsingFinished {
+                    scenarios,
+                    parser_errors,
+                    ..
+                },
+                _,
+            )) => self.plan(scenarios + parser_errors),
+            Ok((Cucumber::Feature(f, ev), _)) => match ev {
+                Feature::Scenario(sc, ev)
+                | Feature::Rule(_, Rule::Scenario(sc, ev)) => {
+                    self.scenario(&f, &sc, ev);
+                }
+                Feature::Started
+                | Feature::Finished
+                | Feature::Rule(_, Rule::Started | Rule::Finished) => {}
+            },
+            Ok((Cucumber::GlobalHookFailed(which, info), _)) => {
+                let description = format!("{} hook", which);
+                self.test_point(
+                    &description,
+                    &Outcome::Failed(Diagnostic {
+                        step: description.clone(),
+                        at: None,
+                        message: coerce_error(&info).into_owned(),
+                    }),
+                );
+            }
+            Ok((Cucumber::Finished, _)) => {
+                self.plan(self.test_points);
+                self.output
+                    .flush()
+                    .unwrap_or_else(|e| panic!("Failed to write TAP: {}", e));
+            }
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'val, W, Val, Out> writer::Arbitrary<'val, W, Val> for Tap<Out>
+where
+    W: World + Debug,
+    Val: AsRef<str> + 'val,
+    Out: io::Write,
+{
+    #[allow(clippy::unused_async)] // false positive: #[async_trait]
+    async fn write(&mut self, val: Val)
+    where
+        'val: 'async_trait,
+    {
+        // Arbitrary output is usually written after the `Cucumber::Finished`
+        // event, so should be flushed on its own.
+        let comment = console::strip_ansi_codes(val.as_ref())
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    "#\n".to_owned()
+                } else {
+                    format!("# {}\n", line)
+                }
+            })
+            .collect::<String>();
+        self.output
+            .write_all(comment.as_bytes())
+            .and_then(|()| self.output.flush())
+            .unwrap_or_else(|e| panic!("Failed to write TAP: {}", e));
+    }
+}
+
+impl<O: io::Write> writer::NonTransforming for Tap<O> {}
+
+impl<Out: io::Write> Tap<Out> {
+    /// Creates a new [`Normalized`] [`Tap`] [`Writer`] outputting [TAP][1]
+    /// into the given `output`.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [1]: https://testanything.org/tap-version-13-specification.html
+    #[must_use]
+    pub fn new<W: Debug + World>(output: Out) -> writer::Normalize<W, Self> {
+        Self::raw(output).normalized()
+    }
+
+    /// Creates a new non-[`Normalized`] [`Tap`] [`Writer`] outputting
+    /// [TAP][1] into the given `output`, and suitable for feeding into
+    /// [`tee()`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`tee()`]: crate::WriterExt::tee
+    /// [1]: https://testanything.org/tap-version-13-specification.html
+    #[must_use]
+    pub fn for_tee(output: Out) -> discard::Arbitrary<discard::Failure<Self>> {
+        Self::raw(output)
+            .discard_failure_writes()
+            .discard_arbitrary_writes()
+    }
+
+    /// Creates a new raw and non-[`Normalized`] [`Tap`] [`Writer`] outputting
+    /// [TAP][1] into the given `output`.
+    ///
+    /// Use it only if you know what you're doing. Otherwise, consider using
+    /// [`Tap::new()`] which creates an already [`Normalized`] version of
+    /// [`Tap`] [`Writer`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [1]: https://testanything.org/tap-version-13-specification.html
+    #[must_use]
+    pub const fn raw(output: Out) -> Self {
+        Self {
+            output,
+            header_written: false,
+            plan_written: false,
+            test_points: 0,
+            outcome: Outcome::Passed,
+        }
+    }
+
+    /// Outputs the `TAP version 13` header, unless it has been output already.
+    fn header(&mut self) {
+        if !self.header_written {
+            self.header_written = true;
+            self.emit("TAP version 13\n");
+        }
+    }
+
+    /// Outputs the `1..N` plan with the given number of test points, unless it
+    /// has been output already.
+    fn plan(&mut self, test_points: usize) {
+        self.header();
+        if !self.plan_written {
+            self.plan_written = true;
+            self.emit(&format!("1..{}\n", test_points));
+        }
+    }
+
+    /// Outputs the given [`parser::Error`] as a failed test point.
+    fn parsing_error(&mut self, err: &parser::Error) {
+        let (description, path) = match err {
+            parser::Error::Parsing(err) => {
+                let path = match err.as_ref() {
+                    gherkin::ParseFileError::Reading { path, .. }
+                    | gherkin::ParseFileError::Parsing { path, .. } => path,
+                };
+                ("Parsing error", path.to_str().map(str::to_owned))
+            }
+            parser::Error::ExampleExpansion(err) => (
+                "Example expansion error",
+                Some(format!(
+                    "{}:{}:{}",
+                    err.path
+                        .as_deref()
+                        .and_then(Path::to_str)
+                        .unwrap_or_default(),
+                    err.pos.line,
+                    err.pos.col,
+                )),
+            ),
+        };
+
+        self.test_point(
+            description,
+            &Outcome::Failed(Diagnostic {
+                step: description.to_owned(),
+                at: path,
+                message: err.to_string(),
+            }),
+        );
+    }
+
+    /// Handles the given [`event::Scenario`], outputting a test point once the
+    /// [`Scenario`] is finished.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn scenario<W>(
+        &mut self,
+        feat: &gherkin::Feature,
+        sc: &gherkin::Scenario,
+        ev: event::Scenario<W>,
+    ) {
+        use event::{Hook, Scenario, SkipReason, Step};
+
+        let outcome = match ev {
+            // Only the last attempt of a retried `Scenario` is reported.
+            Scenario::Started => {
+                self.outcome = Outcome::Passed;
+                return;
+            }
+            Scenario::Finished => {
+                let outcome = mem::replace(&mut self.outcome, Outcome::Passed);
+                self.test_point(&sc.name, &outcome);
+                return;
+            }
+            Scenario::Step(st, Step::Failed(_, _, e))
+            | Scenario::Background(st, Step::Failed(_, _, e)) => {
+                Outcome::Failed(Diagnostic {
+                    step: format!("{} {}", st.keyword, st.value),
+                    at: feat.path.as_deref().and_then(Path::to_str).map(|p| {
+                        format!(
+                            "{}:{}:{}",
+                            p, st.position.line, st.position.col,
+                        )
+                    }),
+                    message: e.to_string(),
+                })
+            }
+            Scenario::Hook(which, Hook::Failed(_, info)) => {
+                Outcome::Failed(Diagnostic {
+                    step: format!("{} hook", which),
+                    at: None,
+                    message: coerce_error(&info).into_owned(),
+                })
+            }
+            Scenario::Step(st, Step::Skipped)
+            | Scenario::Background(st, Step::Skipped) => Outcome::Skipped(
+                format!("no matching step for: {} {}", st.keyword, st.value),
+            ),
+            Scenario::Skipped(SkipReason::BackgroundFailed(st)) => {
+                Outcome::Skipped(format!(
+                    "background step failed: {} {}",
+                    st.keyword, st.value,
+                ))
+            }
+            Scenario::Skipped(SkipReason::RunTimeout) => {
+                Outcome::Skipped("run timeout exceeded".to_owned())
+            }
+            Scenario::Skipped(SkipReason::FailFast) => {
+                Outcome::Skipped("run aborted on failure".to_owned())
+            }
+            Scenario::Skipped(SkipReason::FilteredOut) => {
+                Outcome::Skipped("filtered out".to_owned())
+            }
+            Scenario::Step(..)
+            | Scenario::Background(..)
+            | Scenario::Hook(..)
+            | Scenario::Retried { .. }
+            | Scenario::StepHeartbeat(..)
+            | Scenario::ResourceUsage { .. } => return,
+        };
+
+        // The first failure is the most relevant one.
+        if !matches!(self.outcome, Outcome::Failed(_)) {
+            self.outcome = outcome;
+        }
+    }
+
+    /// Outputs a test point with the given `description` and [`Outcome`].
+    fn test_point(&mut self, description: &str, outcome: &Outcome) {
+        self.header();
+        self.test_points += 1;
+
+        // `#` starts a directive, so should be escaped in descriptions.
+        let description = description.replace('\\', "\\\\").replace('#', "\\#");
+        let line = match outcome {
+            Outcome::Passed => {
+                format!("ok {} - {}\n", self.test_points, description)
+            }
+            Outcome::Skipped(reason) => format!(
+                "ok {} - {} # SKIP {}\n",
+                self.test_points, description, reason,
+            ),
+            Outcome::Failed(diag) => format!(
+                "not ok {} - {}\n{}",
+                self.test_points,
+                description,
+                diag.to_yaml(),
+            ),
+        };
+        self.emit(&line);
+    }
+
+    /// Writes the given `text` into the [`Tap::output`].
+    fn emit(&mut self, text: &str) {
+        self.output
+            .write_all(text.as_bytes())
+            .unwrap_or_else(|e| panic!("Failed to write TAP: {}", e));
+    }
+}
+
+impl Diagnostic {
+    /// Renders this [`Diagnostic`] as an indented [YAML block][1].
+    ///
+    /// [1]: https://testanything.org/tap-version-13-specification.html#yaml-blocks
+    fn to_yaml(&self) -> String {
+        format!(
+            "  ---\n  step: {}\n{}  message: {}\n  ...\n",
+            yaml_string(&self.step),
+            self.at
+                .as_deref()
+                .map(|at| format!("  at: {}\n", yaml_string(at)))
+                .unwrap_or_default(),
+            yaml_string(&console::strip_ansi_codes(&self.message)),
+        )
+    }
+}
+
+/// Renders the given `s` as a double-quoted [YAML] scalar, escaping the
+/// characters which cannot appear in there as is.
+///
+/// [YAML]: https://yaml.org/spec/1.2.2/#731-double-quoted-style
+fn yaml_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                write!(out, "\\x{:02x}", u32::from(c))
+                    .unwrap_or_else(|e| panic!("Failed to write YAML: {}", e));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,383 @@
+// Copyright (c) 2018-2021  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [TeamCity service messages][1] [`Writer`] implementation.
+//!
+//! [1]: https://www.jetbrains.com/help/teamcity/service-messages.html
+
+use std::{
+    fmt::{Debug, Write as _},
+    io, mem,
+};
+
+use async_trait::async_trait;
+
+use crate::{
+    cli, event, parser,
+    writer::{self, basic::coerce_error, discard, Ext as _},
+    Event, World, Writer,
+};
+
+/// [TeamCity service messages][1] [`Writer`] implementation outputting a test
+/// for each [`Scenario`] to an [`io::Write`] implementor, so the run is shown
+/// as a test tree by CI servers and IDEs supporting them.
+///
+/// [`Feature`]s and [`Rule`]s are reported as test suites, and each finished
+/// [`Step`] as a standard output of its [`Scenario`]'s test. A failed
+/// [`Scenario`] is reported with its failed [`Step`] (or hook) and its panic
+/// message, while a skipped one is reported as an ignored test. Parsing errors
+/// and failed global hooks are reported as separate failed tests.
+///
+/// Arbitrary output (like a summary) is written as is, so this [`Writer`] may
+/// be [`Summarize`]d without breaking the report.
+///
+/// # Ordering
+///
+/// This [`Writer`] isn't [`Normalized`] by itself, so should be wrapped into
+/// a [`writer::Normalize`], otherwise will produce messages of interleaved
+/// tests.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Normalized`]: writer::Normalized
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+/// [`Summarize`]: writer::Summarize
+/// [1]: https://www.jetbrains.com/help/teamcity/service-messages.html
+#[derive(Clone, Debug)]
+pub struct TeamCity<Out: io::Write> {
+    /// [`io::Write`] implementor to output [service messages][1] into.
+    ///
+    /// [1]: https://www.jetbrains.com/help/teamcity/service-messages.html
+    output: Out,
+
+    /// Indicator whether the test of the currently executed [`Scenario`] has
+    /// been started already.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    started: bool,
+
+    /// [`Outcome`] of the currently executed [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    outcome: Outcome,
+}
+
+/// Outcome of a [`Scenario`] being reported as a single test.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug)]
+enum Outcome {
+    /// [`Scenario`] has passed so far.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    Passed,
+
+    /// [`Scenario`] has been skipped for the given reason.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    Skipped(String),
+
+    /// [`Scenario`] has failed with the given message and details.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    Failed(String, String),
+}
+
+#[async_trait(?Send)]
+impl<W: World + Debug, Out: io::Write> Writer<W> for TeamCity<Out> {
+    type Cli = cli::Empty;
+
+    #[allow(clippy::unused_async)] // false positive: #[async_trait]
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule};
+
+        match ev.map(Event::split) {
+            Err(err) => self.test(
+                "Parsing error",
+                &Outcome::Failed("Parsing error".to_owned(), err.to_string()),
+            ),
+            Ok((Cucumber::Started, _)) => {}
+            Ok((Cucumber::ParsingFinished { scenarios, .. }, _)) => {
+                self.message("testCount", &[("count", &scenarios.to_string())]);
+            }
+            Ok((Cucumber::Feature(f, ev), _)) => match ev {
+                Feature::Started => {
+                    self.message("testSuiteStarted", &[("name", &f.name)]);
+                }
+                Feature::Rule(r, Rule::Started) => {
+                    self.message("testSuiteStarted", &[("name", &r.name)]);
+                }
+                Feature::Scenario(sc, ev)
+                | Feature::Rule(_, Rule::Scenario(sc, ev)) => {
+                    self.scenario(&sc, ev);
+                }
+                Feature::Rule(r, Rule::Finished) => {
+                    self.message("testSuiteFinished", &[("name", &r.name)]);
+                }
+                Feature::Finished => {
+                    self.message("testSuiteFinished", &[("name", &f.name)]);
+                }
+            },
+            Ok((Cucumber::GlobalHookFailed(which, info), _)) => {
+                let name = format!("{} hook", which);
+                self.test(
+                    &name,
+                    &Outcome::Failed(
+                        format!("{} failed", name),
+                        coerce_error(&info).into_owned(),
+                    ),
+                );
+            }
+            Ok((Cucumber::Finished, _)) => {
+                self.output.flush().unwrap_or_else(|e| {
+                    panic!("Failed to write TeamCity messages: {}", e)
+                });
+            }
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'val, W, Val, Out> writer::Arbitrary<'val, W, Val> for TeamCity<Out>
+where
+    W: World + Debug,
+    Val: AsRef<str> + 'val,
+    Out: io::Write,
+{
+    #[allow(clippy::unused_async)] // false positive: #[async_trait]
+    async fn write(&mut self, val: Val)
+    where
+        'val: 'async_trait,
+    {
+        // Arbitrary output is usually written after the `Cucumber::Finished`
+        // event, so should be flushed on its own.
+        self.output
+            .write_all(console::strip_ansi_codes(val.as_ref()).as_bytes())
+            .and_then(|()| self.output.flush())
+            .unwrap_or_else(|e| {
+                panic!("Failed to write TeamCity messages: {}", e)
+            });
+    }
+}
+
+impl<O: io::Write> writer::NonTransforming for TeamCity<O> {}
+
+impl<Out: io::Write> TeamCity<Out> {
+    /// Creates a new [`Normalized`] [`TeamCity`] [`Writer`] outputting
+    /// [service messages][1] into the given `output`.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [1]: https://www.jetbrains.com/help/teamcity/service-messages.html
+    #[must_use]
+    pub fn new<W: Debug + World>(output: Out) -> writer::Normalize<W, Self> {
+        Self::raw(output).normalized()
+    }
+
+    /// Creates a new non-[`Normalized`] [`TeamCity`] [`Writer`] outputting
+    /// [service messages][1] into the given `output`, and suitable for
+    /// feeding into [`tee()`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`tee()`]: crate::WriterExt::tee
+    /// [1]: https://www.jetbrains.com/help/teamcity/service-messages.html
+    #[must_use]
+    pub fn for_tee(output: Out) -> discard::Arbitrary<discard::Failure<Self>> {
+        Self::raw(output)
+            .discard_failure_writes()
+            .discard_arbitrary_writes()
+    }
+
+    /// Creates a new raw and non-[`Normalized`] [`TeamCity`] [`Writer`]
+    /// outputting [service messages][1] into the given `output`.
+    ///
+    /// Use it only if you know what you're doing. Otherwise, consider using
+    /// [`TeamCity::new()`] which creates an already [`Normalized`] version of
+    /// [`TeamCity`] [`Writer`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [1]: https://www.jetbrains.com/help/teamcity/service-messages.html
+    #[must_use]
+    pub const fn raw(output: Out) -> Self {
+        Self {
+            output,
+            started: false,
+            outcome: Outcome::Passed,
+        }
+    }
+
+    /// Handles the given [`event::Scenario`], reporting the test of the
+    /// [`Scenario`] once it's finished.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn scenario<W>(&mut self, sc: &gherkin::Scenario, ev: event::Scenario<W>) {
+        use event::{Hook, Scenario, SkipReason, Step};
+
+        let outcome = match ev {
+            // Only the last attempt of a retried `Scenario` is reported.
+            Scenario::Started => {
+                self.started = true;
+                self.outcome = Outcome::Passed;
+                self.message("testStarted", &[("name", &sc.name)]);
+                return;
+            }
+            Scenario::Finished => {
+                let outcome = mem::replace(&mut self.outcome, Outcome::Passed);
+                if !mem::take(&mut self.started) {
+                    // Abandoned `Scenario`s are finished without being
+                    // started.
+                    self.message("testStarted", &[("name", &sc.name)]);
+                }
+                self.test_finished(&sc.name, &outcome);
+                return;
+            }
+            Scenario::Step(st, ev) | Scenario::Background(st, ev) => {
+                let status = match &ev {
+                    Step::Passed(_) => "passed",
+                    Step::Failed(..) => "failed",
+                    Step::SoftFailed(..) => "soft failed",
+                    Step::Skipped => "undefined",
+                    Step::Output(out) => {
+                        self.message(
+                            "testStdOut",
+                            &[("name", &sc.name), ("out", out)],
+                        );
+                        return;
+                    }
+                    Step::Started | Step::Matched(_) => return,
+                };
+                let out = format!("{} {}: {}\n", st.keyword, st.value, status);
+                self.message(
+                    "testStdOut",
+                    &[("name", &sc.name), ("out", &out)],
+                );
+
+                match ev {
+                    Step::Failed(_, _, e) => Outcome::Failed(
+                        format!("Step failed: {} {}", st.keyword, st.value),
+                        e.to_string(),
+                    ),
+                    Step::Skipped => Outcome::Skipped(format!(
+                        "no matching step for: {} {}",
+                        st.keyword, st.value,
+                    )),
+                    Step::Started
+                    | Step::Matched(_)
+                    | Step::Passed(_)
+                    | Step::SoftFailed(..)
+                    | Step::Output(_) => return,
+                }
+            }
+            Scenario::Hook(which, Hook::Failed(_, info)) => Outcome::Failed(
+                format!("{} hook failed", which),
+                coerce_error(&info).into_owned(),
+            ),
+            Scenario::Skipped(SkipReason::BackgroundFailed(st)) => {
+                Outcome::Skipped(format!(
+                    "background step failed: {} {}",
+                    st.keyword, st.value,
+                ))
+            }
+            Scenario::Skipped(SkipReason::RunTimeout) => {
+                Outcome::Skipped("run timeout exceeded".to_owned())
+            }
+            Scenario::Skipped(SkipReason::FailFast) => {
+                Outcome::Skipped("run aborted on failure".to_owned())
+            }
+            Scenario::Skipped(SkipReason::FilteredOut) => {
+                Outcome::Skipped("filtered out".to_owned())
+            }
+            Scenario::Hook(..)
+            | Scenario::Retried { .. }
+            | Scenario::StepHeartbeat(..)
+            | Scenario::ResourceUsage { .. } => return,
+        };
+
+        // The first failure is the most relevant one.
+        if !matches!(self.outcome, Outcome::Failed(..)) {
+            self.outcome = outcome;
+        }
+    }
+
+    /// Outputs a whole test with the given `name` and [`Outcome`].
+    fn test(&mut self, name: &str, outcome: &Outcome) {
+        self.message("testStarted", &[("name", name)]);
+        self.test_finished(name, outcome);
+    }
+
+    /// Outputs the end of an already started test with the given `name` and
+    /// [`Outcome`].
+    fn test_finished(&mut self, name: &str, outcome: &Outcome) {
+        match outcome {
+            Outcome::Passed => {}
+            Outcome::Skipped(reason) => {
+                self.message(
+                    "testIgnored",
+                    &[("name", name), ("message", reason)],
+                );
+            }
+            Outcome::Failed(message, details) => {
+                let details = console::strip_ansi_codes(details);
+                self.message(
+                    "testFailed",
+                    &[
+                        ("name", name),
+                        ("message", message),
+                        ("details", &details),
+                    ],
+                );
+            }
+        }
+        self.message("testFinished", &[("name", name)]);
+    }
+
+    /// Outputs a [service message][1] of the given `kind` with the given
+    /// `attrs`ibutes.
+    ///
+    /// [1]: https://www.jetbrains.com/help/teamcity/service-messages.html
+    fn message(&mut self, kind: &str, attrs: &[(&str, &str)]) {
+        let mut line = format!("##teamcity[{}", kind);
+        for (name, value) in attrs {
+            write!(line, " {}='{}'", name, escape(value)).unwrap_or_else(|e| {
+                panic!("Failed to write TeamCity messages: {}", e)
+            });
+        }
+        line.push_str("]\n");
+        self.output.write_all(line.as_bytes()).unwrap_or_else(|e| {
+            panic!("Failed to write TeamCity messages: {}", e)
+        });
+    }
+}
+
+/// Escapes the given `value` of a [service message][1] attribute.
+///
+/// [1]: https://www.jetbrains.com/help/teamcity/service-messages.html#Escaped+Values
+fn escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '|' => out.push_str("||"),
+            '\'' => out.push_str("|'"),
+            '[' => out.push_str("|["),
+            ']' => out.push_str("|]"),
+            '\n' => out.push_str("|n"),
+            '\r' => out.push_str("|r"),
+            '\u{0085}' => out.push_str("|x"),
+            '\u{2028}' => out.push_str("|l"),
+            '\u{2029}' => out.push_str("|p"),
+            c => out.push(c),
+        }
+    }
+    out
+}
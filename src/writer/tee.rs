@@ -8,17 +8,19 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! Passing events to multiple terminating [`Writer`]s simultaneously.
+//! Passing events to multiple terminating [`Writer`]s.
 
 use std::cmp;
 
 use async_trait::async_trait;
-use futures::future;
 
 use crate::{cli, event, parser, writer, Event, World, Writer};
 
-/// Wrapper for passing events to multiple terminating [`Writer`]s
-/// simultaneously.
+/// Wrapper for passing events to multiple terminating [`Writer`]s.
+///
+/// Each event is passed to the `left` [`Writer`] first, and only once it's
+/// handled, to the `right` one, so their outputs never interleave within a
+/// single event.
 ///
 /// # Blanket implementations
 ///
@@ -44,7 +46,7 @@ pub struct Tee<L, R> {
 
 impl<L, R> Tee<L, R> {
     /// Creates a new [`Tee`] [`Writer`], which passes events both to the `left`
-    /// and `right` [`Writer`]s simultaneously.
+    /// and `right` [`Writer`]s, in this order.
     #[must_use]
     pub const fn new(left: L, right: R) -> Self {
         Self { left, right }
@@ -65,11 +67,8 @@ where
         ev: parser::Result<Event<event::Cucumber<W>>>,
         cli: &Self::Cli,
     ) {
-        future::join(
-            self.left.handle_event(ev.clone(), &cli.left),
-            self.right.handle_event(ev, &cli.right),
-        )
-        .await;
+        self.left.handle_event(ev.clone(), &cli.left).await;
+        self.right.handle_event(ev, &cli.right).await;
     }
 }
 
@@ -85,7 +84,8 @@ where
     where
         'val: 'async_trait,
     {
-        future::join(self.left.write(val.clone()), self.right.write(val)).await;
+        self.left.write(val.clone()).await;
+        self.right.write(val).await;
     }
 }
 
@@ -95,6 +95,12 @@ where
     R: writer::Failure<W>,
     Self: Writer<W>,
 {
+    fn execution_has_failed(&self) -> bool {
+        // Either one may fail on its own (like on I/O errors), regardless of
+        // the counted failures.
+        self.left.execution_has_failed() || self.right.execution_has_failed()
+    }
+
     fn failed_steps(&self) -> usize {
         // Either one of them is zero, or both numbers are the same.
         cmp::max(self.left.failed_steps(), self.right.failed_steps())
@@ -109,6 +115,32 @@ where
         // Either one of them is zero, or both numbers are the same.
         cmp::max(self.left.hook_errors(), self.right.hook_errors())
     }
+
+    fn skipped_steps(&self) -> usize {
+        // Either one of them is zero, or both numbers are the same.
+        cmp::max(self.left.skipped_steps(), self.right.skipped_steps())
+    }
+
+    fn passed_steps(&self) -> usize {
+        // Either one of them is zero, or both numbers are the same.
+        cmp::max(self.left.passed_steps(), self.right.passed_steps())
+    }
+
+    fn not_executed_steps(&self) -> usize {
+        // Either one of them is zero, or both numbers are the same.
+        cmp::max(
+            self.left.not_executed_steps(),
+            self.right.not_executed_steps(),
+        )
+    }
+
+    fn timed_out_scenarios(&self) -> usize {
+        // Either one of them is zero, or both numbers are the same.
+        cmp::max(
+            self.left.timed_out_scenarios(),
+            self.right.timed_out_scenarios(),
+        )
+    }
 }
 
 impl<L, R> writer::Normalized for Tee<L, R>
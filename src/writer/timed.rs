@@ -0,0 +1,441 @@
+// Copyright (c) 2018-2021  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Writer`]-wrapper for recording durations of [`Scenario`]s and [`Step`]s.
+//!
+//! [`Scenario`]: gherkin::Scenario
+//! [`Step`]: gherkin::Step
+
+use std::{
+    collections::HashMap,
+    slice,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use derive_more::Deref;
+
+use crate::{event, parser, writer, Event, World, Writer};
+
+/// Default clock of a [`Timed`] [`Writer`].
+pub type Clock = fn() -> Instant;
+
+/// [`Writer`]-wrapper recording durations of every [`Scenario`] and [`Step`]
+/// since their matching `Started` events, available via [`Timed::timings()`]
+/// once the execution is over.
+///
+/// Durations are measured at the moment an event reaches this [`Writer`],
+/// which happens once the executed [`Step`] yields, so they're accurate for
+/// asynchronous [`Step`]s only. To measure the actual execution, rather than
+/// the output order, it should wrap an already [`Normalized`] [`Writer`]:
+/// ```rust
+/// # use std::convert::Infallible;
+/// #
+/// # use async_trait::async_trait;
+/// # use cucumber::{writer, WorldInit, WriterExt as _};
+/// #
+/// # #[derive(Debug, WorldInit)]
+/// # struct MyWorld;
+/// #
+/// # #[async_trait(?Send)]
+/// # impl cucumber::World for MyWorld {
+/// #     type Error = Infallible;
+/// #
+/// #     async fn new() -> Result<Self, Self::Error> {
+/// #         Ok(Self)
+/// #     }
+/// # }
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let timed = MyWorld::cucumber()
+///     .with_writer(writer::Basic::stdout().summarized().timed())
+///     .run("tests/features/readme")
+///     .await;
+///
+/// for sc in timed.timings() {
+///     println!("{}: {:.3}s", sc.scenario.name, sc.duration.as_secs_f64());
+/// }
+/// # }
+/// ```
+///
+/// The clock is [`Instant::now()`] by default, but may be replaced via
+/// [`Timed::with_clock()`] (to make durations deterministic in tests, for
+/// example).
+///
+/// [`Normalized`]: writer::Normalized
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+#[derive(Debug, Deref)]
+pub struct Timed<Wr, C = Clock> {
+    /// Original [`Writer`] to pass events into.
+    #[deref]
+    pub writer: Wr,
+
+    /// Clock to measure durations with.
+    clock: C,
+
+    /// Start of the whole execution, if it has started.
+    started: Option<Instant>,
+
+    /// [`Scenario`]s being executed at the moment.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    in_progress: HashMap<Arc<gherkin::Scenario>, InProgress>,
+
+    /// Already collected durations.
+    timings: ScenarioTimings,
+}
+
+impl<Wr> Timed<Wr> {
+    /// Wraps the given [`Writer`] into a new [`Timed`] one, measuring
+    /// durations with [`Instant::now()`].
+    #[must_use]
+    pub fn new(writer: Wr) -> Self {
+        Self::with_clock(writer, Instant::now)
+    }
+}
+
+impl<Wr, C: Fn() -> Instant> Timed<Wr, C> {
+    /// Wraps the given [`Writer`] into a new [`Timed`] one, measuring
+    /// durations with the provided `clock`.
+    #[must_use]
+    pub fn with_clock(writer: Wr, clock: C) -> Self {
+        Self {
+            writer,
+            clock,
+            started: None,
+            in_progress: HashMap::new(),
+            timings: ScenarioTimings::default(),
+        }
+    }
+
+    /// Returns durations collected so far.
+    #[must_use]
+    pub const fn timings(&self) -> &ScenarioTimings {
+        &self.timings
+    }
+
+    /// Records timing of the given [`event::Cucumber`].
+    fn record<W>(&mut self, ev: &event::Cucumber<W>) {
+        use event::{Cucumber, Feature, Rule};
+
+        match ev {
+            Cucumber::Started => self.started = Some((self.clock)()),
+            Cucumber::Finished => {
+                self.timings.total =
+                    self.started.map(|start| (self.clock)() - start);
+            }
+            Cucumber::Feature(f, Feature::Scenario(sc, ev)) => {
+                self.record_scenario(f, None, sc, ev);
+            }
+            Cucumber::Feature(f, Feature::Rule(r, Rule::Scenario(sc, ev))) => {
+                self.record_scenario(f, Some(r), sc, ev);
+            }
+            Cucumber::ParsingFinished { .. }
+            | Cucumber::GlobalHookFailed(..)
+            | Cucumber::Feature(
+                _,
+                Feature::Started
+                | Feature::Finished
+                | Feature::Rule(_, Rule::Started | Rule::Finished),
+            ) => {}
+        }
+    }
+
+    /// Records timing of the given [`event::Scenario`].
+    fn record_scenario<W>(
+        &mut self,
+        feature: &Arc<gherkin::Feature>,
+        rule: Option<&Arc<gherkin::Rule>>,
+        scenario: &Arc<gherkin::Scenario>,
+        ev: &event::Scenario<W>,
+    ) {
+        use event::Scenario;
+
+        let now = (self.clock)();
+        match ev {
+            // Retried `Scenario`s start over, so only the last attempt is
+            // accounted.
+            Scenario::Started => {
+                drop(self.in_progress.insert(
+                    Arc::clone(scenario),
+                    InProgress {
+                        started: now,
+                        step: None,
+                        steps: Vec::new(),
+                    },
+                ));
+            }
+            Scenario::Background(st, ev) => {
+                if let Some(progress) = self.in_progress.get_mut(scenario) {
+                    progress.record_step(now, st, ev, true);
+                }
+            }
+            Scenario::Step(st, ev) => {
+                if let Some(progress) = self.in_progress.get_mut(scenario) {
+                    progress.record_step(now, st, ev, false);
+                }
+            }
+            Scenario::Finished => {
+                if let Some(progress) = self.in_progress.remove(scenario) {
+                    self.timings.scenarios.push(ScenarioTiming {
+                        feature: Arc::clone(feature),
+                        rule: rule.cloned(),
+                        scenario: Arc::clone(scenario),
+                        duration: now - progress.started,
+                        steps: progress.steps,
+                    });
+                }
+            }
+            Scenario::Hook(..)
+            | Scenario::Retried { .. }
+            | Scenario::Skipped(_)
+            | Scenario::StepHeartbeat(..)
+            | Scenario::ResourceUsage { .. } => {}
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<W, Wr, C> Writer<W> for Timed<Wr, C>
+where
+    W: World,
+    Wr: Writer<W>,
+    C: Fn() -> Instant,
+{
+    type Cli = Wr::Cli;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+        cli: &Self::Cli,
+    ) {
+        if let Ok(ev) = &ev {
+            self.record(ev);
+        }
+        self.writer.handle_event(ev, cli).await;
+    }
+}
+
+#[async_trait(?Send)]
+impl<'val, W, Wr, Val, C> writer::Arbitrary<'val, W, Val> for Timed<Wr, C>
+where
+    W: World,
+    Self: Writer<W>,
+    Wr: writer::Arbitrary<'val, W, Val>,
+    Val: 'val,
+{
+    async fn write(&mut self, val: Val)
+    where
+        'val: 'async_trait,
+    {
+        self.writer.write(val).await;
+    }
+}
+
+impl<W, Wr, C> writer::Failure<W> for Timed<Wr, C>
+where
+    Wr: writer::Failure<W>,
+    Self: Writer<W>,
+{
+    fn execution_has_failed(&self) -> bool {
+        self.writer.execution_has_failed()
+    }
+
+    fn failed_steps(&self) -> usize {
+        self.writer.failed_steps()
+    }
+
+    fn parsing_errors(&self) -> usize {
+        self.writer.parsing_errors()
+    }
+
+    fn hook_errors(&self) -> usize {
+        self.writer.hook_errors()
+    }
+
+    fn skipped_steps(&self) -> usize {
+        self.writer.skipped_steps()
+    }
+
+    fn passed_steps(&self) -> usize {
+        self.writer.passed_steps()
+    }
+
+    fn not_executed_steps(&self) -> usize {
+        self.writer.not_executed_steps()
+    }
+
+    fn timed_out_scenarios(&self) -> usize {
+        self.writer.timed_out_scenarios()
+    }
+}
+
+impl<Wr: writer::Normalized, C> writer::Normalized for Timed<Wr, C> {}
+
+impl<Wr: writer::NonTransforming, C> writer::NonTransforming for Timed<Wr, C> {}
+
+/// Durations collected by a [`Timed`] [`Writer`].
+#[derive(Clone, Debug, Default)]
+pub struct ScenarioTimings {
+    /// Duration of the whole execution, once it has finished.
+    total: Option<Duration>,
+
+    /// Finished [`Scenario`]s in order of their finishing.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    scenarios: Vec<ScenarioTiming>,
+}
+
+impl ScenarioTimings {
+    /// Returns duration of the whole execution, if it has finished.
+    #[must_use]
+    pub const fn total(&self) -> Option<Duration> {
+        self.total
+    }
+
+    /// Returns timings of the finished [`Scenario`]s in order of their
+    /// finishing.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn scenarios(&self) -> &[ScenarioTiming] {
+        &self.scenarios
+    }
+
+    /// Returns an [`Iterator`] over timings of the finished [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub fn iter(&self) -> slice::Iter<'_, ScenarioTiming> {
+        self.scenarios.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ScenarioTimings {
+    type Item = &'a ScenarioTiming;
+    type IntoIter = slice::Iter<'a, ScenarioTiming>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Timing of a single finished [`Scenario`].
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug)]
+pub struct ScenarioTiming {
+    /// [`Feature`] the [`Scenario`] belongs to.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    pub feature: Arc<gherkin::Feature>,
+
+    /// [`Rule`] the [`Scenario`] belongs to, if any.
+    ///
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    pub rule: Option<Arc<gherkin::Rule>>,
+
+    /// Timed [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub scenario: Arc<gherkin::Scenario>,
+
+    /// [`Duration`] between the [`Scenario::Started`] and
+    /// [`Scenario::Finished`] events, including hooks.
+    ///
+    /// [`Scenario::Finished`]: event::Scenario::Finished
+    /// [`Scenario::Started`]: event::Scenario::Started
+    pub duration: Duration,
+
+    /// Timings of the finished [`Step`]s in order of their execution.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub steps: Vec<StepTiming>,
+}
+
+/// Timing of a single finished [`Step`].
+///
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Debug)]
+pub struct StepTiming {
+    /// Timed [`Step`].
+    ///
+    /// [`Step`]: gherkin::Step
+    pub step: Arc<gherkin::Step>,
+
+    /// Indicator whether the [`Step`] is a [`Background`] one.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    pub background: bool,
+
+    /// [`Duration`] between the [`Step::Started`] event and the one finishing
+    /// the [`Step`].
+    ///
+    /// [`Step::Started`]: event::Step::Started
+    /// [`Step`]: gherkin::Step
+    pub duration: Duration,
+}
+
+/// [`Scenario`] being executed by the moment.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Debug)]
+struct InProgress {
+    /// Start of the [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    started: Instant,
+
+    /// Start of the [`Step`] being executed at the moment, if any.
+    ///
+    /// [`Step`]: gherkin::Step
+    step: Option<Instant>,
+
+    /// Timings of the already finished [`Step`]s.
+    ///
+    /// [`Step`]: gherkin::Step
+    steps: Vec<StepTiming>,
+}
+
+impl InProgress {
+    /// Records timing of the given [`event::Step`].
+    fn record_step<W>(
+        &mut self,
+        now: Instant,
+        step: &Arc<gherkin::Step>,
+        ev: &event::Step<W>,
+        background: bool,
+    ) {
+        use event::Step;
+
+        match ev {
+            Step::Started => self.step = Some(now),
+            Step::Output(_) => {}
+            Step::Skipped
+            | Step::Matched(_)
+            | Step::Passed(_)
+            | Step::Failed(..)
+            | Step::SoftFailed(..) => {
+                if let Some(started) = self.step.take() {
+                    self.steps.push(StepTiming {
+                        step: Arc::clone(step),
+                        background,
+                        duration: now - started,
+                    });
+                }
+            }
+        }
+    }
+}
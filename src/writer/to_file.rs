@@ -0,0 +1,298 @@
+// Copyright (c) 2018-2021  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Writer`]-wrapper redirecting output of a [`Writer`] into a file.
+
+use std::{
+    cell::RefCell,
+    fs,
+    io::{self, Write as _},
+    path::PathBuf,
+    rc::Rc,
+};
+
+use async_trait::async_trait;
+use derive_more::Deref;
+
+use crate::{event, parser, writer, Event, World, Writer};
+
+/// [`Writer`]-wrapper redirecting output of a [`Writer`] into a file at the
+/// given path, created (or truncated) lazily on the [`Cucumber::Started`]
+/// event, so the construction itself is infallible.
+///
+/// The wrapped [`Writer`] is constructed with a [`File`] [`io::Write`]
+/// implementor instead of a file handle:
+/// ```rust
+/// # use std::convert::Infallible;
+/// #
+/// # use async_trait::async_trait;
+/// # use cucumber::{writer, WorldInit, WriterExt as _};
+/// #
+/// # #[derive(Debug, WorldInit)]
+/// # struct MyWorld;
+/// #
+/// # #[async_trait(?Send)]
+/// # impl cucumber::World for MyWorld {
+/// #     type Error = Infallible;
+/// #
+/// #     async fn new() -> Result<Self, Self::Error> {
+/// #         Ok(Self)
+/// #     }
+/// # }
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let dir = tempfile::tempdir().unwrap();
+/// # let path = dir.path().join("report.txt");
+/// MyWorld::cucumber()
+///     .with_writer(writer::ToFile::new(path, |file| {
+///         writer::Basic::new(file, writer::Coloring::Never, false)
+///             .summarized()
+///     }))
+///     .run("tests/features/readme")
+///     .await;
+/// # }
+/// ```
+///
+/// Any [`io::Error`] happened while creating or writing the file doesn't
+/// panic, but is kept until the [`Cucumber::Finished`] event, after which is
+/// available via [`ToFile::error()`] and fails the run, while the following
+/// output is discarded. To be accounted by [`writer::Failure`], the wrapped
+/// [`Writer`] should be [`Summarize`]d, rather than the [`ToFile`] itself.
+///
+/// [`Cucumber::Finished`]: event::Cucumber::Finished
+/// [`Cucumber::Started`]: event::Cucumber::Started
+/// [`Summarize`]: writer::Summarize
+#[derive(Debug, Deref)]
+pub struct ToFile<Wr> {
+    /// Original [`Writer`] to pass events into.
+    #[deref]
+    writer: Wr,
+
+    /// [`File`] the original [`Writer`] outputs into.
+    file: File,
+}
+
+impl<Wr> ToFile<Wr> {
+    /// Wraps the [`Writer`] constructed by the given `writer` function with
+    /// a [`File`] at the given `path` into a new [`ToFile`] one.
+    #[must_use]
+    pub fn new<F>(path: impl Into<PathBuf>, writer: F) -> Self
+    where
+        F: FnOnce(File) -> Wr,
+    {
+        let file = File::new(path.into());
+        Self {
+            writer: writer(file.clone()),
+            file,
+        }
+    }
+
+    /// Returns the path of the file being written.
+    #[must_use]
+    pub fn path(&self) -> PathBuf {
+        self.file.0.borrow().path.clone()
+    }
+
+    /// Returns a copy of the first [`io::Error`] happened while creating or
+    /// writing the file, if any.
+    #[must_use]
+    pub fn error(&self) -> Option<io::Error> {
+        self.file
+            .0
+            .borrow()
+            .error
+            .as_ref()
+            .map(|e| io::Error::new(e.kind(), e.to_string()))
+    }
+}
+
+#[async_trait(?Send)]
+impl<W, Wr> Writer<W> for ToFile<Wr>
+where
+    W: World,
+    Wr: Writer<W>,
+{
+    type Cli = Wr::Cli;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+        cli: &Self::Cli,
+    ) {
+        use event::Cucumber;
+
+        let is_finished = match ev.as_deref() {
+            Ok(Cucumber::Started) => {
+                self.file.0.borrow_mut().open();
+                false
+            }
+            Ok(Cucumber::Finished) => true,
+            _ => false,
+        };
+
+        self.writer.handle_event(ev, cli).await;
+
+        if is_finished {
+            self.file.0.borrow_mut().sync();
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'val, W, Wr, Val> writer::Arbitrary<'val, W, Val> for ToFile<Wr>
+where
+    W: World,
+    Self: Writer<W>,
+    Wr: writer::Arbitrary<'val, W, Val>,
+    Val: 'val,
+{
+    async fn write(&mut self, val: Val)
+    where
+        'val: 'async_trait,
+    {
+        // Arbitrary output (like a summary) is usually written after the
+        // `Cucumber::Finished` event, so should be synced on its own.
+        self.writer.write(val).await;
+        self.file.0.borrow_mut().sync();
+    }
+}
+
+impl<W, Wr> writer::Failure<W> for ToFile<Wr>
+where
+    Wr: writer::Failure<W>,
+    Self: Writer<W>,
+{
+    fn execution_has_failed(&self) -> bool {
+        self.writer.execution_has_failed() || self.file.0.borrow().has_failed()
+    }
+
+    fn failed_steps(&self) -> usize {
+        self.writer.failed_steps()
+    }
+
+    fn parsing_errors(&self) -> usize {
+        self.writer.parsing_errors()
+    }
+
+    fn hook_errors(&self) -> usize {
+        self.writer.hook_errors()
+    }
+
+    fn skipped_steps(&self) -> usize {
+        self.writer.skipped_steps()
+    }
+
+    fn passed_steps(&self) -> usize {
+        self.writer.passed_steps()
+    }
+
+    fn not_executed_steps(&self) -> usize {
+        self.writer.not_executed_steps()
+    }
+
+    fn timed_out_scenarios(&self) -> usize {
+        self.writer.timed_out_scenarios()
+    }
+}
+
+impl<Wr: writer::Normalized> writer::Normalized for ToFile<Wr> {}
+
+impl<Wr: writer::NonTransforming> writer::NonTransforming for ToFile<Wr> {}
+
+/// [`io::Write`] implementor of a [`ToFile`] [`Writer`], writing into the
+/// lazily created file.
+///
+/// Never returns an error, keeping the first one happened inside the
+/// [`ToFile`] [`Writer`] instead, and discarding all the following output.
+#[derive(Clone, Debug)]
+pub struct File(Rc<RefCell<State>>);
+
+impl File {
+    /// Creates a new not yet opened [`File`] at the given `path`.
+    fn new(path: PathBuf) -> Self {
+        Self(Rc::new(RefCell::new(State {
+            path,
+            file: None,
+            error: None,
+        })))
+    }
+}
+
+impl io::Write for File {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut state = self.0.borrow_mut();
+        state.open();
+        if let Some(file) = &mut state.file {
+            if let Err(e) = file.write_all(buf) {
+                state.fail(e);
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let mut state = self.0.borrow_mut();
+        if let Some(file) = &mut state.file {
+            if let Err(e) = file.flush() {
+                state.fail(e);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Shared state of a [`File`].
+#[derive(Debug)]
+struct State {
+    /// Path of the file to write into.
+    path: PathBuf,
+
+    /// Opened file, if it was created successfully and no error happened
+    /// since then.
+    file: Option<fs::File>,
+
+    /// The first [`io::Error`] happened while creating or writing the file.
+    error: Option<io::Error>,
+}
+
+impl State {
+    /// Creates (or truncates) the file, unless it was tried already.
+    fn open(&mut self) {
+        if self.file.is_none() && self.error.is_none() {
+            match fs::File::create(&self.path) {
+                Ok(f) => self.file = Some(f),
+                Err(e) => self.error = Some(e),
+            }
+        }
+    }
+
+    /// Flushes and syncs the file to disk, if it's opened.
+    fn sync(&mut self) {
+        if let Some(file) = &mut self.file {
+            if let Err(e) = file.flush().and_then(|()| file.sync_all()) {
+                self.fail(e);
+            }
+        }
+    }
+
+    /// Records the given [`io::Error`], closing the file.
+    fn fail(&mut self, err: io::Error) {
+        self.file = None;
+        if self.error.is_none() {
+            self.error = Some(err);
+        }
+    }
+
+    /// Indicates whether any [`io::Error`] has happened.
+    const fn has_failed(&self) -> bool {
+        self.error.is_some()
+    }
+}
@@ -0,0 +1,50 @@
+use cucumber::{
+    gherkin::{Step, StepType},
+    step::{Collection, Location},
+};
+use futures::FutureExt as _;
+use regex::Regex;
+
+fn step(value: &str) -> Step {
+    Step {
+        keyword: "Given".into(),
+        ty: StepType::Given,
+        value: value.into(),
+        docstring: None,
+        table: None,
+        span: Default::default(),
+        position: Default::default(),
+    }
+}
+
+#[test]
+fn lists_all_possible_matches() {
+    let loc = |line| Location {
+        path: "tests/steps.rs",
+        line,
+        column: 1,
+    };
+    let collection = Collection::<()>::new()
+        .given(Some(loc(20)), Regex::new("^an? cat$").unwrap(), |_, _| {
+            async {}.boxed_local()
+        })
+        .given(Some(loc(10)), Regex::new("^a cat$").unwrap(), |_, _| {
+            async {}.boxed_local()
+        })
+        .given(None, Regex::new("cat").unwrap(), |_, _| {
+            async {}.boxed_local()
+        })
+        .given(None, Regex::new("^a dog$").unwrap(), |_, _| {
+            async {}.boxed_local()
+        });
+
+    let err = collection.find(&step("a cat")).unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Possible matches:\n  \
+           ^a cat$ --> tests/steps.rs:10:1\n  \
+           ^an? cat$ --> tests/steps.rs:20:1\n  \
+           cat",
+    );
+}
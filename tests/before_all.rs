@@ -0,0 +1,104 @@
+mod common;
+
+use std::cell::RefCell;
+
+use cucumber::{given, WorldInit};
+
+use self::common::{sink, World};
+
+thread_local! {
+    /// Log of the executed hooks and `Step`s, in order.
+    static LOG: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+}
+
+#[given("the environment is set up")]
+fn set_up(_: &mut World) {
+    assert!(
+        LOG.with(|l| l.borrow().contains(&"before_all")),
+        "environment is not set up",
+    );
+    LOG.with(|l| l.borrow_mut().push("step"));
+}
+
+#[given("a panicking step")]
+fn panicking(_: &mut World) {
+    panic!("scenario failed");
+}
+
+#[tokio::test]
+async fn runs_once_around_all_scenarios() {
+    let writer = World::cucumber()
+        .before_all(|| async {
+            LOG.with(|l| l.borrow_mut().push("before_all"));
+        })
+        .after_all(|| async {
+            LOG.with(|l| l.borrow_mut().push("after_all"));
+        })
+        .with_writer(sink())
+        .run("tests/features/before_all")
+        .await;
+
+    assert_eq!(
+        LOG.with(|l| l.borrow().clone()),
+        ["before_all", "step", "step", "after_all"],
+    );
+    assert_eq!(writer.scenarios.passed, 2);
+    assert_eq!(writer.failed_hooks, 0);
+}
+
+#[tokio::test]
+async fn failing_before_all_aborts_run() {
+    let writer = World::cucumber()
+        .before_all(|| async {
+            panic!("no environment");
+        })
+        .after_all(|| async {
+            LOG.with(|l| l.borrow_mut().push("after_all"));
+        })
+        .with_writer(sink())
+        .run("tests/features/before_all")
+        .await;
+
+    assert_eq!(LOG.with(|l| l.borrow().clone()), ["after_all"]);
+    assert_eq!(writer.scenarios.total(), 0);
+    assert_eq!(writer.not_executed_scenarios, 2);
+    assert_eq!(writer.failed_hooks, 1);
+}
+
+#[tokio::test]
+async fn failing_after_all_fails_run() {
+    let writer = World::cucumber()
+        .before_all(|| async {
+            LOG.with(|l| l.borrow_mut().push("before_all"));
+        })
+        .after_all(|| async {
+            panic!("no teardown");
+        })
+        .with_writer(sink())
+        .run("tests/features/before_all")
+        .await;
+
+    assert_eq!(writer.scenarios.passed, 2);
+    assert_eq!(writer.failed_hooks, 1);
+}
+
+#[tokio::test]
+async fn after_all_runs_on_failed_scenarios() {
+    let writer = World::cucumber()
+        .before_all(|| async {
+            LOG.with(|l| l.borrow_mut().push("before_all"));
+        })
+        .after_all(|| async {
+            LOG.with(|l| l.borrow_mut().push("after_all"));
+        })
+        .with_writer(sink())
+        .run("tests/features/before_all_failed")
+        .await;
+
+    assert_eq!(
+        LOG.with(|l| l.borrow().clone()),
+        ["before_all", "after_all"],
+    );
+    assert_eq!(writer.scenarios.failed, 1);
+    assert_eq!(writer.failed_hooks, 0);
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use std::{io::Write as _, time::Duration};
+
+use async_trait::async_trait;
+use cucumber::{
+    capture, cli, event, given, parser, then, writer, Event, WorldInit, Writer,
+};
+use tokio::time;
+
+use self::common::World;
+
+#[given(regex = r#"^"(\w+)" says hello$"#)]
+async fn hello(_: &mut World, who: String) {
+    writeln!(capture::stdout(), "{}: hello", who).unwrap();
+}
+
+#[then(regex = r#"^"(\w+)" fails after saying bye$"#)]
+async fn bye(_: &mut World, who: String) {
+    writeln!(capture::stdout(), "{}: bye", who).unwrap();
+    // Let the other `Scenario` write its output in the meantime.
+    time::sleep(Duration::from_millis(50)).await;
+    writeln!(capture::stderr(), "{}: failing", who).unwrap();
+    panic!("{} failed", who);
+}
+
+/// Records captured outputs along with the `Step`s they belong to.
+#[derive(Default)]
+struct Outputs(Vec<(String, String)>);
+
+#[async_trait(?Send)]
+impl Writer<World> for Outputs {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Scenario, Step};
+
+        if let Ok(Cucumber::Feature(
+            _,
+            Feature::Scenario(_, Scenario::Step(st, Step::Output(out))),
+        )) = ev.map(Event::into_inner)
+        {
+            self.0.push((st.value.clone(), out));
+        }
+    }
+}
+
+impl writer::Normalized for Outputs {}
+
+#[tokio::test]
+async fn attaches_captured_output_to_failed_steps_only() {
+    let mut writer = World::cucumber()
+        .capture_output(true)
+        .with_writer(Outputs::default())
+        .run("tests/features/capture_output")
+        .await;
+
+    writer.0.sort();
+    assert_eq!(
+        writer.0,
+        [
+            (
+                "\"first\" fails after saying bye".to_owned(),
+                "first: bye\nfirst: failing\n".to_owned(),
+            ),
+            (
+                "\"second\" fails after saying bye".to_owned(),
+                "second: bye\nsecond: failing\n".to_owned(),
+            ),
+        ],
+    );
+}
+
+#[tokio::test]
+async fn emits_no_output_unless_enabled() {
+    let writer = World::cucumber()
+        .with_writer(Outputs::default())
+        .run("tests/features/capture_output")
+        .await;
+
+    assert!(writer.0.is_empty());
+}
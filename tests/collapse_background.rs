@@ -0,0 +1,94 @@
+use std::{
+    convert::Infallible,
+    io,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use cucumber::{
+    given, then,
+    writer::{self, Coloring},
+    WorldInit, WriterExt as _,
+};
+use futures::FutureExt as _;
+
+#[derive(Debug, Default, WorldInit)]
+struct World {
+    fail_background: bool,
+}
+
+#[async_trait(?Send)]
+impl cucumber::World for World {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Self::Error> {
+        Ok(Self::default())
+    }
+}
+
+#[given("a background step")]
+fn background_step(w: &mut World) {
+    assert!(!w.fail_background, "background failed");
+}
+
+#[given(regex = "(another|a rule) background step")]
+#[then("a step")]
+fn step(_: &mut World) {}
+
+/// Shared output to inspect after a run.
+#[derive(Clone, Default)]
+struct Output(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Runs the test `Feature` with the given [`writer::Basic`] and returns its
+/// output.
+async fn run(basic: impl FnOnce(Output) -> writer::Basic<Output>) -> String {
+    let out = Output::default();
+    drop(
+        World::cucumber()
+            .before(|_, _, sc, w| {
+                w.fail_background =
+                    sc.tags.iter().any(|t| t == "fail_background");
+                async {}.boxed_local()
+            })
+            .with_writer(basic(out.clone()).normalized())
+            .run("tests/features/collapse_background")
+            .await,
+    );
+    let bytes = out.0.lock().unwrap().clone();
+    String::from_utf8(bytes).unwrap()
+}
+
+#[tokio::test]
+async fn outputs_whole_background_by_default() {
+    let output =
+        run(|out| writer::Basic::raw(out, Coloring::Never, false)).await;
+
+    assert_eq!(output.matches("(background)").count(), 0);
+    assert_eq!(output.matches("✔> Given a background step").count(), 4);
+    assert_eq!(output.matches("✔> Given a rule background step").count(), 2);
+}
+
+#[tokio::test]
+async fn collapses_passed_background() {
+    let output = run(|out| {
+        writer::Basic::raw(out, Coloring::Never, false).collapse_background()
+    })
+    .await;
+
+    assert_eq!(output.matches("✔> (background)").count(), 3, "{}", output);
+    assert_eq!(output.matches("✔> Given a background step").count(), 1);
+    assert_eq!(output.matches("✔> And another background step").count(), 1);
+    assert_eq!(output.matches("✘> Given").count(), 1, "{}", output);
+    assert_eq!(output.matches("✔> Given a rule background step").count(), 1);
+}
@@ -0,0 +1,49 @@
+//! Fixtures shared by the integration tests.
+
+// Every test binary uses only a part of the fixtures.
+#![allow(dead_code)]
+
+use std::{convert::Infallible, fmt::Debug, io};
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, parser, writer, Event, WorldInit, Writer, WriterExt as _,
+};
+
+/// Stateless [`cucumber::World`] for the tests not needing any state.
+#[derive(Debug, WorldInit)]
+pub struct World;
+
+#[async_trait(?Send)]
+impl cucumber::World for World {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Self::Error> {
+        Ok(Self)
+    }
+}
+
+/// [`writer::Summarize`]d [`writer::Basic`] discarding its output.
+pub type Summary<W = World> =
+    writer::Summarize<writer::Normalize<W, writer::Basic<io::Sink>>>;
+
+/// Creates a new [`Summary`] [`Writer`] to inspect the run statistics with.
+pub fn sink<W: cucumber::World + Debug>() -> Summary<W> {
+    writer::Basic::new(io::sink(), writer::Coloring::Never, false).summarized()
+}
+
+/// [`Writer`] ignoring all the received events.
+#[derive(Debug)]
+pub struct Discard;
+
+#[async_trait(?Send)]
+impl<W: cucumber::World> Writer<W> for Discard {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        _: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+    }
+}
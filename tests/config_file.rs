@@ -0,0 +1,171 @@
+mod common;
+
+use std::{env, io::Write as _, process::Command};
+
+use cucumber::{cli, parser, runner, writer, WorldInit};
+use structopt::StructOpt;
+use tempfile::NamedTempFile;
+
+use self::common::{sink, World};
+
+type Opts =
+    cli::Opts<parser::basic::Cli, runner::basic::Cli, writer::basic::Cli>;
+
+#[derive(Debug, StructOpt)]
+struct Custom {
+    /// Runs all the scenarios, conflicting with `--name`.
+    #[structopt(long, conflicts_with = "regex")]
+    all: bool,
+}
+
+fn config(content: &str) -> NamedTempFile {
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+    file
+}
+
+#[test]
+fn takes_defaults_from_file() {
+    let file = config(
+        "concurrency = 4\n\
+         verbose = true\n\
+         color = \"never\"\n\
+         name = \"eat\"\n",
+    );
+
+    let opts = Opts::from_iter_with_config(["test"], file.path()).unwrap();
+
+    assert_eq!(opts.runner.concurrency, Some(4));
+    assert!(opts.writer.verbose);
+    assert!(matches!(opts.writer.color, writer::Coloring::Never));
+    assert_eq!(opts.re_filter.unwrap().as_str(), "eat");
+}
+
+#[test]
+fn cli_overrides_file() {
+    let file = config("concurrency = 4\ncolor = \"never\"\n");
+
+    let opts = Opts::from_iter_with_config(
+        ["test", "-c", "2", "--color=always"],
+        file.path(),
+    )
+    .unwrap();
+
+    assert_eq!(opts.runner.concurrency, Some(2));
+    assert!(matches!(opts.writer.color, writer::Coloring::Always));
+}
+
+#[test]
+fn drops_file_options_conflicting_with_cli() {
+    let file = config("all = true\nconcurrency = 4\n");
+
+    let opts =
+        cli::Opts::<
+            parser::basic::Cli,
+            runner::basic::Cli,
+            writer::basic::Cli,
+            Custom,
+        >::from_iter_with_config(["test", "--name", "eat"], file.path())
+        .unwrap();
+
+    assert!(!opts.custom.all);
+    assert_eq!(opts.re_filter.unwrap().as_str(), "eat");
+    assert_eq!(opts.runner.concurrency, Some(4));
+}
+
+#[test]
+fn takes_output_format_from_file() {
+    let file = config("format = \"pretty\"\n");
+
+    let opts = cli::Opts::<
+        parser::basic::Cli,
+        runner::basic::Cli,
+        writer::format::Cli,
+    >::from_iter_with_config(["test"], file.path())
+    .unwrap();
+
+    assert_eq!(opts.writer.format, Some(writer::format::Name::Pretty));
+}
+
+#[tokio::test]
+async fn takes_input_glob_from_file() {
+    let file = config("input = \"tests/features/glob/**/*.feature\"\n");
+
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .with_cli(Opts::from_iter_with_config(["test"], file.path()).unwrap())
+        .run("tests/features/readme")
+        .await;
+
+    assert_eq!(writer.features, 2);
+    assert_eq!(writer.parsing_errors, 0);
+}
+
+/// Environment variable making [`print_path_filter()`] actually run.
+const CHILD_VAR: &str = "CUCUMBER_CONFIG_FILE_CHILD";
+
+/// Runs this test binary's [`print_path_filter()`] test with the given
+/// `args` and `CUCUMBER_PATH` environment variable, and returns its stdout.
+///
+/// Runs in a separate process, so the environment variable doesn't leak into
+/// other tests.
+fn run_child(args: &[&str]) -> String {
+    let output = Command::new(env::current_exe().unwrap())
+        .args(["--ignored", "--exact", "print_path_filter", "--nocapture"])
+        .env(CHILD_VAR, args.join(" "))
+        .env("CUCUMBER_PATH", "tests/features/env/**")
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+#[ignore = "is run by other tests with an environment variable set"]
+fn print_path_filter() {
+    let args = match env::var(CHILD_VAR) {
+        Ok(args) => args,
+        Err(_) => return,
+    };
+
+    let file = config("path = \"tests/features/file/**\"\n");
+    let opts = Opts::from_iter_with_config(
+        ["test"].into_iter().chain(args.split_whitespace()),
+        file.path(),
+    )
+    .unwrap();
+
+    println!("path: {}", opts.path_filter.unwrap().glob());
+}
+
+#[test]
+fn env_overrides_file() {
+    let env = run_child(&[]);
+    let cli = run_child(&["--path", "tests/features/cli/**"]);
+
+    assert!(env.contains("path: tests/features/env/**"), "{}", env);
+    assert!(cli.contains("path: tests/features/cli/**"), "{}", cli);
+}
+
+#[test]
+fn errors_on_invalid_file() {
+    let unsupported = config("[runner]\nconcurrency = 4\n");
+    let invalid = config("concurrency = \n");
+    let unknown = config("unknown = 1\n");
+
+    assert!(matches!(
+        Opts::from_iter_with_config(["test"], unsupported.path()),
+        Err(cli::ConfigFileError::UnsupportedValue(key)) if key == "runner",
+    ));
+    assert!(matches!(
+        Opts::from_iter_with_config(["test"], invalid.path()),
+        Err(cli::ConfigFileError::Toml(_)),
+    ));
+    assert!(matches!(
+        Opts::from_iter_with_config(["test"], unknown.path()),
+        Err(cli::ConfigFileError::Cli(_)),
+    ));
+    assert!(matches!(
+        Opts::from_iter_with_config(["test"], "non-existent.toml"),
+        Err(cli::ConfigFileError::Io(_)),
+    ));
+}
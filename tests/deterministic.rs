@@ -0,0 +1,86 @@
+mod common;
+
+use std::{
+    fs, io,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use cucumber::{
+    cli, given, then,
+    writer::{self, Coloring},
+    WorldInit, WriterExt as _,
+};
+use structopt::StructOpt as _;
+use tokio::time;
+
+use self::common::World;
+
+#[given("a slow step")]
+async fn slow_step(_: &mut World) {
+    time::sleep(Duration::from_millis(100)).await;
+}
+
+#[given("a step")]
+#[then("a step")]
+fn step(_: &mut World) {}
+
+/// Shared output to inspect after a run.
+#[derive(Clone, Default)]
+struct Output(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Runs the test `Feature` with a deterministic [`writer::Basic`], shuffling
+/// its `Scenario`s and emitting heartbeats of its slow `Step`, and returns the
+/// output.
+async fn run() -> String {
+    let mut args = vec![
+        "test",
+        "--shuffle-seed",
+        "7",
+        "--concurrency",
+        "1",
+        "--color",
+        "always",
+    ];
+    if cfg!(feature = "timestamps") {
+        args.push("--show-timings");
+    }
+
+    let out = Output::default();
+    drop(
+        World::cucumber()
+            .step_heartbeat(Duration::from_millis(20))
+            .with_writer(
+                writer::Basic::raw(out.clone(), Coloring::Auto, false)
+                    .deterministic()
+                    .normalized(),
+            )
+            .with_cli(cli::Opts::<_, _, _>::from_iter(args))
+            .run("tests/features/deterministic")
+            .await,
+    );
+    let bytes = out.0.lock().unwrap().clone();
+    String::from_utf8(bytes).unwrap()
+}
+
+#[tokio::test]
+async fn outputs_snapshot() {
+    let expected = fs::read_to_string(
+        "tests/features/deterministic/deterministic.feature.out",
+    )
+    .unwrap();
+
+    assert_eq!(run().await, expected);
+    assert_eq!(run().await, expected);
+}
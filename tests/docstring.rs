@@ -0,0 +1,116 @@
+mod common;
+
+use cucumber::{
+    docstring::{self, StepExt as _},
+    gherkin::{Step, StepType},
+    given, WorldInit,
+};
+use serde::Deserialize;
+
+use self::common::{sink, World};
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct User {
+    name: String,
+    age: u8,
+}
+
+#[given("the user")]
+fn user(_: &mut World, #[step] step: &Step) -> Result<(), docstring::Error> {
+    let _: User = step.docstring_as()?;
+    Ok(())
+}
+
+fn step(docstring: Option<&str>) -> Step {
+    Step {
+        keyword: "Given".into(),
+        ty: StepType::Given,
+        value: "the user".into(),
+        docstring: docstring.map(ToOwned::to_owned),
+        table: None,
+        span: Default::default(),
+        position: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn deserializes_step_docstrings() {
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .run("tests/features/docstring")
+        .await;
+
+    assert_eq!(writer.scenarios.passed, 3);
+    assert_eq!(writer.scenarios.failed, 1);
+}
+
+#[test]
+fn honors_media_type() {
+    let alice = User {
+        name: "Alice".into(),
+        age: 30,
+    };
+
+    let json = step(Some("\n{\"name\": \"Alice\", \"age\": 30}\n"));
+    assert_eq!(json.docstring_as::<User>().unwrap(), alice);
+
+    let yaml = step(Some("yaml\n    name: Alice\n    age: 30\n"));
+    assert_eq!(yaml.docstring_as::<User>().unwrap(), alice);
+    assert_eq!(yaml.docstring_as_yaml::<User>().unwrap(), alice);
+
+    let untyped_yaml = step(Some("\nname: Alice\nage: 30\n"));
+    assert!(untyped_yaml.docstring_as::<User>().is_err());
+    assert_eq!(untyped_yaml.docstring_as_yaml::<User>().unwrap(), alice);
+}
+
+#[test]
+fn errors_with_docstring_line() {
+    let err = step(Some(
+        "json\n  {\n    \"name\": \"Dave\",\n    \"age\": old\n  }\n",
+    ))
+    .docstring_as::<User>()
+    .unwrap_err();
+    assert!(
+        matches!(err, docstring::Error::Json { line: Some(3), .. }),
+        "wrong error: {}",
+        err,
+    );
+
+    let err = step(Some("yaml\n  name: Dave\n  age: old\n"))
+        .docstring_as::<User>()
+        .unwrap_err();
+    assert!(
+        matches!(err, docstring::Error::Yaml { line: Some(2), .. }),
+        "wrong error: {}",
+        err,
+    );
+
+    let err = step(Some("toml\nname = \"Dave\"\n"))
+        .docstring_as::<User>()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Docstring media type `toml` is not supported"
+    );
+
+    let err = step(None).docstring_as::<User>().unwrap_err();
+    assert_eq!(err.to_string(), "Step has no docstring");
+}
+
+#[test]
+fn exposes_raw_content() {
+    let typed = step(Some("yaml\n    name: Alice\n\n    age: 30\n"));
+    assert_eq!(typed.docstring_media_type(), Some("yaml"));
+    assert_eq!(
+        typed.docstring_content().unwrap(),
+        "name: Alice\n\nage: 30\n"
+    );
+
+    let untyped = step(Some("\nname: Alice\n"));
+    assert_eq!(untyped.docstring_media_type(), None);
+    assert_eq!(untyped.docstring_content().unwrap(), "name: Alice\n");
+
+    let missing = step(None);
+    assert_eq!(missing.docstring_media_type(), None);
+    assert!(missing.docstring_content().is_err());
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use std::{cell::Cell, convert::Infallible};
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, writer, WorldInit};
+
+use self::common::sink;
+
+thread_local! {
+    /// Number of the constructed `World`s and executed `Step`s.
+    static EXECUTED: Cell<usize> = Cell::new(0);
+}
+
+#[derive(Debug, WorldInit)]
+struct World;
+
+#[async_trait(?Send)]
+impl cucumber::World for World {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Self::Error> {
+        EXECUTED.with(|e| e.set(e.get() + 1));
+        Ok(Self)
+    }
+}
+
+#[given("a step")]
+#[when("a step")]
+#[then("a step")]
+fn step(_: &mut World) {
+    EXECUTED.with(|e| e.set(e.get() + 1));
+}
+
+#[then(regex = "^an ambiguous step$")]
+fn ambiguous(_: &mut World) {
+    EXECUTED.with(|e| e.set(e.get() + 1));
+}
+
+#[then(regex = "^an? ambiguous step$")]
+fn ambiguous_too(_: &mut World) {
+    EXECUTED.with(|e| e.set(e.get() + 1));
+}
+
+#[tokio::test]
+async fn matches_steps_without_executing_them() {
+    let writer = World::cucumber()
+        .dry_run(true)
+        .with_writer(sink())
+        .run("tests/features/dry_run")
+        .await;
+
+    assert_eq!(EXECUTED.with(Cell::get), 0);
+    assert!(writer.dry_run);
+    assert_eq!(writer.scenarios.passed, 1);
+    assert_eq!(writer.steps.passed, 3);
+    assert_eq!(writer.steps.total(), 3);
+}
+
+#[tokio::test]
+async fn continues_after_unmatched_steps() {
+    let writer = World::cucumber()
+        .dry_run(true)
+        .with_writer(sink())
+        .run("tests/features/dry_run_unmatched")
+        .await;
+
+    assert_eq!(EXECUTED.with(Cell::get), 0);
+    assert_eq!(writer.scenarios.failed, 1);
+    assert_eq!(writer.scenarios.total(), 1);
+    assert_eq!(writer.steps.passed, 2);
+    assert_eq!(writer.steps.skipped, 1);
+    assert_eq!(writer.steps.failed, 1);
+}
+
+#[tokio::test]
+async fn summarizes_undefined_steps() {
+    let writer = World::cucumber()
+        .dry_run(true)
+        .with_writer(sink())
+        .run("tests/features/dry_run_unmatched")
+        .await;
+
+    assert!(writer::out::Styles::new()
+        .summary(&writer)
+        .ends_with("\n1 undefined step"));
+}
+
+#[tokio::test]
+#[should_panic(expected = "2 steps failed")]
+async fn fails_on_unmatched_steps() {
+    World::cucumber()
+        .dry_run(true)
+        .with_writer(sink())
+        .run_and_exit("tests/features/dry_run_unmatched")
+        .await;
+}
+
+#[tokio::test]
+async fn passes_without_unmatched_steps() {
+    World::cucumber()
+        .dry_run(true)
+        .with_writer(sink())
+        .run_and_exit("tests/features/dry_run")
+        .await;
+
+    assert_eq!(EXECUTED.with(Cell::get), 0);
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, Event, Parser, WorldInit, Writer, WriterExt as _,
+};
+use futures::{stream::LocalBoxStream, StreamExt as _};
+
+use self::common::World;
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+/// `Parser` discovering every `Feature` twice, like overlapping globs would.
+struct Twice;
+
+impl Parser<&'static str> for Twice {
+    type Cli = cli::Empty;
+
+    type Output = LocalBoxStream<'static, parser::Result<gherkin::Feature>>;
+
+    fn parse(self, input: &'static str, _: Self::Cli) -> Self::Output {
+        let parse = || {
+            parser::Basic::new()
+                .parse(input, parser::basic::Cli { features: None })
+        };
+        parse().chain(parse()).boxed_local()
+    }
+}
+
+/// Records `(feature, scenario)` names of started `Scenario`s and the number
+/// of parsed `Feature`s.
+#[derive(Default)]
+struct Started {
+    features: usize,
+    scenarios: Vec<String>,
+}
+
+#[async_trait(?Send)]
+impl Writer<World> for Started {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule, Scenario};
+
+        match ev.map(Event::into_inner) {
+            Ok(Cucumber::ParsingFinished { features, .. }) => {
+                self.features = features;
+            }
+            Ok(Cucumber::Feature(
+                _,
+                Feature::Rule(_, Rule::Scenario(sc, Scenario::Started))
+                | Feature::Scenario(sc, Scenario::Started),
+            )) => self.scenarios.push(sc.name.clone()),
+            _ => {}
+        }
+    }
+}
+
+#[tokio::test]
+async fn equal_features_run_once() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(Twice)
+        .with_writer(Started::default().normalized())
+        .run("tests/features/duplicate")
+        .await;
+
+    assert_eq!(writer.features, 1);
+    let mut scenarios = writer.scenarios.clone();
+    scenarios.sort();
+    assert_eq!(scenarios, ["first", "second"]);
+}
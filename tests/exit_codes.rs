@@ -0,0 +1,96 @@
+mod common;
+
+use std::{
+    env,
+    process::{Command, Stdio},
+};
+
+use cucumber::{cli, given, then, ExitCodes, WorldInit};
+use futures::FutureExt as _;
+use structopt::StructOpt as _;
+
+use self::common::{sink, World};
+
+#[given("a passing step")]
+fn passing_step(_: &mut World) {}
+
+#[then("a failing step")]
+fn failing_step(_: &mut World) {
+    panic!("failed");
+}
+
+/// Environment variable making [`run_and_exit()`] actually run, holding the
+/// tag of the `Scenario` to run, or `parse_error` for running a malformed
+/// `Feature`.
+const CHILD_VAR: &str = "CUCUMBER_EXIT_CODES_CHILD";
+
+/// Runs this test binary's [`run_and_exit()`] test for the given `case`, and
+/// returns its exit code.
+fn exit_code(case: &str) -> Option<i32> {
+    Command::new(env::current_exe().unwrap())
+        .args(["--ignored", "--exact", "run_and_exit", "--nocapture"])
+        .env(CHILD_VAR, case)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .unwrap()
+        .code()
+}
+
+#[tokio::test]
+#[ignore = "is run by other tests in a child process"]
+async fn run_and_exit() {
+    let case = match env::var(CHILD_VAR) {
+        Ok(case) => case,
+        Err(_) => return,
+    };
+    let input = if case == "parse_error" {
+        "tests/features/output/parsing_failed.feature"
+    } else {
+        "tests/features/exit_codes"
+    };
+    let tags = format!("@{}", case);
+
+    World::cucumber()
+        .before(|_, _, sc, _| {
+            async move {
+                assert!(!sc.tags.iter().any(|t| t == "hook_failed"), "failed");
+            }
+            .boxed_local()
+        })
+        .with_writer(sink())
+        .with_cli(cli::Opts::<_, _, _>::from_iter(["test", "--tags", &tags]))
+        .exit_codes(ExitCodes {
+            failures: 2,
+            parse_errors: 3,
+            hook_errors: 4,
+            undefined: 5,
+        })
+        .run_and_exit(input)
+        .await;
+}
+
+#[test]
+fn exits_with_zero_when_passed() {
+    assert_eq!(exit_code("passed"), Some(0));
+}
+
+#[test]
+fn exits_with_failures_code() {
+    assert_eq!(exit_code("failed"), Some(2));
+}
+
+#[test]
+fn exits_with_parse_errors_code() {
+    assert_eq!(exit_code("parse_error"), Some(3));
+}
+
+#[test]
+fn exits_with_hook_errors_code() {
+    assert_eq!(exit_code("hook_failed"), Some(4));
+}
+
+#[test]
+fn exits_with_undefined_code() {
+    assert_eq!(exit_code("undefined"), Some(5));
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use std::{cell::Cell, time::Duration};
+
+use cucumber::{given, writer, WorldInit};
+use tokio::time;
+
+use self::common::{sink, World};
+
+thread_local! {
+    /// Indicator whether the slow `Step` has completed.
+    static SLOW_COMPLETED: Cell<bool> = Cell::new(false);
+}
+
+#[given("a passing step")]
+fn passing(_: &mut World) {}
+
+#[given("a failing step")]
+fn failing(_: &mut World) {
+    panic!("failed");
+}
+
+#[given("a slow step")]
+async fn slow(_: &mut World) {
+    time::sleep(Duration::from_millis(300)).await;
+    SLOW_COMPLETED.with(|c| c.set(true));
+}
+
+#[tokio::test]
+async fn executes_all_scenarios_by_default() {
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .with_writer(sink())
+        .run("tests/features/fail_fast")
+        .await;
+
+    assert_eq!(writer.scenarios.passed, 3);
+    assert_eq!(writer.scenarios.failed, 1);
+    assert_eq!(writer.not_executed_scenarios, 0);
+}
+
+#[tokio::test]
+async fn executes_all_scenarios_if_disabled() {
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .fail_fast(true)
+        .fail_fast(false)
+        .with_writer(sink())
+        .run("tests/features/fail_fast")
+        .await;
+
+    assert_eq!(writer.scenarios.passed, 3);
+    assert_eq!(writer.scenarios.failed, 1);
+    assert_eq!(writer.not_executed_scenarios, 0);
+}
+
+#[tokio::test]
+async fn stops_starting_scenarios_after_failure() {
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .fail_fast(true)
+        .with_writer(sink())
+        .run("tests/features/fail_fast")
+        .await;
+
+    assert_eq!(writer.features, 1);
+    assert_eq!(writer.rules, 1);
+    assert_eq!(writer.scenarios.passed, 1);
+    assert_eq!(writer.scenarios.failed, 1);
+    assert_eq!(writer.not_executed_scenarios, 2);
+    assert!(writer::Failure::<World>::execution_has_failed(&writer));
+}
+
+#[tokio::test]
+async fn completes_in_flight_scenarios() {
+    SLOW_COMPLETED.with(|c| c.set(false));
+
+    let writer = World::cucumber()
+        .fail_fast_complete()
+        .with_writer(sink())
+        .run("tests/features/fail_fast_cancel")
+        .await;
+
+    assert_eq!(writer.scenarios.passed, 1);
+    assert_eq!(writer.scenarios.failed, 1);
+    assert!(SLOW_COMPLETED.with(Cell::get));
+}
+
+#[tokio::test]
+async fn cancels_in_flight_scenarios() {
+    SLOW_COMPLETED.with(|c| c.set(false));
+
+    let writer = World::cucumber()
+        .fail_fast(true)
+        .with_writer(sink())
+        .run("tests/features/fail_fast_cancel")
+        .await;
+
+    assert_eq!(writer.scenarios.skipped, 1);
+    assert_eq!(writer.scenarios.failed, 1);
+    assert_eq!(writer.not_executed_scenarios, 0);
+    assert!(writer::Failure::<World>::execution_has_failed(&writer));
+    assert!(!SLOW_COMPLETED.with(Cell::get));
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use std::{io, panic::AssertUnwindSafe};
+
+use cucumber::{
+    given,
+    writer::{self, Coloring},
+    FailIfFn, WorldInit, WriterExt as _,
+};
+use futures::FutureExt as _;
+
+use self::common::World;
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+#[given("a failing step")]
+fn failing_step(_: &mut World) {
+    panic!("failed");
+}
+
+#[tokio::test]
+async fn fails_when_predicate_matches() {
+    let res = World::cucumber()
+        .with_writer(
+            writer::Basic::new(io::sink(), Coloring::Never, false)
+                .summarized()
+                .normalized(),
+        )
+        .fail_if(|summary| summary.undefined_steps > 0)
+        .filter_run_and_exit("tests/features/fail_if", |_, _, sc| {
+            sc.name != "failing"
+        });
+
+    let err = AssertUnwindSafe(res)
+        .catch_unwind()
+        .await
+        .expect_err("should err");
+    let err = err.downcast_ref::<String>().unwrap();
+
+    assert_eq!(err, "`Cucumber::fail_if()` predicate matched");
+}
+
+#[tokio::test]
+async fn ignores_failures_not_matched_by_predicate() {
+    World::cucumber()
+        .with_writer(
+            writer::Basic::new(io::sink(), Coloring::Never, false)
+                .summarized()
+                .normalized(),
+        )
+        .fail_if(|summary| summary.parsing_errors > 0)
+        .run_and_exit("tests/features/fail_if")
+        .await;
+}
+
+/// Indicates whether a run fails with the given `predicate`.
+async fn fails_with(predicate: FailIfFn) -> bool {
+    let res = World::cucumber()
+        .with_writer(
+            writer::Basic::new(io::sink(), Coloring::Never, false)
+                .summarized()
+                .normalized(),
+        )
+        .fail_if(predicate)
+        .run_and_exit("tests/features/fail_if");
+
+    AssertUnwindSafe(res).catch_unwind().await.is_err()
+}
+
+#[tokio::test]
+async fn counts_steps_by_outcome() {
+    assert!(fails_with(|s| s.total_steps == 6).await);
+    assert!(!fails_with(|s| s.total_steps != 6).await);
+
+    assert!(fails_with(|s| s.passed_steps == 2).await);
+    assert!(!fails_with(|s| s.passed_steps != 2).await);
+
+    assert!(fails_with(|s| s.failed_steps == 1).await);
+    assert!(!fails_with(|s| s.failed_steps != 1).await);
+
+    assert!(fails_with(|s| s.undefined_steps == 1).await);
+    assert!(!fails_with(|s| s.undefined_steps != 1).await);
+
+    assert!(fails_with(|s| s.skipped_steps == 2).await);
+    assert!(!fails_with(|s| s.skipped_steps != 2).await);
+}
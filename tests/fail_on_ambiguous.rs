@@ -0,0 +1,140 @@
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, then, writer, Event, WorldInit, Writer,
+};
+
+#[derive(Debug, Default, WorldInit)]
+struct World {
+    cats: usize,
+}
+
+#[async_trait(?Send)]
+impl cucumber::World for World {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Self::Error> {
+        Ok(Self::default())
+    }
+}
+
+#[given("a cat")]
+fn a_cat(w: &mut World) {
+    w.cats += 1;
+}
+
+#[given(regex = "^an? cat$")]
+fn any_cat(w: &mut World) {
+    w.cats += 10;
+}
+
+#[then("it meows")]
+fn meows(w: &mut World) {
+    assert_eq!(w.cats, 1, "wrong step function executed");
+}
+
+/// Records outcomes of `Step`s along with their error messages.
+#[derive(Default)]
+struct Outcomes(Vec<(String, &'static str, String)>);
+
+#[async_trait(?Send)]
+impl Writer<World> for Outcomes {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Scenario, Step};
+
+        if let Ok(Cucumber::Feature(
+            _,
+            Feature::Scenario(_, Scenario::Step(st, ev)),
+        )) = ev.map(Event::into_inner)
+        {
+            let (outcome, err) = match ev {
+                Step::Passed(_) => ("passed", String::new()),
+                Step::Matched(_) => ("matched", String::new()),
+                Step::Failed(_, _, e) => ("failed", e.to_string()),
+                Step::SoftFailed(_, e) => ("soft failed", e.to_string()),
+                _ => return,
+            };
+            self.0.push((st.value.clone(), outcome, err));
+        }
+    }
+}
+
+impl writer::Normalized for Outcomes {}
+
+/// Error listing both of the matching step functions above.
+const AMBIGUOUS: &str = "\
+Step match is ambiguous: Possible matches:
+  ^a cat$ --> tests/fail_on_ambiguous.rs:22:1
+  ^an? cat$ --> tests/fail_on_ambiguous.rs:27:1";
+
+#[tokio::test]
+async fn fails_ambiguous_step_by_default() {
+    let writer = World::cucumber()
+        .with_writer(Outcomes::default())
+        .run("tests/features/fail_on_ambiguous")
+        .await;
+
+    assert_eq!(
+        writer.0,
+        [("a cat".to_owned(), "failed", AMBIGUOUS.to_owned())],
+    );
+}
+
+#[tokio::test]
+async fn fails_ambiguous_step_in_dry_run() {
+    let writer = World::cucumber()
+        .dry_run(true)
+        .with_writer(Outcomes::default())
+        .run("tests/features/fail_on_ambiguous")
+        .await;
+
+    assert_eq!(
+        writer.0,
+        [
+            ("a cat".to_owned(), "failed", AMBIGUOUS.to_owned()),
+            ("it meows".to_owned(), "matched", String::new()),
+        ],
+    );
+}
+
+#[tokio::test]
+async fn warns_about_ambiguous_step_if_disabled() {
+    let writer = World::cucumber()
+        .fail_on_ambiguous(false)
+        .with_writer(Outcomes::default())
+        .run("tests/features/fail_on_ambiguous")
+        .await;
+
+    assert_eq!(
+        writer.0,
+        [
+            ("a cat".to_owned(), "soft failed", AMBIGUOUS.to_owned()),
+            ("it meows".to_owned(), "passed", String::new()),
+        ],
+    );
+}
+
+#[tokio::test]
+async fn warns_about_ambiguous_step_in_dry_run_if_disabled() {
+    let writer = World::cucumber()
+        .dry_run(true)
+        .fail_on_ambiguous(false)
+        .with_writer(Outcomes::default())
+        .run("tests/features/fail_on_ambiguous")
+        .await;
+
+    assert_eq!(
+        writer.0,
+        [
+            ("a cat".to_owned(), "soft failed", AMBIGUOUS.to_owned()),
+            ("it meows".to_owned(), "matched", String::new()),
+        ],
+    );
+}
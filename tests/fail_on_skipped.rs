@@ -0,0 +1,34 @@
+mod common;
+
+use cucumber::{given, WorldInit};
+
+use self::common::{sink, World};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+#[tokio::test]
+async fn fails_on_skipped_steps_unless_allowed_by_tag() {
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .fail_on_skipped()
+        .run("tests/features/fail_on_skipped")
+        .await;
+
+    assert_eq!(writer.writer.steps.failed, 3);
+    assert_eq!(writer.writer.steps.skipped, 1);
+    assert_eq!(writer.writer.scenarios.failed, 3);
+}
+
+#[tokio::test]
+async fn fails_on_skipped_steps_matching_predicate() {
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .fail_on_skipped_with(|_, _, _, step| !step.value.contains("allowed"))
+        .run("tests/features/fail_on_skipped")
+        .await;
+
+    assert_eq!(writer.writer.steps.failed, 3);
+    assert_eq!(writer.writer.steps.skipped, 1);
+    assert_eq!(writer.writer.scenarios.skipped, 1);
+}
@@ -0,0 +1,15 @@
+Feature: background failure
+  Background:
+    Given foo is 1
+
+  @serial
+  Scenario: first
+    Then foo is 2
+
+  @serial
+  Scenario: second
+    Then foo is 2
+
+  @serial
+  Scenario: third
+    Then foo is 2
@@ -0,0 +1,7 @@
+Feature: Global hooks
+
+  Scenario: first
+    Given the environment is set up
+
+  Scenario: second
+    Given the environment is set up
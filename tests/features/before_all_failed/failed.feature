@@ -0,0 +1,4 @@
+Feature: Failing suite
+
+  Scenario: panicking step
+    Given a panicking step
@@ -0,0 +1,9 @@
+Feature: Capturing output
+
+  Scenario: first
+    Given "first" says hello
+    Then "first" fails after saying bye
+
+  Scenario: second
+    Given "second" says hello
+    Then "second" fails after saying bye
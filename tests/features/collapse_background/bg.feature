@@ -0,0 +1,26 @@
+Feature: Collapsed background
+
+  Background:
+    Given a background step
+    And another background step
+
+  Scenario: first
+    Then a step
+
+  Scenario: second
+    Then a step
+
+  @fail_background
+  Scenario: third
+    Then a step
+
+  Rule: rule
+
+    Background:
+      Given a rule background step
+
+    Scenario: fourth
+      Then a step
+
+    Scenario: fifth
+      Then a step
@@ -0,0 +1,8 @@
+Feature: Deterministic
+
+  Scenario: slow
+    Given a slow step
+    Then a step
+
+  Scenario: fast
+    Given a step
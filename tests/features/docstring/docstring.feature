@@ -0,0 +1,32 @@
+Feature: Typed docstrings
+
+  Scenario: default JSON
+    Given the user
+      """
+      {"name": "Alice", "age": 30}
+      """
+
+  Scenario: JSON media type
+    Given the user
+      """json
+      {
+        "name": "Bob",
+        "age": 25
+      }
+      """
+
+  Scenario: YAML media type
+    Given the user
+      """yaml
+      name: Carol
+      age: 41
+      """
+
+  Scenario: malformed
+    Given the user
+      """json
+      {
+        "name": "Dave",
+        "age": old
+      }
+      """
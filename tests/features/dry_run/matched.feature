@@ -0,0 +1,8 @@
+Feature: Dry run
+
+  Background:
+    Given a step
+
+  Scenario: all steps matched
+    When a step
+    Then a step
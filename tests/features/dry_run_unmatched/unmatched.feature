@@ -0,0 +1,7 @@
+Feature: Dry run with unmatched steps
+
+  Scenario: unmatched and ambiguous steps
+    Given a step
+    When an unknown step
+    Then an ambiguous step
+    And a step
@@ -0,0 +1,9 @@
+Feature: Twice
+
+  Scenario: first
+    Given a step
+
+  Rule: rule
+
+    Scenario: second
+      Given a step
@@ -0,0 +1,18 @@
+Feature: Exit codes
+
+  @passed
+  Scenario: passed
+    Given a passing step
+
+  @failed
+  Scenario: failed
+    Given a passing step
+    Then a failing step
+
+  @hook_failed
+  Scenario: hook failed
+    Given a passing step
+
+  @undefined
+  Scenario: undefined
+    Given an undefined step
@@ -0,0 +1,12 @@
+Feature: First
+
+  Scenario: passing
+    Given a passing step
+
+  Rule: rule
+
+    Scenario: failing
+      Given a failing step
+
+    Scenario: not executed
+      Given a passing step
@@ -0,0 +1,4 @@
+Feature: Second
+
+  Scenario: not executed either
+    Given a passing step
@@ -0,0 +1,7 @@
+Feature: Cancel
+
+  Scenario: slow
+    Given a slow step
+
+  Scenario: failing
+    Given a failing step
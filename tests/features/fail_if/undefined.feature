@@ -0,0 +1,13 @@
+Feature: Fail if
+
+  Scenario: defined
+    Given a step
+    And a step
+
+  Scenario: undefined
+    Given an undefined step
+    Then a step
+
+  Scenario: failing
+    Given a failing step
+    Then a step
@@ -0,0 +1,5 @@
+Feature: Ambiguous steps
+
+  Scenario: ambiguous
+    Given a cat
+    Then it meows
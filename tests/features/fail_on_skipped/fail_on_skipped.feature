@@ -0,0 +1,19 @@
+Feature: Fail on skipped
+
+  Scenario: undefined step
+    Given an undefined step
+
+  Scenario: allowed undefined step
+    Given an allowed undefined step
+
+  @allow.skipped
+  Scenario: allowed scenario
+    Given an undefined step
+
+  Rule: with background
+
+    Background:
+      Given an undefined background step
+
+    Scenario: undefined background step
+      Given a step
@@ -0,0 +1,4 @@
+Feature: Format
+
+  Scenario: passing
+    Given a step
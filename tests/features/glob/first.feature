@@ -0,0 +1,4 @@
+Feature: First
+
+  Scenario: first
+    Given a step
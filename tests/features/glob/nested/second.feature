@@ -0,0 +1,4 @@
+Feature: Second
+
+  Scenario: second
+    Given a step
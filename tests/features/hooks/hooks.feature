@@ -0,0 +1,16 @@
+Feature: Hooks
+
+  Scenario: passing
+    Given a passing step
+
+  Scenario: failing
+    Given a failing step
+    And a passing step
+
+  Scenario: undefined
+    Given an undefined step
+    And a passing step
+
+  @fail_before
+  Scenario: failing before
+    Given a passing step
@@ -0,0 +1,22 @@
+@report
+Feature: HTML <report>
+
+  Background:
+    Given a passing step
+
+  Scenario: with arguments
+    Given a step with a table:
+      | name | value   |
+      | a    | <b>&c   |
+    When a step with a doc string:
+      """
+      <script>alert(1)</script>
+      """
+
+  Rule: failures
+
+    Scenario: failing
+      When a failing step
+
+    Scenario: skipped
+      When an undefined step
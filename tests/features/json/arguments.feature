@@ -0,0 +1,15 @@
+Feature: Step arguments
+
+  Background:
+    Given a step with a table:
+      | key | value |
+      | a   | 1     |
+
+  Scenario: docstring
+    When a step with a docstring:
+      """
+      some text
+      """
+
+  Scenario: failing
+    Then a failing step
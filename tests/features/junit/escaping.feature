@@ -0,0 +1,4 @@
+Feature: Escaping <"special"> & 'characters'
+
+  Scenario: CDATA ]]> end
+    Given a step failing with "<b>bold</b> & ]]> end"
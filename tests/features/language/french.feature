@@ -0,0 +1,6 @@
+Fonctionnalité: Français
+
+  Scénario: sans en-tête
+    Étant donné que le compteur vaut 0
+    Quand on incrémente le compteur
+    Alors le compteur vaut 1
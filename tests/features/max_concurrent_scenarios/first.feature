@@ -0,0 +1,10 @@
+Feature: First
+
+  Scenario: first 1
+    Given a slow step
+
+  Scenario: first 2
+    Given a slow step
+
+  Scenario: first 3
+    Given a slow step
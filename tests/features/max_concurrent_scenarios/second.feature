@@ -0,0 +1,10 @@
+Feature: Second
+
+  Scenario: second 1
+    Given a slow step
+
+  Scenario: second 2
+    Given a slow step
+
+  Scenario: second 3
+    Given a slow step
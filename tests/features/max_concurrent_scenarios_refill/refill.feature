@@ -0,0 +1,19 @@
+Feature: Refill
+
+  Scenario: long
+    Given a long step
+
+  Scenario: short 1
+    Given a short step
+
+  Scenario: short 2
+    Given a short step
+
+  Scenario: short 3
+    Given a short step
+
+  Scenario: short 4
+    Given a short step
+
+  Scenario: short 5
+    Given a short step
@@ -0,0 +1,7 @@
+Feature: multilingual
+  Scenario: english
+    Given foo is 0
+    When foo is 1
+    Then foo is 2
+    And foo is 3
+    But foo is 4
@@ -0,0 +1,8 @@
+# language: es
+Característica: multilingual
+  Escenario: spanish
+    Dado foo is 0
+    Cuando foo is 1
+    Entonces foo is 2
+    Y foo is 3
+    Pero foo is 4
@@ -0,0 +1,7 @@
+Feature: First
+
+  Scenario: login succeeds
+    Given a step
+
+  Scenario: logout succeeds
+    Given a step
@@ -0,0 +1,12 @@
+Feature: Second
+
+  Scenario: login fails
+    Given a step
+
+  Rule: admin
+
+    Scenario: admin login
+      Given a step
+
+    Scenario: admin logout
+      Given a step
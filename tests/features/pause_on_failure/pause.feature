@@ -0,0 +1,10 @@
+Feature: Pause on failure
+
+  Scenario: first failing
+    Given a failing step
+
+  Scenario: passing
+    Given a passing step
+
+  Scenario: second failing
+    Given a failing step
@@ -0,0 +1,11 @@
+Feature: Outcome
+
+  Scenario: passing
+    Given a passing step
+
+  Scenario: failing
+    Given a passing step
+    When a failing step
+
+  Scenario: skipped
+    Given an undefined step
@@ -0,0 +1,14 @@
+Feature: Repeated failures
+
+  Scenario Outline: same failure
+    Given value <value>
+    Then it fails
+
+    Examples:
+      | value |
+      | 1     |
+      | 2     |
+      | 3     |
+
+  Scenario: another failure
+    Then it fails differently
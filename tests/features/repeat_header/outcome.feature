@@ -0,0 +1,8 @@
+Feature: Repeat header
+
+  Scenario: passes
+    Given value 1
+
+  Scenario: fails
+    Given value 2
+    Then it fails
@@ -0,0 +1,7 @@
+Feature: Leak
+
+  Scenario: leaking
+    Given a leaked file
+
+  Scenario: clean
+    Given a closed file
@@ -0,0 +1,15 @@
+Feature: Retries
+
+  @flaky
+  Scenario: flaky
+    Given a fresh world
+    When flaky fails 2 times
+
+  @flaky
+  Scenario: broken
+    Given a fresh world
+    When broken fails 5 times
+
+  Scenario: untagged
+    Given a fresh world
+    When untagged fails 1 time
@@ -0,0 +1,13 @@
+Feature: Retry tag
+
+  Background:
+    Given a counted background
+
+  @retry(2)
+  Scenario: tagged
+    Given a fresh world
+    When tagged fails 2 times
+
+  Scenario: untagged
+    Given a fresh world
+    When untagged fails 1 time
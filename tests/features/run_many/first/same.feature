@@ -0,0 +1,7 @@
+Feature: Same name
+
+  Scenario: first one
+    Given a step
+
+  Scenario: first two
+    Given a step
@@ -0,0 +1,7 @@
+Feature: Same name
+
+  Scenario: second one
+    Given a step
+
+  Scenario: second two
+    Given a step
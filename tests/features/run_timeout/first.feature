@@ -0,0 +1,10 @@
+Feature: First
+
+  Scenario: fast
+    Given 0 ms step
+
+  Scenario: slow
+    Given 5000 ms step
+
+  Scenario: late
+    Given 0 ms step
@@ -0,0 +1,6 @@
+Feature: Second
+
+  Rule: rule
+
+    Scenario: unstarted
+      Given 0 ms step
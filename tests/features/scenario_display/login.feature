@@ -0,0 +1,9 @@
+Feature: Login
+
+  Scenario: happy path
+    Given a step
+
+  Rule: Admin
+
+    Scenario: happy path
+      Given a step
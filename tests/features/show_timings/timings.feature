@@ -0,0 +1,8 @@
+Feature: Timings
+
+  Background:
+    Given a slow step
+
+  Scenario: timed
+    When a slow step
+    Then a failing step
@@ -0,0 +1,25 @@
+Feature: first
+
+  Scenario: first 1
+    Given a step
+
+  Scenario: first 2
+    Given a step
+
+  Scenario: first 3
+    Given a step
+
+  Scenario: first 4
+    Given a step
+
+  Scenario: first 5
+    Given a step
+
+  Scenario: first 6
+    Given a step
+
+  Scenario: first 7
+    Given a step
+
+  Scenario: first 8
+    Given a step
@@ -0,0 +1,25 @@
+Feature: second
+
+  Scenario: second 1
+    Given a step
+
+  Scenario: second 2
+    Given a step
+
+  Scenario: second 3
+    Given a step
+
+  Scenario: second 4
+    Given a step
+
+  Scenario: second 5
+    Given a step
+
+  Scenario: second 6
+    Given a step
+
+  Scenario: second 7
+    Given a step
+
+  Scenario: second 8
+    Given a step
@@ -0,0 +1,12 @@
+Feature: Snippets
+
+  Scenario: defined step
+    Given a defined step
+
+  Scenario: undefined step
+    Given I have 5 "red" apples
+
+  Scenario: undefined step with a table
+    When 3 apples are eaten by
+      | name  |
+      | Alice |
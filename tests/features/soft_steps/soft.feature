@@ -0,0 +1,6 @@
+Feature: Soft steps
+
+  Scenario: soft failure
+    Given a soft step that panics
+    When a regular step
+    Then a regular step
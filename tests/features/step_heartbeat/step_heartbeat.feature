@@ -0,0 +1,7 @@
+Feature: Step heartbeat
+
+  Scenario: slow
+    Given a slow step
+
+  Scenario: fast
+    Given a fast step
@@ -0,0 +1,20 @@
+@api
+Feature: Tag breakdown
+
+  @smoke
+  Scenario: passes
+    Given a step
+
+  @smoke @slow
+  Scenario: fails
+    Given a failing step
+
+  Scenario: undefined
+    Given an undefined step
+
+  @billing
+  Rule: billing
+
+    @smoke
+    Scenario: passes in rule
+      Given a step
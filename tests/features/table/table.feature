@@ -0,0 +1,23 @@
+Feature: Typed data tables
+
+  Scenario: records
+    Given the users
+      | name  | age | email         |
+      | Alice | 30  | alice@dev.org |
+      | Bob   | 25  |               |
+    Then there are 2 users
+
+  Scenario: vertical
+    Given the user
+      | name  | Carol |
+      | age   | 41    |
+      | email |       |
+    Then there are 1 users
+
+  Scenario: mismatched type
+    Given the users
+      | name  | age |
+      | Dave  | old |
+
+  Scenario: no table
+    Given the user
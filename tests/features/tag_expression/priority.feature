@@ -0,0 +1,16 @@
+Feature: Priorities
+
+  @priority:1
+  Scenario: low
+    Given a step
+
+  @priority:2 @smoke
+  Scenario: medium
+    Given a step
+
+  @priority:10
+  Scenario: high
+    Given a step
+
+  Scenario: unprioritized
+    Given a step
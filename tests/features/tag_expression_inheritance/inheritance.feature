@@ -0,0 +1,19 @@
+@api
+Feature: Inheritance
+
+  Scenario: plain
+    Given a step
+
+  @wip
+  Scenario: unfinished
+    Given a step
+
+  @smoke
+  Rule: smoke
+
+    Scenario: checked
+      Given a step
+
+    @wip
+    Scenario: checked but unfinished
+      Given a step
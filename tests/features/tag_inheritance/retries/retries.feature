@@ -0,0 +1,9 @@
+@retry(1)
+Feature: Retries
+
+  Scenario: untagged
+    Given untagged fails once
+
+  @flaky
+  Scenario: tagged
+    Given tagged fails once
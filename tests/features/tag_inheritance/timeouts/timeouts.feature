@@ -0,0 +1,9 @@
+@timeout(10ms)
+Feature: Timeouts
+
+  Scenario: untagged
+    Given a slow step
+
+  @slow
+  Scenario: tagged
+    Given a slow step
@@ -0,0 +1,11 @@
+Feature: Outcome
+
+  Scenario: passing
+    Given a passing step
+
+  Scenario: failing #1
+    Given a passing step
+    When a step failing with "quoted" message
+
+  Scenario: skipped
+    Given an undefined step
@@ -0,0 +1,13 @@
+Feature: Outcome [TeamCity]
+
+  Rule: rule
+
+    Scenario: passing
+      Given a passing step
+
+    Scenario: failing 'quoted' |piped|
+      Given a passing step
+      When a step failing with "quoted" message
+
+    Scenario: skipped
+      Given an undefined step
@@ -0,0 +1,10 @@
+Feature: World teardown
+
+  Scenario: released
+    Given a resource
+
+  Scenario: leaked
+    Given a leaking resource
+
+  Scenario: crashed
+    Given a crashing resource
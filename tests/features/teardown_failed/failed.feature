@@ -0,0 +1,9 @@
+Feature: World teardown of failed Scenarios
+
+  Scenario: failed step
+    Given an asynchronously released resource
+    When it panics
+
+  @fail_after
+  Scenario: failed after hook
+    Given an asynchronously released resource
@@ -0,0 +1,5 @@
+Feature: World teardown of a panicked Scenario
+
+  Scenario: panicked
+    Given a resource released on panic
+    When it panics
@@ -0,0 +1,10 @@
+Feature: Theme
+
+  Background:
+    Given a background step
+
+  Scenario: passed
+    When she eats 3 cucumbers
+
+  Scenario: skipped
+    Then an unknown step
@@ -0,0 +1,13 @@
+Feature: Timed
+
+  Background:
+    Given background
+
+  Scenario: first
+    Given step
+    When step
+
+  Rule: rule
+
+    Scenario: second
+      Given step
@@ -0,0 +1,14 @@
+Feature: Timeouts
+
+  Scenario: fast
+    Given 10 ms step
+
+  Scenario: hanging
+    Given 10 ms step
+    When 1000 ms step
+    Then 10 ms step
+
+  Scenario: slow
+    Given 80 ms step
+    When 80 ms step
+    Then 80 ms step
@@ -0,0 +1,16 @@
+@timeout(50ms)
+Feature: Timeout tags
+
+  Scenario: inherited
+    Given 80 ms step
+
+  @timeout(150ms)
+  Scenario: overridden
+    Given 80 ms step
+    When 300 ms step
+
+  Rule: rule
+
+    Scenario: in rule
+      Given 10 ms step
+      When 80 ms step
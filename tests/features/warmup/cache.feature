@@ -0,0 +1,8 @@
+Feature: Cache
+
+  @warmup
+  Scenario: cold cache
+    Given a warmed up cache
+
+  Scenario: counted step
+    Given a counted step
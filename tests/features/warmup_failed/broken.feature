@@ -0,0 +1,8 @@
+Feature: Broken environment
+
+  @warmup
+  Scenario: broken
+    Given a broken environment
+
+  Scenario: not run
+    Given a step not run after a failed warm-up
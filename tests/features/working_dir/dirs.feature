@@ -0,0 +1,9 @@
+Feature: Working directory
+
+  Scenario: first
+    Given an empty working directory
+    When a file is created in it
+
+  Scenario: second
+    Given an empty working directory
+    When a file is created in it
@@ -0,0 +1,4 @@
+Feature: Existing working directory
+
+  Scenario: existing
+    When a file is created in it
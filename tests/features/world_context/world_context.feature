@@ -0,0 +1,7 @@
+Feature: World context
+
+  Scenario: first
+    Given a World constructed from the context
+
+  Scenario: second
+    Given a World constructed from the context
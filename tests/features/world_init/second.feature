@@ -0,0 +1,10 @@
+Feature: Second
+
+  Scenario: 1
+    Given a world
+
+  Scenario: 2
+    Given a world
+
+  Scenario: 3
+    Given a world
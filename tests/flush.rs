@@ -0,0 +1,98 @@
+mod common;
+
+use std::{
+    io::{self, BufWriter},
+    mem,
+    sync::{Arc, Mutex},
+};
+
+use cucumber::{
+    given,
+    writer::{self, Coloring},
+    WorldInit, WriterExt as _,
+};
+
+use self::common::World;
+
+#[given(regex = ".*")]
+fn any_step(_: &mut World) {}
+
+/// Output receiving bytes only once they're flushed out of a [`BufWriter`].
+#[derive(Clone, Default)]
+struct Flushed(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for Flushed {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Flushed {
+    /// Returns a [`BufWriter`] large enough to never flush by itself.
+    fn buffered(&self) -> BufWriter<Self> {
+        BufWriter::with_capacity(1 << 20, self.clone())
+    }
+
+    /// Returns all the flushed output.
+    fn output(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+#[tokio::test]
+async fn basic_writer_flushes_whole_output() {
+    let out = Flushed::default();
+
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::new(out.buffered(), Coloring::Never, false)
+                .summarized(),
+        )
+        .run("tests/features/soft_steps")
+        .await;
+    // Emulates the process exiting right after the run, so no destructors
+    // have a chance to flush the output.
+    mem::forget(writer);
+
+    let output = out.output();
+    assert!(output.contains("Feature: Soft steps"), "{}", output);
+    assert!(output.contains("[Summary]"), "{}", output);
+    assert!(output.ends_with('\n'), "{:?}", output);
+}
+
+#[cfg(feature = "output-json")]
+#[tokio::test]
+async fn json_writer_flushes_whole_output() {
+    let out = Flushed::default();
+
+    let writer = World::cucumber()
+        .with_writer(writer::Json::new(out.buffered()))
+        .run("tests/features/soft_steps")
+        .await;
+    mem::forget(writer);
+
+    let output = out.output();
+    assert!(output.starts_with('['), "{}", output);
+    assert!(output.ends_with("]\n"), "{:?}", output);
+}
+
+#[cfg(feature = "output-junit")]
+#[tokio::test]
+async fn junit_writer_flushes_whole_output() {
+    let out = Flushed::default();
+
+    let writer = World::cucumber()
+        .with_writer(writer::JUnit::new(out.buffered()))
+        .run("tests/features/soft_steps")
+        .await;
+    mem::forget(writer);
+
+    let output = out.output();
+    assert!(output.contains("<testsuites"), "{}", output);
+    assert!(output.ends_with("</testsuites>\n"), "{:?}", output);
+}
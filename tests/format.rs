@@ -0,0 +1,87 @@
+mod common;
+
+use std::{env, process::Command, str::FromStr as _};
+
+use cucumber::{cli, given, writer::format::Name, WorldInit};
+use structopt::StructOpt as _;
+
+use self::common::World;
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+/// Environment variable making [`run_with_format()`] actually run, holding
+/// the format to run with.
+const CHILD_VAR: &str = "CUCUMBER_FORMAT_CHILD";
+
+/// Runs this test binary's [`run_with_format()`] test with the given
+/// `--format`, and returns its stdout.
+fn run_child(format: &str) -> String {
+    let output = Command::new(env::current_exe().unwrap())
+        .args(["--ignored", "--exact", "run_with_format", "--nocapture"])
+        .env(CHILD_VAR, format)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[tokio::test]
+#[ignore = "is run by other tests to capture the stdout"]
+async fn run_with_format() {
+    let format = match env::var(CHILD_VAR) {
+        Ok(format) => format,
+        Err(_) => return,
+    };
+
+    World::cucumber()
+        .with_format_writer()
+        .with_cli(cli::Opts::<_, _, _>::from_iter([
+            "test", "--format", &format,
+        ]))
+        .run("tests/features/format")
+        .await;
+}
+
+#[test]
+fn parses_names() {
+    for (input, name) in [
+        ("pretty", Name::Pretty),
+        ("Progress", Name::Progress),
+        ("TAP", Name::Tap),
+        ("teamcity", Name::TeamCity),
+        ("markdown", Name::Markdown),
+        #[cfg(feature = "output-html")]
+        ("html", Name::Html),
+        #[cfg(feature = "output-junit")]
+        ("junit", Name::JUnit),
+        #[cfg(feature = "output-json")]
+        ("json", Name::Json),
+    ] {
+        assert_eq!(Name::from_str(input).unwrap(), name, "{}", input);
+    }
+    assert_eq!(
+        Name::from_str("yaml").unwrap_err().to_string(),
+        "Unknown or disabled output format: yaml",
+    );
+}
+
+#[test]
+fn selects_writer() {
+    for (format, expected) in [
+        ("pretty", "\u{2714}  Given a step"),
+        ("progress", "[1/1] features"),
+        ("tap", "TAP version 13"),
+        ("teamcity", "##teamcity[testStarted"),
+        ("markdown", "| Feature | Passed | Failed | Skipped |"),
+        #[cfg(feature = "output-html")]
+        ("html", "<!DOCTYPE html>"),
+        #[cfg(feature = "output-junit")]
+        ("junit", "<testsuites"),
+        #[cfg(feature = "output-json")]
+        ("json", "\"keyword\":\"Feature\""),
+    ] {
+        let output = run_child(format);
+
+        assert!(output.contains(expected), "{}:\n{}", format, output);
+    }
+}
@@ -0,0 +1,120 @@
+mod common;
+
+use cucumber::{given, parser, Parser as _, WorldInit};
+use futures::StreamExt as _;
+
+use self::common::{sink, World};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+/// Parses the given `input` with the default `Parser`, returning names of the
+/// parsed `Feature`s and the number of errors.
+async fn parse(input: &str) -> (Vec<String>, usize) {
+    let parsed = parser::Basic::new()
+        .parse(input, parser::basic::Cli { features: None })
+        .collect::<Vec<_>>()
+        .await;
+    let errors = parsed.iter().filter(|f| f.is_err()).count();
+    let names = parsed.into_iter().filter_map(Result::ok).map(|f| f.name);
+    (names.collect(), errors)
+}
+
+/// Parses the given glob `patterns` with the default `Parser`, returning names
+/// of the parsed `Feature`s and the number of errors.
+async fn parse_globs(patterns: &[&str]) -> (Vec<String>, usize) {
+    let parsed = parser::Basic::new()
+        .parse(
+            patterns.iter().copied().collect::<parser::basic::Globs>(),
+            parser::basic::Cli { features: None },
+        )
+        .collect::<Vec<_>>()
+        .await;
+    let errors = parsed.iter().filter(|f| f.is_err()).count();
+    let names = parsed.into_iter().filter_map(Result::ok).map(|f| f.name);
+    (names.collect(), errors)
+}
+
+#[tokio::test]
+async fn expands_glob_in_sorted_order() {
+    assert_eq!(
+        parse("tests/features/glob/**/*.feature").await,
+        (vec!["First".to_owned(), "Second".to_owned()], 0),
+    );
+    assert_eq!(
+        parse("tests/features/glob/nested/*.feature").await,
+        (vec!["Second".to_owned()], 0),
+    );
+}
+
+#[tokio::test]
+async fn ignores_non_feature_matches() {
+    assert_eq!(
+        parse("tests/features/glob/**/*").await,
+        (vec!["First".to_owned(), "Second".to_owned()], 0),
+    );
+}
+
+#[tokio::test]
+async fn errors_on_invalid_or_unmatched_glob() {
+    assert_eq!(parse("tests/features/glob/**/*.txt").await, (vec![], 1));
+    assert_eq!(parse("tests/features/glob/[").await, (vec![], 1));
+}
+
+#[tokio::test]
+async fn runs_features_matching_glob() {
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .run("tests/features/glob/**/second.feature")
+        .await;
+
+    assert_eq!(writer.features, 1);
+    assert_eq!(writer.scenarios.passed, 1);
+}
+
+#[tokio::test]
+async fn excludes_features_matching_negated_globs() {
+    assert_eq!(
+        parse_globs(&[
+            "tests/features/glob/nested/*.feature",
+            "tests/features/glob/*.feature",
+        ])
+        .await,
+        (vec!["First".to_owned(), "Second".to_owned()], 0),
+    );
+    assert_eq!(
+        parse_globs(&[
+            "tests/features/glob/**/*.feature",
+            "!tests/features/glob/nested/**",
+        ])
+        .await,
+        (vec!["First".to_owned()], 0),
+    );
+}
+
+#[tokio::test]
+async fn errors_on_invalid_or_unmatched_globs() {
+    assert_eq!(
+        parse_globs(&[
+            "tests/features/glob/**/*.feature",
+            "!tests/features/glob/**",
+        ])
+        .await,
+        (vec![], 1),
+    );
+    assert_eq!(parse_globs(&["tests/features/glob/["]).await, (vec![], 1));
+}
+
+#[tokio::test]
+async fn runs_features_matching_globs() {
+    let writer = World::cucumber::<&str>()
+        .with_writer(sink())
+        .run_globs([
+            "tests/features/glob/**/*.feature",
+            "!tests/features/glob/*.feature",
+        ])
+        .await;
+
+    assert_eq!(writer.features, 1);
+    assert_eq!(writer.scenarios.passed, 1);
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use std::{cell::RefCell, collections::BTreeMap, convert::Infallible};
+
+use async_trait::async_trait;
+use cucumber::{event, given, WorldInit};
+use futures::FutureExt as _;
+
+use self::common::sink;
+
+thread_local! {
+    /// Outcomes passed to the `after` hook by `Scenario` names.
+    static OUTCOMES: RefCell<BTreeMap<String, String>> =
+        RefCell::new(BTreeMap::new());
+
+    /// Names of `Scenario`s the passing `Step` was executed in.
+    static EXECUTED: RefCell<Vec<String>> = RefCell::new(Vec::new());
+}
+
+#[derive(Debug, WorldInit)]
+struct World {
+    scenario: String,
+}
+
+#[async_trait(?Send)]
+impl cucumber::World for World {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Self::Error> {
+        Ok(Self {
+            scenario: String::new(),
+        })
+    }
+}
+
+#[given("a passing step")]
+fn passing(w: &mut World) {
+    EXECUTED.with(|e| e.borrow_mut().push(w.scenario.clone()));
+}
+
+#[given("a failing step")]
+fn failing(_: &mut World) {
+    panic!("failed");
+}
+
+#[tokio::test]
+async fn after_hook_receives_scenario_outcome() {
+    let writer = World::cucumber()
+        .before(|_, _, sc, w| {
+            async move {
+                assert!(
+                    !sc.tags.iter().any(|t| t == "fail_before"),
+                    "before failed",
+                );
+                w.scenario = sc.name.clone();
+            }
+            .boxed_local()
+        })
+        .after(|_, _, sc, finished, _| {
+            let outcome = match finished {
+                event::ScenarioFinished::BeforeHookFailed(_) => "before failed",
+                event::ScenarioFinished::StepPassed => "passed",
+                event::ScenarioFinished::StepSkipped => "skipped",
+                event::ScenarioFinished::StepFailed(_, err) => {
+                    assert!(err.to_string().contains("failed"), "{}", err);
+                    "failed"
+                }
+            };
+            OUTCOMES.with(|o| {
+                drop(o.borrow_mut().insert(sc.name.clone(), outcome.into()));
+            });
+            async {}.boxed_local()
+        })
+        .with_writer(sink())
+        .run("tests/features/hooks")
+        .await;
+
+    assert_eq!(
+        OUTCOMES.with(|o| o.borrow().clone()),
+        [
+            ("failing", "failed"),
+            ("failing before", "before failed"),
+            ("passing", "passed"),
+            ("undefined", "skipped"),
+        ]
+        .iter()
+        .map(|(sc, o)| ((*sc).to_owned(), (*o).to_owned()))
+        .collect::<BTreeMap<_, _>>(),
+    );
+    assert_eq!(EXECUTED.with(|e| e.borrow().clone()), ["passing"]);
+    assert_eq!(writer.failed_hooks, 1);
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use std::fs;
+
+use cucumber::{given, when, writer, WorldInit, WriterExt as _};
+use tempfile::NamedTempFile;
+
+use self::common::World;
+
+#[given("a passing step")]
+#[given("a step with a table:")]
+#[when("a step with a doc string:")]
+fn passing(_: &mut World) {}
+
+#[when("a failing step")]
+fn failing(_: &mut World) {
+    panic!("expected <ok>");
+}
+
+#[tokio::test]
+async fn renders_self_contained_report() {
+    let file = NamedTempFile::new().unwrap();
+    let html = World::cucumber()
+        .with_writer(writer::Html::new(file.reopen().unwrap()))
+        .run("tests/features/html")
+        .await;
+
+    assert_eq!(writer::Failure::<World>::failed_steps(&html), 1);
+    assert_eq!(writer::Failure::<World>::skipped_steps(&html), 1);
+
+    let report = fs::read_to_string(file.path()).unwrap();
+    assert!(report.starts_with("<!DOCTYPE html>"));
+    assert!(!report.contains("<link") && !report.contains("src="));
+    assert!(report
+        .contains("3 scenarios (1 passed, 1 failed, 1 skipped), 7 steps",));
+    assert!(report.contains("Feature:</span> HTML &lt;report&gt;"));
+    assert!(report.contains("<span class=\"tags\">@report</span>"));
+    assert!(report.contains("<td>&lt;b&gt;&amp;c</td>"));
+    assert!(
+        report.contains("<pre>&lt;script&gt;alert(1)&lt;/script&gt;</pre>",)
+    );
+    assert!(report.contains("<pre class=\"error\">expected &lt;ok&gt;</pre>"));
+    assert!(report.contains("<details class=\"rule failed\" open>"));
+    assert!(report.contains("<li class=\"step passed background\">"));
+}
+
+#[tokio::test]
+async fn writes_report_once_when_repeated() {
+    let file = NamedTempFile::new().unwrap();
+    let _ = World::cucumber()
+        .with_writer(writer::Html::new(file.reopen().unwrap()).repeat_failed())
+        .run("tests/features/html")
+        .await;
+
+    let report = fs::read_to_string(file.path()).unwrap();
+    assert_eq!(report.matches("<!DOCTYPE html>").count(), 1);
+}
@@ -1,7 +1,11 @@
 use std::{convert::Infallible, fs, io::Read as _};
 
 use async_trait::async_trait;
-use cucumber::{given, then, when, writer, WorldInit};
+use cucumber::{
+    given, then, when,
+    writer::{self, Failure as _},
+    WorldInit,
+};
 use futures::FutureExt as _;
 use regex::RegexBuilder;
 use tempfile::NamedTempFile;
@@ -16,35 +20,17 @@ fn step(world: &mut World) {
     }
 }
 
-#[tokio::main]
-async fn main() {
-    let mut file = NamedTempFile::new().unwrap();
-    drop(
-        World::cucumber()
-            .before(|_, _, sc, _| {
-                async {
-                    if sc.tags.iter().any(|t| t == "fail_before") {
-                        panic!("Tag!");
-                    }
-                }
-                .boxed_local()
-            })
-            .after(|_, _, sc, _| {
-                async {
-                    if sc.tags.iter().any(|t| t == "fail_after") {
-                        panic!("Tag!");
-                    }
-                }
-                .boxed_local()
-            })
-            .with_writer(writer::Json::new(file.reopen().unwrap()))
-            .run("tests/features/wait")
-            .await,
-    );
+#[given("a step with a table:")]
+#[when("a step with a docstring:")]
+fn step_with_argument(_: &mut World) {}
 
-    let mut buffer = String::new();
-    file.read_to_string(&mut buffer).unwrap();
+#[then("a failing step")]
+fn failing_step(_: &mut World) {
+    panic!("failed");
+}
 
+#[tokio::main]
+async fn main() {
     // Required to strip out non-deterministic parts of output, so we could
     // compare them well.
     let non_deterministic = RegexBuilder::new(
@@ -59,13 +45,93 @@ async fn main() {
     .build()
     .unwrap();
 
+    let writers: [fn(fs::File) -> _; 2] =
+        [writer::Json::new, writer::Json::streaming];
+    for new_writer in writers {
+        let mut file = NamedTempFile::new().unwrap();
+        drop(
+            World::cucumber()
+                .before(|_, _, sc, _| {
+                    async {
+                        if sc.tags.iter().any(|t| t == "fail_before") {
+                            panic!("Tag!");
+                        }
+                    }
+                    .boxed_local()
+                })
+                .after(|_, _, sc, _, _| {
+                    async {
+                        if sc.tags.iter().any(|t| t == "fail_after") {
+                            panic!("Tag!");
+                        }
+                    }
+                    .boxed_local()
+                })
+                .with_writer(new_writer(file.reopen().unwrap()))
+                .run("tests/features/wait")
+                .await,
+        );
+
+        let mut buffer = String::new();
+        file.read_to_string(&mut buffer).unwrap();
+
+        assert_eq!(
+            non_deterministic.replace_all(&buffer, ""),
+            non_deterministic.replace_all(
+                &fs::read_to_string("tests/json/correct.json").unwrap(),
+                "",
+            ),
+        );
+    }
+
+    step_arguments_and_failures().await;
+}
+
+/// Checks that [`writer::Json`] serializes step arguments, attributes
+/// `Background` steps to their `Scenario`s and tracks failures.
+async fn step_arguments_and_failures() {
+    let mut file = NamedTempFile::new().unwrap();
+    let writer = World::cucumber()
+        .with_writer(writer::Json::new(file.reopen().unwrap()))
+        .run("tests/features/json")
+        .await;
+
+    assert_eq!(writer.failed_steps(), 1);
+    assert_eq!(writer.parsing_errors(), 0);
+
+    let mut buffer = String::new();
+    file.read_to_string(&mut buffer).unwrap();
+    let json = serde_json::from_str::<serde_json::Value>(&buffer).unwrap();
+
+    let elements = json[0]["elements"].as_array().unwrap();
+    let kinds = elements
+        .iter()
+        .map(|el| (el["type"].as_str().unwrap(), el["name"].as_str().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        [
+            ("background", "docstring"),
+            ("scenario", "docstring"),
+            ("background", "failing"),
+            ("scenario", "failing"),
+        ],
+    );
+
+    for bg in [&elements[0], &elements[2]] {
+        assert_eq!(
+            bg["steps"][0]["rows"],
+            serde_json::json!([
+                {"cells": ["key", "value"]},
+                {"cells": ["a", "1"]},
+            ]),
+        );
+    }
     assert_eq!(
-        non_deterministic.replace_all(&buffer, ""),
-        non_deterministic.replace_all(
-            &fs::read_to_string("tests/json/correct.json").unwrap(),
-            "",
-        ),
+        elements[1]["steps"][0]["doc_string"]["value"],
+        "\nsome text\n"
     );
+    assert_eq!(elements[3]["steps"][0]["result"]["status"], "Failed");
 }
 
 #[derive(Clone, Copy, Debug, WorldInit)]
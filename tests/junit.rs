@@ -1,7 +1,7 @@
 use std::{convert::Infallible, fs, io::Read as _};
 
 use async_trait::async_trait;
-use cucumber::{given, then, when, writer, WorldInit};
+use cucumber::{given, then, when, writer, WorldInit, WriterExt as _};
 use regex::Regex;
 use tempfile::NamedTempFile;
 
@@ -15,6 +15,11 @@ fn step(world: &mut World) {
     }
 }
 
+#[given(regex = r#"^a step failing with "(.+)"$"#)]
+fn failing_step(_: &mut World, msg: String) {
+    panic!("\u{1b}[31m{}\u{1b}[0m\u{7}", msg);
+}
+
 #[tokio::main]
 async fn main() {
     let mut file = NamedTempFile::new().unwrap();
@@ -44,6 +49,63 @@ async fn main() {
             "",
         ),
     );
+
+    let mut file = NamedTempFile::new().unwrap();
+    drop(
+        World::cucumber()
+            .with_writer(writer::JUnit::new(file.reopen().unwrap()))
+            .run("tests/features/junit")
+            .await,
+    );
+
+    let mut buffer = String::new();
+    file.read_to_string(&mut buffer).unwrap();
+
+    assert!(!buffer.chars().any(|c| c.is_control() && c != '\n'));
+    assert!(buffer.contains(
+        "name=\"Feature: Escaping &lt;&quot;special&quot;&gt; &amp; \
+         &apos;characters&apos;",
+    ));
+    assert!(buffer.contains(
+        "message=\"Step panicked. Captured output: \
+         &lt;b&gt;bold&lt;/b&gt; &amp; ]]&gt; end\"",
+    ));
+    assert_eq!(
+        buffer.matches("<![CDATA[").count(),
+        buffer.matches("]]>").count(),
+    );
+    assert!(buffer.contains("]]]]><![CDATA[>"));
+
+    let (mut serial, mut other) =
+        (NamedTempFile::new().unwrap(), NamedTempFile::new().unwrap());
+    drop(
+        World::cucumber()
+            .with_writer(
+                writer::Route::new(writer::JUnit::raw(other.reopen().unwrap()))
+                    .route(
+                        "serial",
+                        writer::JUnit::raw(serial.reopen().unwrap()),
+                    )
+                    .normalized(),
+            )
+            .run("tests/features/wait")
+            .await,
+    );
+
+    let (mut serial_buf, mut other_buf) = (String::new(), String::new());
+    serial.read_to_string(&mut serial_buf).unwrap();
+    other.read_to_string(&mut other_buf).unwrap();
+
+    let testcases = Regex::new("testcase name=\"([^:]+: [^:]+)").unwrap();
+    let names = |buf: &str| {
+        testcases
+            .captures_iter(buf)
+            .map(|c| c[1].to_owned())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(names(&serial_buf), ["Scenario: 1 sec", "Scenario: 1 sec"]);
+    assert!(!names(&other_buf).is_empty());
+    assert!(!names(&other_buf).iter().any(|n| n == "Scenario: 1 sec"));
 }
 
 #[derive(Clone, Copy, Debug, WorldInit)]
@@ -0,0 +1,100 @@
+use std::{convert::Infallible, io};
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, writer, WorldInit, WriterExt as _};
+
+#[derive(Debug, WorldInit)]
+struct World(usize);
+
+#[async_trait(?Send)]
+impl cucumber::World for World {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Self::Error> {
+        Ok(Self(0))
+    }
+}
+
+#[given(expr = "le compteur vaut {int}")]
+#[then(expr = "le compteur vaut {int}")]
+fn counter_is(w: &mut World, n: usize) {
+    assert_eq!(w.0, n);
+}
+
+#[when("on incrémente le compteur")]
+fn increment(w: &mut World) {
+    w.0 += 1;
+}
+
+const FEATURE: &str = "\
+Fonctionnalité: Texte
+
+  Scénario: sans en-tête
+    Étant donné que le compteur vaut 0
+    Quand on incrémente le compteur
+    Alors le compteur vaut 1
+";
+
+/// Returns a `Writer` summarizing the run without any output.
+fn sink() -> writer::Summarize<writer::Normalize<World, writer::Basic<io::Sink>>>
+{
+    writer::Basic::new(io::sink(), writer::Coloring::Never, false).summarized()
+}
+
+#[tokio::test]
+async fn parses_files_in_configured_language() {
+    let writer = World::cucumber()
+        .language("fr")
+        .unwrap()
+        .with_writer(sink())
+        .run("tests/features/language")
+        .await;
+
+    assert_eq!(writer.parsing_errors, 0);
+    assert_eq!(writer.scenarios.passed, 1);
+    assert_eq!(writer.steps.passed, 3);
+}
+
+#[tokio::test]
+async fn parses_text_in_configured_language() {
+    let writer = World::cucumber_text()
+        .language("fr")
+        .unwrap()
+        .with_writer(sink())
+        .run(FEATURE)
+        .await;
+
+    assert_eq!(writer.parsing_errors, 0);
+    assert_eq!(writer.scenarios.passed, 1);
+}
+
+#[tokio::test]
+async fn prefers_language_header() {
+    let writer = World::cucumber_text()
+        .language("es")
+        .unwrap()
+        .with_writer(sink())
+        .run(format!("# language: fr\n{}", FEATURE))
+        .await;
+
+    assert_eq!(writer.parsing_errors, 0);
+    assert_eq!(writer.scenarios.passed, 1);
+}
+
+#[test]
+fn errors_on_unsupported_language() {
+    let err = World::cucumber::<&str>().language("xx").unwrap_err();
+
+    assert_eq!(err.to_string(), "Language xx isn't supported");
+}
+
+#[tokio::test]
+async fn reports_unsupported_language_header_as_parsing_error() {
+    let writer = World::cucumber_text()
+        .with_writer(sink())
+        .run("# language: xx\nFeature: Unknown\n")
+        .await;
+
+    assert_eq!(writer.parsing_errors, 1);
+    assert_eq!(writer.features, 0);
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use std::fs;
+
+use cucumber::{given, when, writer, WorldInit, WriterExt as _};
+use tempfile::NamedTempFile;
+
+use self::common::World;
+
+#[given("a passing step")]
+fn passing(_: &mut World) {}
+
+#[when(regex = r#"^a step failing with "(\S+)" message$"#)]
+fn failing(_: &mut World, msg: String) {
+    panic!("[{}]\nsecond line", msg);
+}
+
+#[tokio::test]
+async fn outputs_summary_report() {
+    let file = NamedTempFile::new().unwrap();
+    let writer = World::cucumber()
+        .with_writer(writer::Markdown::new(file.reopen().unwrap()))
+        .run("tests/features/markdown")
+        .await;
+
+    assert_eq!(writer::Failure::<World>::failed_steps(&writer), 1);
+    assert_eq!(writer::Failure::<World>::skipped_steps(&writer), 1);
+    assert_eq!(
+        fs::read_to_string(file.path()).unwrap(),
+        format!(
+            "## Cucumber results\n\
+             \n\
+             | Feature | Passed | Failed | Skipped |\n\
+             | :--- | ---: | ---: | ---: |\n\
+             | Outcome \\|piped\\| &lt;Markdown&gt; | 1 | 1 | 1 |\n\
+             \n\
+             **Pass rate:** 33.33% (1 of 3 scenarios passed)\n\
+             \n\
+             ### Failures\n\
+             \n\
+             <details>\n\
+             <summary>Outcome |piped| &lt;Markdown&gt; \u{203a} failing</summary>\n\
+             \n\
+             **Failed:** `When a step failing with \"quoted\" message` \
+             at `{}/tests/features/markdown/outcome.feature:10:7`\n\
+             \n\
+             ```text\n\
+             Step panicked. Captured output: [quoted]\n\
+             second line\n\
+             ```\n\
+             \n\
+             </details>\n",
+            env!("CARGO_MANIFEST_DIR"),
+        ),
+    );
+}
+
+#[tokio::test]
+async fn outputs_summary_as_code_block() {
+    let file = NamedTempFile::new().unwrap();
+    let _ = World::cucumber()
+        .with_writer(writer::Markdown::new(file.reopen().unwrap()).summarized())
+        .run("tests/features/markdown")
+        .await;
+
+    let output = fs::read_to_string(file.path()).unwrap();
+    let summary = output.split_once("</details>\n").unwrap().1;
+
+    assert!(summary.starts_with("\n```\n"));
+    assert!(summary.contains("3 scenarios (1 passed, 1 skipped, 1 failed)"));
+    assert!(summary.ends_with("```\n"));
+}
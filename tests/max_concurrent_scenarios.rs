@@ -0,0 +1,96 @@
+mod common;
+
+use std::{cell::Cell, time::Duration};
+
+use cucumber::{given, writer, WorldInit};
+use tokio::time;
+
+use self::common::{sink, World};
+
+thread_local! {
+    /// Number of currently executed `Scenario`s.
+    static IN_FLIGHT: Cell<usize> = Cell::new(0);
+
+    /// Maximum number of `Scenario`s executed simultaneously.
+    static MAX_IN_FLIGHT: Cell<usize> = Cell::new(0);
+
+    /// Number of finished short `Scenario`s.
+    static SHORT_FINISHED: Cell<usize> = Cell::new(0);
+
+    /// Number of short `Scenario`s finished while the long one was executed.
+    static FINISHED_DURING_LONG: Cell<usize> = Cell::new(0);
+}
+
+#[given("a slow step")]
+async fn step(_: &mut World) {
+    let current = IN_FLIGHT.with(|c| {
+        c.set(c.get() + 1);
+        c.get()
+    });
+    MAX_IN_FLIGHT.with(|m| m.set(m.get().max(current)));
+
+    time::sleep(Duration::from_millis(10)).await;
+
+    IN_FLIGHT.with(|c| c.set(c.get() - 1));
+}
+
+#[given("a long step")]
+async fn long_step(_: &mut World) {
+    let before = SHORT_FINISHED.with(Cell::get);
+    time::sleep(Duration::from_millis(300)).await;
+    let after = SHORT_FINISHED.with(Cell::get);
+    FINISHED_DURING_LONG.with(|f| f.set(after - before));
+}
+
+#[given("a short step")]
+async fn short_step(_: &mut World) {
+    time::sleep(Duration::from_millis(10)).await;
+    SHORT_FINISHED.with(|f| f.set(f.get() + 1));
+}
+
+async fn max_in_flight(max: Option<usize>) -> usize {
+    MAX_IN_FLIGHT.with(|m| m.set(0));
+
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(max)
+        .with_writer(sink())
+        .run("tests/features/max_concurrent_scenarios")
+        .await;
+    assert!(!writer::Failure::<World>::execution_has_failed(&writer));
+
+    MAX_IN_FLIGHT.with(Cell::get)
+}
+
+#[tokio::test]
+async fn limits_scenarios_across_features() {
+    assert_eq!(max_in_flight(Some(4)).await, 4);
+}
+
+#[tokio::test]
+async fn runs_scenarios_serially() {
+    assert_eq!(max_in_flight(Some(1)).await, 1);
+}
+
+#[tokio::test]
+async fn zero_limit_runs_all_scenarios() {
+    assert_eq!(max_in_flight(Some(0)).await, 6);
+}
+
+#[tokio::test]
+async fn runs_all_scenarios_without_limit() {
+    assert_eq!(max_in_flight(None).await, 6);
+}
+
+#[tokio::test]
+async fn refills_slots_once_scenario_finishes() {
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(2)
+        .with_writer(sink())
+        .run("tests/features/max_concurrent_scenarios_refill")
+        .await;
+    assert!(!writer::Failure::<World>::execution_has_failed(&writer));
+
+    // All the short `Scenario`s are executed one by one in the second slot,
+    // while the long one still occupies the first one.
+    assert_eq!(FINISHED_DURING_LONG.with(Cell::get), 5);
+}
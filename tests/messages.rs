@@ -0,0 +1,142 @@
+mod common;
+
+use std::collections::HashSet;
+
+use cucumber::{
+    given, then, when,
+    writer::{self, Failure as _},
+    WorldInit,
+};
+use serde_json::Value;
+
+use self::common::World;
+
+#[given("a step with a table:")]
+#[when("a step with a docstring:")]
+fn step_with_argument(_: &mut World) {}
+
+#[then("a failing step")]
+fn failing_step(_: &mut World) {
+    panic!("failed");
+}
+
+/// Runs `tests/features/json` returning the output messages.
+async fn messages() -> Vec<Value> {
+    let mut out = Vec::new();
+    let writer = World::cucumber()
+        .with_writer(writer::NdJson::new(&mut out))
+        .run("tests/features/json")
+        .await;
+    assert_eq!(writer.failed_steps(), 1);
+    drop(writer);
+
+    String::from_utf8(out)
+        .unwrap()
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect()
+}
+
+/// Returns the only key of the given envelope `message`.
+fn kind(message: &Value) -> &str {
+    let obj = message.as_object().unwrap();
+    assert_eq!(obj.len(), 1, "not an envelope: {}", message);
+    obj.keys().next().unwrap()
+}
+
+#[tokio::test]
+async fn outputs_messages_in_order() {
+    let kinds = messages()
+        .await
+        .iter()
+        .map(|m| kind(m).to_owned())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        kinds,
+        [
+            "meta",
+            "testRunStarted",
+            "gherkinDocument",
+            "pickle",
+            "pickle",
+            "testCase",
+            "testCaseStarted",
+            "testStepStarted",
+            "testStepFinished",
+            "testStepStarted",
+            "testStepFinished",
+            "testCaseFinished",
+            "testCase",
+            "testCaseStarted",
+            "testStepStarted",
+            "testStepFinished",
+            "testStepStarted",
+            "testStepFinished",
+            "testCaseFinished",
+            "testRunFinished",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn reuses_identifiers() {
+    let messages = messages().await;
+    let of_kind = |k: &'static str| {
+        messages
+            .iter()
+            .filter(move |m| kind(m) == k)
+            .map(move |m| &m[k])
+    };
+
+    let pickle_steps = of_kind("pickle")
+        .flat_map(|p| p["steps"].as_array().unwrap())
+        .map(|s| s["id"].as_str().unwrap())
+        .collect::<HashSet<_>>();
+    let test_steps = of_kind("testCase")
+        .flat_map(|c| c["testSteps"].as_array().unwrap())
+        .map(|s| {
+            assert!(pickle_steps.contains(s["pickleStepId"].as_str().unwrap()));
+            s["id"].as_str().unwrap()
+        })
+        .collect::<HashSet<_>>();
+    let started = of_kind("testCaseStarted")
+        .map(|c| c["id"].as_str().unwrap())
+        .collect::<HashSet<_>>();
+
+    assert_eq!(pickle_steps.len(), 4);
+    assert_eq!(test_steps.len(), 4);
+    for k in ["testStepStarted", "testStepFinished"] {
+        for m in of_kind(k) {
+            assert!(test_steps.contains(m["testStepId"].as_str().unwrap()));
+            assert!(started.contains(m["testCaseStartedId"].as_str().unwrap()));
+        }
+    }
+}
+
+#[tokio::test]
+async fn reports_step_results_and_arguments() {
+    let messages = messages().await;
+
+    let statuses = messages
+        .iter()
+        .filter(|m| kind(m) == "testStepFinished")
+        .map(|m| m["testStepFinished"]["testStepResult"]["status"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(statuses, ["PASSED", "PASSED", "PASSED", "FAILED"]);
+
+    let pickle = &messages[3]["pickle"];
+    assert_eq!(pickle["name"], "docstring");
+    assert_eq!(
+        pickle["steps"][0]["argument"]["dataTable"]["rows"][1]["cells"][0]
+            ["value"],
+        "a",
+    );
+    assert_eq!(
+        pickle["steps"][1]["argument"]["docString"]["content"],
+        "\nsome text\n",
+    );
+
+    let finished = &messages.last().unwrap()["testRunFinished"];
+    assert_eq!(finished["success"], false);
+}
@@ -0,0 +1,194 @@
+mod common;
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, writer, Event, WorldInit, Writer, WriterExt as _,
+};
+use regex::Regex;
+use structopt::StructOpt as _;
+
+use self::common::{sink, World};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+/// Records started `Feature`s, `Rule`s and `Scenario`s, along with the run
+/// boundaries.
+#[derive(Default)]
+struct Started(Vec<String>);
+
+#[async_trait(?Send)]
+impl Writer<World> for Started {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule, Scenario};
+
+        let started = match ev.map(Event::into_inner) {
+            Ok(Cucumber::Started) => "started".into(),
+            Ok(Cucumber::ParsingFinished { scenarios, .. }) => {
+                format!("parsed {}", scenarios)
+            }
+            Ok(Cucumber::Finished) => "finished".into(),
+            Ok(Cucumber::Feature(f, Feature::Started)) => {
+                format!("feature {}", f.name)
+            }
+            Ok(Cucumber::Feature(_, Feature::Rule(r, Rule::Started))) => {
+                format!("rule {}", r.name)
+            }
+            Ok(Cucumber::Feature(
+                _,
+                Feature::Rule(_, Rule::Scenario(sc, Scenario::Started))
+                | Feature::Scenario(sc, Scenario::Started),
+            )) => format!("scenario {}", sc.name),
+            _ => return,
+        };
+        self.0.push(started);
+    }
+}
+
+/// Returns the events recorded with the given `--name` filter.
+async fn started_with_name(re: &str) -> Vec<String> {
+    let cli = cli::Opts::<_, _, _>::from_iter(["test", "--name", re]);
+    World::cucumber()
+        .max_concurrent_scenarios(1)
+        .with_writer(Started::default().normalized())
+        .with_cli(cli)
+        .run("tests/features/name_filter")
+        .await
+        .0
+        .clone()
+}
+
+#[tokio::test]
+async fn filters_scenarios_by_name() {
+    assert_eq!(
+        started_with_name("^login").await,
+        [
+            "started",
+            "parsed 2",
+            "feature First",
+            "scenario login succeeds",
+            "feature Second",
+            "scenario login fails",
+            "finished",
+        ],
+    );
+    assert_eq!(
+        started_with_name("admin lo").await,
+        [
+            "started",
+            "parsed 2",
+            "feature Second",
+            "rule admin",
+            "scenario admin login",
+            "scenario admin logout",
+            "finished",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn filters_scenarios_by_own_name_filter() {
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .with_writer(Started::default().normalized())
+        .with_cli(cli::Opts::<_, _, _>::from_iter(["test"]))
+        .filter_name(Regex::new("LOGIN").unwrap())
+        .filter_name_ignore_case(true)
+        .run("tests/features/name_filter")
+        .await;
+
+    assert_eq!(
+        writer.0,
+        [
+            "started",
+            "parsed 3",
+            "feature First",
+            "scenario login succeeds",
+            "feature Second",
+            "scenario login fails",
+            "rule admin",
+            "scenario admin login",
+            "finished",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn filters_scenarios_by_feature_name() {
+    let cli = cli::Opts::<_, _, _>::from_iter([
+        "test",
+        "--name",
+        "^first$",
+        "--name-ignore-case",
+        "--name-match-feature",
+    ]);
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .with_writer(Started::default().normalized())
+        .with_cli(cli)
+        .filter_name(Regex::new("admin").unwrap())
+        .run("tests/features/name_filter")
+        .await;
+
+    assert_eq!(
+        writer.0,
+        [
+            "started",
+            "parsed 2",
+            "feature First",
+            "scenario login succeeds",
+            "scenario logout succeeds",
+            "finished",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn filters_scenarios_by_predicate() {
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .with_writer(Started::default().normalized())
+        .with_cli(cli::Opts::<_, _, _>::from_iter(["test"]))
+        .filter_run("tests/features/name_filter", |_, rule, sc| {
+            rule.is_none() && sc.name.contains("out")
+        })
+        .await;
+
+    assert_eq!(
+        writer.0,
+        [
+            "started",
+            "parsed 1",
+            "feature First",
+            "scenario logout succeeds",
+            "finished",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn reports_empty_run_if_nothing_matches() {
+    assert_eq!(
+        started_with_name("^nothing$").await,
+        ["started", "parsed 0", "finished"],
+    );
+
+    let cli = cli::Opts::<_, _, _>::from_iter(["test", "--name", "^nothing$"]);
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .with_cli(cli)
+        .run("tests/features/name_filter")
+        .await;
+
+    assert_eq!(writer.features, 0);
+    assert_eq!(writer.rules, 0);
+    assert_eq!(writer.scenarios.total(), 0);
+    assert_eq!(writer.steps.total(), 0);
+    assert!(!writer::Failure::<World>::execution_has_failed(&writer));
+}
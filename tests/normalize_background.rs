@@ -0,0 +1,133 @@
+mod common;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, parser, Event, Parser as _, Writer, WriterExt as _,
+};
+use futures::StreamExt as _;
+
+use self::common::World;
+
+/// Records `Background` and regular `Step` events as they're received.
+#[derive(Default)]
+struct Recorder(Vec<String>);
+
+#[async_trait(?Send)]
+impl Writer<World> for Recorder {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Scenario};
+
+        if let Ok(Cucumber::Feature(_, Feature::Scenario(sc, ev))) =
+            ev.map(Event::into_inner)
+        {
+            let record = match ev {
+                Scenario::Background(st, _) => {
+                    format!("{}: background {}", sc.name, st.value)
+                }
+                Scenario::Step(st, _) => {
+                    format!("{}: step {}", sc.name, st.value)
+                }
+                _ => return,
+            };
+            self.0.push(record);
+        }
+    }
+}
+
+const FEATURE: &str = "\
+Feature: Normalization
+
+  Background:
+    Given a background step
+
+  Scenario: first
+    Given a step
+
+  Scenario: second
+    Given a step
+";
+
+#[tokio::test]
+async fn emits_background_steps_before_regular_ones() {
+    let feature = parser::Text::new()
+        .parse(FEATURE, cli::Empty::default())
+        .next()
+        .await
+        .unwrap()
+        .unwrap();
+    let bg = Arc::new(feature.background.as_ref().unwrap().steps[0].clone());
+    let first = Arc::new(feature.scenarios[0].clone());
+    let second = Arc::new(feature.scenarios[1].clone());
+    let step = |sc: &gherkin::Scenario| Arc::new(sc.steps[0].clone());
+    let feature = Arc::new(feature);
+
+    let sc = |sc: &Arc<gherkin::Scenario>, ev| {
+        event::Cucumber::scenario(
+            Arc::clone(&feature),
+            None,
+            Arc::clone(sc),
+            ev,
+        )
+    };
+    let events = vec![
+        event::Cucumber::Started,
+        event::Cucumber::feature_started(Arc::clone(&feature)),
+        sc(&first, event::Scenario::Started),
+        sc(&second, event::Scenario::Started),
+        // Events of the second `Scenario` are inserted out of order, while
+        // the first one still holds the output.
+        sc(&second, event::Scenario::step_started(step(&second))),
+        sc(
+            &second,
+            event::Scenario::background_step_started(Arc::clone(&bg)),
+        ),
+        sc(&second, event::Scenario::step_skipped(step(&second))),
+        sc(
+            &second,
+            event::Scenario::background_step_skipped(Arc::clone(&bg)),
+        ),
+        sc(&second, event::Scenario::Finished),
+        sc(
+            &first,
+            event::Scenario::background_step_started(Arc::clone(&bg)),
+        ),
+        sc(
+            &first,
+            event::Scenario::background_step_skipped(Arc::clone(&bg)),
+        ),
+        sc(&first, event::Scenario::step_started(step(&first))),
+        sc(&first, event::Scenario::step_skipped(step(&first))),
+        sc(&first, event::Scenario::Finished),
+        event::Cucumber::feature_finished(Arc::clone(&feature)),
+        event::Cucumber::Finished,
+    ];
+
+    let mut writer = Recorder::default().normalized();
+    for ev in events {
+        writer
+            .handle_event(Ok(Event::new(ev)), &cli::Empty::default())
+            .await;
+    }
+
+    assert_eq!(
+        writer.0,
+        [
+            "first: background a background step",
+            "first: background a background step",
+            "first: step a step",
+            "first: step a step",
+            "second: background a background step",
+            "second: background a background step",
+            "second: step a step",
+            "second: step a step",
+        ],
+    );
+}
@@ -0,0 +1,253 @@
+mod common;
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, parser, writer, Event, Parser as _, Writer, WriterExt as _,
+};
+use futures::StreamExt as _;
+
+use self::common::World;
+
+/// Records `Feature`s, `Step` heartbeats and finished `Scenario`s as they're
+/// received.
+#[derive(Default)]
+struct Recorder(Vec<String>);
+
+#[async_trait(?Send)]
+impl Writer<World> for Recorder {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Scenario};
+
+        let record = match ev.map(Event::into_inner) {
+            Ok(Cucumber::Feature(f, Feature::Started)) => {
+                format!("{}: started", f.name)
+            }
+            Ok(Cucumber::Feature(f, Feature::Finished)) => {
+                format!("{}: finished", f.name)
+            }
+            Ok(Cucumber::Feature(
+                _,
+                Feature::Scenario(sc, ev)
+                | Feature::Rule(_, event::Rule::Scenario(sc, ev)),
+            )) => match ev {
+                Scenario::StepHeartbeat(_, elapsed) => {
+                    format!("{}: {}s", sc.name, elapsed.as_secs())
+                }
+                Scenario::Finished => format!("{}: finished", sc.name),
+                _ => return,
+            },
+            _ => return,
+        };
+        self.0.push(record);
+    }
+}
+
+/// Parses a `Feature` with the given `name` and two `Scenario`s.
+async fn feature(name: &str) -> Arc<gherkin::Feature> {
+    let input = format!(
+        "Feature: {name}\n\
+         \n  Scenario: {name} first\n    Given a step\n\
+         \n  Scenario: {name} second\n    Given a step\n",
+        name = name,
+    );
+    let feature = parser::Text::new()
+        .parse(input, cli::Empty::default())
+        .next()
+        .await
+        .unwrap()
+        .unwrap();
+    Arc::new(feature)
+}
+
+/// Returns events of the `Scenario` with the given `index` of the `feature`.
+fn scenario(
+    feature: &Arc<gherkin::Feature>,
+    index: usize,
+    beats: u64,
+) -> Vec<event::Cucumber<World>> {
+    let sc = Arc::new(feature.scenarios[index].clone());
+    let step = Arc::new(sc.steps[0].clone());
+    let ev = |ev| {
+        event::Cucumber::scenario(
+            Arc::clone(feature),
+            None,
+            Arc::clone(&sc),
+            ev,
+        )
+    };
+
+    let mut events = vec![ev(event::Scenario::Started)];
+    events.extend((1..=beats).map(|secs| {
+        ev(event::Scenario::StepHeartbeat(
+            Arc::clone(&step),
+            Duration::from_secs(secs),
+        ))
+    }));
+    events.push(ev(event::Scenario::Finished));
+    events
+}
+
+/// Feeds the given `events` to the provided `writer`, returning its records.
+async fn write(
+    mut writer: writer::Normalize<World, Recorder>,
+    events: Vec<event::Cucumber<World>>,
+) -> Vec<String> {
+    for ev in events {
+        writer
+            .handle_event(Ok(Event::new(ev)), &cli::Empty::default())
+            .await;
+    }
+    writer.writer.0
+}
+
+/// Returns events of two concurrently running `Scenario`s of a single
+/// `Feature`, with the second one having heartbeats while the first one is
+/// still running.
+async fn concurrent_scenarios() -> Vec<event::Cucumber<World>> {
+    let f = feature("A").await;
+    let (first, second) = (scenario(&f, 0, 1), scenario(&f, 1, 2));
+
+    let mut events = vec![
+        event::Cucumber::Started,
+        event::Cucumber::feature_started(Arc::clone(&f)),
+    ];
+    let (first_started, first_rest) = first.split_at(1);
+    events.extend(first_started.iter().cloned());
+    events.extend(second);
+    events.extend(first_rest.iter().cloned());
+    events.push(event::Cucumber::feature_finished(f));
+    events.push(event::Cucumber::Finished);
+    events
+}
+
+#[tokio::test]
+async fn queues_concurrent_scenarios_by_default() {
+    let events = concurrent_scenarios().await;
+
+    assert_eq!(
+        write(Recorder::default().normalized(), events).await,
+        [
+            "A: started",
+            "A first: 1s",
+            "A first: finished",
+            "A second: 1s",
+            "A second: 2s",
+            "A second: finished",
+            "A: finished",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn writes_concurrent_scenarios_of_single_feature_eagerly() {
+    let events = concurrent_scenarios().await;
+
+    assert_eq!(
+        write(writer::Normalize::eager(Recorder::default()), events).await,
+        [
+            "A: started",
+            "A second: 1s",
+            "A second: 2s",
+            "A second: finished",
+            "A first: 1s",
+            "A first: finished",
+            "A: finished",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn queues_overlapping_features_eagerly() {
+    let (a, b) = (feature("A").await, feature("B").await);
+
+    let mut events = vec![
+        event::Cucumber::Started,
+        event::Cucumber::feature_started(Arc::clone(&a)),
+    ];
+    events.extend(scenario(&a, 0, 1));
+    events.push(event::Cucumber::feature_started(Arc::clone(&b)));
+    events.extend(scenario(&b, 0, 1));
+    events.extend(scenario(&a, 1, 1));
+    events.push(event::Cucumber::feature_finished(a));
+    events.extend(scenario(&b, 1, 1));
+    events.push(event::Cucumber::feature_finished(b));
+    events.push(event::Cucumber::Finished);
+
+    assert_eq!(
+        write(writer::Normalize::eager(Recorder::default()), events).await,
+        [
+            "A: started",
+            "A first: 1s",
+            "A first: finished",
+            "A second: 1s",
+            "A second: finished",
+            "A: finished",
+            "B: started",
+            "B first: 1s",
+            "B first: finished",
+            "B second: 1s",
+            "B second: finished",
+            "B: finished",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn writes_concurrent_scenarios_of_single_rule_eagerly() {
+    let f = parser::Text::new()
+        .parse(
+            "Feature: A\n\n  Rule: R\n\
+             \n    Scenario: first\n      Given a step\n\
+             \n    Scenario: second\n      Given a step\n",
+            cli::Empty::default(),
+        )
+        .next()
+        .await
+        .unwrap()
+        .unwrap();
+    let r = Arc::new(f.rules[0].clone());
+    let (first, second) = (
+        Arc::new(r.scenarios[0].clone()),
+        Arc::new(r.scenarios[1].clone()),
+    );
+    let f = Arc::new(f);
+    let sc = |sc: &Arc<gherkin::Scenario>, ev| {
+        event::Cucumber::scenario(
+            Arc::clone(&f),
+            Some(Arc::clone(&r)),
+            Arc::clone(sc),
+            ev,
+        )
+    };
+
+    let events = vec![
+        event::Cucumber::Started,
+        event::Cucumber::feature_started(Arc::clone(&f)),
+        event::Cucumber::rule_started(Arc::clone(&f), Arc::clone(&r)),
+        sc(&first, event::Scenario::Started),
+        sc(&second, event::Scenario::Started),
+        sc(&second, event::Scenario::Finished),
+        sc(&first, event::Scenario::Finished),
+        event::Cucumber::rule_finished(Arc::clone(&f), Arc::clone(&r)),
+        event::Cucumber::feature_finished(Arc::clone(&f)),
+        event::Cucumber::Finished,
+    ];
+
+    assert_eq!(
+        write(writer::Normalize::eager(Recorder::default()), events).await,
+        [
+            "A: started",
+            "second: finished",
+            "first: finished",
+            "A: finished"
+        ],
+    );
+}
@@ -0,0 +1,173 @@
+mod common;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, parser, writer, Event, Parser as _, Writer, WriterExt as _,
+};
+use futures::StreamExt as _;
+
+use self::common::World;
+
+/// Records started `Feature`s, `Rule`s and `Scenario`s as they're received.
+#[derive(Default)]
+struct Recorder(Vec<String>);
+
+#[async_trait(?Send)]
+impl Writer<World> for Recorder {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule, Scenario};
+
+        let record = match ev.map(Event::into_inner) {
+            Ok(Cucumber::Feature(f, Feature::Started)) => f.name.clone(),
+            Ok(Cucumber::Feature(_, Feature::Rule(r, Rule::Started))) => {
+                format!("  {}", r.name)
+            }
+            Ok(Cucumber::Feature(
+                _,
+                Feature::Scenario(sc, Scenario::Started)
+                | Feature::Rule(_, Rule::Scenario(sc, Scenario::Started)),
+            )) => format!("    {}", sc.name),
+            Ok(Cucumber::Finished) => "finished".to_owned(),
+            _ => return,
+        };
+        self.0.push(record);
+    }
+}
+
+/// Parses a `Feature` from the given `text`, reporting it with the given
+/// `path`.
+async fn feature(path: &str, text: &str) -> Arc<gherkin::Feature> {
+    Arc::new(
+        parser::Text::new()
+            .path(path)
+            .parse(text, cli::Empty::default())
+            .next()
+            .await
+            .unwrap()
+            .unwrap(),
+    )
+}
+
+/// Feeds the given `events` into the given `writer`, returning it.
+async fn feed<Wr: Writer<World, Cli = cli::Empty>>(
+    mut writer: Wr,
+    events: Vec<event::Cucumber<World>>,
+) -> Wr {
+    for ev in events {
+        writer
+            .handle_event(Ok(Event::new(ev)), &cli::Empty::default())
+            .await;
+    }
+    writer
+}
+
+#[tokio::test]
+async fn emits_in_order_of_paths_and_positions() {
+    let first = feature(
+        "a.feature",
+        "Feature: first\n\
+         \n  \
+           Scenario: one\n    \
+             Given a step\n\
+         \n  \
+           Rule: rule\n\
+         \n    \
+             Scenario: two\n      \
+               Given a step\n\
+         \n    \
+             Scenario: three\n      \
+               Given a step\n",
+    )
+    .await;
+    let second = feature(
+        "b.feature",
+        "Feature: second\n\
+         \n  \
+           Scenario: four\n    \
+             Given a step\n",
+    )
+    .await;
+    let rule = Arc::new(first.rules[0].clone());
+    let one = Arc::new(first.scenarios[0].clone());
+    let two = Arc::new(rule.scenarios[0].clone());
+    let three = Arc::new(rule.scenarios[1].clone());
+    let four = Arc::new(second.scenarios[0].clone());
+
+    let sc = |f: &Arc<gherkin::Feature>,
+              r: Option<&Arc<gherkin::Rule>>,
+              sc: &Arc<gherkin::Scenario>,
+              ev| {
+        event::Cucumber::scenario(
+            Arc::clone(f),
+            r.map(Arc::clone),
+            Arc::clone(sc),
+            ev,
+        )
+    };
+    // Everything starts in the reversed order.
+    let events = || {
+        vec![
+            event::Cucumber::Started,
+            event::Cucumber::feature_started(Arc::clone(&second)),
+            sc(&second, None, &four, event::Scenario::Started),
+            event::Cucumber::feature_started(Arc::clone(&first)),
+            event::Cucumber::rule_started(
+                Arc::clone(&first),
+                Arc::clone(&rule),
+            ),
+            sc(&first, Some(&rule), &three, event::Scenario::Started),
+            sc(&first, Some(&rule), &two, event::Scenario::Started),
+            sc(&first, None, &one, event::Scenario::Started),
+            sc(&second, None, &four, event::Scenario::Finished),
+            event::Cucumber::feature_finished(Arc::clone(&second)),
+            sc(&first, Some(&rule), &two, event::Scenario::Finished),
+            sc(&first, Some(&rule), &three, event::Scenario::Finished),
+            event::Cucumber::rule_finished(
+                Arc::clone(&first),
+                Arc::clone(&rule),
+            ),
+            sc(&first, None, &one, event::Scenario::Finished),
+            event::Cucumber::feature_finished(Arc::clone(&first)),
+            event::Cucumber::Finished,
+        ]
+    };
+
+    let sorted =
+        feed(writer::Normalize::sorted(Recorder::default()), events()).await;
+    assert_eq!(
+        sorted.0,
+        [
+            "first",
+            "    one",
+            "  rule",
+            "    two",
+            "    three",
+            "second",
+            "    four",
+            "finished",
+        ],
+    );
+
+    let normalized = feed(Recorder::default().normalized(), events()).await;
+    assert_eq!(
+        normalized.0,
+        [
+            "second",
+            "    four",
+            "first",
+            "  rule",
+            "    three",
+            "    two",
+            "    one",
+            "finished",
+        ],
+    );
+}
@@ -0,0 +1,171 @@
+mod common;
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use cucumber::{cli, event, parser, writer, Event, Parser as _, Writer};
+use futures::StreamExt as _;
+use once_cell::sync::Lazy;
+
+use self::common::{Discard, World};
+
+static REPORTS: Lazy<Mutex<Vec<String>>> = Lazy::new(Mutex::default);
+static SORTED_REPORTS: Lazy<Mutex<Vec<String>>> = Lazy::new(Mutex::default);
+
+const STUCK: &str = "\
+Feature: Stuck
+
+  Scenario: first
+    Given a step
+
+  Rule: rule
+
+    Scenario: second
+      Given a step
+";
+
+const WAITING: &str = "\
+Feature: Waiting
+
+  Scenario: third
+    Given a step
+";
+
+async fn parse(text: &str) -> Arc<gherkin::Feature> {
+    Arc::new(
+        parser::Text::new()
+            .parse(text, cli::Empty::default())
+            .next()
+            .await
+            .unwrap()
+            .unwrap(),
+    )
+}
+
+async fn feed<Wr: Writer<World>>(
+    writer: &mut Wr,
+    events: Vec<event::Cucumber<World>>,
+) where
+    Wr::Cli: Default,
+{
+    for ev in events {
+        writer
+            .handle_event(Ok(Event::new(ev)), &Wr::Cli::default())
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn reports_feature_holding_output_once() {
+    let stuck = parse(STUCK).await;
+    let waiting = parse(WAITING).await;
+    let first = Arc::new(stuck.scenarios[0].clone());
+    let rule = Arc::new(stuck.rules[0].clone());
+    let second = Arc::new(rule.scenarios[0].clone());
+    let third = Arc::new(waiting.scenarios[0].clone());
+
+    let sc = |feat: &Arc<gherkin::Feature>, rule: Option<&Arc<_>>, sc, ev| {
+        event::Cucumber::scenario(
+            Arc::clone(feat),
+            rule.map(Arc::clone),
+            Arc::clone(sc),
+            ev,
+        )
+    };
+
+    let mut writer = writer::Normalize::new(Discard)
+        .watchdog_with(Duration::from_millis(50), |stuck| {
+            REPORTS.lock().unwrap().push(stuck.to_string())
+        });
+
+    feed(
+        &mut writer,
+        vec![
+            event::Cucumber::Started,
+            event::Cucumber::feature_started(Arc::clone(&stuck)),
+            sc(&stuck, None, &first, event::Scenario::Started),
+            event::Cucumber::rule_started(
+                Arc::clone(&stuck),
+                Arc::clone(&rule),
+            ),
+            sc(&stuck, Some(&rule), &second, event::Scenario::Started),
+            event::Cucumber::feature_started(Arc::clone(&waiting)),
+        ],
+    )
+    .await;
+    assert!(REPORTS.lock().unwrap().is_empty(), "reported too early");
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    feed(
+        &mut writer,
+        vec![
+            sc(&waiting, None, &third, event::Scenario::Started),
+            sc(&waiting, None, &third, event::Scenario::Finished),
+            event::Cucumber::feature_finished(Arc::clone(&waiting)),
+        ],
+    )
+    .await;
+
+    let reports = REPORTS.lock().unwrap().clone();
+    assert_eq!(reports.len(), 1, "reported not once: {:?}", reports);
+    let (head, pending) = reports[0].split_once(", with").unwrap();
+    assert!(
+        head.starts_with("Feature `Stuck` (<string>) hasn't progressed for "),
+        "unexpected report: {}",
+        head,
+    );
+    assert!(
+        head.ends_with("holding the output of 1 other feature"),
+        "unexpected report: {}",
+        head,
+    );
+    assert_eq!(
+        pending,
+        " pending scenarios:\n  \
+         Scenario: first (3:3)\n  \
+         Scenario: second (8:5)",
+    );
+}
+
+#[tokio::test]
+async fn ignores_finished_feature_waiting_for_sorted_output() {
+    let finished = parse(WAITING).await;
+    let running = parse(STUCK).await;
+    let third = Arc::new(finished.scenarios[0].clone());
+    let first = Arc::new(running.scenarios[0].clone());
+
+    let mut writer = writer::Normalize::sorted(Discard)
+        .watchdog_with(Duration::from_millis(50), |stuck| {
+            SORTED_REPORTS.lock().unwrap().push(stuck.to_string())
+        });
+
+    let sc = |feat: &Arc<gherkin::Feature>, sc, ev| {
+        event::Cucumber::scenario(Arc::clone(feat), None, Arc::clone(sc), ev)
+    };
+    feed(
+        &mut writer,
+        vec![
+            event::Cucumber::Started,
+            event::Cucumber::feature_started(Arc::clone(&finished)),
+            sc(&finished, &third, event::Scenario::Started),
+            sc(&finished, &third, event::Scenario::Finished),
+            event::Cucumber::feature_finished(Arc::clone(&finished)),
+            event::Cucumber::feature_started(Arc::clone(&running)),
+        ],
+    )
+    .await;
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    feed(
+        &mut writer,
+        vec![
+            sc(&running, &first, event::Scenario::Started),
+            sc(&running, &first, event::Scenario::Finished),
+        ],
+    )
+    .await;
+
+    assert!(SORTED_REPORTS.lock().unwrap().is_empty());
+}
@@ -145,7 +145,7 @@ static SPAN_OR_PATH_RE: Lazy<Regex> = Lazy::new(|| {
 mod spec {
     use std::fs;
 
-    use cucumber::{WorldInit as _, WriterExt as _};
+    use cucumber::{writer, WorldInit as _, WriterExt as _};
     use globwalk::GlobWalkerBuilder;
 
     use super::{DebugWriter, World};
@@ -178,4 +178,25 @@ mod spec {
             assert_eq!(normalized.0, out, "file: {}", file);
         }
     }
+
+    #[tokio::test]
+    async fn matches_steps_regardless_of_language() {
+        let writer = World::cucumber().run("tests/features/multilingual").await;
+
+        assert_eq!(writer::Failure::<World>::failed_steps(&writer), 0);
+        assert_eq!(writer::Failure::<World>::skipped_steps(&writer), 0);
+        assert_eq!(writer::Failure::<World>::parsing_errors(&writer), 0);
+    }
+
+    #[tokio::test]
+    async fn skips_feature_on_background_failure() {
+        let writer = World::cucumber()
+            .skip_feature_on_background_failure()
+            .run("tests/features/background_failure")
+            .await;
+
+        assert_eq!(writer.scenarios.failed, 1);
+        assert_eq!(writer.scenarios.skipped, 2);
+        assert_eq!(writer.steps.failed, 1);
+    }
 }
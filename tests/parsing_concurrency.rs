@@ -0,0 +1,51 @@
+mod common;
+
+use std::path::PathBuf;
+
+use cucumber::{given, parser, Parser as _, WorldInit};
+use futures::StreamExt as _;
+
+use self::common::{sink, World};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+/// Parses the given `path` with the given number of `workers`, returning paths
+/// of the parsed `Feature`s, or `None`s for the parsing errors.
+async fn parse(path: &str, workers: usize) -> Vec<Option<PathBuf>> {
+    parser::Basic::new()
+        .concurrency(workers)
+        .parse(path, parser::basic::Cli { features: None })
+        .map(|f| f.ok().and_then(|f| f.path))
+        .collect()
+        .await
+}
+
+#[tokio::test]
+async fn parses_in_same_order_as_sequentially() {
+    let sequential = parse("tests/features", 1).await;
+
+    assert!(sequential.len() > 10);
+    assert!(sequential.iter().any(Option::is_none));
+    for workers in [0, 2, 3, 64] {
+        assert_eq!(
+            parse("tests/features", workers).await,
+            sequential,
+            "workers: {}",
+            workers,
+        );
+    }
+}
+
+#[tokio::test]
+async fn runs_features_parsed_concurrently() {
+    let writer = World::cucumber()
+        .parsing_concurrency(4)
+        .with_writer(sink())
+        .run("tests/features/glob")
+        .await;
+
+    assert_eq!(writer.features, 2);
+    assert_eq!(writer.scenarios.passed, 2);
+    assert_eq!(writer.parsing_errors, 0);
+}
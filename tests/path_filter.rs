@@ -0,0 +1,42 @@
+mod common;
+
+use cucumber::{cli, given, Glob, WorldInit};
+use structopt::StructOpt as _;
+
+use self::common::{sink, World};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+/// Runs `Feature`s matching the given `glob` with the provided CLI `args`,
+/// returning the numbers of run `Feature`s and passed `Scenario`s.
+async fn run_by_path(glob: &str, args: &[&str]) -> (usize, usize) {
+    let args = ["test"].iter().chain(args);
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .with_cli(cli::Opts::<_, _, _>::from_iter(args))
+        .filter_run_by_path("tests/features/glob", Glob::new(glob).unwrap())
+        .await;
+    (writer.features, writer.scenarios.passed)
+}
+
+#[tokio::test]
+async fn runs_only_features_matching_glob() {
+    assert_eq!(
+        run_by_path("tests/features/glob/first.*", &[]).await,
+        (1, 1)
+    );
+    assert_eq!(run_by_path("tests/features/glob/**", &[]).await, (2, 2));
+    assert_eq!(run_by_path("**/nested/*.feature", &[]).await, (1, 1));
+    assert_eq!(run_by_path("tests/features/unknown/**", &[]).await, (0, 0));
+}
+
+#[tokio::test]
+async fn cli_path_takes_precedence_over_glob() {
+    let args = ["--path", "**/nested/**"];
+
+    assert_eq!(
+        run_by_path("tests/features/unknown/**", &args).await,
+        (1, 1)
+    );
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use std::{
+    cell::Cell,
+    io::{self, Read},
+    thread,
+    time::Duration,
+};
+
+use cucumber::{cli, given, runner, writer, WorldInit, WriterExt as _};
+use futures::FutureExt as _;
+use structopt::StructOpt as _;
+use tokio::time;
+
+use self::common::World;
+
+thread_local! {
+    /// Number of `Step`s being executed at the moment.
+    static RUNNING: Cell<usize> = Cell::new(0);
+
+    /// Maximum number of `Step`s being executed at once.
+    static MAX_RUNNING: Cell<usize> = Cell::new(0);
+}
+
+#[given("a passing step")]
+async fn passing(_: &mut World) {
+    track_running().await;
+}
+
+#[given("a failing step")]
+async fn failing(_: &mut World) {
+    track_running().await;
+    panic!("failed");
+}
+
+async fn track_running() {
+    let running = RUNNING.with(|r| r.get()) + 1;
+    RUNNING.with(|r| r.set(running));
+    MAX_RUNNING.with(|m| m.set(m.get().max(running)));
+    time::sleep(Duration::from_millis(10)).await;
+    RUNNING.with(|r| r.set(r.get() - 1));
+}
+
+/// Input, taking the given time to read each line.
+struct Slow(Duration, io::Cursor<&'static str>);
+
+impl Read for Slow {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        thread::sleep(self.0);
+        self.1.read(buf)
+    }
+}
+
+type Summary =
+    writer::Summarize<writer::Normalize<World, writer::Basic<io::Sink>>>;
+
+fn summary() -> Summary {
+    writer::Basic::new(io::sink(), writer::Coloring::Never, false).summarized()
+}
+
+#[tokio::test]
+async fn continues_on_enter_one_scenario_at_a_time() {
+    let cli = cli::Opts::<_, _, _>::from_iter(["test", "--concurrency", "4"]);
+
+    let writer = World::cucumber()
+        .with_runner(
+            runner::Basic::default()
+                .pause_on_failure(io::Cursor::new("\n\n"))
+                .steps(World::collection()),
+        )
+        .with_writer(summary())
+        .with_cli(cli)
+        .run("tests/features/pause_on_failure")
+        .await;
+
+    assert_eq!(writer.scenarios.passed, 1);
+    assert_eq!(writer.scenarios.failed, 2);
+    assert_eq!(MAX_RUNNING.with(Cell::get), 1);
+}
+
+#[tokio::test]
+async fn aborts_on_q() {
+    let writer = World::cucumber()
+        .with_runner(
+            runner::Basic::default()
+                .pause_on_failure(io::Cursor::new("q\n"))
+                .steps(World::collection()),
+        )
+        .with_writer(summary())
+        .run("tests/features/pause_on_failure")
+        .await;
+
+    assert_eq!(writer.scenarios.passed, 0);
+    assert_eq!(writer.scenarios.failed, 1);
+}
+
+#[tokio::test]
+async fn suspends_timeouts_while_paused() {
+    let writer = World::cucumber()
+        .with_runner(
+            runner::Basic::default()
+                .after(|_, _, _, _, _| async {}.boxed_local())
+                .scenario_timeout(Duration::from_millis(200))
+                .pause_on_failure(io::BufReader::new(Slow(
+                    Duration::from_millis(300),
+                    io::Cursor::new("\n\n"),
+                )))
+                .steps(World::collection()),
+        )
+        .with_writer(summary())
+        .run("tests/features/pause_on_failure")
+        .await;
+
+    assert_eq!(writer.scenarios.passed, 1);
+    assert_eq!(writer.scenarios.failed, 2);
+    assert_eq!(writer.failed_hooks, 0);
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use std::{fs, time::Duration};
+
+use cucumber::{given, when, writer, WorldInit, WriterExt as _};
+use tempfile::NamedTempFile;
+
+use self::common::World;
+
+#[given("a passing step")]
+fn passing(_: &mut World) {}
+
+#[when("a failing step")]
+fn failing(_: &mut World) {
+    panic!("failed");
+}
+
+#[tokio::test]
+async fn outputs_plain_lines_on_progress() {
+    let file = NamedTempFile::new().unwrap();
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .with_writer(
+            writer::Progress::new(file.reopen().unwrap())
+                .interval(Duration::ZERO),
+        )
+        .run("tests/features/progress")
+        .await;
+
+    assert_eq!(writer::Failure::<World>::failed_steps(&writer), 1);
+    assert_eq!(writer::Failure::<World>::skipped_steps(&writer), 1);
+    assert_eq!(
+        fs::read_to_string(file.path()).unwrap(),
+        "[0/1] features \u{2022} 0/3 scenarios \u{2022} 0 failed\n\
+         [0/1] features \u{2022} 1/3 scenarios \u{2022} 0 failed\n\
+         [0/1] features \u{2022} 2/3 scenarios \u{2022} 1 failed\n\
+         [0/1] features \u{2022} 3/3 scenarios \u{2022} 1 failed\n\
+         [1/1] features \u{2022} 3/3 scenarios \u{2022} 1 failed\n",
+    );
+}
+
+#[tokio::test]
+async fn throttles_plain_lines() {
+    let file = NamedTempFile::new().unwrap();
+    let _ = World::cucumber()
+        .with_writer(writer::Progress::new(file.reopen().unwrap()))
+        .run("tests/features/progress")
+        .await;
+
+    assert_eq!(
+        fs::read_to_string(file.path()).unwrap(),
+        "[0/1] features \u{2022} 0/3 scenarios \u{2022} 0 failed\n\
+         [1/1] features \u{2022} 3/3 scenarios \u{2022} 1 failed\n",
+    );
+}
+
+#[tokio::test]
+async fn redraws_live_line_and_clears_it_before_summary() {
+    let file = NamedTempFile::new().unwrap();
+    let _ = World::cucumber()
+        .with_writer(
+            writer::Progress::new(file.reopen().unwrap())
+                .live(true)
+                .summarized(),
+        )
+        .run("tests/features/progress")
+        .await;
+
+    let output = fs::read_to_string(file.path()).unwrap();
+    let (progress, summary) = output.rsplit_once("\r\x1b[2K").unwrap();
+
+    assert!(
+        progress.starts_with(
+            "\r\x1b[2K[0/1] features \u{2022} 0/3 scenarios \u{2022} 0 failed",
+        ),
+        "unexpected progress: {:?}",
+        progress,
+    );
+    assert!(
+        progress.ends_with(
+            "\r\x1b[2K[1/1] features \u{2022} 3/3 scenarios \u{2022} 1 failed",
+        ),
+        "unexpected progress: {:?}",
+        progress,
+    );
+    assert!(!progress.contains('\n'), "not redrawn: {:?}", progress);
+    assert!(
+        summary.contains("3 scenarios (1 passed, 1 skipped, 1 failed)"),
+        "unexpected summary: {}",
+        summary,
+    );
+}
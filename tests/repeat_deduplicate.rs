@@ -0,0 +1,180 @@
+mod common;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, then, writer, Event, Parser as _, WorldInit,
+    Writer, WriterExt as _,
+};
+use futures::StreamExt as _;
+
+use self::common::World;
+
+#[given(regex = r"^value \d+$")]
+fn value(_: &mut World) {}
+
+#[then("it fails")]
+fn fails(_: &mut World) {
+    panic!("boom");
+}
+
+#[then("it fails differently")]
+fn fails_differently(_: &mut World) {
+    panic!("bang");
+}
+
+/// Records panic messages of failed `Step`s, including re-outputted ones.
+#[derive(Default)]
+struct Failures(Vec<String>);
+
+#[async_trait(?Send)]
+impl Writer<World> for Failures {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Scenario, Step, StepError};
+
+        if let Ok(Cucumber::Feature(
+            _,
+            Feature::Scenario(
+                _,
+                Scenario::Step(_, Step::Failed(_, _, StepError::Panic(info))),
+            ),
+        )) = ev.map(Event::into_inner)
+        {
+            let msg = info
+                .downcast_ref::<String>()
+                .cloned()
+                .or_else(|| info.downcast_ref::<&str>().map(|s| s.to_string()))
+                .unwrap();
+            self.0.push(msg);
+        }
+    }
+}
+
+impl writer::NonTransforming for Failures {}
+
+#[tokio::test]
+async fn repeats_every_failure_by_default() {
+    let writer = World::cucumber()
+        .with_writer(writer::Repeat::failed(Failures::default()).normalized())
+        .run("tests/features/repeat_deduplicate")
+        .await;
+
+    assert_eq!(writer.0.len(), 8);
+    assert_eq!(&writer.0[4..], ["boom", "boom", "boom", "bang"]);
+}
+
+#[tokio::test]
+async fn collapses_duplicate_failures() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Repeat::failed(Failures::default())
+                .deduplicate(true)
+                .normalized(),
+        )
+        .run("tests/features/repeat_deduplicate")
+        .await;
+
+    assert_eq!(writer.0.len(), 6);
+    assert_eq!(&writer.0[4..], ["boom × 3", "bang"]);
+}
+
+#[tokio::test]
+async fn repeats_distinct_steps_with_same_wording_when_unique() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Repeat::failed_unique(Failures::default()).normalized(),
+        )
+        .run("tests/features/repeat_deduplicate")
+        .await;
+
+    assert_eq!(writer.0.len(), 8);
+    assert_eq!(&writer.0[4..], ["boom", "boom", "boom", "bang"]);
+}
+
+const UNIQUE: &str = "\
+Feature: Unique
+
+  Scenario: retried
+    Then it fails
+
+  Scenario: twice
+    Then it fails
+    Then it fails
+";
+
+#[tokio::test]
+async fn repeats_each_step_of_last_attempt_once_when_unique() {
+    let feature = parser::Text::new()
+        .parse(UNIQUE, cli::Empty::default())
+        .next()
+        .await
+        .unwrap()
+        .unwrap();
+    let retried = Arc::new(feature.scenarios[0].clone());
+    let twice = Arc::new(feature.scenarios[1].clone());
+    let feature = Arc::new(feature);
+
+    let sc = |sc: &Arc<gherkin::Scenario>, ev| {
+        event::Cucumber::scenario(
+            Arc::clone(&feature),
+            None,
+            Arc::clone(sc),
+            ev,
+        )
+    };
+    let failed = |sc: &gherkin::Scenario, i: usize, msg: &'static str| {
+        event::Scenario::Step(
+            Arc::new(sc.steps[i].clone()),
+            event::Step::Failed(
+                None,
+                None,
+                event::StepError::Panic(Arc::new(msg)),
+            ),
+        )
+    };
+    let events = vec![
+        event::Cucumber::Started,
+        event::Cucumber::feature_started(Arc::clone(&feature)),
+        // A custom `Runner` may emit events of failed attempts too.
+        sc(&retried, event::Scenario::Started),
+        sc(&retried, failed(&retried, 0, "first attempt")),
+        sc(&retried, event::Scenario::Finished),
+        sc(
+            &retried,
+            event::Scenario::retried(
+                1,
+                1,
+                event::StepError::Panic(Arc::new("first attempt")),
+            ),
+        ),
+        sc(&retried, event::Scenario::Started),
+        sc(&retried, failed(&retried, 0, "last attempt")),
+        sc(&retried, event::Scenario::Finished),
+        sc(&twice, event::Scenario::Started),
+        sc(&twice, failed(&twice, 0, "first step")),
+        sc(&twice, failed(&twice, 0, "first step")),
+        sc(&twice, failed(&twice, 1, "second step")),
+        sc(&twice, event::Scenario::Finished),
+        event::Cucumber::feature_finished(Arc::clone(&feature)),
+        event::Cucumber::Finished,
+    ];
+
+    let mut writer = writer::Repeat::failed_unique(Failures::default());
+    for ev in events {
+        writer
+            .handle_event(Ok(Event::new(ev)), &cli::Empty::default())
+            .await;
+    }
+
+    assert_eq!(
+        &writer.0[5..],
+        ["last attempt", "first step", "second step"],
+    );
+}
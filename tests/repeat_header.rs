@@ -0,0 +1,92 @@
+mod common;
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, then, writer, Event, WorldInit, Writer,
+    WriterExt as _,
+};
+
+use self::common::World;
+
+#[given(regex = r"^value \d+$")]
+fn value(_: &mut World) {}
+
+#[then("it fails")]
+fn fails(_: &mut World) {
+    panic!("boom");
+}
+
+/// Records failed `Step`s and arbitrary output in the order they're received.
+#[derive(Default)]
+struct Output(Vec<String>);
+
+#[async_trait(?Send)]
+impl Writer<World> for Output {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Scenario, Step};
+
+        if let Ok(Cucumber::Feature(
+            _,
+            Feature::Scenario(_, Scenario::Step(st, Step::Failed(..))),
+        )) = ev.map(Event::into_inner)
+        {
+            self.0.push(format!("failed: {}", st.value));
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'val> writer::Arbitrary<'val, World, String> for Output {
+    async fn write(&mut self, val: String)
+    where
+        'val: 'async_trait,
+    {
+        self.0.push(val);
+    }
+}
+
+impl writer::NonTransforming for Output {}
+
+#[tokio::test]
+async fn surrounds_repeated_failures_with_header() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Repeat::with_header(
+                Output::default(),
+                "=== Failures summary ===",
+            )
+            .normalized(),
+        )
+        .run("tests/features/repeat_header")
+        .await;
+
+    assert_eq!(
+        writer.0,
+        [
+            "failed: it fails",
+            "=== Failures summary ===",
+            "failed: it fails",
+            "========================",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn omits_header_when_nothing_is_repeated() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Repeat::skipped(Output::default())
+                .header("=== Skipped ===")
+                .normalized(),
+        )
+        .run("tests/features/repeat_header")
+        .await;
+
+    assert_eq!(writer.0, ["failed: it fails"]);
+}
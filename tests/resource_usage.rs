@@ -0,0 +1,73 @@
+mod common;
+
+use std::{collections::HashMap, fs, mem};
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, runner, Event, WorldInit, Writer, WriterExt as _,
+};
+
+use self::common::World;
+
+#[given("a leaked file")]
+fn leaked_file(_: &mut World) {
+    mem::forget(fs::File::open("Cargo.toml").unwrap());
+}
+
+#[given("a closed file")]
+fn closed_file(_: &mut World) {
+    drop(fs::File::open("Cargo.toml").unwrap());
+}
+
+/// Records `event::ResourceUsage` samples by `Scenario` names.
+#[derive(Default)]
+struct Usages(HashMap<String, (event::ResourceUsage, event::ResourceUsage)>);
+
+#[async_trait(?Send)]
+impl Writer<World> for Usages {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Scenario};
+
+        if let Ok(Cucumber::Feature(
+            _,
+            Feature::Scenario(sc, Scenario::ResourceUsage { start, end }),
+        )) = ev.map(Event::into_inner)
+        {
+            let _ = self.0.insert(sc.name.clone(), (start, end));
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn reports_leaked_file_descriptors() {
+    let writer = World::cucumber()
+        .with_runner(
+            runner::Basic::default()
+                .max_concurrent_scenarios(1)
+                .resource_usage()
+                .steps(World::collection()),
+        )
+        .with_writer(Usages::default().normalized())
+        .run("tests/features/resource_usage")
+        .await;
+
+    assert_eq!(writer.0.len(), 2);
+    for (start, end) in writer.0.values() {
+        assert!(start.rss.is_some() && end.rss.is_some());
+    }
+
+    let (start, end) = writer.0["leaking"];
+    assert!(
+        end.open_fds > start.open_fds,
+        "leaked file descriptor isn't reported: {:?} -> {:?}",
+        start,
+        end,
+    );
+}
@@ -0,0 +1,222 @@
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    convert::Infallible,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, when, Event, WorldInit, Writer, WriterExt as _,
+};
+
+thread_local! {
+    /// Number of failures of each step so far.
+    static FAILURES: RefCell<HashMap<String, usize>> =
+        RefCell::new(HashMap::new());
+
+    /// Number of `Background` executions so far.
+    static BACKGROUNDS: RefCell<usize> = RefCell::new(0);
+}
+
+#[derive(Debug, Default, WorldInit)]
+struct World {
+    touched: bool,
+}
+
+#[async_trait(?Send)]
+impl cucumber::World for World {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Self::Error> {
+        Ok(Self::default())
+    }
+}
+
+#[given("a fresh world")]
+fn fresh_world(w: &mut World) {
+    assert!(!w.touched, "world is reused");
+    w.touched = true;
+}
+
+#[given("a counted background")]
+fn counted_background(_: &mut World) {
+    BACKGROUNDS.with(|b| *b.borrow_mut() += 1);
+}
+
+#[when(regex = r"^(\w+) fails (\d+) times?$")]
+fn fails(_: &mut World, key: String, times: usize) {
+    let failed = FAILURES.with(|f| {
+        let mut failures = f.borrow_mut();
+        let failed = failures.entry(key).or_default();
+        *failed += 1;
+        *failed
+    });
+    assert!(failed > times, "failure #{}", failed);
+}
+
+/// Records outcomes of every `Scenario` by its name.
+#[derive(Default)]
+struct Outcomes(BTreeMap<String, Vec<String>>);
+
+#[async_trait(?Send)]
+impl Writer<World> for Outcomes {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule, Scenario, Step};
+
+        let (sc, ev) = match ev.map(Event::into_inner) {
+            Ok(Cucumber::Feature(
+                _,
+                Feature::Rule(_, Rule::Scenario(sc, ev))
+                | Feature::Scenario(sc, ev),
+            )) => (sc, ev),
+            _ => return,
+        };
+        let outcome = match ev {
+            Scenario::Retried {
+                attempt, retries, ..
+            } => format!("retried {}/{}", attempt, retries),
+            Scenario::Started => "started".into(),
+            Scenario::Step(st, Step::Passed(_)) => {
+                format!("passed {}", st.value)
+            }
+            Scenario::Step(st, Step::Failed(..)) => {
+                format!("failed {}", st.value)
+            }
+            Scenario::Finished => "finished".into(),
+            _ => return,
+        };
+        self.0.entry(sc.name.clone()).or_default().push(outcome);
+    }
+}
+
+#[tokio::test]
+async fn retries_failed_scenarios_matching_filter() {
+    let writer = World::cucumber()
+        .retries(2)
+        .retry_filter("@flaky".parse::<cucumber::tag::Expression>().unwrap())
+        .with_writer(Outcomes::default().normalized())
+        .run("tests/features/retries")
+        .await;
+
+    let outcomes =
+        |sc: &str| writer.0[sc].iter().map(String::as_str).collect::<Vec<_>>();
+    assert_eq!(
+        outcomes("flaky"),
+        [
+            "retried 1/2",
+            "retried 2/2",
+            "started",
+            "passed a fresh world",
+            "passed flaky fails 2 times",
+            "finished",
+        ],
+    );
+    assert_eq!(
+        outcomes("broken"),
+        [
+            "retried 1/2",
+            "retried 2/2",
+            "started",
+            "passed a fresh world",
+            "failed broken fails 5 times",
+            "finished",
+        ],
+    );
+    assert_eq!(
+        outcomes("untagged"),
+        [
+            "started",
+            "passed a fresh world",
+            "failed untagged fails 1 time",
+            "finished",
+        ],
+    );
+}
+
+impl cucumber::writer::NonTransforming for Outcomes {}
+
+#[tokio::test]
+async fn repeats_retried_passed_scenarios() {
+    let writer = World::cucumber()
+        .retries(2)
+        .retry_filter("@flaky".parse::<cucumber::tag::Expression>().unwrap())
+        .with_writer(Outcomes::default().repeat_retried().normalized())
+        .run("tests/features/retries")
+        .await;
+
+    let outcomes = |sc: &str| {
+        writer.writer.0[sc]
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+    };
+    let flaky = [
+        "retried 1/2",
+        "retried 2/2",
+        "started",
+        "passed a fresh world",
+        "passed flaky fails 2 times",
+        "finished",
+    ];
+    assert_eq!(outcomes("flaky"), [flaky, flaky].concat());
+    assert_eq!(outcomes("broken").len(), 6);
+    assert_eq!(outcomes("untagged").len(), 4);
+}
+
+#[tokio::test]
+async fn does_not_retry_by_default() {
+    let writer = World::cucumber()
+        .with_writer(Outcomes::default().normalized())
+        .run("tests/features/retries")
+        .await;
+
+    assert!(writer
+        .0
+        .values()
+        .flatten()
+        .all(|outcome| !outcome.starts_with("retried")));
+}
+
+#[tokio::test]
+async fn retry_tag_overrides_global_setting() {
+    let started = Instant::now();
+    let writer = World::cucumber()
+        .retries(1)
+        .retry_after(Duration::from_millis(50))
+        .with_writer(Outcomes::default().normalized())
+        .run("tests/features/retries_tag")
+        .await;
+
+    let outcomes =
+        |sc: &str| writer.0[sc].iter().map(String::as_str).collect::<Vec<_>>();
+    assert_eq!(
+        outcomes("tagged"),
+        [
+            "retried 1/2",
+            "retried 2/2",
+            "started",
+            "passed a fresh world",
+            "passed tagged fails 2 times",
+            "finished",
+        ],
+    );
+    assert_eq!(
+        outcomes("untagged"),
+        [
+            "retried 1/1",
+            "started",
+            "passed a fresh world",
+            "passed untagged fails 1 time",
+            "finished",
+        ],
+    );
+    assert_eq!(BACKGROUNDS.with(|b| *b.borrow()), 5);
+    assert!(started.elapsed() >= Duration::from_millis(100));
+}
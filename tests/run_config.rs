@@ -0,0 +1,62 @@
+mod common;
+
+use std::time::Duration;
+
+use cucumber::{cli, tag, WorldInit};
+use structopt::StructOpt as _;
+
+use self::common::World;
+
+#[test]
+fn merges_builder_settings_with_cli() {
+    let cli = cli::Opts::<_, _, _>::from_iter([
+        "test",
+        "--tags",
+        "@smoke and not (@slow or priority < 2)",
+        "--shuffle-seed",
+        "42",
+        "--fail-fast",
+    ]);
+    let mut cucumber = World::cucumber::<&str>()
+        .retries(2)
+        .retry_after(Duration::from_millis(10))
+        .step_timeout(Duration::from_secs(1))
+        .scenario_timeout(Duration::from_secs(5))
+        .run_timeout(Duration::from_secs(60))
+        .shuffle(7)
+        .dry_run(true)
+        .with_cli(cli);
+
+    let config = cucumber.run_config();
+
+    assert_eq!(
+        config.tags_filter.as_deref(),
+        Some("@smoke and not (@slow or priority < 2)"),
+    );
+    assert_eq!(config.tag_inheritance, tag::Inheritance::Inherit);
+    assert_eq!(config.retries, 2);
+    assert_eq!(config.retry_after, Some(Duration::from_millis(10)));
+    assert_eq!(config.shuffle_seed, Some(42));
+    assert!(config.fail_fast);
+    assert!(config.dry_run);
+    assert_eq!(config.step_timeout, Some(Duration::from_secs(1)));
+    assert_eq!(config.scenario_timeout, Some(Duration::from_secs(5)));
+    assert_eq!(config.run_timeout, Some(Duration::from_secs(60)));
+}
+
+#[test]
+fn defaults_to_unconfigured() {
+    let mut cucumber = World::cucumber::<&str>()
+        .with_cli(cli::Opts::<_, _, _>::from_iter(["test"]));
+
+    let config = cucumber.run_config();
+
+    assert_eq!(config.tags_filter, None);
+    assert_eq!(config.retry_after, None);
+    assert_eq!(config.shuffle_seed, None);
+    assert!(!config.fail_fast);
+    assert!(!config.dry_run);
+    assert_eq!(config.step_timeout, None);
+    assert_eq!(config.scenario_timeout, None);
+    assert_eq!(config.run_timeout, None);
+}
@@ -0,0 +1,112 @@
+mod common;
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, Event, WorldInit, Writer, WriterExt as _,
+};
+use structopt::StructOpt as _;
+use tokio::time;
+
+use self::common::World;
+
+#[given("a step")]
+async fn step(_: &mut World) {
+    time::sleep(Duration::from_millis(10)).await;
+}
+
+/// Records started and finished `Feature`s (by their directory) and
+/// `Scenario`s, along with the number of parsed `Feature`s.
+#[derive(Default)]
+struct Recorded {
+    features: usize,
+    events: Vec<String>,
+}
+
+#[async_trait(?Send)]
+impl Writer<World> for Recorded {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Scenario};
+
+        let dir = |f: &gherkin::Feature| {
+            f.path
+                .as_ref()
+                .and_then(|p| p.parent()?.file_name())
+                .map(|d| d.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        };
+        match ev.map(Event::into_inner) {
+            Ok(Cucumber::ParsingFinished { features, .. }) => {
+                self.features = features;
+            }
+            Ok(Cucumber::Feature(f, Feature::Started)) => {
+                self.events.push(format!("started {}", dir(&f)));
+            }
+            Ok(Cucumber::Feature(f, Feature::Finished)) => {
+                self.events.push(format!("finished {}", dir(&f)));
+            }
+            Ok(Cucumber::Feature(
+                _,
+                Feature::Scenario(sc, Scenario::Finished),
+            )) => self.events.push(sc.name.clone()),
+            _ => {}
+        }
+    }
+}
+
+#[tokio::test]
+async fn runs_features_from_multiple_paths_once() {
+    let writer = World::cucumber::<&str>()
+        .with_writer(Recorded::default().normalized())
+        .with_cli(cli::Opts::<_, _, _>::from_iter(["test"]))
+        .run_many([
+            "tests/features/run_many/first",
+            "tests/features/run_many/second/same.feature",
+            "tests/features/run_many/**/*.feature",
+        ])
+        .await;
+
+    assert_eq!(writer.features, 2);
+    assert_eq!(
+        writer.events,
+        [
+            "started first",
+            "first one",
+            "first two",
+            "finished first",
+            "started second",
+            "second one",
+            "second two",
+            "finished second",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn parses_paths_in_given_order() {
+    use cucumber::Parser as _;
+    use futures::StreamExt as _;
+
+    let features = parser::Basic::new()
+        .parse(
+            [
+                "tests/features/run_many/second",
+                "tests/features/run_many/first",
+            ]
+            .into_iter()
+            .collect::<parser::basic::Paths>(),
+            parser::basic::Cli { features: None },
+        )
+        .map(|f| f.unwrap().scenarios[0].name.clone())
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(features, ["second one", "first one"]);
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, runner, Event, WorldInit, WorldTeardown, Writer,
+    WriterExt as _,
+};
+use futures::FutureExt as _;
+use tokio::time;
+
+use self::common::World;
+
+#[async_trait(?Send)]
+impl WorldTeardown for World {
+    async fn drop(self) -> Result<(), Self::Error> {
+        let _ = TORN_DOWN.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+static TORN_DOWN: AtomicUsize = AtomicUsize::new(0);
+
+static ABANDONED_AFTER_HOOKS: AtomicUsize = AtomicUsize::new(0);
+
+#[given(regex = r"(\d+) ms step")]
+async fn step(_: &mut World, ms: u64) {
+    time::sleep(Duration::from_millis(ms)).await;
+}
+
+/// Records `(scenario, outcome)` of every finished `Scenario`.
+#[derive(Default)]
+struct Outcomes(Vec<(String, &'static str)>);
+
+#[async_trait(?Send)]
+impl Writer<World> for Outcomes {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule, Scenario, SkipReason, Step};
+
+        let (sc, ev) = match ev.map(Event::into_inner) {
+            Ok(Cucumber::Feature(
+                _,
+                Feature::Rule(_, Rule::Scenario(sc, ev))
+                | Feature::Scenario(sc, ev),
+            )) => (sc, ev),
+            _ => return,
+        };
+        let outcome = match ev {
+            Scenario::Step(_, Step::Passed(_)) => "passed",
+            Scenario::Skipped(SkipReason::RunTimeout) => "timed out",
+            _ => return,
+        };
+        self.0.push((sc.name.clone(), outcome));
+    }
+}
+
+#[tokio::test]
+async fn run_timeout_skips_and_abandons_scenarios() {
+    let writer = World::cucumber()
+        .with_runner(
+            runner::Basic::default()
+                .max_concurrent_scenarios(1)
+                .run_timeout(Duration::from_millis(500))
+                .run_timeout_grace_period(Duration::from_millis(100))
+                .steps(World::collection()),
+        )
+        .with_writer(Outcomes::default().normalized())
+        .run("tests/features/run_timeout")
+        .await;
+
+    let outcomes = writer
+        .0
+        .iter()
+        .map(|(sc, outcome)| (sc.as_str(), *outcome))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        outcomes,
+        [
+            ("fast", "passed"),
+            ("slow", "timed out"),
+            ("late", "timed out"),
+            ("unstarted", "timed out"),
+        ],
+    );
+}
+
+#[tokio::test]
+async fn run_timeout_cleans_up_abandoned_scenarios() {
+    let _ = World::cucumber()
+        .with_runner(
+            runner::Basic::default()
+                .max_concurrent_scenarios(1)
+                .run_timeout(Duration::from_millis(500))
+                .run_timeout_grace_period(Duration::from_millis(100))
+                .after(|_, _, sc, _, world| {
+                    async move {
+                        if sc.name == "slow" && world.is_some() {
+                            let _ = ABANDONED_AFTER_HOOKS
+                                .fetch_add(1, Ordering::SeqCst);
+                        }
+                    }
+                    .boxed_local()
+                })
+                .teardown()
+                .steps(World::collection()),
+        )
+        .with_writer(Outcomes::default().normalized())
+        .run("tests/features/run_timeout")
+        .await;
+
+    assert_eq!(ABANDONED_AFTER_HOOKS.load(Ordering::SeqCst), 1);
+    assert_eq!(TORN_DOWN.load(Ordering::SeqCst), 2);
+}
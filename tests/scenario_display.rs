@@ -0,0 +1,96 @@
+mod common;
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, Event, WorldInit, Writer, WriterExt as _,
+};
+use futures::FutureExt as _;
+use once_cell::sync::Lazy;
+
+use self::common::World;
+
+static HOOKED: Lazy<Mutex<Vec<String>>> = Lazy::new(Mutex::default);
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+/// Records names of started `Scenario`s.
+#[derive(Default)]
+struct Names(Vec<String>);
+
+#[async_trait(?Send)]
+impl Writer<World> for Names {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule, Scenario};
+
+        if let Ok(Cucumber::Feature(
+            _,
+            Feature::Rule(_, Rule::Scenario(sc, Scenario::Started))
+            | Feature::Scenario(sc, Scenario::Started),
+        )) = ev.map(Event::into_inner)
+        {
+            self.0.push(sc.name.clone());
+        }
+    }
+}
+
+#[tokio::test]
+async fn renders_scenario_names() {
+    let writer = World::cucumber()
+        .with_writer(Names::default().normalized())
+        .max_concurrent_scenarios(1)
+        .scenario_display(|feature, rule, scenario| {
+            let rule = rule.map(|r| format!("{} :: ", r.name));
+            format!(
+                "{} :: {}{}",
+                feature.name,
+                rule.unwrap_or_default(),
+                scenario.name,
+            )
+        })
+        .run("tests/features/scenario_display")
+        .await;
+
+    assert_eq!(
+        writer.0,
+        ["Login :: happy path", "Login :: Admin :: happy path"],
+    );
+}
+
+#[tokio::test]
+async fn keeps_bare_names_by_default() {
+    let writer = World::cucumber()
+        .with_writer(Names::default().normalized())
+        .max_concurrent_scenarios(1)
+        .run("tests/features/scenario_display")
+        .await;
+
+    assert_eq!(writer.0, ["happy path", "happy path"]);
+}
+
+#[tokio::test]
+async fn keeps_bare_names_for_runner() {
+    let writer = World::cucumber()
+        .with_writer(Names::default().normalized())
+        .max_concurrent_scenarios(1)
+        .before(|_, _, sc, _| {
+            HOOKED.lock().unwrap().push(sc.name.clone());
+            async {}.boxed_local()
+        })
+        .scenario_display(|feature, _, scenario| {
+            format!("{} :: {}", feature.name, scenario.name)
+        })
+        .run("tests/features/scenario_display")
+        .await;
+
+    assert_eq!(*HOOKED.lock().unwrap(), ["happy path", "happy path"]);
+    assert_eq!(writer.0, ["Login :: happy path", "Login :: happy path"]);
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use std::sync::Mutex;
+
+use cucumber::{given, when, WorldInit};
+use once_cell::sync::Lazy;
+
+use self::common::World;
+
+static PASSED: Lazy<Mutex<Vec<String>>> = Lazy::new(Mutex::default);
+static FAILED: Lazy<Mutex<Vec<String>>> = Lazy::new(Mutex::default);
+
+#[given("a passing step")]
+fn passing(_: &mut World) {}
+
+#[when("a failing step")]
+fn failing(_: &mut World) {
+    panic!("failed");
+}
+
+#[tokio::test]
+async fn notifies_about_scenario_outcome() {
+    let _ = World::cucumber()
+        .on_scenario_passed(|_, _, sc| {
+            PASSED.lock().unwrap().push(sc.name.clone());
+        })
+        .on_scenario_failed(|_, _, sc| {
+            FAILED.lock().unwrap().push(sc.name.clone());
+        })
+        .run("tests/features/scenario_outcome")
+        .await;
+
+    assert_eq!(*PASSED.lock().unwrap(), ["passing"]);
+    assert_eq!(*FAILED.lock().unwrap(), ["failing"]);
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use std::{
+    io,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use cucumber::{
+    given, then, when,
+    writer::{self, Coloring},
+    WorldInit, WriterExt as _,
+};
+use regex::Regex;
+use tokio::time;
+
+use self::common::World;
+
+#[given("a slow step")]
+#[when("a slow step")]
+async fn slow(_: &mut World) {
+    time::sleep(Duration::from_millis(50)).await;
+}
+
+#[then("a failing step")]
+fn failing(_: &mut World) {
+    panic!("failed");
+}
+
+/// Shared output to inspect after a run.
+#[derive(Clone, Default)]
+struct Output(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Runs the test `Feature` with a [`writer::Basic`] outputting timings if
+/// `show_timings` is `true`, and returns its output.
+async fn run(show_timings: bool) -> String {
+    let out = Output::default();
+    let mut basic = writer::Basic::raw(out.clone(), Coloring::Never, false);
+    basic.apply_cli(writer::basic::Cli {
+        verbose: false,
+        color: Coloring::Never,
+        snippets: false,
+        show_timings,
+    });
+    drop(
+        World::cucumber()
+            .with_writer(basic.normalized())
+            .run("tests/features/show_timings")
+            .await,
+    );
+    let bytes = out.0.lock().unwrap().clone();
+    String::from_utf8(bytes).unwrap()
+}
+
+#[tokio::test]
+async fn outputs_step_timings() {
+    let output = run(true).await;
+
+    let timed = Regex::new(
+        r"(?m)^\s+(✔>|✔ |✘ ) (Given|When|Then) a (slow|failing) step \((\d+)ms\)$",
+    )
+    .unwrap();
+    let timings = timed
+        .captures_iter(&output)
+        .map(|c| (c[3].to_owned(), c[4].parse::<u64>().unwrap()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(timings.len(), 3, "{}", output);
+    for (step, ms) in timings {
+        if step == "slow" {
+            assert!(ms >= 50, "{}", output);
+        }
+    }
+}
+
+#[tokio::test]
+async fn omits_step_timings_by_default() {
+    let output = run(false).await;
+
+    assert!(output.contains("Given a slow step\n"), "{}", output);
+    assert!(!output.contains("ms)"), "{}", output);
+}
@@ -0,0 +1,134 @@
+mod common;
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, writer, Event, WorldInit, Writer, WriterExt as _,
+};
+
+use self::common::{sink, World};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+/// Records the reported shuffle seed and names of the started `Scenario`s in
+/// order of their execution.
+#[derive(Default)]
+struct Order {
+    seed: Option<u64>,
+    scenarios: Vec<String>,
+}
+
+#[async_trait(?Send)]
+impl Writer<World> for Order {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Scenario};
+
+        match ev.map(Event::into_inner) {
+            Ok(Cucumber::ParsingFinished { shuffle_seed, .. }) => {
+                self.seed = shuffle_seed;
+            }
+            Ok(Cucumber::Feature(
+                _,
+                Feature::Scenario(sc, Scenario::Started),
+            )) => {
+                self.scenarios.push(sc.name.clone());
+            }
+            _ => {}
+        }
+    }
+}
+
+async fn run(seed: Option<Option<u64>>) -> writer::Normalize<World, Order> {
+    run_concurrently(seed, 1).await
+}
+
+async fn run_concurrently(
+    seed: Option<Option<u64>>,
+    max: usize,
+) -> writer::Normalize<World, Order> {
+    let cucumber = World::cucumber().max_concurrent_scenarios(max);
+    let cucumber = match seed {
+        Some(seed) => cucumber.shuffle(seed),
+        None => cucumber,
+    };
+    cucumber
+        .with_writer(Order::default().normalized())
+        .run("tests/features/shuffle")
+        .await
+}
+
+fn in_file_order() -> Vec<String> {
+    ["first", "second"]
+        .iter()
+        .flat_map(|f| (1..=8).map(move |i| format!("{} {}", f, i)))
+        .collect()
+}
+
+#[tokio::test]
+async fn keeps_order_by_default() {
+    let order = run(None).await;
+
+    assert_eq!(order.seed, None);
+    assert_eq!(order.scenarios, in_file_order());
+}
+
+#[tokio::test]
+async fn shuffles_reproducibly_with_seed() {
+    let order = run(Some(Some(42))).await;
+
+    assert_eq!(order.seed, Some(42));
+    assert_ne!(order.scenarios, in_file_order());
+    assert_eq!(order.scenarios, run(Some(Some(42))).await.scenarios);
+
+    let mut sorted = order.scenarios.clone();
+    sorted.sort();
+    assert_eq!(sorted, in_file_order());
+}
+
+#[tokio::test]
+async fn keeps_features_order() {
+    let order = run(Some(Some(7))).await;
+
+    assert!(order.scenarios[..8]
+        .iter()
+        .all(|sc| sc.starts_with("first")));
+    assert!(order.scenarios[8..]
+        .iter()
+        .all(|sc| sc.starts_with("second")));
+}
+
+#[tokio::test]
+async fn reports_generated_seed_for_replay() {
+    let order = run(Some(None)).await;
+    let seed = order.seed.expect("generated seed is not reported");
+
+    assert_eq!(order.scenarios, run(Some(Some(seed))).await.scenarios);
+}
+
+#[tokio::test]
+async fn composes_with_max_concurrent_scenarios() {
+    let order = run_concurrently(Some(Some(42)), 3).await;
+
+    assert_eq!(order.seed, Some(42));
+    assert_eq!(order.scenarios, run(Some(Some(42))).await.scenarios);
+}
+
+#[tokio::test]
+async fn summarizes_seed() {
+    let writer = World::cucumber()
+        .shuffle(13)
+        .with_writer(sink())
+        .run("tests/features/shuffle")
+        .await;
+
+    assert_eq!(writer.shuffle_seed, Some(13));
+    assert!(writer::out::Styles::new()
+        .summary(&writer)
+        .ends_with("Shuffled with seed 13 (replay with `--shuffle-seed 13`)",));
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use cucumber::{
+    given,
+    writer::{self, Coloring},
+    WorldInit, WriterExt as _,
+};
+
+use self::common::{sink, World};
+
+#[path = "snippets/stubs.rs"]
+mod stubs;
+
+/// Source of the [`stubs`] module.
+const STUBS: &str = include_str!("snippets/stubs.rs");
+
+#[given("a defined step")]
+fn defined(_: &mut World) {}
+
+/// Shared output to inspect after a run.
+#[derive(Clone, Default)]
+struct Output(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Runs the test `Feature` with a [`writer::Basic`] outputting snippets if
+/// `snippets` is `true`, and returns its output.
+async fn run(snippets: bool) -> String {
+    let out = Output::default();
+    let mut basic = writer::Basic::raw(out.clone(), Coloring::Never, false);
+    basic.apply_cli(writer::basic::Cli {
+        verbose: false,
+        color: Coloring::Never,
+        snippets,
+        #[cfg(feature = "timestamps")]
+        show_timings: false,
+    });
+    drop(
+        World::cucumber()
+            .with_writer(basic.normalized())
+            .run("tests/features/snippets")
+            .await,
+    );
+    let bytes = out.0.lock().unwrap().clone();
+    String::from_utf8(bytes).unwrap()
+}
+
+#[tokio::test]
+async fn reports_undefined_steps() {
+    let output = run(false).await;
+
+    assert_eq!(output.matches("Step undefined: ").count(), 2, "{}", output);
+    assert!(!output.contains("todo!()"), "{}", output);
+}
+
+#[tokio::test]
+async fn outputs_snippets_for_undefined_steps() {
+    let output = run(true).await;
+
+    assert!(
+        output.contains(
+            "#[given(regex = r#\"^I have (\\d+) \"red\" apples$\"#)]\n      \
+             fn i_have_n_red_apples(world: &mut World, n1: u64) {\n",
+        ),
+        "{}",
+        output,
+    );
+    assert!(
+        output.contains(
+            "#[when(regex = r\"^(\\d+) apples are eaten by$\")]\n      \
+             fn n_apples_are_eaten_by(\
+             world: &mut World, n1: u64, #[step] step: &Step) {\n",
+        ),
+        "{}",
+        output,
+    );
+    assert_eq!(output.matches("todo!()").count(), 2, "{}", output);
+}
+
+#[tokio::test]
+async fn outputs_compilable_snippets() {
+    let output = run(true).await.replace("\n      ", "\n");
+
+    for snippet in STUBS.split("\n\n").filter(|s| s.contains("todo!()")) {
+        assert!(output.contains(snippet.trim_end()), "{}", output);
+    }
+
+    let summary = stubs::World::cucumber()
+        .with_writer(sink())
+        .run("tests/features/snippets")
+        .await;
+
+    assert_eq!(summary.steps.failed, 2);
+    assert_eq!(summary.steps.skipped, 1);
+}
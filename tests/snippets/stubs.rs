@@ -0,0 +1,31 @@
+//! Stubs for the undefined `Step`s of `tests/features/snippets`, exactly as
+//! suggested by `writer::Basic`.
+
+#![allow(unused_variables)]
+
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{gherkin::Step, given, when, WorldInit};
+
+#[derive(Debug, WorldInit)]
+pub struct World;
+
+#[async_trait(?Send)]
+impl cucumber::World for World {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Self::Error> {
+        Ok(Self)
+    }
+}
+
+#[given(regex = r#"^I have (\d+) "red" apples$"#)]
+fn i_have_n_red_apples(world: &mut World, n1: u64) {
+    todo!()
+}
+
+#[when(regex = r"^(\d+) apples are eaten by$")]
+fn n_apples_are_eaten_by(world: &mut World, n1: u64, #[step] step: &Step) {
+    todo!()
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, then, when, writer, Event, WorldInit, Writer,
+    WriterExt as _,
+};
+
+use self::common::{sink, World};
+
+#[given(regex = "a soft step that panics", soft = true)]
+fn soft_step(_: &mut World) {
+    panic!("only informational");
+}
+
+#[when("a regular step")]
+#[then("a regular step")]
+fn regular_step(_: &mut World) {}
+
+/// Records finished `Step`s along with their outcome.
+#[derive(Default)]
+struct Outcomes(Vec<(String, &'static str)>);
+
+#[async_trait(?Send)]
+impl Writer<World> for Outcomes {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Scenario, Step};
+
+        if let Ok(Cucumber::Feature(
+            _,
+            Feature::Scenario(_, Scenario::Step(st, ev)),
+        )) = ev.map(Event::into_inner)
+        {
+            let outcome = match ev {
+                Step::Started | Step::Output(_) => return,
+                Step::Passed(_) => "passed",
+                Step::Matched(_) => "matched",
+                Step::Skipped => "skipped",
+                Step::Failed(..) => "failed",
+                Step::SoftFailed(..) => "soft failed",
+            };
+            self.0.push((st.value.clone(), outcome));
+        }
+    }
+}
+
+#[tokio::test]
+async fn soft_step_failure_does_not_fail_scenario() {
+    let writer = World::cucumber()
+        .with_writer(Outcomes::default().normalized())
+        .run("tests/features/soft_steps")
+        .await;
+
+    assert_eq!(
+        writer.0,
+        [
+            ("a soft step that panics".to_owned(), "soft failed"),
+            ("a regular step".to_owned(), "passed"),
+            ("a regular step".to_owned(), "passed"),
+        ],
+    );
+}
+
+#[tokio::test]
+async fn summary_counts_soft_failures_separately() {
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .run("tests/features/soft_steps")
+        .await;
+
+    assert_eq!(writer.soft_failed_steps, 1);
+    assert_eq!(writer.steps.passed, 3);
+    assert_eq!(writer.scenarios.passed, 1);
+    assert!(!writer::Failure::<World>::execution_has_failed(&writer));
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use std::{
+    env,
+    io::Write as _,
+    process::{Command, Stdio},
+};
+
+use cucumber::{cli, given, parser, WorldInit};
+use structopt::StructOpt as _;
+
+use self::common::{sink, World};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+/// Environment variable making [`run_from_stdin()`] actually run.
+const CHILD_VAR: &str = "CUCUMBER_STDIN_PARSER_CHILD";
+
+/// Runs this test binary's [`run_from_stdin()`] test with the given `input`
+/// piped into its stdin, and returns its stdout.
+fn run_child(input: &str) -> String {
+    let mut child = Command::new(env::current_exe().unwrap())
+        .args(["--ignored", "--exact", "run_from_stdin", "--nocapture"])
+        .env(CHILD_VAR, "1")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[tokio::test]
+#[ignore = "is run by other tests with a piped stdin"]
+async fn run_from_stdin() {
+    if env::var_os(CHILD_VAR).is_none() {
+        return;
+    }
+
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::Stdin::new())
+        .with_writer(sink())
+        .with_cli(cli::Opts::<_, _, _>::from_iter(["test"]))
+        .run(())
+        .await;
+
+    println!(
+        "features: {}, passed: {}, parsing errors: {}",
+        writer.features, writer.scenarios.passed, writer.parsing_errors,
+    );
+}
+
+#[test]
+fn runs_feature_from_stdin() {
+    let output = run_child(
+        "Feature: Piped\n  \
+           Scenario: first\n    Given a step\n  \
+           Scenario: second\n    Given a step\n",
+    );
+
+    assert!(
+        output.contains("features: 1, passed: 2, parsing errors: 0"),
+        "{}",
+        output,
+    );
+}
+
+#[test]
+fn reports_malformed_stdin_as_parsing_error() {
+    let output = run_child("Feature: Broken\n  Scenario:\n  Unknown: 1");
+
+    assert!(
+        output.contains("features: 0, passed: 0, parsing errors: 1"),
+        "{}",
+        output,
+    );
+}
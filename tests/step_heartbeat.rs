@@ -0,0 +1,68 @@
+mod common;
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, Event, WorldInit, Writer, WriterExt as _,
+};
+use tokio::time;
+
+use self::common::World;
+
+#[given("a slow step")]
+async fn slow(_: &mut World) {
+    time::sleep(Duration::from_millis(250)).await;
+}
+
+#[given("a fast step")]
+fn fast(_: &mut World) {}
+
+/// Records `(scenario, elapsed)` of every `Step` heartbeat.
+#[derive(Default)]
+struct Heartbeats(Vec<(String, Duration)>);
+
+#[async_trait(?Send)]
+impl Writer<World> for Heartbeats {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule, Scenario};
+
+        if let Ok(Cucumber::Feature(
+            _,
+            Feature::Rule(_, Rule::Scenario(sc, Scenario::StepHeartbeat(_, e)))
+            | Feature::Scenario(sc, Scenario::StepHeartbeat(_, e)),
+        )) = ev.map(Event::into_inner)
+        {
+            self.0.push((sc.name.clone(), e));
+        }
+    }
+}
+
+#[tokio::test]
+async fn emits_heartbeats_of_slow_steps() {
+    let writer = World::cucumber()
+        .with_writer(Heartbeats::default().normalized())
+        .step_heartbeat(Duration::from_millis(50))
+        .run("tests/features/step_heartbeat")
+        .await;
+
+    assert!(!writer.0.is_empty());
+    assert!(writer.0.iter().all(|(sc, _)| sc == "slow"));
+    assert!(writer.0.windows(2).all(|w| w[0].1 < w[1].1));
+}
+
+#[tokio::test]
+async fn emits_no_heartbeats_by_default() {
+    let writer = World::cucumber()
+        .with_writer(Heartbeats::default().normalized())
+        .run("tests/features/step_heartbeat")
+        .await;
+
+    assert!(writer.0.is_empty());
+}
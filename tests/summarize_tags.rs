@@ -0,0 +1,70 @@
+mod common;
+
+use std::io;
+
+use cucumber::{given, writer, WorldInit, WriterExt as _};
+
+use self::common::{sink, World};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+#[given("a failing step")]
+fn failing_step(_: &mut World) {
+    panic!("failed");
+}
+
+#[tokio::test]
+async fn groups_scenarios_by_inherited_tags() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::new(io::sink(), writer::Coloring::Never, false)
+                .summarized()
+                .tag_breakdown(true),
+        )
+        .run("tests/features/summarize_tags")
+        .await;
+
+    let tags = writer
+        .tags
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|(tag, s)| (tag.as_str(), s.passed, s.skipped, s.failed))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tags,
+        [
+            ("api", 2, 1, 1),
+            ("billing", 1, 0, 0),
+            ("slow", 0, 0, 1),
+            ("smoke", 2, 0, 1),
+        ],
+    );
+
+    let summary = writer::out::Styles::new().summary(&writer);
+    assert!(
+        summary.ends_with(
+            "\nScenarios by tag:\n  \
+               @api     4 scenarios (2 passed, 1 skipped, 1 failed)\n  \
+               @billing 1 scenario (1 passed)\n  \
+               @slow    1 scenario (1 failed)\n  \
+               @smoke   3 scenarios (2 passed, 1 failed)",
+        ),
+        "{}",
+        summary,
+    );
+}
+
+#[tokio::test]
+async fn does_not_group_by_tags_by_default() {
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .run("tests/features/summarize_tags")
+        .await;
+
+    assert!(writer.tags.is_none());
+    assert!(!writer::out::Styles::new()
+        .summary(&writer)
+        .contains("Scenarios by tag:"));
+}
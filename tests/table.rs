@@ -0,0 +1,154 @@
+mod common;
+
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{
+    gherkin::{Step, Table},
+    given,
+    table::{self, Ext as _, StepExt as _},
+    then, WorldInit,
+};
+use serde::Deserialize;
+
+use self::common::sink;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct User {
+    name: String,
+    age: u8,
+    email: Option<String>,
+}
+
+#[derive(Debug, WorldInit)]
+struct World {
+    users: Vec<User>,
+}
+
+#[async_trait(?Send)]
+impl cucumber::World for World {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Self::Error> {
+        Ok(Self { users: Vec::new() })
+    }
+}
+
+#[given("the users")]
+fn users(world: &mut World, #[step] step: &Step) -> Result<(), table::Error> {
+    world.users = step.table_rows()?;
+    Ok(())
+}
+
+#[given("the user")]
+fn user(world: &mut World, #[step] step: &Step) -> Result<(), table::Error> {
+    world.users.push(step.data_table()?.vertical_as()?);
+    Ok(())
+}
+
+#[then(expr = "there are {int} users")]
+fn users_count(world: &mut World, count: usize) {
+    assert_eq!(world.users.len(), count);
+}
+
+fn table(rows: &[&[&str]]) -> Table {
+    Table {
+        rows: rows
+            .iter()
+            .map(|r| r.iter().map(|&c| c.to_owned()).collect())
+            .collect(),
+        span: Default::default(),
+        position: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn deserializes_step_tables() {
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .run("tests/features/table")
+        .await;
+
+    assert_eq!(writer.scenarios.passed, 2);
+    assert_eq!(writer.scenarios.failed, 2);
+}
+
+#[test]
+fn deserializes_records() {
+    let users = table(&[
+        &["name", "age", "email"],
+        &["Alice", "30", "alice@dev.org"],
+        &["Bob", "25", ""],
+    ])
+    .rows_as::<User>()
+    .unwrap();
+
+    assert_eq!(
+        users,
+        [
+            User {
+                name: "Alice".into(),
+                age: 30,
+                email: Some("alice@dev.org".into()),
+            },
+            User {
+                name: "Bob".into(),
+                age: 25,
+                email: None,
+            },
+        ],
+    );
+}
+
+#[test]
+fn deserializes_vertical() {
+    let user = table(&[&["name", "Carol"], &["age", "41"], &["email", ""]])
+        .vertical_as::<User>()
+        .unwrap();
+
+    assert_eq!(
+        user,
+        User {
+            name: "Carol".into(),
+            age: 41,
+            email: None,
+        },
+    );
+}
+
+#[test]
+fn errors_descriptively() {
+    let err = table(&[&["name", "age"], &["Alice", "30"], &["Dave", "old"]])
+        .rows_as::<User>()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Failed to deserialize data table row 3, column `age`: \
+         invalid value: string \"old\", expected u8",
+    );
+
+    let err = table(&[&["name"], &["Carol"]])
+        .rows_as::<User>()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Failed to deserialize data table row 2: missing field `age`",
+    );
+
+    let err = table(&[&["name", "Carol"], &["age", "-1"]])
+        .vertical_as::<User>()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Failed to deserialize vertical data table field `age`: \
+         invalid value: string \"-1\", expected u8",
+    );
+
+    let err = table(&[&["name", "Carol", "41"]])
+        .vertical_as::<User>()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Vertical data table should have 2 columns, but has 3",
+    );
+}
@@ -0,0 +1,196 @@
+mod common;
+
+use cucumber::{
+    cli, given,
+    tag::{Comparison, Expression, ParseError},
+    WorldInit,
+};
+use structopt::StructOpt as _;
+
+use self::common::{sink, World};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+/// Returns the number of `Scenario`s passed with the given `--tags` filter.
+async fn passed_with(tags: &str) -> usize {
+    let cli = cli::Opts::<_, _, _>::from_iter(["test", "--tags", tags]);
+    World::cucumber()
+        .with_writer(sink())
+        .with_cli(cli)
+        .run("tests/features/tag_expression")
+        .await
+        .scenarios
+        .passed
+}
+
+#[tokio::test]
+async fn filters_by_tag_value_comparison() {
+    assert_eq!(passed_with("priority >= 2").await, 2);
+    assert_eq!(passed_with("@priority>2").await, 1);
+    assert_eq!(passed_with("priority == 1 or @smoke").await, 2);
+    assert_eq!(passed_with("not priority < 2").await, 3);
+    assert_eq!(passed_with("priority != 2 and not @smoke").await, 2);
+}
+
+/// Returns the number of `Scenario`s passed with the given
+/// [`Cucumber::filter_tags()`] expression.
+///
+/// [`Cucumber::filter_tags()`]: cucumber::Cucumber::filter_tags()
+async fn passed_with_inherited(tags: &str) -> usize {
+    World::cucumber()
+        .with_writer(sink())
+        .with_cli(cli::Opts::<_, _, _>::from_iter(["test"]))
+        .filter_tags(tags.parse().unwrap())
+        .run("tests/features/tag_expression_inheritance")
+        .await
+        .scenarios
+        .passed
+}
+
+#[tokio::test]
+async fn filters_by_inherited_tags() {
+    assert_eq!(passed_with_inherited("@api").await, 4);
+    assert_eq!(passed_with_inherited("@smoke and not @wip").await, 1);
+    assert_eq!(passed_with_inherited("@api and not @smoke").await, 2);
+    assert_eq!(passed_with_inherited("not (@wip or @smoke)").await, 1);
+    assert_eq!(passed_with_inherited("@wip or @smoke and @api").await, 3);
+}
+
+/// Runs with the given [`Cucumber::filter_tags()`] expression, returning the
+/// numbers of passed and skipped `Scenario`s, along with the number of `Rule`s.
+///
+/// [`Cucumber::filter_tags()`]: cucumber::Cucumber::filter_tags()
+async fn run_with_filter_tags(tags: &str, skip: bool) -> (usize, usize, usize) {
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .with_cli(cli::Opts::<_, _, _>::from_iter(["test"]))
+        .filter_tags(tags.parse().unwrap())
+        .skip_filtered_out(skip)
+        .run("tests/features/tag_expression_inheritance")
+        .await;
+
+    assert_eq!(writer.scenarios.failed, 0);
+    assert_eq!(writer.not_executed_scenarios, 0);
+    (
+        writer.scenarios.passed,
+        writer.scenarios.skipped,
+        writer.rules,
+    )
+}
+
+#[tokio::test]
+async fn filter_tags_omits_not_matching() {
+    assert_eq!(
+        run_with_filter_tags("@smoke and not @wip", false).await,
+        (1, 0, 1),
+    );
+    assert_eq!(run_with_filter_tags("not @smoke", false).await, (2, 0, 0));
+    assert_eq!(run_with_filter_tags("@unknown", false).await, (0, 0, 0));
+}
+
+#[tokio::test]
+async fn filter_tags_skips_not_matching() {
+    assert_eq!(
+        run_with_filter_tags("@smoke and not @wip", true).await,
+        (1, 3, 1),
+    );
+    assert_eq!(run_with_filter_tags("not @smoke", true).await, (2, 2, 1));
+    assert_eq!(run_with_filter_tags("@unknown", true).await, (0, 4, 1));
+}
+
+#[tokio::test]
+async fn cli_tags_take_precedence_over_filter_tags() {
+    let passed = World::cucumber()
+        .with_writer(sink())
+        .with_cli(cli::Opts::<_, _, _>::from_iter(["test", "--tags", "@wip"]))
+        .filter_tags("@smoke".parse().unwrap())
+        .run("tests/features/tag_expression_inheritance")
+        .await
+        .scenarios
+        .passed;
+
+    assert_eq!(passed, 2);
+}
+
+#[test]
+fn parses_comparisons_with_precedence() {
+    let expr = "@a or not priority <= 2 and @b".parse::<Expression>();
+
+    assert_eq!(
+        expr,
+        Ok(Expression::Or(
+            Box::new(Expression::Tag("a".into())),
+            Box::new(Expression::And(
+                Box::new(Expression::Not(Box::new(Expression::Compare(
+                    "priority".into(),
+                    Comparison::Le,
+                    "2".into(),
+                )))),
+                Box::new(Expression::Tag("b".into())),
+            )),
+        )),
+    );
+    assert_eq!(
+        r"@with\ space\(\)".parse::<Expression>(),
+        Ok(Expression::Tag("with space()".into())),
+    );
+}
+
+#[test]
+fn reports_invalid_expressions() {
+    assert_eq!(
+        "priority >=".parse::<Expression>(),
+        Err(ParseError::UnexpectedEnd),
+    );
+    assert_eq!(
+        "priority @high".parse::<Expression>(),
+        Err(ParseError::UnexpectedToken("@high".into())),
+    );
+    assert_eq!(
+        "(@a or @b".parse::<Expression>(),
+        Err(ParseError::UnexpectedEnd),
+    );
+    assert_eq!(
+        r"@a\b".parse::<Expression>(),
+        Err(ParseError::InvalidEscape('b')),
+    );
+    assert_eq!("".parse::<Expression>(), Err(ParseError::UnexpectedEnd));
+    assert_eq!(
+        "@a @b".parse::<Expression>(),
+        Err(ParseError::UnexpectedToken("@b".into())),
+    );
+    assert_eq!(
+        "@a and or @b".parse::<Expression>(),
+        Err(ParseError::UnexpectedToken("or".into())),
+    );
+    assert_eq!(
+        "@a)".parse::<Expression>(),
+        Err(ParseError::UnexpectedToken(")".into())),
+    );
+}
+
+#[test]
+fn displays_parseable_expressions() {
+    for (input, displayed) in [
+        (
+            "@a or not priority <= 2 and @b",
+            "@a or not priority <= 2 and @b",
+        ),
+        (
+            "(@a or @b) and not (@c and @d)",
+            "(@a or @b) and not (@c and @d)",
+        ),
+        ("@a or (@b or @c)", "@a or (@b or @c)"),
+        ("((@a and @b))", "@a and @b"),
+        (
+            r"@with\ space\(\) and key = v\<1",
+            r"@with\ space\(\) and key == v\<1",
+        ),
+    ] {
+        let expr = input.parse::<Expression>().unwrap();
+
+        assert_eq!(expr.to_string(), displayed);
+        assert_eq!(displayed.parse::<Expression>(), Ok(expr));
+    }
+}
@@ -0,0 +1,160 @@
+mod common;
+
+use std::{cell::RefCell, collections::HashSet, rc::Rc, time::Duration};
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, tag::Inheritance, writer, Event, WorldInit,
+    Writer,
+};
+use structopt::StructOpt as _;
+use tokio::time;
+
+use self::common::{sink, World};
+
+thread_local! {
+    /// Keys of the steps failed once already.
+    static FAILED: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+}
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+#[given(regex = r"^(\w+) fails once$")]
+fn fails_once(_: &mut World, key: String) {
+    let first = FAILED.with(|f| f.borrow_mut().insert(key));
+    assert!(!first, "first attempt");
+}
+
+#[given("a slow step")]
+async fn slow_step(_: &mut World) {
+    time::sleep(Duration::from_millis(100)).await;
+}
+
+/// Runs the given `Feature`s with the provided [`Inheritance`] policy,
+/// returning the numbers of passed and failed `Scenario`s.
+async fn run(policy: Inheritance, input: &str) -> (usize, usize) {
+    FAILED.with(|f| f.borrow_mut().clear());
+
+    let writer = World::cucumber()
+        .tag_inheritance(policy)
+        .with_writer(sink())
+        .with_cli(cli::Opts::<_, _, _>::from_iter(["test"]))
+        .run(input)
+        .await;
+    (writer.scenarios.passed, writer.scenarios.failed)
+}
+
+/// Returns the number of `Scenario`s passed with the given tag expression and
+/// [`Inheritance`] policy.
+async fn passed_with(policy: Inheritance, tags: &str) -> usize {
+    World::cucumber()
+        .tag_inheritance(policy)
+        .with_writer(sink())
+        .with_cli(cli::Opts::<_, _, _>::from_iter(["test"]))
+        .filter_tags(tags.parse().unwrap())
+        .run("tests/features/tag_expression_inheritance")
+        .await
+        .scenarios
+        .passed
+}
+
+#[tokio::test]
+async fn filters_by_inherited_tags() {
+    assert_eq!(passed_with(Inheritance::Inherit, "@api").await, 4);
+    assert_eq!(passed_with(Inheritance::Inherit, "@smoke").await, 2);
+    assert_eq!(passed_with(Inheritance::Inherit, "@wip").await, 2);
+}
+
+#[tokio::test]
+async fn filters_by_overriding_tags() {
+    assert_eq!(passed_with(Inheritance::Override, "@api").await, 1);
+    assert_eq!(passed_with(Inheritance::Override, "@smoke").await, 1);
+    assert_eq!(passed_with(Inheritance::Override, "@wip").await, 2);
+}
+
+#[tokio::test]
+async fn retries_by_inherited_tags() {
+    let retries = "tests/features/tag_inheritance/retries";
+
+    assert_eq!(run(Inheritance::Inherit, retries).await, (2, 0));
+}
+
+#[tokio::test]
+async fn retries_by_overriding_tags() {
+    let retries = "tests/features/tag_inheritance/retries";
+
+    assert_eq!(run(Inheritance::Override, retries).await, (1, 1));
+}
+
+#[tokio::test]
+async fn times_out_by_inherited_tags() {
+    let timeouts = "tests/features/tag_inheritance/timeouts";
+
+    assert_eq!(run(Inheritance::Inherit, timeouts).await, (0, 2));
+}
+
+#[tokio::test]
+async fn times_out_by_overriding_tags() {
+    let timeouts = "tests/features/tag_inheritance/timeouts";
+
+    assert_eq!(run(Inheritance::Override, timeouts).await, (1, 1));
+}
+
+/// Records names of the started `Scenario`s.
+#[derive(Clone, Default)]
+struct Scenarios(Rc<RefCell<Vec<String>>>);
+
+#[async_trait(?Send)]
+impl Writer<World> for Scenarios {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule, Scenario};
+
+        if let Ok(Cucumber::Feature(
+            _,
+            Feature::Scenario(sc, Scenario::Started)
+            | Feature::Rule(_, Rule::Scenario(sc, Scenario::Started)),
+        )) = ev.map(Event::into_inner)
+        {
+            self.0.borrow_mut().push(sc.name.clone());
+        }
+    }
+}
+
+impl writer::Normalized for Scenarios {}
+
+/// Routes `Scenario`s tagged with `@api` with the given [`Inheritance`]
+/// policy, returning the names of the routed ones.
+async fn routed_with(policy: Inheritance) -> Vec<String> {
+    let (api, other) = (Scenarios::default(), Scenarios::default());
+
+    let _ = World::cucumber()
+        .with_writer(writer::Route::new(other).route("api", api.clone()))
+        .tag_inheritance(policy)
+        .with_cli(cli::Opts::<_, _, _>::from_iter(["test"]))
+        .run("tests/features/tag_expression_inheritance")
+        .await;
+
+    let mut routed = api.0.borrow().clone();
+    routed.sort();
+    routed
+}
+
+#[tokio::test]
+async fn routes_by_inherited_tags() {
+    assert_eq!(
+        routed_with(Inheritance::Inherit).await,
+        ["checked", "checked but unfinished", "plain", "unfinished"],
+    );
+}
+
+#[tokio::test]
+async fn routes_by_overriding_tags() {
+    assert_eq!(routed_with(Inheritance::Override).await, ["plain"]);
+}
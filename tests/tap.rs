@@ -0,0 +1,60 @@
+mod common;
+
+use std::fs;
+
+use cucumber::{given, when, writer, WorldInit, WriterExt as _};
+use tempfile::NamedTempFile;
+
+use self::common::World;
+
+#[given("a passing step")]
+fn passing(_: &mut World) {}
+
+#[when(regex = r#"^a step failing with "(\S+)" message$"#)]
+fn failing(_: &mut World, msg: String) {
+    panic!("\"{}\"\nsecond line", msg);
+}
+
+#[tokio::test]
+async fn outputs_test_point_per_scenario() {
+    let file = NamedTempFile::new().unwrap();
+    let _ = World::cucumber()
+        .with_writer(writer::Tap::new(file.reopen().unwrap()))
+        .run("tests/features/tap")
+        .await;
+
+    let output = fs::read_to_string(file.path()).unwrap();
+    let path = fs::canonicalize("tests/features/tap/outcome.feature").unwrap();
+    assert_eq!(
+        output,
+        format!(
+            "TAP version 13\n\
+             1..3\n\
+             ok 1 - passing\n\
+             not ok 2 - failing \\#1\n  \
+               ---\n  \
+               step: \"When a step failing with \\\"quoted\\\" message\"\n  \
+               at: \"{}:8:5\"\n  \
+               message: \"Step panicked. Captured output: \
+                          \\\"quoted\\\"\\nsecond line\"\n  \
+               ...\n\
+             ok 3 - skipped # SKIP no matching step for: \
+                    Given an undefined step\n",
+            path.display(),
+        ),
+    );
+}
+
+#[tokio::test]
+async fn outputs_summary_as_comments() {
+    let file = NamedTempFile::new().unwrap();
+    let _ = World::cucumber()
+        .with_writer(writer::Tap::new(file.reopen().unwrap()).summarized())
+        .run("tests/features/tap")
+        .await;
+
+    let output = fs::read_to_string(file.path()).unwrap();
+    let summary = output.split_once("Given an undefined step\n").unwrap().1;
+    assert!(summary.starts_with("# [Summary]\n"), "{}", summary);
+    assert!(summary.lines().all(|l| l == "#" || l.starts_with("# ")));
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use std::fs;
+
+use cucumber::{given, when, writer, WorldInit, WriterExt as _};
+use tempfile::NamedTempFile;
+
+use self::common::World;
+
+#[given("a passing step")]
+fn passing(_: &mut World) {}
+
+#[when(regex = r#"^a step failing with "(\S+)" message$"#)]
+fn failing(_: &mut World, msg: String) {
+    panic!("[{}]\nsecond line", msg);
+}
+
+#[tokio::test]
+async fn outputs_test_per_scenario() {
+    let file = NamedTempFile::new().unwrap();
+    let _ = World::cucumber()
+        .with_writer(writer::TeamCity::new(file.reopen().unwrap()))
+        .run("tests/features/teamcity")
+        .await;
+
+    assert_eq!(
+        fs::read_to_string(file.path()).unwrap(),
+        "##teamcity[testCount count='3']\n\
+         ##teamcity[testSuiteStarted name='Outcome |[TeamCity|]']\n\
+         ##teamcity[testSuiteStarted name='rule']\n\
+         ##teamcity[testStarted name='passing']\n\
+         ##teamcity[testStdOut name='passing' \
+                    out='Given a passing step: passed|n']\n\
+         ##teamcity[testFinished name='passing']\n\
+         ##teamcity[testStarted name='failing |'quoted|' ||piped||']\n\
+         ##teamcity[testStdOut name='failing |'quoted|' ||piped||' \
+                    out='Given a passing step: passed|n']\n\
+         ##teamcity[testStdOut name='failing |'quoted|' ||piped||' \
+                    out='When a step failing with \"quoted\" message: \
+                         failed|n']\n\
+         ##teamcity[testFailed name='failing |'quoted|' ||piped||' \
+                    message='Step failed: \
+                             When a step failing with \"quoted\" message' \
+                    details='Step panicked. Captured output: \
+                             |[quoted|]|nsecond line']\n\
+         ##teamcity[testFinished name='failing |'quoted|' ||piped||']\n\
+         ##teamcity[testStarted name='skipped']\n\
+         ##teamcity[testStdOut name='skipped' \
+                    out='Given an undefined step: undefined|n']\n\
+         ##teamcity[testIgnored name='skipped' \
+                    message='no matching step for: Given an undefined step']\n\
+         ##teamcity[testFinished name='skipped']\n\
+         ##teamcity[testSuiteFinished name='rule']\n\
+         ##teamcity[testSuiteFinished name='Outcome |[TeamCity|]']\n",
+    );
+}
+
+#[tokio::test]
+async fn outputs_summary_as_is() {
+    let file = NamedTempFile::new().unwrap();
+    let _ = World::cucumber()
+        .with_writer(writer::TeamCity::new(file.reopen().unwrap()).summarized())
+        .run("tests/features/teamcity")
+        .await;
+
+    let output = fs::read_to_string(file.path()).unwrap();
+    let summary = output
+        .split_once(
+            "##teamcity[testSuiteFinished name='Outcome |[TeamCity|]']\n",
+        )
+        .unwrap()
+        .1;
+
+    assert!(summary.contains("3 scenarios (1 passed, 1 skipped, 1 failed)"));
+    assert!(!summary.contains("##teamcity"));
+}
@@ -0,0 +1,147 @@
+mod common;
+
+use std::{
+    cell::Cell,
+    io,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use cucumber::{given, when, WorldInit, WorldTeardown};
+use futures::FutureExt as _;
+use tokio::time;
+
+use self::common::sink;
+
+#[derive(Debug, WorldInit)]
+struct World {
+    leaking: bool,
+    crashing: bool,
+    released: Option<&'static AtomicUsize>,
+}
+
+#[async_trait(?Send)]
+impl cucumber::World for World {
+    type Error = io::Error;
+
+    async fn new() -> Result<Self, Self::Error> {
+        Ok(Self {
+            leaking: false,
+            crashing: false,
+            released: None,
+        })
+    }
+}
+
+#[async_trait(?Send)]
+impl WorldTeardown for World {
+    async fn drop(self) -> Result<(), Self::Error> {
+        TORN_DOWN.with(|t| t.set(t.get() + 1));
+        assert!(!self.crashing, "resource crashed");
+        if let Some(released) = self.released {
+            time::sleep(Duration::from_millis(10)).await;
+            let _ = released.fetch_add(1, Ordering::SeqCst);
+        }
+        if self.leaking {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "resource is not released",
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[given("a resource")]
+fn resource(_: &mut World) {}
+
+#[given("a leaking resource")]
+fn leaking_resource(world: &mut World) {
+    world.leaking = true;
+}
+
+#[given("a crashing resource")]
+fn crashing_resource(world: &mut World) {
+    world.crashing = true;
+}
+
+#[given("a resource released on panic")]
+fn resource_released_on_panic(world: &mut World) {
+    world.released = Some(&RELEASED_ON_PANIC);
+}
+
+#[when("it panics")]
+fn panics(_: &mut World) {
+    panic!("resource user panicked");
+}
+
+#[given("an asynchronously released resource")]
+fn asynchronously_released_resource(world: &mut World) {
+    world.released = Some(&RELEASED_ASYNCHRONOUSLY);
+}
+
+thread_local! {
+    /// Number of `World`s torn down on the current thread.
+    static TORN_DOWN: Cell<usize> = Cell::new(0);
+}
+
+static RELEASED_ON_PANIC: AtomicUsize = AtomicUsize::new(0);
+
+static RELEASED_ASYNCHRONOUSLY: AtomicUsize = AtomicUsize::new(0);
+
+#[tokio::test]
+async fn failed_teardown_fails_scenario() {
+    let writer = World::cucumber()
+        .teardown()
+        .with_writer(sink())
+        .run("tests/features/teardown")
+        .await;
+
+    assert_eq!(writer.scenarios.passed, 1);
+    assert_eq!(writer.scenarios.failed, 2);
+    assert_eq!(writer.failed_hooks, 2);
+}
+
+#[tokio::test]
+async fn every_scenario_is_torn_down() {
+    let writer = World::cucumber()
+        .teardown()
+        .with_writer(sink())
+        .run("tests/features/teardown")
+        .await;
+
+    assert_eq!(TORN_DOWN.with(Cell::get), writer.scenarios.total());
+}
+
+#[tokio::test]
+async fn panicked_step_is_torn_down() {
+    let writer = World::cucumber()
+        .teardown()
+        .with_writer(sink())
+        .run("tests/features/teardown_failed/panicked.feature")
+        .await;
+
+    assert_eq!(writer.scenarios.failed, 1);
+    assert_eq!(writer.failed_hooks, 0);
+    assert_eq!(RELEASED_ON_PANIC.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn failed_scenarios_are_torn_down_asynchronously() {
+    let writer = World::cucumber()
+        .after(|_, _, sc, _, _| {
+            async move {
+                assert!(!sc.tags.iter().any(|t| t == "fail_after"), "failed");
+            }
+            .boxed_local()
+        })
+        .teardown()
+        .with_writer(sink())
+        .run("tests/features/teardown_failed/failed.feature")
+        .await;
+
+    assert_eq!(writer.scenarios.failed, 2);
+    assert_eq!(writer.failed_hooks, 1);
+    assert_eq!(RELEASED_ASYNCHRONOUSLY.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use std::{cell::RefCell, io, rc::Rc};
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, parser,
+    writer::{self, Arbitrary as _},
+    Event, Writer, WriterExt as _,
+};
+
+use self::common::World;
+
+/// [`Writer`] recording its name into a shared log, optionally yielding to
+/// the executor before doing so.
+struct Recorder {
+    name: &'static str,
+    log: Rc<RefCell<Vec<&'static str>>>,
+    yields: bool,
+}
+
+impl Recorder {
+    async fn record(&self) {
+        if self.yields {
+            tokio::task::yield_now().await;
+        }
+        self.log.borrow_mut().push(self.name);
+    }
+}
+
+#[async_trait(?Send)]
+impl Writer<World> for Recorder {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        _: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        self.record().await;
+    }
+}
+
+#[async_trait(?Send)]
+impl<'val> writer::Arbitrary<'val, World, &'static str> for Recorder {
+    async fn write(&mut self, _: &'static str)
+    where
+        'val: 'async_trait,
+    {
+        self.record().await;
+    }
+}
+
+#[tokio::test]
+async fn passes_events_to_left_writer_first() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let left = Recorder {
+        name: "left",
+        log: Rc::clone(&log),
+        yields: true,
+    };
+    let right = Recorder {
+        name: "right",
+        log: Rc::clone(&log),
+        yields: false,
+    };
+    let mut tee = writer::Tee::new(left, right);
+    let cli = cli::Compose {
+        left: cli::Empty::default(),
+        right: cli::Empty::default(),
+    };
+
+    for ev in [event::Cucumber::Started, event::Cucumber::Finished] {
+        tee.handle_event(Ok(Event::new(ev)), &cli).await;
+    }
+    tee.write("summary").await;
+
+    assert_eq!(
+        *log.borrow(),
+        ["left", "right", "left", "right", "left", "right"],
+    );
+}
+
+#[tokio::test]
+async fn fails_when_either_writer_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("missing").join("report.txt");
+
+    let mut tee = writer::Tee::new(
+        writer::Basic::new(io::sink(), writer::Coloring::Never, false)
+            .summarized(),
+        writer::ToFile::new(path, |file| {
+            writer::Basic::new(file, writer::Coloring::Never, false)
+                .summarized()
+        }),
+    );
+    let basic = writer::basic::Cli {
+        verbose: false,
+        color: writer::Coloring::Never,
+        snippets: false,
+        #[cfg(feature = "timestamps")]
+        show_timings: false,
+    };
+    let cli = cli::Compose {
+        left: basic,
+        right: basic,
+    };
+
+    for ev in [event::Cucumber::Started, event::Cucumber::Finished] {
+        Writer::<World>::handle_event(&mut tee, Ok(Event::new(ev)), &cli).await;
+    }
+
+    assert_eq!(writer::Failure::<World>::failed_steps(&tee), 0);
+    assert!(writer::Failure::<World>::execution_has_failed(&tee));
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use std::path::Path;
+
+use cucumber::{given, parser, Parser as _, WorldInit};
+use futures::StreamExt as _;
+
+use self::common::{sink, World};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+const FEATURE: &str = "\
+Feature: In-memory
+
+  Scenario Outline: outline
+    Given a step
+
+    Examples:
+      | n |
+      | 1 |
+      | 2 |
+";
+
+#[tokio::test]
+async fn parses_feature_with_synthetic_path() {
+    let features = parser::Text::new()
+        .parse(FEATURE, cucumber::cli::Empty::default())
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(features.len(), 1);
+    let feature = features[0].as_ref().unwrap();
+    assert_eq!(feature.name, "In-memory");
+    assert_eq!(feature.path.as_deref(), Some(Path::new("<string>")));
+    assert_eq!(feature.scenarios.len(), 2);
+}
+
+#[tokio::test]
+async fn runs_feature_from_text() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::Text::new().path("in_memory.feature"))
+        .with_writer(sink())
+        .run(FEATURE)
+        .await;
+
+    assert_eq!(writer.features, 1);
+    assert_eq!(writer.scenarios.passed, 2);
+    assert_eq!(writer.parsing_errors, 0);
+}
+
+#[tokio::test]
+async fn reports_parsing_errors() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::Text::new())
+        .with_writer(sink())
+        .run("Feature: Broken\n  Scenario:\n    Given\n  Unknown: 1")
+        .await;
+
+    assert_eq!(writer.features, 0);
+    assert_eq!(writer.parsing_errors, 1);
+}
+
+#[tokio::test]
+async fn runs_feature_via_cucumber_text() {
+    let writer = World::cucumber_text()
+        .with_writer(sink())
+        .run(FEATURE.to_owned())
+        .await;
+
+    assert_eq!(writer.features, 1);
+    assert_eq!(writer.scenarios.passed, 2);
+    assert_eq!(writer.parsing_errors, 0);
+}
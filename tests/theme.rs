@@ -0,0 +1,79 @@
+mod common;
+
+use std::fs;
+
+use cucumber::{
+    given, when,
+    writer::{
+        self,
+        out::{Color, Theme},
+        Coloring,
+    },
+    WorldInit, WriterExt as _,
+};
+use tempfile::NamedTempFile;
+
+use self::common::World;
+
+#[given("a background step")]
+fn background_step(_: &mut World) {}
+
+#[when(regex = r"^she eats (\d+) cucumbers$")]
+fn eats(_: &mut World, _count: usize) {}
+
+async fn run(theme: Option<Theme>, color: Coloring) -> String {
+    let file = NamedTempFile::new().unwrap();
+    let mut basic = writer::Basic::raw(file.reopen().unwrap(), color, false);
+    if let Some(theme) = theme {
+        basic = basic.with_theme(theme);
+    }
+    let _ = World::cucumber()
+        .with_writer(basic.normalized())
+        .run("tests/features/theme")
+        .await;
+    fs::read_to_string(file.path()).unwrap()
+}
+
+#[tokio::test]
+async fn remaps_colors() {
+    let output = run(
+        Some(Theme {
+            passed: Color::Ansi256(40),
+            failed: Color::Ansi256(160),
+            skipped: Color::Ansi256(244),
+            background: Color::Rgb(0, 0, 255),
+            bold: false,
+        }),
+        Coloring::Always,
+    )
+    .await;
+
+    assert!(output.contains("\u{1b}[38;5;40m"), "{}", output);
+    assert!(output.contains("\u{1b}[38;5;244m"), "{}", output);
+    assert!(output.contains("\u{1b}[38;5;21m"), "{}", output);
+    assert!(!output.contains("\u{1b}[1m"), "{}", output);
+    assert!(!output.contains("\u{1b}[32m"), "{}", output);
+}
+
+#[tokio::test]
+async fn colors_by_default_when_forced() {
+    let output = run(None, Coloring::Always).await;
+
+    assert!(output.contains("\u{1b}[32m"), "{}", output);
+    assert!(output.contains("\u{1b}[36m"), "{}", output);
+    assert!(output.contains("\u{1b}[1m"), "{}", output);
+}
+
+#[tokio::test]
+async fn never_colors_when_disabled() {
+    let output = run(
+        Some(Theme {
+            passed: Color::Ansi256(40),
+            ..Theme::default()
+        }),
+        Coloring::Never,
+    )
+    .await;
+
+    assert!(!output.contains('\u{1b}'), "{}", output);
+}
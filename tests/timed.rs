@@ -0,0 +1,76 @@
+mod common;
+
+use std::{
+    cell::Cell,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use cucumber::{given, when, writer, WorldInit, WriterExt as _};
+
+use self::common::{Discard, World};
+
+#[given("background")]
+#[given("step")]
+#[when("step")]
+fn step(_: &mut World) {}
+
+#[tokio::test]
+async fn records_durations_with_injected_clock() {
+    // Every reading advances the clock by 1 ms, so durations are equal to the
+    // number of timed events passed since the matching `Started` one.
+    let base = Instant::now();
+    let ticks = Rc::new(Cell::new(0));
+    let clock = move || {
+        ticks.set(ticks.get() + 1);
+        base + Duration::from_millis(ticks.get())
+    };
+
+    let timed = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .with_writer(writer::Timed::with_clock(Discard.normalized(), clock))
+        .run("tests/features/timed")
+        .await;
+    let timings = timed.timings();
+
+    let ms = Duration::from_millis;
+    assert_eq!(timings.total(), Some(ms(15)));
+
+    let scenarios = timings
+        .iter()
+        .map(|sc| {
+            let steps = sc
+                .steps
+                .iter()
+                .map(|st| (st.step.value.as_str(), st.background, st.duration))
+                .collect::<Vec<_>>();
+            (
+                sc.scenario.name.as_str(),
+                sc.rule.as_ref().map(|r| r.name.as_str()),
+                sc.duration,
+                steps,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        scenarios,
+        [
+            (
+                "first",
+                None,
+                ms(7),
+                vec![
+                    ("background", true, ms(1)),
+                    ("step", false, ms(1)),
+                    ("step", false, ms(1)),
+                ],
+            ),
+            (
+                "second",
+                Some("rule"),
+                ms(5),
+                vec![("background", true, ms(1)), ("step", false, ms(1))],
+            ),
+        ],
+    );
+}
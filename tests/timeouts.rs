@@ -0,0 +1,155 @@
+mod common;
+
+use std::{cell::RefCell, time::Duration};
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, then, when, Event, WorldInit, Writer,
+    WriterExt as _,
+};
+use tokio::time;
+
+use self::common::World;
+
+thread_local! {
+    /// Durations of all the completed (not cancelled) steps.
+    static COMPLETED: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+}
+
+#[given(regex = r"(\d+) ms step")]
+#[when(regex = r"(\d+) ms step")]
+#[then(regex = r"(\d+) ms step")]
+async fn step(_: &mut World, ms: u64) {
+    time::sleep(Duration::from_millis(ms)).await;
+    COMPLETED.with(|c| c.borrow_mut().push(ms));
+}
+
+/// Records `(scenario, step, outcome)` of every finished `Step`.
+#[derive(Default)]
+struct Outcomes(Vec<(String, String, String)>);
+
+#[async_trait(?Send)]
+impl Writer<World> for Outcomes {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule, Scenario, Step, StepError};
+
+        let (sc, st, ev) = match ev.map(Event::into_inner) {
+            Ok(Cucumber::Feature(
+                _,
+                Feature::Rule(_, Rule::Scenario(sc, Scenario::Step(st, ev)))
+                | Feature::Scenario(sc, Scenario::Step(st, ev)),
+            )) => (sc, st, ev),
+            _ => return,
+        };
+        let outcome = match ev {
+            Step::Passed(_) => "passed".into(),
+            Step::Failed(_, _, StepError::TimedOut(t)) => {
+                format!("timed out after {}ms", t.as_millis())
+            }
+            Step::Failed(..) => "failed".into(),
+            _ => return,
+        };
+        self.0.push((sc.name.clone(), st.value.clone(), outcome));
+    }
+}
+
+impl Outcomes {
+    fn of(&self, scenario: &str) -> Vec<(&str, &str)> {
+        self.0
+            .iter()
+            .filter(|(sc, ..)| sc == scenario)
+            .map(|(_, st, outcome)| (st.as_str(), outcome.as_str()))
+            .collect()
+    }
+}
+
+#[tokio::test]
+async fn step_timeout_cancels_step() {
+    COMPLETED.with(|c| c.borrow_mut().clear());
+
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .step_timeout(Duration::from_millis(200))
+        .with_writer(Outcomes::default().normalized())
+        .run("tests/features/timeouts")
+        .await;
+
+    assert_eq!(writer.of("fast"), [("10 ms step", "passed")]);
+    assert_eq!(
+        writer.of("hanging"),
+        [
+            ("10 ms step", "passed"),
+            ("1000 ms step", "timed out after 200ms"),
+        ],
+    );
+    assert_eq!(
+        writer.of("slow"),
+        [
+            ("80 ms step", "passed"),
+            ("80 ms step", "passed"),
+            ("80 ms step", "passed"),
+        ],
+    );
+    assert!(COMPLETED.with(|c| !c.borrow().contains(&1000)));
+}
+
+#[tokio::test]
+async fn scenario_timeout_covers_all_steps() {
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .scenario_timeout(Duration::from_millis(200))
+        .with_writer(Outcomes::default().normalized())
+        .run("tests/features/timeouts")
+        .await;
+
+    assert_eq!(writer.of("fast"), [("10 ms step", "passed")]);
+    assert_eq!(
+        writer.of("hanging"),
+        [
+            ("10 ms step", "passed"),
+            ("1000 ms step", "timed out after 200ms"),
+        ],
+    );
+    assert_eq!(
+        writer.of("slow"),
+        [
+            ("80 ms step", "passed"),
+            ("80 ms step", "passed"),
+            ("80 ms step", "timed out after 200ms"),
+        ],
+    );
+}
+
+#[tokio::test]
+async fn timeout_tag_overrides_step_timeout() {
+    let writer = World::cucumber()
+        .step_timeout(Duration::from_millis(20))
+        .with_writer(Outcomes::default().normalized())
+        .run("tests/features/timeouts_tag")
+        .await;
+
+    assert_eq!(
+        writer.of("inherited"),
+        [("80 ms step", "timed out after 50ms")],
+    );
+    assert_eq!(
+        writer.of("overridden"),
+        [
+            ("80 ms step", "passed"),
+            ("300 ms step", "timed out after 150ms"),
+        ],
+    );
+    assert_eq!(
+        writer.of("in rule"),
+        [
+            ("10 ms step", "passed"),
+            ("80 ms step", "timed out after 50ms"),
+        ],
+    );
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use std::{fs, io};
+
+use cucumber::{given, then, when, writer, WorldInit, WriterExt as _};
+
+use self::common::World;
+
+#[given(regex = ".*")]
+#[when(regex = ".*")]
+#[then(regex = ".*")]
+fn step(_: &mut World) {}
+
+#[tokio::test]
+async fn writes_output_into_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("report.txt");
+
+    let writer = World::cucumber()
+        .with_writer(writer::ToFile::new(&path, |file| {
+            writer::Basic::new(file, writer::Coloring::Never, false)
+                .summarized()
+        }))
+        .run("tests/features/readme")
+        .await;
+
+    assert!(writer.error().is_none());
+    assert!(!writer::Failure::<World>::execution_has_failed(&writer));
+
+    let output = fs::read_to_string(&path).unwrap();
+    assert!(output.contains("Feature: Eating too much cucumbers"));
+    assert!(output.contains("[Summary]"));
+}
+
+#[tokio::test]
+async fn keeps_error_without_panicking() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("missing").join("report.txt");
+
+    let writer = World::cucumber()
+        .with_writer(writer::ToFile::new(&path, |file| {
+            writer::Basic::new(file, writer::Coloring::Never, false)
+                .summarized()
+        }))
+        .run("tests/features/readme")
+        .await;
+
+    assert_eq!(writer.error().unwrap().kind(), io::ErrorKind::NotFound);
+    assert!(writer::Failure::<World>::execution_has_failed(&writer));
+    assert!(!path.exists());
+}
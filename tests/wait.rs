@@ -30,7 +30,7 @@ async fn main() {
             }
             .boxed_local()
         })
-        .after(move |_, _, _, _| time::sleep(cli.custom.pause).boxed_local())
+        .after(move |_, _, _, _, _| time::sleep(cli.custom.pause).boxed_local())
         .with_cli(cli)
         .run_and_exit("tests/features/wait");
 
@@ -0,0 +1,61 @@
+mod common;
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use cucumber::{given, WorldInit};
+
+use self::common::{sink, World};
+
+static CACHE_WARMED: AtomicUsize = AtomicUsize::new(0);
+static COUNTED: AtomicUsize = AtomicUsize::new(0);
+static NOT_RUN: AtomicBool = AtomicBool::new(true);
+
+#[given("a warmed up cache")]
+fn warmed_cache(_: &mut World) {
+    let _ = CACHE_WARMED.fetch_add(1, Ordering::SeqCst);
+}
+
+#[given("a counted step")]
+fn counted_step(_: &mut World) {
+    let _ = COUNTED.fetch_add(1, Ordering::SeqCst);
+}
+
+#[given("a broken environment")]
+fn broken_env(_: &mut World) {
+    panic!("environment is broken");
+}
+
+#[given("a step not run after a failed warm-up")]
+fn not_run_step(_: &mut World) {
+    NOT_RUN.store(false, Ordering::SeqCst);
+}
+
+#[tokio::test]
+async fn warmup_outcomes_are_excluded() {
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .warmup(|_, _, sc| sc.tags.iter().any(|t| t == "warmup"))
+        .run("tests/features/warmup")
+        .await;
+
+    assert_eq!(CACHE_WARMED.load(Ordering::SeqCst), 2);
+    assert_eq!(COUNTED.load(Ordering::SeqCst), 1);
+    assert_eq!(writer.features, 1);
+    assert_eq!(writer.scenarios.passed, 2);
+    assert_eq!(writer.scenarios.failed, 0);
+    assert_eq!(writer.steps.passed, 2);
+}
+
+#[tokio::test]
+async fn warmup_failure_aborts_run() {
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .warmup(|_, _, sc| sc.tags.iter().any(|t| t == "warmup"))
+        .run("tests/features/warmup_failed")
+        .await;
+
+    assert!(NOT_RUN.load(Ordering::SeqCst));
+    assert_eq!(writer.failed_hooks, 1);
+    assert_eq!(writer.scenarios.passed, 0);
+    assert_eq!(writer.scenarios.failed, 0);
+}
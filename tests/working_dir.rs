@@ -0,0 +1,79 @@
+mod common;
+
+use std::{env, fs, path::PathBuf, process, sync::Mutex};
+
+use cucumber::{given, step, when, WorldInit};
+use once_cell::sync::Lazy;
+
+use self::common::{sink, World};
+
+static DIRS: Lazy<Mutex<Vec<PathBuf>>> = Lazy::new(Mutex::default);
+
+#[given("an empty working directory")]
+fn empty_dir(_: &mut World, #[step] ctx: &step::Context) {
+    let dir = ctx.working_dir.clone().expect("no working directory");
+    assert!(dir.is_dir());
+    assert_eq!(dir.read_dir().unwrap().count(), 0);
+    DIRS.lock().unwrap().push(dir);
+}
+
+#[when("a file is created in it")]
+fn create_file(_: &mut World, #[step] ctx: &step::Context) {
+    let dir = ctx.working_dir.as_ref().expect("no working directory");
+    fs::write(dir.join("file.txt"), "content").unwrap();
+}
+
+#[tokio::test]
+async fn provides_each_scenario_with_temp_dir() {
+    let cwd = env::current_dir().unwrap();
+
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .temp_working_dir()
+        .run("tests/features/working_dir")
+        .await;
+
+    assert_eq!(writer.scenarios.passed, 2);
+    assert_eq!(env::current_dir().unwrap(), cwd);
+
+    let dirs = DIRS.lock().unwrap();
+    assert_eq!(dirs.len(), 2);
+    assert_ne!(dirs[0], dirs[1]);
+    assert!(
+        dirs.iter().all(|d| !d.exists()),
+        "not cleaned up: {:?}",
+        dirs
+    );
+}
+
+#[tokio::test]
+async fn fails_scenario_if_dir_cannot_be_created() {
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .working_dir(|_, _, _| PathBuf::from("Cargo.toml").join("nested"))
+        .run("tests/features/working_dir")
+        .await;
+
+    assert_eq!(writer.scenarios.failed, 2);
+    assert_eq!(writer.failed_hooks, 2);
+}
+
+#[tokio::test]
+async fn keeps_existing_dir() {
+    fn existing_dir() -> PathBuf {
+        env::temp_dir().join(format!("cucumber-existing-{}", process::id()))
+    }
+
+    fs::create_dir_all(existing_dir()).unwrap();
+
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .working_dir(|_, _, _| existing_dir())
+        .run("tests/features/working_dir_existing")
+        .await;
+
+    assert_eq!(writer.scenarios.passed, 1);
+    assert!(existing_dir().join("file.txt").exists(), "dir is removed");
+
+    fs::remove_dir_all(existing_dir()).unwrap();
+}
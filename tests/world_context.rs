@@ -0,0 +1,69 @@
+mod common;
+
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use async_trait::async_trait;
+use cucumber::{given, WorldInit, WorldWithContext};
+
+use self::common::sink;
+
+#[derive(Debug, WorldInit)]
+struct World {
+    from_context: bool,
+}
+
+#[async_trait(?Send)]
+impl cucumber::World for World {
+    type Error = io::Error;
+
+    async fn new() -> Result<Self, Self::Error> {
+        Err(io::Error::new(io::ErrorKind::Other, "no context provided"))
+    }
+}
+
+#[async_trait(?Send)]
+impl WorldWithContext for World {
+    type Context = Arc<AtomicUsize>;
+
+    async fn new_with(ctx: Self::Context) -> Result<Self, Self::Error> {
+        let _ = ctx.fetch_add(1, Ordering::SeqCst);
+        Ok(Self { from_context: true })
+    }
+}
+
+#[given("a World constructed from the context")]
+fn constructed_from_context(world: &mut World) {
+    assert!(world.from_context);
+}
+
+#[tokio::test]
+async fn constructs_worlds_from_context() {
+    let created = Arc::new(AtomicUsize::new(0));
+
+    let writer = World::cucumber()
+        .with_context(Arc::clone(&created))
+        .with_writer(sink())
+        .run("tests/features/world_context")
+        .await;
+
+    assert_eq!(writer.scenarios.passed, 2);
+    assert_eq!(writer.scenarios.failed, 0);
+    assert_eq!(created.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn uses_world_new_without_context() {
+    let writer = World::cucumber()
+        .with_writer(sink())
+        .run("tests/features/world_context")
+        .await;
+
+    assert_eq!(writer.scenarios.passed, 0);
+    assert_eq!(writer.scenarios.failed, 2);
+}
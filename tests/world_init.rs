@@ -0,0 +1,79 @@
+use std::{
+    io,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, Event, WorldInit, Writer, WriterExt as _,
+};
+use itertools::Itertools as _;
+use tokio::time;
+
+static CONSTRUCTED: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, WorldInit)]
+struct World;
+
+#[async_trait(?Send)]
+impl cucumber::World for World {
+    type Error = io::Error;
+
+    async fn new() -> Result<Self, Self::Error> {
+        // Later constructions fail sooner, reversing the order of failures.
+        let n = CONSTRUCTED.fetch_add(1, Ordering::SeqCst);
+        time::sleep(Duration::from_millis(50 * 6_u64.saturating_sub(n))).await;
+        Err(io::Error::new(io::ErrorKind::Other, "no world"))
+    }
+}
+
+#[given("a world")]
+fn world(_: &mut World) {}
+
+/// Records `(feature, scenario)` names of failed `World` constructions.
+#[derive(Default)]
+struct InitFailures(Vec<(String, String)>);
+
+#[async_trait(?Send)]
+impl Writer<World> for InitFailures {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Scenario, Step};
+
+        if let Ok(Cucumber::Feature(
+            feat,
+            Feature::Scenario(sc, Scenario::Step(_, Step::Failed(_, _, e))),
+        )) = ev.map(Event::into_inner)
+        {
+            assert!(e.to_string().contains("failed to initialize World"));
+            self.0.push((feat.name.clone(), sc.name.clone()));
+        }
+    }
+}
+
+#[tokio::test]
+async fn world_init_failures_are_normalized() {
+    let writer = World::cucumber()
+        .with_writer(InitFailures::default().normalized())
+        .run("tests/features/world_init")
+        .await;
+    let failures = &writer.0;
+
+    assert_eq!(failures.len(), 6);
+    assert_eq!(
+        failures.iter().map(|(feat, _)| feat).dedup().count(),
+        2,
+        "failures of different features are interleaved: {:?}",
+        failures,
+    );
+    for (_, scenarios) in &failures.iter().group_by(|(feat, _)| feat) {
+        let scenarios = scenarios.map(|(_, sc)| sc.as_str()).collect_vec();
+        assert_eq!(scenarios, ["1", "2", "3"]);
+    }
+}